@@ -1,13 +1,14 @@
-use std::{env, io::{Read, Write}, path::Path, sync::Arc};
+use std::{env, fmt::{self, Display}, io::{Read, Write}, path::Path, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use ratatui::layout::Rect;
 use tracing::warn;
-use yazi_shared::{env_exists, term::Term};
+use yazi_config::PREVIEW;
+use yazi_shared::{env_exists, in_path, term::Term};
 
-use super::{Iterm2, Kitty, KittyOld};
-use crate::{ueberzug::Ueberzug, Sixel, SHOWN, TMUX};
+use super::{Chafa, Iterm2, Kitty, KittyOld};
+use crate::{ueberzug::Ueberzug, Sixel, Tmux, SHOWN, TMUX};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Adaptor {
@@ -15,11 +16,11 @@ pub enum Adaptor {
 	KittyOld,
 	Iterm2,
 	Sixel,
+	Chafa,
 
 	// Supported by Überzug++
 	X11,
 	Wayland,
-	Chafa,
 }
 
 #[derive(Clone)]
@@ -83,6 +84,10 @@ impl Adaptor {
 	}
 
 	pub(super) fn detect() -> Self {
+		if let Some(adaptor) = Self::via_config() {
+			return adaptor;
+		}
+
 		let mut protocols = match Self::emulator() {
 			Emulator::Unknown(adapters) => adapters,
 			Emulator::Kitty => vec![Self::Kitty],
@@ -104,23 +109,30 @@ impl Adaptor {
 		if env_exists("ZELLIJ_SESSION_NAME") {
 			protocols.retain(|p| *p == Self::Sixel);
 		}
-		if *TMUX && protocols.len() > 1 {
-			protocols.retain(|p| *p != Self::KittyOld);
+		if *TMUX {
+			Tmux::negotiate();
+			if protocols.len() > 1 {
+				protocols.retain(|p| *p != Self::KittyOld);
+			}
 		}
 		if let Some(p) = protocols.first() {
 			return *p;
 		}
 
-		match env::var("XDG_SESSION_TYPE").unwrap_or_default().as_str() {
-			"x11" => return Self::X11,
-			"wayland" => return Self::Wayland,
-			_ => warn!("[Adaptor] Could not identify XDG_SESSION_TYPE"),
-		}
-		if env_exists("WAYLAND_DISPLAY") {
-			return Self::Wayland;
-		}
-		if env_exists("DISPLAY") {
-			return Self::X11;
+		if in_path("ueberzug") {
+			match env::var("XDG_SESSION_TYPE").unwrap_or_default().as_str() {
+				"x11" => return Self::X11,
+				"wayland" => return Self::Wayland,
+				_ => warn!("[Adaptor] Could not identify XDG_SESSION_TYPE"),
+			}
+			if env_exists("WAYLAND_DISPLAY") {
+				return Self::Wayland;
+			}
+			if env_exists("DISPLAY") {
+				return Self::X11;
+			}
+		} else {
+			warn!("[Adaptor] Überzug++ is not installed");
 		}
 		if std::fs::symlink_metadata("/proc/sys/fs/binfmt_misc/WSLInterop").is_ok() {
 			return Self::KittyOld;
@@ -130,6 +142,34 @@ impl Adaptor {
 		Self::Chafa
 	}
 
+	fn via_config() -> Option<Self> {
+		if PREVIEW.backend.is_empty() {
+			return None;
+		}
+
+		let (term, program) = Self::via_env();
+		let name = PREVIEW.backend.get(&program).or_else(|| PREVIEW.backend.get(&term))?;
+		let adaptor = match name.as_str() {
+			"kitty" => Self::Kitty,
+			"kitty-old" => Self::KittyOld,
+			"iterm2" => Self::Iterm2,
+			"sixel" => Self::Sixel,
+			"x11" => Self::X11,
+			"wayland" => Self::Wayland,
+			"chafa" => Self::Chafa,
+			_ => {
+				warn!("[Adaptor] Unknown backend in the config: {name}");
+				return None;
+			}
+		};
+
+		let bin = if adaptor.needs_ueberzug() { "ueberzug" } else { "chafa" };
+		if matches!(adaptor, Self::X11 | Self::Wayland | Self::Chafa) && !in_path(bin) {
+			warn!("[Adaptor] `{bin}` is required by the {name} backend, but it's not installed");
+		}
+		Some(adaptor)
+	}
+
 	fn via_env() -> (String, String) {
 		fn tmux_env(name: &str) -> Result<String> {
 			let output = std::process::Command::new("tmux").args(["show-environment", name]).output()?;
@@ -197,9 +237,9 @@ impl Adaptor {
 	}
 }
 
-impl ToString for Adaptor {
-	fn to_string(&self) -> String {
-		match self {
+impl Display for Adaptor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
 			Self::Kitty => "kitty",
 			Self::KittyOld => "kitty",
 			Self::Iterm2 => "iterm2",
@@ -207,8 +247,7 @@ impl ToString for Adaptor {
 			Self::X11 => "x11",
 			Self::Wayland => "wayland",
 			Self::Chafa => "chafa",
-		}
-		.to_string()
+		})
 	}
 }
 
@@ -221,6 +260,7 @@ impl Adaptor {
 			Self::KittyOld => KittyOld::image_show(path, rect).await,
 			Self::Iterm2 => Iterm2::image_show(path, rect).await,
 			Self::Sixel => Sixel::image_show(path, rect).await,
+			Self::Chafa => Chafa::image_show(path, rect).await,
 			_ => Ueberzug::image_show(path, rect).await,
 		}
 	}
@@ -233,8 +273,9 @@ impl Adaptor {
 		match self {
 			Self::Kitty => Kitty::image_erase(rect),
 			Self::Iterm2 => Iterm2::image_erase(rect),
-			Self::KittyOld => KittyOld::image_erase(),
+			Self::KittyOld => KittyOld::image_erase(1),
 			Self::Sixel => Sixel::image_erase(rect),
+			Self::Chafa => Chafa::image_erase(rect),
 			_ => Ueberzug::image_erase(rect),
 		}
 	}
@@ -257,7 +298,5 @@ impl Adaptor {
 	}
 
 	#[inline]
-	pub(super) fn needs_ueberzug(self) -> bool {
-		!matches!(self, Self::Kitty | Self::KittyOld | Self::Iterm2 | Self::Sixel)
-	}
+	pub(super) fn needs_ueberzug(self) -> bool { matches!(self, Self::X11 | Self::Wayland) }
 }
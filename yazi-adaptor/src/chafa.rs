@@ -0,0 +1,58 @@
+use std::{io::{stdout, BufWriter, Write}, path::Path, process::Stdio};
+
+use anyhow::{bail, Result};
+use ratatui::layout::Rect;
+use tokio::process::Command;
+use yazi_shared::term::Term;
+
+use crate::Adaptor;
+
+/// Draws the image as colored Unicode blocks, which works on any terminal.
+pub(super) struct Chafa;
+
+impl Chafa {
+	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<(u32, u32)> {
+		let output = Command::new("chafa")
+			.args(["-f", "symbols", "--polite", "on", "--animate", "off", "-s"])
+			.arg(format!("{}x{}", rect.width, rect.height))
+			.arg(path)
+			.stdin(Stdio::null())
+			.stderr(Stdio::null())
+			.kill_on_drop(true)
+			.output()
+			.await?;
+
+		if !output.status.success() {
+			bail!("chafa exited with {}", output.status);
+		}
+
+		let lines: Vec<_> = output.stdout.split(|&b| b == b'\n').filter(|l| !l.is_empty()).collect();
+		let area = Rect { height: rect.height.min(lines.len() as u16), ..rect };
+		let size = Term::ratio()
+			.map(|(r1, r2)| ((area.width as f64 * r1) as u32, (area.height as f64 * r2) as u32))
+			.unwrap_or((area.width as u32, area.height as u32));
+
+		Adaptor::Chafa.image_hide()?;
+		Adaptor::shown_store(area, size);
+		Term::move_lock(BufWriter::new(stdout().lock()), (rect.x, rect.y), |stdout| {
+			for (y, line) in lines.into_iter().take(area.height as usize).enumerate() {
+				Term::move_to(stdout, rect.x, rect.y + y as u16)?;
+				stdout.write_all(line)?;
+			}
+			stdout.write_all(b"\x1b[0m")?;
+			Ok(size)
+		})
+	}
+
+	pub(super) fn image_erase(rect: Rect) -> Result<()> {
+		let stdout = BufWriter::new(stdout().lock());
+		let s = " ".repeat(rect.width as usize);
+		Term::move_lock(stdout, (0, 0), |stdout| {
+			for y in rect.top()..rect.bottom() {
+				Term::move_to(stdout, rect.x, y)?;
+				stdout.write_all(s.as_bytes())?;
+			}
+			Ok(())
+		})
+	}
+}
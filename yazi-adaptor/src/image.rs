@@ -1,29 +1,40 @@
-use std::{fs::File, io::BufReader, path::{Path, PathBuf}};
+use std::{path::{Path, PathBuf}, sync::{atomic::{AtomicBool, Ordering}, Arc}};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use exif::{In, Tag};
-use image::{codecs::jpeg::JpegEncoder, imageops::{self, FilterType}, io::Limits, DynamicImage};
+use image::{codecs::jpeg::{JpegDecoder, JpegEncoder}, imageops::{self, FilterType}, io::Limits, DynamicImage, ImageDecoder, ImageFormat, RgbImage};
 use ratatui::layout::Rect;
+use tokio::sync::Semaphore;
 use yazi_config::{PREVIEW, TASKS};
-use yazi_shared::term::Term;
+use yazi_shared::{term::Term, RoCell};
+
+// Bounds the number of images being decoded for the preview at the same time
+static WORKERS: RoCell<Semaphore> = RoCell::new();
 
 pub struct Image;
 
+// Set once the future waiting for the decoding is dropped, e.g. the hovered file has changed
+#[derive(Default)]
+struct Cancel(Arc<AtomicBool>);
+
+impl Drop for Cancel {
+	fn drop(&mut self) { self.0.store(true, Ordering::Relaxed); }
+}
+
 impl Image {
+	pub(super) fn init() { WORKERS.init(Semaphore::new(TASKS.image_workers as usize)); }
+
 	pub async fn precache(path: &Path, cache: PathBuf) -> Result<()> {
 		let orientation = Self::orientation(path).await?;
 
-		let path = path.to_owned();
-		let mut img = tokio::task::spawn_blocking(move || {
-			Self::set_limits(image::io::Reader::open(path)?.with_guessed_format()?).decode()
-		})
-		.await??;
-
 		let (mut w, mut h) = (PREVIEW.max_width, PREVIEW.max_height);
 		if (5..=8).contains(&orientation) {
 			(w, h) = (h, w);
 		}
 
+		let path = path.to_owned();
+		let mut img = tokio::task::spawn_blocking(move || Self::decode(&path, w, h)).await??;
+
 		let buf = tokio::task::spawn_blocking(move || {
 			if img.width() > w || img.height() > h {
 				img = img.resize(w, h, Self::filter());
@@ -51,23 +62,32 @@ impl Image {
 	pub(super) async fn downscale(path: &Path, rect: Rect) -> Result<DynamicImage> {
 		let orientation = Self::orientation(path).await?;
 
-		let path = path.to_owned();
-		let mut img = tokio::task::spawn_blocking(move || {
-			Self::set_limits(image::io::Reader::open(path)?.with_guessed_format()?).decode()
-		})
-		.await??;
-
 		let (mut w, mut h) = Self::max_size(rect);
 		if (5..=8).contains(&orientation) {
 			(w, h) = (h, w);
 		}
 
-		// Fast path.
-		if img.width() <= w && img.height() <= h && orientation <= 1 {
-			return Ok(img);
-		}
+		let permit = WORKERS.acquire().await?;
+		let cancel = Cancel::default();
+		let cancelled = cancel.0.clone();
 
+		let path = path.to_owned();
 		tokio::task::spawn_blocking(move || {
+			let _permit = permit;
+			if cancelled.load(Ordering::Relaxed) {
+				bail!("cancelled");
+			}
+
+			let mut img = Self::decode(&path, w, h)?;
+			if cancelled.load(Ordering::Relaxed) {
+				bail!("cancelled");
+			}
+
+			// Fast path.
+			if img.width() <= w && img.height() <= h && orientation <= 1 {
+				return Ok(img);
+			}
+
 			if img.width() > w || img.height() > h {
 				img = img.resize(w, h, Self::filter())
 			}
@@ -76,6 +96,18 @@ impl Image {
 		.await?
 	}
 
+	pub(super) async fn thumbnail(path: &Path, w: u32, h: u32) -> Result<RgbImage> {
+		let orientation = Self::orientation(path).await?;
+
+		let path = path.to_owned();
+		tokio::task::spawn_blocking(move || {
+			let (w, h) = if (5..=8).contains(&orientation) { (h, w) } else { (w, h) };
+			let img = Self::decode(&path, w, h)?;
+			Ok(Self::rotate(img.thumbnail(w, h), orientation).into_rgb8())
+		})
+		.await?
+	}
+
 	pub(super) fn max_size(rect: Rect) -> (u32, u32) {
 		Term::ratio()
 			.map(|(r1, r2)| {
@@ -143,7 +175,22 @@ impl Image {
 		img
 	}
 
-	fn set_limits(mut r: image::io::Reader<BufReader<File>>) -> image::io::Reader<BufReader<File>> {
+	// Decodes the image at a reduced size no smaller than `w` x `h` if the format
+	// supports it, to avoid decoding large images at their full resolution.
+	fn decode(path: &Path, w: u32, h: u32) -> Result<DynamicImage> {
+		let mut reader = image::io::Reader::open(path)?.with_guessed_format()?;
+		if reader.format() != Some(ImageFormat::Jpeg) {
+			reader.limits(Self::limits());
+			return Ok(reader.decode()?);
+		}
+
+		let mut decoder = JpegDecoder::new(reader.into_inner())?;
+		decoder.set_limits(Self::limits())?;
+		decoder.scale(w.min(u16::MAX as u32) as u16, h.min(u16::MAX as u32) as u16)?;
+		Ok(DynamicImage::from_decoder(decoder)?)
+	}
+
+	fn limits() -> Limits {
 		let mut limits = Limits::no_limits();
 		if TASKS.image_alloc > 0 {
 			limits.max_alloc = Some(TASKS.image_alloc as u64);
@@ -154,7 +201,6 @@ impl Image {
 		if TASKS.image_bound[1] > 0 {
 			limits.max_image_height = Some(TASKS.image_bound[1] as u32);
 		}
-		r.limits(limits);
-		r
+		limits
 	}
 }
@@ -7,7 +7,7 @@ use ratatui::layout::Rect;
 use yazi_shared::term::Term;
 
 use super::image::Image;
-use crate::{adaptor::Adaptor, CLOSE, START};
+use crate::{adaptor::Adaptor, Tmux, CLOSE, START};
 
 pub(super) struct Iterm2;
 
@@ -20,7 +20,7 @@ impl Iterm2 {
 		Adaptor::Iterm2.image_hide()?;
 		Adaptor::shown_store(rect, size);
 		Term::move_lock(stdout().lock(), (rect.x, rect.y), |stdout| {
-			stdout.write_all(&b)?;
+			Tmux::write_at(stdout, (rect.x, rect.y), &b)?;
 			Ok(size)
 		})
 	}
@@ -37,7 +37,7 @@ impl Iterm2 {
 		})
 	}
 
-	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
+	pub(super) async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
 		tokio::task::spawn_blocking(move || {
 			let size = (img.width(), img.height());
 
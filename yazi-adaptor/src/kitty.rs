@@ -315,7 +315,7 @@ impl Kitty {
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<(u32, u32)> {
 		let img = Image::downscale(path, rect).await?;
 		let size = (img.width(), img.height());
-		let b = Self::encode(img).await?;
+		let b = Self::encode(img, 1).await?;
 
 		Adaptor::Kitty.image_hide()?;
 		Adaptor::shown_store(rect, size);
@@ -329,9 +329,7 @@ impl Kitty {
 				buf.clear();
 				buf.push_str("\x1b[38;5;1m");
 				for x in 0..rect.width {
-					buf.push('\u{10EEEE}');
-					buf.push(*DIACRITICS.get(y as usize).unwrap_or(&DIACRITICS[0]));
-					buf.push(*DIACRITICS.get(x as usize).unwrap_or(&DIACRITICS[0]));
+					buf.extend(Self::placeholder(x, y));
 				}
 				buf.push_str("\x1b[0m");
 				stdout.write_all(buf.as_bytes())?;
@@ -350,13 +348,32 @@ impl Kitty {
 				stdout.write_all(s.as_bytes())?;
 			}
 
-			stdout.write_all(format!("{}_Gq=1,a=d,d=A{}\\{}", START, ESCAPE, CLOSE).as_bytes())?;
+			// Only the preview's, leaving those of the thumbnails in the grid
+			stdout.write_all(format!("{}_Gq=1,a=d,d=I,i=1{}\\{}", START, ESCAPE, CLOSE).as_bytes())?;
 			Ok(())
 		})
 	}
 
-	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
-		fn output(raw: &[u8], format: u8, size: (u32, u32)) -> Result<Vec<u8>> {
+	/// Transmits the thumbnail of the grid with the `id`, to be drawn by its placeholders.
+	pub(super) async fn transmit(img: DynamicImage, id: u32) -> Result<()> {
+		let b = Self::encode(img, id).await?;
+		let mut stdout = stdout().lock();
+		stdout.write_all(&b)?;
+		Ok(stdout.flush()?)
+	}
+
+	/// The placeholder of the cell at `x`, `y` in the image, whose id is set by the foreground.
+	#[inline]
+	pub(super) fn placeholder(x: u16, y: u16) -> [char; 3] {
+		[
+			'\u{10EEEE}',
+			*DIACRITICS.get(y as usize).unwrap_or(&DIACRITICS[0]),
+			*DIACRITICS.get(x as usize).unwrap_or(&DIACRITICS[0]),
+		]
+	}
+
+	async fn encode(img: DynamicImage, id: u32) -> Result<Vec<u8>> {
+		fn output(raw: &[u8], id: u32, format: u8, size: (u32, u32)) -> Result<Vec<u8>> {
 			let b64: Vec<_> = general_purpose::STANDARD.encode(raw).chars().collect();
 
 			let mut it = b64.chunks(4096).peekable();
@@ -364,8 +381,9 @@ impl Kitty {
 			if let Some(first) = it.next() {
 				write!(
 					buf,
-					"{}_Gq=1,a=T,i=1,C=1,U=1,f={},s={},v={},m={};{}{}\\{}",
+					"{}_Gq=1,a=T,i={},C=1,U=1,f={},s={},v={},m={};{}{}\\{}",
 					START,
+					id,
 					format,
 					size.0,
 					size.1,
@@ -394,9 +412,9 @@ impl Kitty {
 
 		let size = (img.width(), img.height());
 		tokio::task::spawn_blocking(move || match img {
-			DynamicImage::ImageRgb8(v) => output(v.as_raw(), 24, size),
-			DynamicImage::ImageRgba8(v) => output(v.as_raw(), 32, size),
-			v => output(v.to_rgb8().as_raw(), 24, size),
+			DynamicImage::ImageRgb8(v) => output(v.as_raw(), id, 24, size),
+			DynamicImage::ImageRgba8(v) => output(v.as_raw(), id, 32, size),
+			v => output(v.to_rgb8().as_raw(), id, 24, size),
 		})
 		.await?
 	}
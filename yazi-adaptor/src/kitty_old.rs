@@ -7,7 +7,7 @@ use ratatui::layout::Rect;
 use yazi_shared::term::Term;
 
 use super::image::Image;
-use crate::{adaptor::Adaptor, CLOSE, ESCAPE, START};
+use crate::{adaptor::Adaptor, Tmux, CLOSE, ESCAPE, START};
 
 pub(super) struct KittyOld;
 
@@ -15,26 +15,27 @@ impl KittyOld {
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<(u32, u32)> {
 		let img = Image::downscale(path, rect).await?;
 		let size = (img.width(), img.height());
-		let b = Self::encode(img).await?;
+		let b = Self::encode(img, 1).await?;
 
 		Adaptor::KittyOld.image_hide()?;
 		Adaptor::shown_store(rect, size);
 		Term::move_lock(stdout().lock(), (rect.x, rect.y), |stdout| {
-			stdout.write_all(&b)?;
+			Tmux::write_at(stdout, (rect.x, rect.y), &b)?;
 			Ok(size)
 		})
 	}
 
+	/// Deletes the image with the `id`, which is 1 for the preview, and the others for the grid.
 	#[inline]
-	pub(super) fn image_erase() -> Result<()> {
+	pub(super) fn image_erase(id: u32) -> Result<()> {
 		let mut stdout = stdout().lock();
-		stdout.write_all(format!("{}_Gq=1,a=d,d=A{}\\{}", START, ESCAPE, CLOSE).as_bytes())?;
+		stdout.write_all(format!("{}_Gq=1,a=d,d=I,i={}{}\\{}", START, id, ESCAPE, CLOSE).as_bytes())?;
 		stdout.flush()?;
 		Ok(())
 	}
 
-	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
-		fn output(raw: &[u8], format: u8, size: (u32, u32)) -> Result<Vec<u8>> {
+	pub(super) async fn encode(img: DynamicImage, id: u32) -> Result<Vec<u8>> {
+		fn output(raw: &[u8], id: u32, format: u8, size: (u32, u32)) -> Result<Vec<u8>> {
 			let b64: Vec<_> = general_purpose::STANDARD.encode(raw).chars().collect();
 
 			let mut it = b64.chunks(4096).peekable();
@@ -42,8 +43,9 @@ impl KittyOld {
 			if let Some(first) = it.next() {
 				write!(
 					buf,
-					"{}_Gq=1,a=T,z=-1,C=1,f={},s={},v={},m={};{}{}\\{}",
+					"{}_Gq=1,a=T,i={},z=-1,C=1,f={},s={},v={},m={};{}{}\\{}",
 					START,
+					id,
 					format,
 					size.0,
 					size.1,
@@ -72,9 +74,9 @@ impl KittyOld {
 
 		let size = (img.width(), img.height());
 		tokio::task::spawn_blocking(move || match img {
-			DynamicImage::ImageRgb8(v) => output(v.as_raw(), 24, size),
-			DynamicImage::ImageRgba8(v) => output(v.as_raw(), 32, size),
-			v => output(v.to_rgb8().as_raw(), 24, size),
+			DynamicImage::ImageRgb8(v) => output(v.as_raw(), id, 24, size),
+			DynamicImage::ImageRgba8(v) => output(v.as_raw(), id, 32, size),
+			v => output(v.to_rgb8().as_raw(), id, 24, size),
 		})
 		.await?
 	}
@@ -1,21 +1,26 @@
 #![allow(clippy::unit_arg)]
 
 mod adaptor;
+mod chafa;
 mod image;
 mod iterm2;
 mod kitty;
 mod kitty_old;
 mod sixel;
+mod thumbnail;
+mod tmux;
 mod ueberzug;
 
 use adaptor::*;
+use chafa::*;
 use iterm2::*;
 use kitty::*;
 use kitty_old::*;
 use sixel::*;
+use tmux::*;
 use yazi_shared::{env_exists, RoCell};
 
-pub use crate::image::*;
+pub use crate::{image::*, thumbnail::*};
 
 pub static ADAPTOR: RoCell<Adaptor> = RoCell::new();
 
@@ -35,16 +40,10 @@ pub fn init() {
 	ESCAPE.init(if *TMUX { "\x1b\x1b" } else { "\x1b" });
 
 	SHOWN.with(Default::default);
+	Image::init();
+	Thumbnail::init();
+	Sixel::init();
 
 	ADAPTOR.init(Adaptor::detect());
 	ADAPTOR.start();
-
-	if *TMUX {
-		_ = std::process::Command::new("tmux")
-			.args(["set", "-p", "allow-passthrough", "on"])
-			.stdin(std::process::Stdio::null())
-			.stdout(std::process::Stdio::null())
-			.stderr(std::process::Stdio::null())
-			.spawn();
-	}
 }
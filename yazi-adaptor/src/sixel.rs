@@ -1,31 +1,76 @@
-use std::{io::{stdout, BufWriter, Write}, path::Path};
+use std::{io::{stdout, BufWriter, Write}, path::{Path, PathBuf}, sync::Arc, time::SystemTime};
 
 use anyhow::{bail, Result};
 use color_quant::NeuQuant;
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
+use parking_lot::Mutex;
 use ratatui::layout::Rect;
-use yazi_config::PREVIEW;
-use yazi_shared::term::Term;
+use yazi_config::{preview::SixelDither, PREVIEW};
+use yazi_shared::{term::Term, RoCell};
+
+use crate::{adaptor::Adaptor, Image, Tmux, CLOSE, ESCAPE, START};
+
+// The last encoded image, so that showing the same preview again,
+// e.g. after the folder is refreshed, doesn't encode or transmit it again
+static LAST: RoCell<Mutex<Option<Last>>> = RoCell::new();
+
+struct Last {
+	path:  PathBuf,
+	rect:  Rect,
+	mtime: Option<SystemTime>,
+	size:  (u32, u32),
+	bytes: Arc<Vec<u8>>,
+	shown: bool,
+}
 
-use crate::{adaptor::Adaptor, Image, CLOSE, ESCAPE, START};
+impl Last {
+	#[inline]
+	fn is(&self, path: &Path, rect: Rect) -> bool { self.path == path && self.rect == rect }
+}
 
 pub(super) struct Sixel;
 
 impl Sixel {
+	pub(super) fn init() { LAST.with(Default::default); }
+
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<(u32, u32)> {
-		let img = Image::downscale(path, rect).await?;
-		let size = (img.width(), img.height());
-		let b = Self::encode(img).await?;
+		let mtime = tokio::fs::metadata(path).await.and_then(|m| m.modified()).ok();
+		let last = LAST
+			.lock()
+			.as_ref()
+			.filter(|l| l.is(path, rect) && l.mtime == mtime)
+			.map(|l| (l.size, l.bytes.clone(), l.shown));
+
+		let (size, b) = match last {
+			Some((size, _, true)) if Adaptor::Sixel.shown_load().is_some() => return Ok(size),
+			Some((size, b, _)) => (size, b),
+			None => {
+				let img = Image::downscale(path, rect).await?;
+				let size = (img.width(), img.height());
+				let b = Arc::new(Self::encode(img).await?);
+
+				let bytes = b.clone();
+				*LAST.lock() = Some(Last { path: path.to_owned(), rect, mtime, size, bytes, shown: false });
+				(size, b)
+			}
+		};
 
 		Adaptor::Sixel.image_hide()?;
 		Adaptor::shown_store(rect, size);
 		Term::move_lock(stdout().lock(), (rect.x, rect.y), |stdout| {
-			stdout.write_all(&b)?;
+			Tmux::write_at(stdout, (rect.x, rect.y), &b)?;
+			if let Some(l) = LAST.lock().as_mut().filter(|l| l.is(path, rect)) {
+				l.shown = true;
+			}
 			Ok(size)
 		})
 	}
 
 	pub(super) fn image_erase(rect: Rect) -> Result<()> {
+		if let Some(l) = LAST.lock().as_mut() {
+			l.shown = false;
+		}
+
 		let stdout = BufWriter::new(stdout().lock());
 		let s = " ".repeat(rect.width as usize);
 		Term::move_lock(stdout, (0, 0), |stdout| {
@@ -37,7 +82,7 @@ impl Sixel {
 		})
 	}
 
-	async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
+	pub(super) async fn encode(img: DynamicImage) -> Result<Vec<u8>> {
 		let alpha = img.color().has_alpha();
 		if img.width() == 0 || img.height() == 0 {
 			bail!("image is empty");
@@ -45,7 +90,8 @@ impl Sixel {
 
 		tokio::task::spawn_blocking(move || {
 			let img = img.into_rgba8();
-			let nq = NeuQuant::new(PREVIEW.sixel_fraction as i32, 256 - alpha as usize, &img);
+			let colors = PREVIEW.sixel_colors as usize - alpha as usize;
+			let nq = NeuQuant::new(PREVIEW.sixel_fraction as i32, colors, &img);
 
 			let mut buf: Vec<u8> = Vec::with_capacity(1 << 16);
 			write!(buf, "{}P0;1;8q\"1;1;{};{}", START, img.width(), img.height())?;
@@ -62,44 +108,107 @@ impl Sixel {
 				)?;
 			}
 
-			for y in 0..img.height() {
-				let c = (b'?' + (1 << (y % 6))) as char;
+			let indices = Self::quantize(&img, &nq, alpha);
+			Self::bands(&mut buf, &indices, img.width() as usize, alpha)?;
 
-				let mut last = 0;
-				let mut repeat = 0usize;
-				for x in 0..img.width() {
-					let pixel = img.get_pixel(x, y).0;
-					let idx = if pixel[3] == 0 { 0 } else { nq.index_of(&pixel) as u8 + alpha as u8 };
+			write!(buf, "{}\\{}", ESCAPE, CLOSE)?;
+			Ok(buf)
+		})
+		.await?
+	}
 
-					if idx == last || repeat == 0 {
-						(last, repeat) = (idx, repeat + 1);
-						continue;
-					}
+	/// Maps each pixel to its index in the palette, where 0 is the transparent one
+	/// if the image has alpha, diffusing the error to the neighbours if dithering.
+	fn quantize(img: &RgbaImage, nq: &NeuQuant, alpha: bool) -> Vec<u8> {
+		let (w, h) = (img.width() as usize, img.height() as usize);
+		let dither = PREVIEW.sixel_dither == SixelDither::FloydSteinberg;
+
+		// The errors carried to the current and the next row, 16 times the actual
+		// value, with a column of padding on each side
+		let mut cur = vec![[0i32; 3]; w + 2];
+		let mut next = vec![[0i32; 3]; w + 2];
+
+		let mut indices = Vec::with_capacity(w * h);
+		for (y, row) in img.rows().enumerate() {
+			for (x, p) in row.enumerate() {
+				let p = p.0;
+				if alpha && p[3] == 0 {
+					indices.push(0);
+					continue;
+				}
 
-					if repeat > 1 {
-						write!(buf, "#{last}!{repeat}{c}")?;
-					} else {
-						write!(buf, "#{last}{c}")?;
-					}
+				let want = [0, 1, 2].map(|i| (p[i] as i32 + cur[x + 1][i] / 16).clamp(0, 255));
+				let idx = nq.index_of(&[want[0] as u8, want[1] as u8, want[2] as u8, p[3]]);
+				indices.push(idx as u8 + alpha as u8);
+				if !dither {
+					continue;
+				}
 
-					(last, repeat) = (idx, 1);
+				let got = nq.lookup(idx).unwrap_or_default();
+				for (i, (want, got)) in want.into_iter().zip(got).enumerate() {
+					let err = want - got as i32;
+					cur[x + 2][i] += err * 7;
+					next[x][i] += err * 3;
+					next[x + 1][i] += err * 5;
+					next[x + 2][i] += err;
 				}
+			}
+
+			if y + 1 < h {
+				std::mem::swap(&mut cur, &mut next);
+				next.fill([0; 3]);
+			}
+		}
+		indices
+	}
 
-				if repeat > 1 {
-					write!(buf, "#{last}!{repeat}{c}")?;
-				} else {
-					write!(buf, "#{last}{c}")?;
+	/// Writes the pixels six rows at a time, in one pass per color used in the band.
+	fn bands(buf: &mut Vec<u8>, indices: &[u8], w: usize, alpha: bool) -> Result<()> {
+		let mut masks = vec![0u8; w];
+		for band in indices.chunks(w * 6) {
+			let mut used = [false; 256];
+			band.iter().for_each(|&i| used[i as usize] = true);
+			used[0] &= !alpha;
+
+			let mut first = true;
+			for color in (0..256).filter(|&c| used[c]) {
+				masks.fill(0);
+				for (r, row) in band.chunks(w).enumerate() {
+					for (m, _) in masks.iter_mut().zip(row).filter(|(_, &i)| i as usize == color) {
+						*m |= 1 << r;
+					}
 				}
 
-				write!(buf, "$")?;
-				if y % 6 == 5 {
-					write!(buf, "-")?;
+				if !first {
+					buf.push(b'$');
 				}
+				first = false;
+
+				write!(buf, "#{color}")?;
+				Self::runs(buf, &masks)?;
 			}
+			buf.push(b'-');
+		}
+		Ok(())
+	}
 
-			write!(buf, "{}\\{}", ESCAPE, CLOSE)?;
-			Ok(buf)
-		})
-		.await?
+	/// Writes a row of sixels, compressing the repeated ones.
+	fn runs(buf: &mut Vec<u8>, masks: &[u8]) -> Result<()> {
+		// The empty ones at the end don't need to be drawn
+		let end = masks.iter().rposition(|&m| m != 0).map_or(0, |i| i + 1);
+
+		let mut x = 0;
+		while x < end {
+			let m = masks[x];
+			let n = masks[x..end].iter().take_while(|&&c| c == m).count();
+			if n > 3 {
+				write!(buf, "!{n}")?;
+				buf.push(b'?' + m);
+			} else {
+				buf.extend(std::iter::repeat_n(b'?' + m, n));
+			}
+			x += n;
+		}
+		Ok(())
 	}
 }
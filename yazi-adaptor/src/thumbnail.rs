@@ -0,0 +1,382 @@
+use std::{collections::HashMap, io::{stdout, BufWriter}, mem, path::{Path, PathBuf}, sync::{atomic::{AtomicU32, Ordering}, Arc}, time::{Duration, Instant}};
+
+use anyhow::Result;
+use image::{DynamicImage, RgbImage};
+use parking_lot::Mutex;
+use ratatui::{backend::{Backend, CrosstermBackend}, buffer::Buffer, layout::Rect, style::Color};
+use tokio::sync::Semaphore;
+use yazi_config::TASKS;
+use yazi_shared::{emit, term::{Colors, Term}, RoCell};
+
+use crate::{ueberzug::Ueberzug, Adaptor, Image, Iterm2, Kitty, KittyOld, Sixel, Tmux, ADAPTOR};
+
+type Key = (PathBuf, u32, u32);
+
+/// A thumbnail in the grid, in the form the backend shows it.
+pub enum Thumb {
+	// Drawn with half blocks, for the backends that can only show one image at a time
+	Cells(RgbImage),
+	// Transmitted to Kitty once it's ready, and drawn with the placeholders of its id
+	Kitty { id: u32, size: (u32, u32) },
+	// The escape sequences of the image, written at its place each time it's shown
+	Bytes { id: u32, size: (u32, u32), bytes: Vec<u8> },
+	// The file itself, shown by Überzug++
+	File { id: u32, path: PathBuf },
+}
+
+enum Entry {
+	Pending,
+	Failed(Instant),
+	// The thumbnail, and when it was last used, by the `tick` of the cache
+	Done(Arc<Thumb>, u64),
+}
+
+#[derive(Default)]
+struct Cache {
+	entries: HashMap<Key, Entry>,
+	tick:    u64,
+}
+
+// A thumbnail shown over the cells of `rect`, apart from the text of the buffer
+#[derive(Clone)]
+struct Placement {
+	rect:  Rect,
+	thumb: Arc<Thumb>,
+}
+
+#[derive(Default)]
+struct Frame {
+	// Placed by the frame being drawn, by the last full frame, and those on the screen
+	placed:  Vec<Placement>,
+	wanted:  Vec<Placement>,
+	shown:   Vec<Placement>,
+	// Cleared by the popups of the frame being drawn, which the thumbnails mustn't be shown over
+	covered: Vec<Rect>,
+	// Whether to hide and show all of them again by the next frame
+	reset:   bool,
+}
+
+// Thumbnails kept in memory, along with the ones being generated or failed
+static CACHE: RoCell<Mutex<Cache>> = RoCell::new();
+
+static FRAME: RoCell<Mutex<Frame>> = RoCell::new();
+
+// Bounds the number of thumbnails being generated at the same time
+static WORKERS: RoCell<Semaphore> = RoCell::new();
+
+// The id of the next thumbnail in the terminal, where 1 is the preview
+static ID: AtomicU32 = AtomicU32::new(2);
+
+const CACHE_LIMIT: usize = 1000;
+
+// A failed one is tried again after this, in case the file was still being written
+const FAILED_RETRY: Duration = Duration::from_secs(10);
+
+pub struct Thumbnail;
+
+impl Thumbnail {
+	pub(super) fn init() {
+		CACHE.with(Default::default);
+		FRAME.with(Default::default);
+		WORKERS.init(Semaphore::new(TASKS.thumb_workers as usize));
+	}
+
+	/// Returns the thumbnail of the image at `path` that fits in `area`,
+	/// or schedules its generation and triggers a render once it's ready.
+	pub fn get(path: &Path, area: Rect) -> Option<Arc<Thumb>> {
+		let native = Self::native();
+		let (w, h) = if native {
+			Image::max_size(area)
+		} else {
+			// Each cell holds two pixels stacked vertically, drawn with a half block
+			(area.width as u32, area.height as u32 * 2)
+		};
+		let key = (path.to_owned(), w, h);
+
+		let mut cache = CACHE.lock();
+		cache.tick += 1;
+		let tick = cache.tick;
+		match cache.entries.get_mut(&key) {
+			Some(Entry::Done(thumb, used)) => {
+				*used = tick;
+				return Some(thumb.clone());
+			}
+			Some(Entry::Pending) => return None,
+			Some(Entry::Failed(at)) if at.elapsed() < FAILED_RETRY => return None,
+			Some(Entry::Failed(_)) | None => {}
+		}
+
+		if cache.entries.len() >= CACHE_LIMIT {
+			cache.evict();
+		}
+
+		// Überzug++ reads the file by itself
+		if native && ADAPTOR.needs_ueberzug() {
+			let thumb = Arc::new(Thumb::File { id: Self::next_id(), path: key.0.clone() });
+			cache.entries.insert(key, Entry::Done(thumb.clone(), tick));
+			return Some(thumb);
+		}
+		cache.entries.insert(key.clone(), Entry::Pending);
+
+		tokio::spawn(async move {
+			let _permit = WORKERS.acquire().await;
+			let result = Self::generate(&key.0, (key.1, key.2), native).await;
+
+			let mut cache = CACHE.lock();
+			let entry = match result {
+				Ok(thumb) => Entry::Done(Arc::new(thumb), cache.tick),
+				Err(_) => Entry::Failed(Instant::now()),
+			};
+			let done = matches!(entry, Entry::Done(..));
+			cache.entries.insert(key, entry);
+			drop(cache);
+
+			if done {
+				emit!(Render);
+			}
+		});
+		None
+	}
+
+	/// Draws the thumbnail in `area` of the buffer, or places it there to be shown by the backend
+	/// once the frame is drawn.
+	pub fn draw(area: Rect, thumb: &Arc<Thumb>, buf: &mut Buffer) {
+		let area = area.intersection(*buf.area());
+		let rect = match thumb.as_ref() {
+			Thumb::Cells(img) => return Self::draw_cells(area, img, buf),
+			Thumb::Kitty { size, .. } | Thumb::Bytes { size, .. } => Self::fit(area, *size),
+			Thumb::File { .. } => area,
+		};
+
+		for y in rect.top()..rect.bottom() {
+			for x in rect.left()..rect.right() {
+				let cell = buf.get_mut(x, y);
+				cell.reset();
+				if let Thumb::Kitty { id, .. } = thumb.as_ref() {
+					cell.set_symbol(&String::from_iter(Kitty::placeholder(x - rect.x, y - rect.y)));
+					cell.set_fg(Color::Rgb((id >> 16) as u8, (id >> 8) as u8, *id as u8));
+				}
+			}
+		}
+
+		if !matches!(thumb.as_ref(), Thumb::Kitty { .. }) {
+			let placement = Placement { rect, thumb: thumb.clone() };
+			let mut frame = FRAME.lock();
+			if !frame.placed.contains(&placement) {
+				frame.placed.push(placement);
+			}
+		}
+	}
+
+	/// Keeps the thumbnails from being shown over the popup cleared in `area`.
+	#[inline]
+	pub fn collide(area: Rect) { FRAME.lock().covered.push(area); }
+
+	/// Shows the thumbnails placed by the frame just drawn, and hides those no longer placed or
+	/// covered by a popup, restoring their cells from `buf`. A partial frame only hides the ones
+	/// covered by it, as it doesn't place them again.
+	pub fn flush(buf: &Buffer, full: bool) {
+		let frame = &mut *FRAME.lock();
+		let covered = mem::take(&mut frame.covered);
+		let visible = |p: &Placement| !covered.iter().any(|r| r.intersects(p.rect));
+
+		if full {
+			frame.wanted = mem::take(&mut frame.placed).into_iter().filter(visible).collect();
+		}
+		let wanted: Vec<_> = frame.wanted.iter().filter(|&p| visible(p)).cloned().collect();
+
+		if mem::take(&mut frame.reset) {
+			mem::take(&mut frame.shown).iter().for_each(|p| _ = Self::hide(p, Some(buf)));
+		}
+
+		for p in frame.shown.iter().filter(|p| !wanted.contains(p)) {
+			Self::hide(p, Some(buf)).ok();
+		}
+		for p in wanted.iter().filter(|p| !frame.shown.contains(p)) {
+			Self::show(p).ok();
+		}
+		frame.shown = wanted;
+	}
+
+	/// Shows all the thumbnails again by the next frame, as the screen may have been cleared.
+	#[inline]
+	pub fn reset() { FRAME.lock().reset = true; }
+
+	/// Hides all the thumbnails at once, for the screen is about to be left,
+	/// and they're shown again by the next frame once it's back.
+	pub fn hide_all() {
+		let mut frame = FRAME.lock();
+		for p in mem::take(&mut frame.shown) {
+			Self::hide(&p, None).ok();
+		}
+	}
+
+	// Whether the backend shows the thumbnails by itself, rather than by half blocks,
+	// which needs the size of the cells in pixels to fit them
+	fn native() -> bool {
+		match *ADAPTOR {
+			Adaptor::Chafa => false,
+			Adaptor::X11 | Adaptor::Wayland => true,
+			// The id is set by the foreground, which mustn't be degraded
+			Adaptor::Kitty => Term::ratio().is_some() && Colors::get() == Colors::TrueColor,
+			_ => Term::ratio().is_some(),
+		}
+	}
+
+	#[inline]
+	fn next_id() -> u32 { ID.fetch_add(1, Ordering::Relaxed) & 0xffffff }
+
+	async fn generate(path: &Path, (w, h): (u32, u32), native: bool) -> Result<Thumb> {
+		let img = Image::thumbnail(path, w, h).await?;
+		if !native {
+			return Ok(Thumb::Cells(img));
+		}
+
+		let (id, size) = (Self::next_id(), img.dimensions());
+		let img = DynamicImage::ImageRgb8(img);
+		Ok(match *ADAPTOR {
+			Adaptor::Kitty => {
+				Kitty::transmit(img, id).await?;
+				Thumb::Kitty { id, size }
+			}
+			Adaptor::KittyOld => Thumb::Bytes { id, size, bytes: KittyOld::encode(img, id).await? },
+			Adaptor::Iterm2 => Thumb::Bytes { id, size, bytes: Iterm2::encode(img).await? },
+			Adaptor::Sixel => Thumb::Bytes { id, size, bytes: Sixel::encode(img).await? },
+			_ => Thumb::Cells(img.into_rgb8()),
+		})
+	}
+
+	fn show(p: &Placement) -> Result<()> {
+		let (x, y) = (p.rect.x, p.rect.y);
+		match p.thumb.as_ref() {
+			Thumb::Bytes { bytes, .. } => Term::move_lock(stdout().lock(), (x, y), |stdout| {
+				Ok(Tmux::write_at(stdout, (x, y), bytes)?)
+			}),
+			Thumb::File { id, path } => {
+				Ueberzug::send(&format!("yazi-{id}"), Some((path.clone(), p.rect)))
+			}
+			Thumb::Cells(_) | Thumb::Kitty { .. } => Ok(()),
+		}
+	}
+
+	// Hides the thumbnail by the backend, or by drawing the cells under it again from `buf`
+	fn hide(p: &Placement, buf: Option<&Buffer>) -> Result<()> {
+		match p.thumb.as_ref() {
+			Thumb::Bytes { id, .. } if *ADAPTOR == Adaptor::KittyOld => KittyOld::image_erase(*id),
+			Thumb::Bytes { .. } => {
+				let Some(buf) = buf else { return Ok(()) };
+				let rect = p.rect.intersection(buf.area);
+				let cells = rect.rows().flat_map(|r| r.columns()).map(|c| (c.x, c.y, buf.get(c.x, c.y)));
+
+				Term::move_lock(BufWriter::new(stdout().lock()), (0, 0), |stdout| {
+					let mut backend = CrosstermBackend::new(stdout);
+					backend.draw(cells)?;
+					Ok(backend.flush()?)
+				})
+			}
+			Thumb::File { id, .. } => Ueberzug::send(&format!("yazi-{id}"), None),
+			Thumb::Cells(_) | Thumb::Kitty { .. } => Ok(()),
+		}
+	}
+
+	// The cells taken by an image of `size` pixels, centered in `area`
+	fn fit(area: Rect, size: (u32, u32)) -> Rect {
+		let Some((r1, r2)) = Term::ratio() else {
+			return area;
+		};
+
+		let w = ((size.0 as f64 / r1).ceil() as u16).min(area.width);
+		let h = ((size.1 as f64 / r2).ceil() as u16).min(area.height);
+		Rect {
+			x:      area.x + (area.width - w) / 2,
+			y:      area.y + (area.height - h) / 2,
+			width:  w,
+			height: h,
+		}
+	}
+
+	fn draw_cells(area: Rect, img: &RgbImage, buf: &mut Buffer) {
+		let (w, h) = (img.width() as u16, img.height().div_ceil(2) as u16);
+
+		let x0 = area.x + area.width.saturating_sub(w) / 2;
+		let y0 = area.y + area.height.saturating_sub(h) / 2;
+		let pixel = |x: u16, y: u32| {
+			img.get_pixel_checked(x as u32, y).map(|p| Color::Rgb(p[0], p[1], p[2]))
+		};
+
+		for y in 0..h.min(area.height) {
+			for x in 0..w.min(area.width) {
+				let cell = buf.get_mut(x0 + x, y0 + y);
+				cell.set_symbol("▀");
+				if let Some(c) = pixel(x, y as u32 * 2) {
+					cell.set_fg(c);
+				}
+				cell.set_bg(pixel(x, y as u32 * 2 + 1).unwrap_or(Color::Reset));
+			}
+		}
+	}
+}
+
+impl PartialEq for Placement {
+	fn eq(&self, other: &Self) -> bool {
+		self.rect == other.rect && Arc::ptr_eq(&self.thumb, &other.thumb)
+	}
+}
+
+impl Cache {
+	// Makes room by dropping the failed ones, and the least recently used half of the thumbnails
+	// if that's not enough, keeping those being generated, which are put in once they're done
+	fn evict(&mut self) {
+		self.entries.retain(|_, e| !matches!(e, Entry::Failed(_)));
+		if self.entries.len() < CACHE_LIMIT {
+			return;
+		}
+
+		let mut used: Vec<_> = self
+			.entries
+			.values()
+			.filter_map(|e| if let Entry::Done(_, u) = e { Some(*u) } else { None })
+			.collect();
+		if used.is_empty() {
+			return;
+		}
+
+		let mid = used.len() / 2;
+		let (_, &mut median, _) = used.select_nth_unstable(mid);
+		self.entries.retain(|_, e| !matches!(e, Entry::Done(_, u) if *u < median));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_evict() {
+		let mut cache = Cache::default();
+		let thumb = Arc::new(Thumb::Cells(RgbImage::new(1, 1)));
+		for i in 0..CACHE_LIMIT as u32 {
+			let entry = match i % 10 {
+				0 => Entry::Pending,
+				1 => Entry::Failed(Instant::now()),
+				_ => Entry::Done(thumb.clone(), i as u64),
+			};
+			cache.entries.insert((PathBuf::new(), i, i), entry);
+		}
+
+		// Not full once the failed ones are gone
+		cache.evict();
+		assert_eq!(cache.entries.len(), CACHE_LIMIT / 10 * 9);
+		assert!(!cache.entries.values().any(|e| matches!(e, Entry::Failed(_))));
+
+		// Full of the pending and the thumbnails, of which the recent half is kept
+		for i in CACHE_LIMIT as u32..CACHE_LIMIT as u32 + 100 {
+			cache.entries.insert((PathBuf::new(), i, i), Entry::Done(thumb.clone(), i as u64));
+		}
+		cache.evict();
+		assert_eq!(cache.entries.values().filter(|e| matches!(e, Entry::Pending)).count(), 100);
+		assert_eq!(cache.entries.values().filter(|e| matches!(e, Entry::Done(..))).count(), 450);
+		assert!(cache.entries.contains_key(&(PathBuf::new(), 1099, 1099)));
+		assert!(!cache.entries.contains_key(&(PathBuf::new(), 2, 2)));
+	}
+}
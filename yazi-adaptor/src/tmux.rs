@@ -0,0 +1,78 @@
+use std::{io::{self, Write}, process::Stdio, sync::atomic::{AtomicBool, AtomicU32, Ordering}};
+
+use tokio::process::Command;
+use tracing::warn;
+
+use crate::TMUX;
+
+// The position of the pane's top-left corner in the outer terminal, as `left << 16 | top`
+static OFFSET: AtomicU32 = AtomicU32::new(0);
+static REFRESHING: AtomicBool = AtomicBool::new(false);
+
+pub(super) struct Tmux;
+
+impl Tmux {
+	/// Makes sure the pane lets the image sequences through to the outer terminal, turning
+	/// `allow-passthrough` on in the background if needed, without holding up the start for it.
+	pub(super) fn negotiate() {
+		tokio::spawn(async {
+			// Versions before 3.3 don't have the option, and always let them through
+			let off = Self::run(&["show", "-Apv", "allow-passthrough"])
+				.await
+				.is_some_and(|v| !v.starts_with("on") && !v.starts_with("all"));
+			if off && Self::run(&["set", "-p", "allow-passthrough", "on"]).await.is_none() {
+				warn!("[Adaptor] tmux `allow-passthrough` is off, and couldn't be turned on");
+			}
+		});
+		Self::refresh();
+	}
+
+	/// Writes an image sequence that is placed at the outer terminal's cursor.
+	///
+	/// tmux doesn't always flush its own cursor movements before a passthrough,
+	/// so the outer cursor is moved to the cell explicitly, taking the offset of
+	/// the pane into account, and restored afterwards.
+	pub(super) fn write_at(w: &mut impl Write, (x, y): (u16, u16), b: &[u8]) -> io::Result<()> {
+		if !*TMUX {
+			return w.write_all(b);
+		}
+
+		let (left, top) = Self::offset();
+		write!(w, "\x1bPtmux;\x1b\x1b7\x1b\x1b[{};{}H\x1b\\", top + y + 1, left + x + 1)?;
+		w.write_all(b)?;
+		w.write_all(b"\x1bPtmux;\x1b\x1b8\x1b\\")
+	}
+
+	// The cached offset, refreshed in the background on each use for the pane may have been moved
+	// since, as asking tmux for it holds up the image until it answers.
+	fn offset() -> (u16, u16) {
+		Self::refresh();
+		let n = OFFSET.load(Ordering::Relaxed);
+		((n >> 16) as u16, n as u16)
+	}
+
+	fn refresh() {
+		if REFRESHING.swap(true, Ordering::Relaxed) {
+			return;
+		}
+		tokio::spawn(async {
+			let offset = Self::run(&["display", "-p", "#{pane_left} #{pane_top}"]).await.and_then(|s| {
+				let (x, y) = s.split_once(' ')?;
+				Some((x.parse::<u16>().ok()?, y.parse::<u16>().ok()?))
+			});
+			if let Some((x, y)) = offset {
+				OFFSET.store((x as u32) << 16 | y as u32, Ordering::Relaxed);
+			}
+			REFRESHING.store(false, Ordering::Relaxed);
+		});
+	}
+
+	async fn run(args: &[&str]) -> Option<String> {
+		let output =
+			Command::new("tmux").args(args).stdin(Stdio::null()).stderr(Stdio::null()).output().await;
+		match output {
+			Ok(o) if o.status.success() => Some(String::from_utf8_lossy(&o.stdout).trim().to_owned()),
+			_ => None,
+		}
+	}
+}
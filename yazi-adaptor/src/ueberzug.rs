@@ -10,8 +10,9 @@ use yazi_shared::RoCell;
 
 use crate::{Adaptor, Image};
 
+// The identifier of the image, "yazi" for the preview, and where to show it, or `None` to remove it
 #[allow(clippy::type_complexity)]
-static DEMON: RoCell<Option<UnboundedSender<Option<(PathBuf, Rect)>>>> = RoCell::new();
+static DEMON: RoCell<Option<UnboundedSender<(String, Option<(PathBuf, Rect)>)>>> = RoCell::new();
 
 pub(super) struct Ueberzug;
 
@@ -21,11 +22,11 @@ impl Ueberzug {
 			return DEMON.init(None);
 		}
 
-		let mut child = Self::create_demon(adaptor).ok();
-		let (tx, mut rx) = mpsc::unbounded_channel();
-
+		let (tx, mut rx) = mpsc::unbounded_channel::<(String, _)>();
 		tokio::spawn(async move {
-			while let Some(cmd) = rx.recv().await {
+			// Spawned off the startup, for the first frame not to wait for it
+			let mut child = Self::create_demon(adaptor).ok();
+			while let Some((id, cmd)) = rx.recv().await {
 				let exit = child.as_mut().and_then(|c| c.try_wait().ok());
 				if exit != Some(None) {
 					child = None;
@@ -34,7 +35,7 @@ impl Ueberzug {
 					child = Self::create_demon(adaptor).ok();
 				}
 				if let Some(c) = &mut child {
-					Self::send_command(c, cmd).await.ok();
+					Self::send_command(c, &id, cmd).await.ok();
 				}
 			}
 		});
@@ -42,12 +43,8 @@ impl Ueberzug {
 	}
 
 	pub(super) async fn image_show(path: &Path, rect: Rect) -> Result<(u32, u32)> {
-		if let Some(tx) = &*DEMON {
-			tx.send(Some((path.to_path_buf(), rect)))?;
-			Adaptor::shown_store(rect, (0, 0));
-		} else {
-			bail!("uninitialized ueberzug");
-		}
+		Self::send("yazi", Some((path.to_path_buf(), rect)))?;
+		Adaptor::shown_store(rect, (0, 0));
 
 		let path = path.to_owned();
 		let ImageSize { width: w, height: h } =
@@ -62,9 +59,12 @@ impl Ueberzug {
 		Ok(((w as f64 * ratio).round() as u32, (h as f64 * ratio).round() as u32))
 	}
 
-	pub(super) fn image_erase(_: Rect) -> Result<()> {
+	pub(super) fn image_erase(_: Rect) -> Result<()> { Self::send("yazi", None) }
+
+	/// Shows the image with the `identifier`, or removes it if `cmd` is `None`.
+	pub(super) fn send(identifier: &str, cmd: Option<(PathBuf, Rect)>) -> Result<()> {
 		if let Some(tx) = &*DEMON {
-			Ok(tx.send(None)?)
+			Ok(tx.send((identifier.to_owned(), cmd))?)
 		} else {
 			bail!("uninitialized ueberzug");
 		}
@@ -92,7 +92,7 @@ impl Ueberzug {
 		rect
 	}
 
-	async fn send_command(child: &mut Child, cmd: Option<(PathBuf, Rect)>) -> Result<()> {
+	async fn send_command(child: &mut Child, id: &str, cmd: Option<(PathBuf, Rect)>) -> Result<()> {
 		let stdin = child.stdin.as_mut().unwrap();
 		if let Some((path, rect)) = cmd {
 			debug!("ueberzug rect before adjustment: {:?}", rect);
@@ -100,7 +100,8 @@ impl Ueberzug {
 			debug!("ueberzug rect after adjustment: {:?}", rect);
 
 			let s = format!(
-				r#"{{"action":"add","identifier":"yazi","x":{},"y":{},"max_width":{},"max_height":{},"path":"{}"}}{}"#,
+				r#"{{"action":"add","identifier":"{}","x":{},"y":{},"max_width":{},"max_height":{},"path":"{}"}}{}"#,
+				id,
 				rect.x,
 				rect.y,
 				rect.width,
@@ -111,9 +112,9 @@ impl Ueberzug {
 			debug!("ueberzug command: {}", s);
 			stdin.write_all(s.as_bytes()).await?;
 		} else {
-			debug!("ueberzug command: remove");
+			debug!("ueberzug command: remove {id}");
 			stdin
-				.write_all(format!(r#"{{"action":"remove","identifier":"yazi"}}{}"#, "\n").as_bytes())
+				.write_all(format!(r#"{{"action":"remove","identifier":"{}"}}{}"#, id, "\n").as_bytes())
 				.await?;
 		}
 		Ok(())
@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Debug, Parser)]
+#[command(
+	name = "ya",
+	version,
+	after_help = concat!(
+		"Each command talks to an instance through its server, which is off by default,\n",
+		"so `server = true` has to be under `[manager]` of its yazi.toml"
+	)
+)]
+pub(super) struct Args {
+	#[command(subcommand)]
+	pub(super) command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub(super) enum Command {
+	/// Print the state of a running instance, as JSON
+	Get(CommandGet),
+	/// Run a command moving around in a running instance, e.g. `ya emit-to 1234 reveal /a/file`
+	EmitTo(CommandEmitTo),
+	/// Write the state of a running instance as `--state-file` does, or print it without a path
+	ExportState(CommandExportState),
+	/// Print the process ID and cwd of each running instance, as JSON
+	ListInstances,
+	/// Print the changes of the state of a running instance as they happen, a JSON object a line
+	Subscribe(CommandSubscribe),
+}
+
+#[derive(Debug, clap::Args)]
+pub(super) struct CommandGet {
+	/// What to get
+	#[arg(value_enum)]
+	pub(super) what: What,
+
+	/// The process ID of the instance, `$YAZI_PID` or the only one running by default
+	#[arg(long)]
+	pub(super) pid: Option<u32>,
+}
+
+#[derive(Debug, clap::Args)]
+pub(super) struct CommandEmitTo {
+	/// The process ID of the instance
+	pub(super) pid: u32,
+
+	/// The command, and the arguments of it
+	#[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+	pub(super) cmd: Vec<String>,
+}
+
+#[derive(Debug, clap::Args)]
+pub(super) struct CommandExportState {
+	/// The file to write it to, the `--state-file` of the instance by default
+	pub(super) path: Option<PathBuf>,
+
+	/// The process ID of the instance, `$YAZI_PID` or the only one running by default
+	#[arg(long)]
+	pub(super) pid: Option<u32>,
+}
+
+#[derive(Debug, clap::Args)]
+pub(super) struct CommandSubscribe {
+	/// The process ID of the instance, `$YAZI_PID` or the only one running by default
+	#[arg(long)]
+	pub(super) pid: Option<u32>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(super) enum What {
+	/// The cwd of the active tab
+	Cwd,
+	/// The hovered file of the active tab
+	Hovered,
+	/// The selected files of the active tab
+	Selection,
+	/// The cwd, hovered file and label of each tab
+	Tabs,
+}
+
+impl What {
+	pub(super) fn as_str(self) -> &'static str {
+		match self {
+			Self::Cwd => "cwd",
+			Self::Hovered => "hovered",
+			Self::Selection => "selection",
+			Self::Tabs => "tabs",
+		}
+	}
+}
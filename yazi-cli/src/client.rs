@@ -0,0 +1,150 @@
+use std::{env, io::{BufRead, BufReader, Write}};
+
+use anyhow::{bail, Context, Result};
+use serde_json::{json, Value};
+use yazi_shared::pid_alive;
+
+// The socket of an instance, or its named pipe on Windows, opened as a file
+#[cfg(unix)]
+type Stream = std::os::unix::net::UnixStream;
+#[cfg(windows)]
+type Stream = std::fs::File;
+
+pub(super) struct Client;
+
+impl Client {
+	/// Calls the method on the instance, and returns the result of it.
+	pub(super) fn call(pid: Option<u32>, method: &str, params: Value) -> Result<Value> {
+		let (pid, mut stream) = Self::connect(pid)?;
+		Self::request(pid, &mut stream, method, params)
+	}
+
+	/// Subscribes to the changes of the state of the instance, until it's gone.
+	pub(super) fn subscribe(pid: Option<u32>, mut f: impl FnMut(Value)) -> Result<()> {
+		let (pid, mut stream) = Self::connect(pid)?;
+		if Self::request(pid, &mut stream, "subscribe", Value::Null)? != Value::Bool(true) {
+			bail!("The instance {pid} doesn't tell the changes of it");
+		}
+
+		for line in BufReader::new(stream).lines() {
+			let mut note: Value = serde_json::from_str(&line?)?;
+			if note["method"] == "event" {
+				f(note["params"].take());
+			}
+		}
+		Ok(())
+	}
+
+	fn connect(pid: Option<u32>) -> Result<(u32, Stream)> {
+		// Whoever else could write to it could have planted a socket of theirs
+		#[cfg(unix)]
+		{
+			let dir = yazi_shared::instances_dir();
+			if !yazi_shared::is_private_dir(&dir) {
+				bail!("Refusing to connect, as {} isn't private to the user", dir.display());
+			}
+		}
+
+		let pid = Self::pid(pid)?;
+		let stream = Self::open(pid).with_context(|| {
+			format!("Failed to connect to the instance {pid}, is `server = true` in its yazi.toml?")
+		})?;
+		Ok((pid, stream))
+	}
+
+	fn request(pid: u32, stream: &mut Stream, method: &str, params: Value) -> Result<Value> {
+		let req = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+		writeln!(stream, "{req}")?;
+
+		// A byte at a time, for nothing after the response to be read ahead and lost
+		let mut line = vec![];
+		for b in std::io::Read::bytes(&*stream) {
+			match b? {
+				b'\n' => break,
+				b => line.push(b),
+			}
+		}
+		if line.is_empty() {
+			bail!("The instance {pid} closed the connection without answering");
+		}
+
+		let mut resp: Value = serde_json::from_slice(&line)?;
+		if let Some(e) = resp.get("error") {
+			bail!("{}", e["message"].as_str().unwrap_or("Unknown error"));
+		}
+		Ok(resp["result"].take())
+	}
+
+	/// The process IDs of the instances running, that can be connected to,
+	/// removing the sockets left by those gone without cleaning up.
+	#[cfg(unix)]
+	pub(super) fn instances() -> Vec<u32> {
+		use std::fs;
+
+		use yazi_shared::{instances_dir, is_private_dir};
+
+		let dir = instances_dir();
+		let Some(it) = is_private_dir(&dir).then(|| fs::read_dir(&dir).ok()).flatten() else {
+			return vec![];
+		};
+
+		let mut pids: Vec<_> = it
+			.flatten()
+			.filter_map(|e| e.file_name().to_str()?.strip_suffix(".sock")?.parse().ok())
+			.filter(|&pid| {
+				if pid_alive(pid) {
+					return Self::open(pid).is_ok();
+				}
+				fs::remove_file(dir.join(format!("{pid}.sock"))).ok();
+				false
+			})
+			.collect();
+		pids.sort_unstable();
+		pids
+	}
+
+	/// The process IDs of the instances running, by the named pipes `yazi-<pid>`.
+	#[cfg(windows)]
+	pub(super) fn instances() -> Vec<u32> {
+		let Ok(it) = std::fs::read_dir(r"\\.\pipe\") else {
+			return vec![];
+		};
+
+		let mut pids: Vec<_> = it
+			.flatten()
+			.filter_map(|e| e.file_name().to_str()?.strip_prefix("yazi-")?.parse().ok())
+			.filter(|&pid| pid_alive(pid))
+			.collect();
+		pids.sort_unstable();
+		pids.dedup();
+		pids
+	}
+
+	fn pid(pid: Option<u32>) -> Result<u32> {
+		let pid = pid.or_else(|| env::var("YAZI_PID").ok()?.parse().ok());
+		if let Some(pid) = pid {
+			return Ok(pid);
+		}
+
+		match Self::instances()[..] {
+			[pid] => Ok(pid),
+			[] => bail!("No instance of yazi is running"),
+			ref pids => {
+				let pids: Vec<_> = pids.iter().map(|p| p.to_string()).collect();
+				bail!("{} instances are running, pick one with `--pid`: {}", pids.len(), pids.join(", "))
+			}
+		}
+	}
+
+	#[cfg(unix)]
+	#[inline]
+	fn open(pid: u32) -> std::io::Result<Stream> {
+		Stream::connect(yazi_shared::instances_dir().join(format!("{pid}.sock")))
+	}
+
+	#[cfg(windows)]
+	#[inline]
+	fn open(pid: u32) -> std::io::Result<Stream> {
+		std::fs::OpenOptions::new().read(true).write(true).open(yazi_shared::instance_pipe(pid))
+	}
+}
@@ -0,0 +1,45 @@
+mod args;
+mod client;
+
+use args::*;
+use clap::Parser;
+use yazi_shared::fs::lossless::Lossless;
+
+fn main() -> anyhow::Result<()> {
+	let args = Args::parse();
+
+	match args.command {
+		Command::Get(cmd) => {
+			let params = serde_json::json!({ "what": cmd.what.as_str() });
+			let result = client::Client::call(cmd.pid, "get", params)?;
+			println!("{}", serde_json::to_string_pretty(&result)?);
+		}
+		Command::EmitTo(cmd) => {
+			let params = serde_json::json!({ "cmd": cmd.cmd });
+			client::Client::call(Some(cmd.pid), "emit", params)?;
+		}
+		Command::ExportState(cmd) => {
+			let path = cmd.path.map(std::path::absolute).transpose()?;
+			let params = serde_json::json!({ "path": path.as_deref().map(Lossless) });
+			let state = client::Client::call(cmd.pid, "export_state", params)?;
+			if path.is_none() {
+				println!("{}", serde_json::to_string_pretty(&state)?);
+			}
+		}
+		Command::ListInstances => {
+			let list: Vec<_> = client::Client::instances()
+				.into_iter()
+				.map(|pid| {
+					let cwd = client::Client::call(Some(pid), "get", serde_json::json!({ "what": "cwd" }));
+					serde_json::json!({ "pid": pid, "cwd": cwd.ok() })
+				})
+				.collect();
+			println!("{}", serde_json::to_string_pretty(&list)?);
+		}
+		Command::Subscribe(cmd) => {
+			client::Client::subscribe(cmd.pid, |event| println!("{event}"))?;
+		}
+	}
+
+	Ok(())
+}
@@ -1,26 +1,67 @@
 use std::path::PathBuf;
 
-use clap::{command, Parser};
+use clap::Parser;
 
 #[derive(Debug, Parser)]
 #[command(name = "yazi")]
 pub struct Args {
-	/// Set the current working entry
-	#[arg(index = 1)]
-	pub entry: Option<PathBuf>,
+	/// Set the current working entries, a tab each, of a directory, or a file to reveal in its own
+	#[arg(index = 1, num_args = 0..)]
+	pub entries: Vec<PathBuf>,
+
+	/// Reveal the file in the instance this is run from the shell of, or in a tab of its own,
+	/// for which that instance has to have `server = true` under `[manager]`
+	#[arg(long)]
+	pub reveal: Option<PathBuf>,
 
 	/// Write the cwd on exit to this file
 	#[arg(long)]
 	pub cwd_file:     Option<PathBuf>,
+	/// Write the cwd, hovered file and selection on exit to this file, as JSON
+	#[arg(long)]
+	pub state_file:   Option<PathBuf>,
 	/// Write the selected files on open emitted by the chooser mode
 	#[arg(long)]
 	pub chooser_file: Option<PathBuf>,
 
+	/// Write the changes of the cwd, hovered and selected files, tabs and tasks to this file,
+	/// one JSON object a line, for external tools to follow. Not `-`, as the UI is drawn to
+	/// stdout, so give a FIFO to read them from a pipe
+	#[arg(long, value_parser = not_stdout)]
+	pub events_json:  Option<PathBuf>,
+
 	/// Clear the cache directory
 	#[arg(long, action)]
 	pub clear_cache: bool,
 
+	/// Check the config files for mistakes, report them with their locations, and exit
+	#[arg(long, action)]
+	pub check_config: bool,
+
+	/// Report the time spent in each phase of the startup, and exit after the first frame
+	#[arg(long, action)]
+	pub profile_startup: bool,
+
+	/// Record the keys pressed, the text pasted and the commands run to this file, a JSON a line
+	#[arg(long)]
+	pub record: Option<PathBuf>,
+
+	/// Replay the keys and the text recorded with `--record`, at the pace they were in
+	#[arg(long, requires = "sandbox")]
+	pub replay:  Option<PathBuf>,
+	/// Replay in this directory, a copy of the one recorded in, for its files not to be touched
+	#[arg(long)]
+	pub sandbox: Option<PathBuf>,
+
 	/// Print version
 	#[arg(short = 'V', long)]
 	pub version: bool,
 }
+
+fn not_stdout(s: &str) -> Result<PathBuf, String> {
+	if s == "-" {
+		Err("stdout is where the UI is drawn, give a file or a FIFO instead".to_owned())
+	} else {
+		Ok(PathBuf::from(s))
+	}
+}
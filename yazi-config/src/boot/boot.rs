@@ -1,4 +1,4 @@
-use std::{ffi::OsString, fs, path::{Path, PathBuf}, process};
+use std::{env, ffi::OsString, fs, path::{Path, PathBuf}, process};
 
 use clap::Parser;
 use serde::Serialize;
@@ -9,38 +9,74 @@ use crate::{Xdg, ARGS};
 
 #[derive(Debug, Serialize)]
 pub struct Boot {
-	pub cwd:  PathBuf,
-	pub file: Option<OsString>,
+	// The directory of each tab to open, and the file to reveal in it if any
+	pub cwds:  Vec<PathBuf>,
+	pub files: Vec<Option<OsString>>,
 
 	pub config_dir: PathBuf,
 	pub plugin_dir: PathBuf,
 	pub state_dir:  PathBuf,
+
+	// The directories of the overlays laid onto the config, by the hostname and `$YAZI_PROFILE`
+	pub overlays: Vec<PathBuf>,
 }
 
 impl Boot {
-	fn parse_entry(entry: Option<&Path>) -> (PathBuf, Option<OsString>) {
+	fn parse_entry(entry: Option<&Path>, reveal: bool) -> (PathBuf, Option<OsString>) {
 		let entry = match entry {
 			Some(p) => expand_path(p),
 			None => return (current_cwd().unwrap(), None),
 		};
 
 		let parent = entry.parent();
-		if parent.is_none() || entry.is_dir() {
+		if parent.is_none() || (!reveal && entry.is_dir()) {
 			return (entry, None);
 		}
 
-		return (parent.unwrap().to_owned(), Some(entry.file_name().unwrap().to_owned()));
+		(parent.unwrap().to_owned(), Some(entry.file_name().unwrap().to_owned()))
+	}
+
+	/// The overlays that exist, from the lowest precedence to the highest: `hosts/<hostname>`,
+	/// or `hosts/<name>` by the hostname up to the first dot, then `profiles/<$YAZI_PROFILE>`.
+	fn overlays(config_dir: &Path) -> Vec<PathBuf> {
+		#[cfg(unix)]
+		let host = yazi_shared::hostname().ok();
+		#[cfg(windows)]
+		let host = env::var("COMPUTERNAME").ok();
+
+		let host = host.filter(|h| !h.is_empty()).and_then(|h| {
+			let short = h.split('.').next().unwrap_or_default().to_owned();
+			[h, short].into_iter().map(|h| config_dir.join("hosts").join(h)).find(|p| p.is_dir())
+		});
+		let profile = env::var("YAZI_PROFILE").ok().filter(|s| !s.is_empty());
+
+		host
+			.into_iter()
+			.chain(profile.map(|p| config_dir.join("profiles").join(p)).filter(|p| p.is_dir()))
+			.collect()
 	}
 }
 
 impl Default for Boot {
 	fn default() -> Self {
-		let (cwd, file) = Self::parse_entry(ARGS.entry.as_deref());
+		// A replay is always in its sandbox, where the recorded keys work the same
+		let sandbox = ARGS.sandbox.as_ref().filter(|_| ARGS.replay.is_some());
+		let (cwds, files) = if let Some(sandbox) = sandbox {
+			(vec![expand_path(sandbox)], vec![None])
+		} else if ARGS.entries.is_empty() && ARGS.reveal.is_none() {
+			(vec![current_cwd().unwrap()], vec![None])
+		} else {
+			let entries = ARGS.entries.iter().map(|p| Self::parse_entry(Some(p), false));
+			entries.chain(ARGS.reveal.iter().map(|p| Self::parse_entry(Some(p), true))).unzip()
+		};
+
+		let config_dir = Xdg::config_dir().unwrap();
 		let boot = Self {
-			cwd,
-			file,
+			cwds,
+			files,
 
-			config_dir: Xdg::config_dir().unwrap(),
+			overlays: Self::overlays(&config_dir),
+			config_dir,
 			plugin_dir: Xdg::plugin_dir().unwrap(),
 			state_dir: Xdg::state_dir().unwrap(),
 		};
@@ -0,0 +1,460 @@
+use std::{fs, io, iter, ops::Range, path::PathBuf, process};
+
+use serde::de::DeserializeOwned;
+use toml::{Spanned, Table, Value};
+use validator::{Validate, ValidationErrors};
+
+use super::{locate, Diagnostic, Node, Severity};
+use crate::{keymap::{Control, Keymap}, log::Log, manager::Manager, open::Open, plugin::PluginRule, popup::{Input, Select}, preview::PreviewShadow, protect::Protect, tasks::Tasks, theme::Theme, which::Which, Preset, ARGS, BOOT};
+
+// Deserializes the parts of a merged config, as they're loaded on startup
+type Probe = fn(&Table) -> Result<(), String>;
+
+const YAZI_PROBES: [Probe; 10] = [
+	|d| valid(section::<Manager>(d, "manager")?.validate()),
+	|d| valid(section::<PreviewShadow>(d, "preview")?.validate()),
+	|d| whole::<Open>(d).map(|_| ()),
+	|d| valid(section::<Tasks>(d, "tasks")?.validate()),
+	|d| section::<Protect>(d, "protect").map(|_| ()),
+	|d| {
+		section::<Table>(d, "plugin")?.into_iter().try_for_each(|(_, v)| {
+			v.try_into::<Vec<PluginRule>>().map(|_| ()).map_err(|e| e.message().to_owned())
+		})
+	},
+	|d| section::<Input>(d, "input").map(|_| ()),
+	|d| section::<Select>(d, "select").map(|_| ()),
+	|d| section::<Which>(d, "which").map(|_| ()),
+	|d| whole::<Log>(d).map(|_| ()),
+];
+
+const KEYMAP_PROBES: [Probe; 1] = [|d| whole::<Keymap>(d).map(|_| ())];
+
+const THEME_PROBES: [Probe; 1] = [|d| {
+	let theme = whole::<Theme>(d)?;
+	valid(theme.manager.validate())?;
+	valid(theme.which.validate())
+}];
+
+const PLUGIN_RULE: &[&str] = &["cond", "name", "mime", "exec", "sync", "multi", "prio"];
+
+// The keys of the tables in the array at each `section.key` of a file, `*` for any name
+const ITEMS: [(&str, &str, &[&str]); 10] = [
+	("yazi.toml", "manager.columns", &["name", "align"]),
+	("yazi.toml", "opener.*", &["exec", "block", "orphan", "capture", "desc", "for"]),
+	("yazi.toml", "open.rules", &["name", "mime", "use", "ssh", "gui", "os", "desktop"]),
+	("yazi.toml", "plugin.preloaders", PLUGIN_RULE),
+	("yazi.toml", "plugin.previewers", PLUGIN_RULE),
+	("yazi.toml", "plugin.spotters", PLUGIN_RULE),
+	("yazi.toml", "tasks.remove_rules", &["mount", "fstype", "action"]),
+	("keymap.toml", "*.keymap", &["on", "exec", "desc", "group"]),
+	("theme.toml", "filetype.rules", &[
+		"is", "name", "mime", "prio", "icon", "fg", "bg", "bold", "dim", "italic", "underline", "blink",
+		"blink_rapid", "reversed", "hidden", "crossed",
+	]),
+	("theme.toml", "icon.rules", &["name", "text", "fg"]),
+];
+
+// The arrays that `prepend_*` and `append_*` can add to
+const MIXABLE: [(&str, &str); 5] = [
+	("yazi.toml", "plugin.preloaders"),
+	("yazi.toml", "plugin.previewers"),
+	("yazi.toml", "plugin.spotters"),
+	("keymap.toml", "*.keymap"),
+	("theme.toml", "icon.rules"),
+];
+
+// The sections whose keys are named by the user
+const NAMED: [(&str, &str); 1] = [("yazi.toml", "opener")];
+
+/// The mistakes in the config files, found before they're loaded.
+///
+/// Besides the syntax and type errors that would stop yazi from starting, it warns about
+/// the unknown keys, the keybindings shadowed by others, and the opener rules never
+/// reached, which would otherwise be ignored silently.
+#[derive(Debug)]
+pub struct Check {
+	pub diagnostics: Vec<Diagnostic>,
+}
+
+struct Source {
+	name:   &'static str,
+	path:   PathBuf,
+	src:    String,
+	preset: Table,
+}
+
+impl Check {
+	/// Checks the config, printing the problems and exiting if it's `--check-config`,
+	/// or if there's an error, which yazi can't start with.
+	pub fn run_or_exit() -> Self {
+		let check = Self::run();
+		let errors = check.count(Severity::Error);
+
+		if ARGS.check_config {
+			check.report();
+			match (errors, check.count(Severity::Warning)) {
+				(0, 0) => println!("No problems found in {}", BOOT.config_dir.display()),
+				(e, w) => println!("\n{e} error(s), {w} warning(s)"),
+			}
+			process::exit((errors > 0) as i32);
+		} else if errors > 0 {
+			check.report();
+			process::exit(1);
+		}
+		check
+	}
+
+	#[inline]
+	pub fn count(&self, severity: Severity) -> usize {
+		self.diagnostics.iter().filter(|d| d.severity == severity).count()
+	}
+
+	fn report(&self) {
+		for d in &self.diagnostics {
+			println!("{d}");
+		}
+	}
+
+	fn run() -> Self {
+		let mut check = Self { diagnostics: vec![] };
+		let files: [(_, _, &[Probe]); 3] = [
+			("yazi.toml", include_str!("../../preset/yazi.toml"), &YAZI_PROBES),
+			("keymap.toml", include_str!("../../preset/keymap.toml"), &KEYMAP_PROBES),
+			("theme.toml", include_str!("../../preset/theme.toml"), &THEME_PROBES),
+		];
+
+		// Each overlay is checked by itself, as it'd be without the others
+		for dir in iter::once(&BOOT.config_dir).chain(&BOOT.overlays) {
+			for (name, preset, probes) in files {
+				check.file(dir.join(name), name, preset, probes);
+			}
+		}
+		check
+	}
+
+	fn file(&mut self, path: PathBuf, name: &'static str, preset: &str, probes: &[Probe]) {
+		let src = match fs::read_to_string(&path) {
+			Ok(s) => s,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => return,
+			Err(e) => {
+				let message = format!("cannot be read: {e}");
+				let d = Diagnostic { severity: Severity::Error, file: path, line: 0, col: 0, message };
+				return self.diagnostics.push(d);
+			}
+		};
+
+		let before = self.diagnostics.len();
+		self.source(Source { name, path, src, preset: preset.parse().unwrap() }, probes);
+		self.diagnostics[before..].sort_by_key(|d| (d.line, d.col));
+	}
+
+	fn source(&mut self, src: Source, probes: &[Probe]) {
+		let user = match toml::from_str::<Node>(&src.src) {
+			Ok(n) => n,
+			Err(e) => {
+				let message = e.message().trim().replace('\n', ", ");
+				return self.push(Severity::Error, &src, e.span(), message);
+			}
+		};
+
+		self.keys(&src, &user);
+
+		let Value::Table(mut merged) = user.value() else { unreachable!() };
+		Preset::merge(&mut merged, &src.preset, 2);
+		if let Err(e) = Self::probe(probes, &merged) {
+			self.locate_errors(&src, &user, probes, e);
+		}
+
+		match src.name {
+			"yazi.toml" => self.open_rules(&src, &user, &merged),
+			"keymap.toml" => self.keybindings(&src, &user),
+			_ => {}
+		}
+	}
+
+	fn push(&mut self, severity: Severity, src: &Source, span: Option<Range<usize>>, msg: String) {
+		let (line, col) = span.map_or((0, 0), |s| locate(&src.src, s));
+		self.diagnostics.push(Diagnostic { severity, file: src.path.clone(), line, col, message: msg });
+	}
+
+	/// Warns about the sections and keys unknown to the preset, and those of the tables in
+	/// the arrays, such as the keybindings and rules.
+	fn keys(&mut self, src: &Source, user: &Node) {
+		for (section, node) in user.entries() {
+			let name = section.get_ref().as_str();
+			let Some(preset) = src.preset.get(name) else {
+				let hint = Self::suggest(name, src.preset.keys());
+				let message = format!("unknown section `[{name}]`{hint}");
+				self.push(Severity::Warning, src, Some(section.span()), message);
+				continue;
+			};
+
+			let Some(preset) = preset.as_table() else {
+				continue;
+			};
+			if !matches!(node.get_ref(), Node::Table(_)) {
+				let message = format!("`{name}` must be a table, as `[{name}]`");
+				self.push(Severity::Error, src, Some(section.span()), message);
+				continue;
+			}
+
+			let named = NAMED.contains(&(src.name, name));
+			for (key, value) in node.get_ref().entries() {
+				let k = key.get_ref().as_str();
+				let base = ["prepend_", "append_"]
+					.into_iter()
+					.find_map(|p| k.strip_prefix(p))
+					.filter(|&b| MIXABLE.iter().any(|&(f, p)| f == src.name && Self::path(p, name, b)));
+
+				let field = if named || preset.contains_key(k) {
+					k
+				} else if let Some(base) = base {
+					base
+				} else {
+					let hint = Self::suggest(k, preset.keys());
+					let message = format!("unknown key `{k}` in `[{name}]`{hint}");
+					self.push(Severity::Warning, src, Some(key.span()), message);
+					continue;
+				};
+
+				let Some(&(.., fields)) =
+					ITEMS.iter().find(|&&(f, p, _)| f == src.name && Self::path(p, name, field))
+				else {
+					continue;
+				};
+				for item in value.get_ref().items() {
+					for (f, _) in item.get_ref().entries() {
+						if !fields.contains(&f.get_ref().as_str()) {
+							let (f, hint) = (f.get_ref(), Self::suggest(f.get_ref(), fields));
+							let message = format!("unknown key `{f}` in the `{k}` of `[{name}]`{hint}");
+							self.push(Severity::Warning, src, Some(item.span()), message);
+						}
+					}
+				}
+			}
+		}
+	}
+
+	/// Finds the values that the merged config fails to load by, by loading the preset with
+	/// each of them in turn, or each table of an array.
+	fn locate_errors(&mut self, src: &Source, user: &Node, probes: &[Probe], error: String) {
+		let before = self.diagnostics.len();
+		for (section, node) in user.entries() {
+			let Some(preset) = src.preset.get(section.get_ref()).and_then(|v| v.as_table()) else {
+				continue;
+			};
+
+			for (key, value) in node.get_ref().entries() {
+				let overlay = |v: Value| {
+					let (mut doc, mut table) = (src.preset.clone(), preset.clone());
+					table.insert(key.get_ref().clone(), v);
+					doc.insert(section.get_ref().clone(), Value::Table(table));
+					Self::probe(probes, &doc)
+				};
+
+				let invalid = |e| format!("invalid `{}` in `[{}]`: {e}", key.get_ref(), section.get_ref());
+				let items = value.get_ref().items();
+				if !items.is_empty() && items.iter().all(|i| matches!(i.get_ref(), Node::Table(_))) {
+					for item in items {
+						if let Err(e) = overlay(Value::Array(vec![item.get_ref().value()])) {
+							self.push(Severity::Error, src, Some(item.span()), invalid(e));
+						}
+					}
+				} else if let Err(e) = overlay(value.get_ref().value()) {
+					self.push(Severity::Error, src, Some(key.span()), invalid(e));
+				}
+			}
+		}
+
+		// None of the values fails by itself, but only with the others
+		if self.diagnostics.len() == before {
+			self.push(Severity::Error, src, None, error);
+		}
+	}
+
+	/// Warns about the keybindings that can't be reached, as another one before takes their
+	/// keys, either one of the user's or of the preset.
+	fn keybindings(&mut self, src: &Source, user: &Node) {
+		for (layer, preset) in &src.preset {
+			let Some(node) = user.get(layer) else {
+				continue;
+			};
+
+			let control = |n: &Spanned<Node>| {
+				n.get_ref().value().try_into::<Control>().ok().map(|c| (c, Some(n.span())))
+			};
+			let list = |k: &str| node.get_ref().get(k).map(|n| n.get_ref().items()).unwrap_or_default();
+
+			let mut all: Vec<_> = list("prepend_keymap").iter().filter_map(control).collect();
+			if node.get_ref().get("keymap").is_some() {
+				all.extend(list("keymap").iter().filter_map(control));
+			} else {
+				let preset = preset.get("keymap").and_then(|v| v.as_array()).into_iter().flatten();
+				all.extend(preset.filter_map(|v| v.clone().try_into::<Control>().ok()).map(|c| (c, None)));
+			}
+			all.extend(list("append_keymap").iter().filter_map(control));
+
+			// A single key runs at once, and a sequence runs as soon as it's typed
+			let mut shadowed = vec![None; all.len()];
+			for j in 0..all.len() {
+				for i in 0..j {
+					let (a, b) = (&all[i].0.on, &all[j].0.on);
+					if a.is_empty() || b.is_empty() || a[0] != b[0] {
+						continue;
+					} else if a.len() == 1 || b.len() == 1 || b.starts_with(a) {
+						shadowed[j].get_or_insert(i);
+					} else if a.starts_with(b) {
+						shadowed[i].get_or_insert(j);
+					}
+				}
+			}
+
+			for (j, by) in shadowed.into_iter().enumerate() {
+				let (Some(i), Some(span)) = (by, all[j].1.clone()) else {
+					continue;
+				};
+				let by = match all[i].1.clone() {
+					Some(s) => format!("`{}` at line {}", all[i].0.on(), locate(&src.src, s).0),
+					None => format!("`{}` of the preset", all[i].0.on()),
+				};
+				let on = all[j].0.on();
+				let message = format!("`{on}` in `[{layer}]` is never reached, as {by} takes its keys");
+				self.push(Severity::Warning, src, Some(span), message);
+			}
+		}
+	}
+
+	/// Warns about the opener rules never reached, as one before matches every file they do,
+	/// and those using openers not defined.
+	fn open_rules(&mut self, src: &Source, user: &Node, merged: &Table) {
+		let Some(rules) = user.get("open").and_then(|n| n.get_ref().get("rules")) else {
+			return;
+		};
+
+		let openers = merged.get("opener").and_then(|v| v.as_table());
+		// The lines of the first rules matching every file, directory, or both
+		let (mut files, mut dirs, mut all) = (None, None, None);
+		let mut seen = vec![];
+
+		for rule in rules.get_ref().items() {
+			let node = rule.get_ref();
+			let line = locate(&src.src, rule.span()).0;
+			let pattern = |k: &str| {
+				node.get(k).and_then(|n| n.get_ref().as_str()).map(|s| s.trim_start_matches("\\s"))
+			};
+			let (name, mime) = (pattern("name"), pattern("mime"));
+
+			let by = match (name, mime) {
+				_ if all.is_some() => all,
+				(Some(n), None) if n.ends_with('/') => dirs,
+				(Some(_), None) => files,
+				_ => None,
+			};
+			let same = || seen.iter().find(|&&(n, m, _)| (n, m) == (name, mime)).map(|t| t.2);
+			if let Some(by) = by.or_else(same) {
+				let message = format!("the rule is never reached, as the one at line {by} covers it");
+				self.push(Severity::Warning, src, Some(rule.span()), message);
+			}
+
+			// One applying only in some environments doesn't cover the others
+			let conditional = ["ssh", "gui", "os", "desktop"].into_iter().any(|k| node.get(k).is_some());
+			if !conditional {
+				match (name, mime) {
+					(_, Some("*")) => _ = all.get_or_insert(line),
+					(Some("*"), _) => _ = files.get_or_insert(line),
+					(Some("*/"), _) => _ = dirs.get_or_insert(line),
+					_ => {}
+				}
+				if let (None, Some(f), Some(d)) = (all, files, dirs) {
+					all = Some(f.max(d));
+				}
+				seen.push((name, mime, line));
+			}
+
+			let Some(use_) = node.get("use") else {
+				continue;
+			};
+			let uses: Vec<_> = match use_.get_ref() {
+				Node::Array(a) => a.iter().filter_map(|u| u.get_ref().as_str()).collect(),
+				n => n.as_str().into_iter().collect(),
+			};
+			for u in uses.into_iter().filter(|&u| openers.is_none_or(|o| !o.contains_key(u))) {
+				let hint = Self::suggest(u, openers.into_iter().flat_map(|o| o.keys()));
+				let message = format!("the opener `{u}` isn't defined in `[opener]`{hint}");
+				self.push(Severity::Warning, src, Some(use_.span()), message);
+			}
+		}
+	}
+
+	#[inline]
+	fn probe(probes: &[Probe], doc: &Table) -> Result<(), String> {
+		probes.iter().try_for_each(|p| p(doc))
+	}
+
+	#[inline]
+	fn path(pattern: &str, section: &str, key: &str) -> bool {
+		let (s, k) = pattern.split_once('.').unwrap_or_default();
+		(s == "*" || s == section) && (k == "*" || k == key)
+	}
+
+	/// The closest of the candidates to a misspelled name, as a hint.
+	fn suggest<I>(name: &str, candidates: I) -> String
+	where
+		I: IntoIterator,
+		I::Item: AsRef<str>,
+	{
+		candidates
+			.into_iter()
+			.map(|c| (Self::distance(name, c.as_ref()), c))
+			.filter(|(d, _)| *d <= 2)
+			.min_by_key(|(d, _)| *d)
+			.map(|(_, c)| format!(", did you mean `{}`?", c.as_ref()))
+			.unwrap_or_default()
+	}
+
+	// The Levenshtein distance
+	fn distance(a: &str, b: &str) -> usize {
+		let b: Vec<_> = b.chars().collect();
+		let mut row: Vec<_> = (0..=b.len()).collect();
+		for (i, ca) in a.chars().enumerate() {
+			let mut prev = row[0];
+			row[0] = i + 1;
+			for (j, &cb) in b.iter().enumerate() {
+				let cur = row[j + 1];
+				row[j + 1] = (prev + (ca != cb) as usize).min(row[j] + 1).min(cur + 1);
+				prev = cur;
+			}
+		}
+		row[b.len()]
+	}
+}
+
+fn section<T: DeserializeOwned>(doc: &Table, name: &str) -> Result<T, String> {
+	let value = doc.get(name).cloned().unwrap_or_else(|| Value::Table(Table::new()));
+	value.try_into().map_err(|e: toml::de::Error| e.message().to_owned())
+}
+
+#[inline]
+fn whole<T: DeserializeOwned>(doc: &Table) -> Result<T, String> {
+	Value::Table(doc.clone()).try_into().map_err(|e: toml::de::Error| e.message().to_owned())
+}
+
+#[inline]
+fn valid(res: Result<(), ValidationErrors>) -> Result<(), String> { res.map_err(|e| e.to_string()) }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_suggest() {
+		assert_eq!(Check::distance("show_hiden", "show_hidden"), 1);
+		assert_eq!(Check::distance("mangaer", "manager"), 2);
+		assert_eq!(Check::suggest("sort_bi", ["sort_by", "sort_reverse"]), ", did you mean `sort_by`?");
+		assert_eq!(Check::suggest("ratios", ["linemode"]), "");
+
+		assert!(Check::path("*.keymap", "manager", "keymap"));
+		assert!(Check::path("opener.*", "opener", "edit"));
+		assert!(!Check::path("open.rules", "opener", "rules"));
+	}
+}
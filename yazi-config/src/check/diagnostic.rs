@@ -0,0 +1,31 @@
+use std::{fmt, path::PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+	Error,
+	Warning,
+}
+
+/// A mistake in a config file, at the line and column it's at, or 0 for the whole file.
+#[derive(Debug)]
+pub struct Diagnostic {
+	pub severity: Severity,
+	pub file:     PathBuf,
+	pub line:     usize,
+	pub col:      usize,
+	pub message:  String,
+}
+
+impl fmt::Display for Diagnostic {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let severity = match self.severity {
+			Severity::Error => "error",
+			Severity::Warning => "warning",
+		};
+		if self.line == 0 {
+			write!(f, "{}: {severity}: {}", self.file.display(), self.message)
+		} else {
+			write!(f, "{}:{}:{}: {severity}: {}", self.file.display(), self.line, self.col, self.message)
+		}
+	}
+}
@@ -0,0 +1,7 @@
+mod check;
+mod diagnostic;
+mod node;
+
+pub use check::*;
+pub use diagnostic::*;
+use node::*;
@@ -0,0 +1,122 @@
+use std::{fmt, ops::Range};
+
+use serde::{de::{MapAccess, SeqAccess, Visitor}, Deserialize, Deserializer};
+use toml::{Spanned, Table, Value};
+
+/// A TOML document, or a value in it, that remembers where each key and value of it is,
+/// for the diagnostics to point at.
+#[derive(Debug)]
+pub(super) enum Node {
+	Table(Vec<(Spanned<String>, Spanned<Node>)>),
+	Array(Vec<Spanned<Node>>),
+	Value(Value),
+}
+
+impl Node {
+	pub(super) fn get(&self, key: &str) -> Option<&Spanned<Node>> {
+		match self {
+			Self::Table(t) => t.iter().find(|(k, _)| k.get_ref() == key).map(|(_, v)| v),
+			_ => None,
+		}
+	}
+
+	#[inline]
+	pub(super) fn entries(&self) -> &[(Spanned<String>, Spanned<Node>)] {
+		match self {
+			Self::Table(t) => t,
+			_ => &[],
+		}
+	}
+
+	#[inline]
+	pub(super) fn items(&self) -> &[Spanned<Node>] {
+		match self {
+			Self::Array(a) => a,
+			_ => &[],
+		}
+	}
+
+	#[inline]
+	pub(super) fn as_str(&self) -> Option<&str> {
+		match self {
+			Self::Value(v) => v.as_str(),
+			_ => None,
+		}
+	}
+
+	/// The plain value, without the locations.
+	pub(super) fn value(&self) -> Value {
+		match self {
+			Self::Table(t) => Value::Table(
+				t.iter().map(|(k, v)| (k.get_ref().clone(), v.get_ref().value())).collect::<Table>(),
+			),
+			Self::Array(a) => Value::Array(a.iter().map(|v| v.get_ref().value()).collect()),
+			Self::Value(v) => v.clone(),
+		}
+	}
+}
+
+impl<'de> Deserialize<'de> for Node {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		struct NodeVisitor;
+
+		impl<'de> Visitor<'de> for NodeVisitor {
+			type Value = Node;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a TOML value")
+			}
+
+			fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> { Ok(Node::Value(v.into())) }
+
+			fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> { Ok(Node::Value(v.into())) }
+
+			fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+				Ok(Node::Value((v as i64).into()))
+			}
+
+			fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> { Ok(Node::Value(v.into())) }
+
+			fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> { Ok(Node::Value(v.into())) }
+
+			fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+				Ok(Node::Value(v.into()))
+			}
+
+			fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+			where
+				A: SeqAccess<'de>,
+			{
+				let mut items = vec![];
+				while let Some(item) = seq.next_element()? {
+					items.push(item);
+				}
+				Ok(Node::Array(items))
+			}
+
+			fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+			where
+				A: MapAccess<'de>,
+			{
+				let mut entries = vec![];
+				while let Some(key) = map.next_key()? {
+					entries.push((key, map.next_value()?));
+				}
+				Ok(Node::Table(entries))
+			}
+		}
+
+		deserializer.deserialize_any(NodeVisitor)
+	}
+}
+
+/// The 1-based line and column of the byte offset in the source.
+pub(super) fn locate(src: &str, span: Range<usize>) -> (usize, usize) {
+	let before = &src[..span.start.min(src.len())];
+	let line = before.matches('\n').count() + 1;
+	let col = before.rsplit('\n').next().map_or(0, |s| s.chars().count()) + 1;
+	(line, col)
+}
@@ -4,13 +4,16 @@ use serde::Deserialize;
 use yazi_shared::event::Cmd;
 
 use super::Key;
+use crate::tr_config;
 
 #[derive(Debug, Default, Deserialize)]
 pub struct Control {
-	pub on:   Vec<Key>,
+	pub on:    Vec<Key>,
 	#[serde(deserialize_with = "super::exec_deserialize")]
-	pub exec: Vec<Cmd>,
-	pub desc: Option<String>,
+	pub exec:  Vec<Cmd>,
+	pub desc:  Option<String>,
+	// The category in the help, instead of the one of its command
+	pub group: Option<String>,
 }
 
 impl Control {
@@ -29,15 +32,20 @@ impl Control {
 		self.exec.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; ")
 	}
 
+	/// The description, in the language in use if it's a preset one.
 	#[inline]
-	pub fn desc_or_exec(&self) -> Cow<str> {
-		if let Some(ref s) = self.desc { Cow::Borrowed(s) } else { self.exec().into() }
+	pub fn desc(&self) -> Option<&str> { self.desc.as_deref().map(|d| tr_config!(d)) }
+
+	#[inline]
+	pub fn desc_or_exec(&self) -> Cow<'_, str> {
+		if let Some(s) = self.desc() { Cow::Borrowed(s) } else { self.exec().into() }
 	}
 
 	#[inline]
 	pub fn contains(&self, s: &str) -> bool {
 		let s = s.to_lowercase();
-		self.desc.as_ref().map(|d| d.to_lowercase().contains(&s)) == Some(true)
+		let desc = |d: &str| d.to_lowercase().contains(&s);
+		self.desc.as_deref().is_some_and(|d| desc(d) || desc(tr_config!(d)))
 			|| self.exec().to_lowercase().contains(&s)
 			|| self.on().to_lowercase().contains(&s)
 	}
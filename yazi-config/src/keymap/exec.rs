@@ -1,6 +1,6 @@
 use std::fmt;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use serde::{de::{self, Visitor}, Deserializer};
 use yazi_shared::event::Cmd;
 
@@ -10,26 +10,6 @@ where
 {
 	struct ExecVisitor;
 
-	fn parse(s: &str) -> Result<Cmd> {
-		let s = shell_words::split(s)?;
-		if s.is_empty() {
-			bail!("`exec` cannot be empty");
-		}
-
-		let mut cmd = Cmd { name: s[0].clone(), ..Default::default() };
-		for arg in s.into_iter().skip(1) {
-			if arg.starts_with("--") {
-				let mut arg = arg.splitn(2, '=');
-				let key = arg.next().unwrap().trim_start_matches('-');
-				let val = arg.next().unwrap_or("").to_string();
-				cmd.named.insert(key.to_string(), val);
-			} else {
-				cmd.args.push(arg);
-			}
-		}
-		Ok(cmd)
-	}
-
 	impl<'de> Visitor<'de> for ExecVisitor {
 		type Value = Vec<Cmd>;
 
@@ -43,7 +23,7 @@ where
 		{
 			let mut cmds = vec![];
 			while let Some(value) = &seq.next_element::<String>()? {
-				cmds.push(parse(value).map_err(de::Error::custom)?);
+				cmds.push(value.parse().map_err(de::Error::custom)?);
 			}
 			if cmds.is_empty() {
 				return Err(de::Error::custom("`exec` within [keymap] cannot be empty"));
@@ -55,7 +35,7 @@ where
 		where
 			E: de::Error,
 		{
-			Ok(vec![parse(value).map_err(de::Error::custom)?])
+			Ok(vec![value.parse().map_err(de::Error::custom)?])
 		}
 	}
 
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display}, str::FromStr};
 
 use anyhow::bail;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -60,6 +60,16 @@ impl From<KeyEvent> for Key {
 	}
 }
 
+impl From<Key> for KeyEvent {
+	fn from(key: Key) -> Self {
+		let mut modifiers = KeyModifiers::empty();
+		modifiers.set(KeyModifiers::SHIFT, key.shift);
+		modifiers.set(KeyModifiers::CONTROL, key.ctrl);
+		modifiers.set(KeyModifiers::ALT, key.alt);
+		KeyEvent::new(key.code, modifiers)
+	}
+}
+
 impl FromStr for Key {
 	type Err = anyhow::Error;
 
@@ -132,11 +142,11 @@ impl TryFrom<String> for Key {
 	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
 }
 
-impl ToString for Key {
-	fn to_string(&self) -> String {
+impl Display for Key {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		if let Some(c) = self.plain() {
 			let c = if self.shift { c.to_ascii_uppercase() } else { c };
-			return if c == ' ' { "<Space>".to_string() } else { c.to_string() };
+			return if c == ' ' { write!(f, "<Space>") } else { write!(f, "{c}") };
 		}
 
 		let mut s = "<".to_string();
@@ -187,6 +197,6 @@ impl ToString for Key {
 			_ => "Unknown",
 		};
 
-		s + code + ">"
+		write!(f, "{s}{code}>")
 	}
 }
@@ -8,7 +8,12 @@ use crate::{Preset, MERGED_KEYMAP};
 pub struct Keymap {
 	pub manager:    Vec<Control>,
 	pub tasks:      Vec<Control>,
+	pub mounts:     Vec<Control>,
+	pub sidebar:    Vec<Control>,
+	pub notify:     Vec<Control>,
 	pub select:     Vec<Control>,
+	pub chmod:      Vec<Control>,
+	pub spot:       Vec<Control>,
 	pub input:      Vec<Control>,
 	pub help:       Vec<Control>,
 	pub completion: Vec<Control>,
@@ -23,7 +28,12 @@ impl<'de> Deserialize<'de> for Keymap {
 		struct Shadow {
 			manager:    Inner,
 			tasks:      Inner,
+			mounts:     Inner,
+			sidebar:    Inner,
+			notify:     Inner,
 			select:     Inner,
+			chmod:      Inner,
+			spot:       Inner,
 			input:      Inner,
 			help:       Inner,
 			completion: Inner,
@@ -44,8 +54,18 @@ impl<'de> Deserialize<'de> for Keymap {
 		#[rustfmt::skip]
 		Preset::mix(&mut shadow.tasks.keymap, shadow.tasks.prepend_keymap, shadow.tasks.append_keymap);
 		#[rustfmt::skip]
+		Preset::mix(&mut shadow.mounts.keymap, shadow.mounts.prepend_keymap, shadow.mounts.append_keymap);
+		#[rustfmt::skip]
+		Preset::mix(&mut shadow.sidebar.keymap, shadow.sidebar.prepend_keymap, shadow.sidebar.append_keymap);
+		#[rustfmt::skip]
+		Preset::mix(&mut shadow.notify.keymap, shadow.notify.prepend_keymap, shadow.notify.append_keymap);
+		#[rustfmt::skip]
 		Preset::mix(&mut shadow.select.keymap, shadow.select.prepend_keymap, shadow.select.append_keymap);
 		#[rustfmt::skip]
+		Preset::mix(&mut shadow.chmod.keymap, shadow.chmod.prepend_keymap, shadow.chmod.append_keymap);
+		#[rustfmt::skip]
+		Preset::mix(&mut shadow.spot.keymap, shadow.spot.prepend_keymap, shadow.spot.append_keymap);
+		#[rustfmt::skip]
 		Preset::mix(&mut shadow.input.keymap, shadow.input.prepend_keymap, shadow.input.append_keymap);
 		#[rustfmt::skip]
 		Preset::mix(&mut shadow.help.keymap, shadow.help.prepend_keymap, shadow.help.append_keymap);
@@ -55,7 +75,12 @@ impl<'de> Deserialize<'de> for Keymap {
 		Ok(Self {
 			manager:    shadow.manager.keymap,
 			tasks:      shadow.tasks.keymap,
+			mounts:     shadow.mounts.keymap,
+			sidebar:    shadow.sidebar.keymap,
+			notify:     shadow.notify.keymap,
 			select:     shadow.select.keymap,
+			chmod:      shadow.chmod.keymap,
+			spot:       shadow.spot.keymap,
 			input:      shadow.input.keymap,
 			help:       shadow.help.keymap,
 			completion: shadow.completion.keymap,
@@ -74,7 +99,12 @@ impl Keymap {
 			Layer::App => unreachable!(),
 			Layer::Manager => &self.manager,
 			Layer::Tasks => &self.tasks,
+			Layer::Mounts => &self.mounts,
+			Layer::Sidebar => &self.sidebar,
+			Layer::Notify => &self.notify,
 			Layer::Select => &self.select,
+			Layer::Chmod => &self.chmod,
+			Layer::Spot => &self.spot,
 			Layer::Input => &self.input,
 			Layer::Help => &self.help,
 			Layer::Completion => &self.completion,
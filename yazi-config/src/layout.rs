@@ -9,4 +9,7 @@ pub struct Layout {
 	pub preview: Rect,
 
 	pub status: Rect,
+
+	// How many files the current pane shows, which is more than its height in a grid
+	pub current_limit: usize,
 }
@@ -1,10 +1,16 @@
 #![allow(clippy::module_inception)]
 
-use yazi_shared::RoCell;
+use std::sync::atomic::Ordering;
+
+use manager::SizeUnits;
+use yazi_shared::{fs::{SIZE_DECIMAL, SIZE_RAW}, RoCell};
 
 mod boot;
+pub mod check;
 pub mod keymap;
 mod layout;
+pub mod local;
+mod locale;
 mod log;
 pub mod manager;
 pub mod open;
@@ -14,6 +20,7 @@ pub mod popup;
 mod preset;
 pub mod preview;
 mod priority;
+pub mod protect;
 mod tasks;
 pub mod theme;
 mod validation;
@@ -21,6 +28,7 @@ pub mod which;
 mod xdg;
 
 pub use layout::*;
+pub use locale::format_time;
 pub(crate) use pattern::*;
 pub(crate) use preset::*;
 pub use priority::*;
@@ -28,6 +36,7 @@ pub(crate) use xdg::*;
 
 pub static ARGS: RoCell<boot::Args> = RoCell::new();
 pub static BOOT: RoCell<boot::Boot> = RoCell::new();
+pub static CHECK: RoCell<check::Check> = RoCell::new();
 pub static LAYOUT: RoCell<arc_swap::ArcSwap<Layout>> = RoCell::new();
 
 static MERGED_KEYMAP: RoCell<String> = RoCell::new();
@@ -35,13 +44,15 @@ static MERGED_THEME: RoCell<String> = RoCell::new();
 static MERGED_YAZI: RoCell<String> = RoCell::new();
 
 pub static KEYMAP: RoCell<keymap::Keymap> = RoCell::new();
+pub static LOCALE: RoCell<locale::Locale> = RoCell::new();
 pub static LOG: RoCell<log::Log> = RoCell::new();
 pub static MANAGER: RoCell<manager::Manager> = RoCell::new();
 pub static OPEN: RoCell<open::Open> = RoCell::new();
 pub static PLUGIN: RoCell<plugin::Plugin> = RoCell::new();
 pub static PREVIEW: RoCell<preview::Preview> = RoCell::new();
+pub static PROTECT: RoCell<protect::Protect> = RoCell::new();
 pub static TASKS: RoCell<tasks::Tasks> = RoCell::new();
-pub static THEME: RoCell<theme::Theme> = RoCell::new();
+pub static THEME: RoCell<arc_swap::ArcSwap<theme::Theme>> = RoCell::new();
 pub static INPUT: RoCell<popup::Input> = RoCell::new();
 pub static SELECT: RoCell<popup::Select> = RoCell::new();
 pub static WHICH: RoCell<which::Which> = RoCell::new();
@@ -49,6 +60,7 @@ pub static WHICH: RoCell<which::Which> = RoCell::new();
 pub fn init() {
 	ARGS.with(Default::default);
 	BOOT.with(Default::default);
+	CHECK.init(check::Check::run_or_exit());
 	LAYOUT.with(Default::default);
 
 	MERGED_KEYMAP.with(Preset::keymap);
@@ -56,14 +68,20 @@ pub fn init() {
 	MERGED_YAZI.with(Preset::yazi);
 
 	KEYMAP.with(Default::default);
+	LOCALE.with(Default::default);
 	LOG.with(Default::default);
 	MANAGER.with(Default::default);
 	OPEN.with(Default::default);
 	PLUGIN.with(Default::default);
 	PREVIEW.with(Default::default);
+	PROTECT.with(Default::default);
 	TASKS.with(Default::default);
 	THEME.with(Default::default);
 	INPUT.with(Default::default);
 	SELECT.with(Default::default);
 	WHICH.with(Default::default);
+
+	// The sizes are formatted in yazi-shared, which can't read the config
+	SIZE_DECIMAL.store(MANAGER.size_units == SizeUnits::Decimal, Ordering::Relaxed);
+	SIZE_RAW.store(MANAGER.size_raw, Ordering::Relaxed);
 }
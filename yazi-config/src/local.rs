@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{manager::SortBy, plugin::PluginRule};
+
+/// The settings a `.yazi.toml` overrides, for the directory it's in and those under it.
+///
+/// Only the keys here are allowed, a file with any other is rejected as a whole.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Local {
+	#[serde(default)]
+	pub manager: LocalManager,
+	#[serde(default)]
+	pub plugin:  LocalPlugin,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocalManager {
+	// Sorting
+	pub sort_by:        Option<SortBy>,
+	pub sort_sensitive: Option<bool>,
+	pub sort_reverse:   Option<bool>,
+	pub sort_dir_first: Option<bool>,
+
+	// Display
+	pub linemode:     Option<String>,
+	pub show_hidden:  Option<bool>,
+	pub show_ignored: Option<bool>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocalPlugin {
+	/// Tried before the previewers of `yazi.toml`.
+	#[serde(default)]
+	pub previewers: Vec<PluginRule>,
+}
+
+impl Local {
+	pub const NAME: &'static str = ".yazi.toml";
+
+	#[inline]
+	pub fn parse(s: &str) -> Result<Self, toml::de::Error> { toml::from_str(s) }
+
+	#[inline]
+	pub fn previewer(&self, path: &Path, mime: &str) -> Option<&PluginRule> {
+		PluginRule::previewer(&self.plugin.previewers, path, mime)
+	}
+}
@@ -0,0 +1,231 @@
+use std::{collections::{HashMap, HashSet}, env, fs};
+
+use serde::Deserialize;
+use toml::Value;
+
+use crate::{BOOT, MERGED_YAZI};
+
+/// The translations of the user-facing strings into the language in use.
+///
+/// Like gettext, each string is looked up by its English text, the `msgid`,
+/// which is also what's shown if there's no translation for it, so a language
+/// pack only has to translate what it can, and English needs none.
+#[derive(Debug)]
+pub struct Locale {
+	pub language: String,
+	messages:     HashMap<String, Message>,
+	// The strings of the preset config, the only ones of the config to be translated,
+	// as those of the user's own are in the language they want already
+	presets:      HashSet<String>,
+	// Why the language pack is left out, if it's malformed, for it to be warned about on startup
+	pub error:    Option<String>,
+}
+
+/// A translation, of either a single form, or the singular and plural forms
+/// chosen by the number `{n}` in it.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Message {
+	Single(String),
+	Plural { one: String, other: String },
+}
+
+impl Locale {
+	/// The translation of the `msgid`, or itself if there's none.
+	#[inline]
+	pub fn get<'a>(&'a self, msgid: &'a str) -> &'a str {
+		match self.messages.get(msgid) {
+			Some(Message::Single(s)) => s,
+			Some(Message::Plural { other, .. }) => other,
+			None => msgid,
+		}
+	}
+
+	/// The translation of the `msgid` in the form for the number `n`.
+	#[inline]
+	pub fn plural<'a>(&'a self, msgid: &'a str, n: usize) -> &'a str {
+		match self.messages.get(msgid) {
+			Some(Message::Plural { one, .. }) if n == 1 => one,
+			_ => self.get(msgid),
+		}
+	}
+
+	/// The translation of the `msgid`, with each `{name}` in it replaced by its value.
+	///
+	/// A number `n` picks the form of the translation, and the `{s}` of the English
+	/// text, which is "s" if it's more than 1.
+	pub fn format(&self, msgid: &str, vars: &[(&str, String)]) -> String {
+		let s = Self::number(vars).map_or_else(|| self.get(msgid), |n| self.plural(msgid, n));
+		Self::fill(s, vars)
+	}
+
+	/// Like `get`, but for a string of the config, which is only translated if it's the preset one.
+	#[inline]
+	pub fn get_config<'a>(&'a self, s: &'a str) -> &'a str {
+		if self.presets.contains(s) { self.get(s) } else { s }
+	}
+
+	/// Like `format`, but for a string of the config, which is only translated if it's the preset
+	/// one, and otherwise has its `{name}`s replaced as is.
+	pub fn format_config(&self, s: &str, vars: &[(&str, String)]) -> String {
+		if self.presets.contains(s) { self.format(s, vars) } else { Self::fill(s, vars) }
+	}
+
+	#[inline]
+	fn number(vars: &[(&str, String)]) -> Option<usize> {
+		vars.iter().find(|&&(k, _)| k == "n").and_then(|(_, v)| v.parse().ok())
+	}
+
+	// In a single pass, for a `{name}` or `{s}` in a value to be kept as is
+	fn fill(s: &str, vars: &[(&str, String)]) -> String {
+		let n = Self::number(vars);
+		let (mut out, mut rest) = (String::with_capacity(s.len()), s);
+		while let Some(i) = rest.find('{') {
+			out.push_str(&rest[..i]);
+			rest = &rest[i..];
+			let Some(j) = rest.find('}') else {
+				break;
+			};
+
+			let name = &rest[1..j];
+			match vars.iter().find(|&&(k, _)| k == name) {
+				Some((_, value)) => out.push_str(value),
+				None if name == "s" && n.is_some() => out.push_str(if n > Some(1) { "s" } else { "" }),
+				None => out.push_str(&rest[..=j]),
+			}
+			rest = &rest[j + 1..];
+		}
+		out.push_str(rest);
+		out
+	}
+
+	// All the strings in the preset `yazi.toml` and `keymap.toml`
+	fn presets() -> HashSet<String> {
+		fn collect(value: Value, set: &mut HashSet<String>) {
+			match value {
+				Value::String(s) => _ = set.insert(s),
+				Value::Array(a) => a.into_iter().for_each(|v| collect(v, set)),
+				Value::Table(t) => t.into_iter().for_each(|(_, v)| collect(v, set)),
+				_ => {}
+			}
+		}
+
+		let mut set = HashSet::new();
+		for s in [include_str!("../../preset/yazi.toml"), include_str!("../../preset/keymap.toml")] {
+			collect(s.parse::<toml::Table>().unwrap().into(), &mut set);
+		}
+		set
+	}
+
+	/// The language to use, by the `LC_ALL`, `LC_MESSAGES` or `LANG` environment
+	/// variables unless set, trimmed of the encoding and modifier, e.g. `de_DE`.
+	fn detect(language: &str) -> String {
+		let s = if language.is_empty() {
+			["LC_ALL", "LC_MESSAGES", "LANG"]
+				.into_iter()
+				.find_map(|k| env::var(k).ok().filter(|s| !s.is_empty()))
+				.unwrap_or_default()
+		} else {
+			language.to_owned()
+		};
+
+		let s = s.split(['.', '@']).next().unwrap_or_default();
+		if s.is_empty() || s == "C" || s == "POSIX" { "en".to_owned() } else { s.replace('-', "_") }
+	}
+}
+
+impl Default for Locale {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			locale: Shadow,
+		}
+		#[derive(Deserialize)]
+		struct Shadow {
+			language: String,
+		}
+
+		let outer: Outer = toml::from_str(&MERGED_YAZI).unwrap();
+		let language = Self::detect(&outer.locale.language);
+
+		// The pack of the language in the region, e.g. `de_DE.toml`, or of the language, `de.toml`
+		let dir = BOOT.config_dir.join("locales");
+		let lang = language.split('_').next().unwrap_or_default();
+		let Some((path, s)) = [language.as_str(), lang]
+			.into_iter()
+			.map(|name| dir.join(format!("{name}.toml")))
+			.find_map(|p| fs::read_to_string(&p).ok().map(|s| (p, s)))
+		else {
+			return Self {
+				language,
+				messages: Default::default(),
+				presets: Default::default(),
+				error: None,
+			};
+		};
+
+		match toml::from_str(&s) {
+			Ok(messages) => Self { language, messages, presets: Self::presets(), error: None },
+			Err(e) => {
+				let error = format!("Failed to parse {}, falling back to English:\n{e}", path.display());
+				Self {
+					language,
+					messages: Default::default(),
+					presets: Default::default(),
+					error: Some(error),
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_format() {
+		let locale = Locale {
+			language: "de".to_owned(),
+			messages: toml::from_str(
+				r#"
+				"Copy {from} to {to}" = "{from} nach {to} kopieren"
+				"{n} task{s} running" = { one = "Eine Aufgabe läuft", other = "{n} Aufgaben laufen" }
+				"Link {n} file{s} as:" = "{n} Dateien verknüpfen als:"
+				"Open with:" = "Öffnen mit:"
+				"#,
+			)
+			.unwrap(),
+			presets:  ["Link {n} file{s} as:".to_owned()].into(),
+			error:    None,
+		};
+
+		let vars = [("from", "a".to_owned()), ("to", "b".to_owned())];
+		assert_eq!(locale.format("Copy {from} to {to}", &vars), "a nach b kopieren");
+		assert_eq!(locale.format("Delete {path}", &[("path", "a".to_owned())]), "Delete a");
+
+		assert_eq!(locale.plural("{n} task{s} running", 1), "Eine Aufgabe läuft");
+		assert_eq!(locale.format("{n} task{s} running", &[("n", "3".to_owned())]), "3 Aufgaben laufen");
+
+		assert_eq!(locale.format("{n} file{s}", &[("n", "2".to_owned())]), "2 files");
+		assert_eq!(locale.format("{n} file{s}", &[("n", "1".to_owned())]), "1 file");
+		assert_eq!(locale.format("{n} file{s}", &[("n", "0".to_owned())]), "0 file");
+
+		// The values are left as they are, even with a `{name}` or `{s}` in them
+		let vars = [("from", "{to}".to_owned()), ("to", "b".to_owned())];
+		assert_eq!(locale.format("Copy {from} to {to}", &vars), "{to} nach b kopieren");
+		let vars = [("n", "2".to_owned()), ("dir", "/a{s}".to_owned())];
+		assert_eq!(locale.format("{n} file{s} in {dir}", &vars), "2 files in /a{s}");
+
+		// Only the strings of the preset config are translated, not those of the user's own
+		let vars = [("n", "2".to_owned())];
+		assert_eq!(locale.format_config("Link {n} file{s} as:", &vars), "2 Dateien verknüpfen als:");
+		assert_eq!(locale.format_config("Link {n} thing{s}:", &vars), "Link 2 things:");
+		assert_eq!(locale.get_config("Open with:"), "Open with:");
+
+		assert!(Locale::presets().contains("Open with:"));
+		assert_eq!(Locale::detect("de_DE.UTF-8"), "de_DE");
+		assert_eq!(Locale::detect("C"), "en");
+		assert_eq!(Locale::detect("pt-BR"), "pt_BR");
+	}
+}
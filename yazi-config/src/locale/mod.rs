@@ -0,0 +1,27 @@
+mod locale;
+mod time;
+
+pub use locale::*;
+pub use time::*;
+
+/// Translates the `msgid`, with each `{name}` in it replaced by the value given.
+#[macro_export]
+macro_rules! tr {
+	($msgid:expr) => {
+		$crate::LOCALE.get($msgid)
+	};
+	($msgid:expr, $($name:ident = $value:expr),+ $(,)?) => {
+		$crate::LOCALE.format($msgid, &[$((stringify!($name), $value.to_string())),+])
+	};
+}
+
+/// Translates a string of the config like `tr!`, but only if it's the preset one.
+#[macro_export]
+macro_rules! tr_config {
+	($s:expr) => {
+		$crate::LOCALE.get_config($s)
+	};
+	($s:expr, $($name:ident = $value:expr),+ $(,)?) => {
+		$crate::LOCALE.format_config($s, &[$((stringify!($name), $value.to_string())),+])
+	};
+}
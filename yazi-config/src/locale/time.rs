@@ -0,0 +1,83 @@
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local};
+
+use crate::tr;
+
+// At most how long a time formatted by `strftime` can be
+#[cfg(unix)]
+const STRFTIME_MAX: usize = 4096;
+
+/// The time in the format of `strftime`, with the names of the locale of `LC_TIME`,
+/// or if it's "relative", as how long ago it is, such as "3 h ago" or "yesterday".
+pub fn format_time(time: SystemTime, format: &str) -> String {
+	if format == "relative" { relative(time) } else { strftime(time, format) }
+}
+
+fn relative(time: SystemTime) -> String {
+	// In the future, e.g. a clock that's off
+	let Ok(ago) = SystemTime::now().duration_since(time) else {
+		return strftime(time, "%x");
+	};
+
+	let days = (Local::now().date_naive() - DateTime::<Local>::from(time).date_naive()).num_days();
+	match ago.as_secs() {
+		s if s < 60 => tr!("just now").to_owned(),
+		s if s < 3600 => tr!("{n} min ago", n = s / 60),
+		s if days == 0 => tr!("{n} h ago", n = s / 3600),
+		_ if days == 1 => tr!("yesterday").to_owned(),
+		_ if days < 7 => tr!("{n} days ago", n = days),
+		_ => strftime(time, "%x"),
+	}
+}
+
+#[cfg(unix)]
+fn strftime(time: SystemTime, format: &str) -> String {
+	use std::{ffi::CString, mem, time::UNIX_EPOCH};
+
+	let secs = match time.duration_since(UNIX_EPOCH) {
+		Ok(d) => d.as_secs() as libc::time_t,
+		Err(e) => -(e.duration().as_secs() as libc::time_t),
+	};
+	let Ok(format) = CString::new(format) else {
+		return String::new();
+	};
+
+	let mut tm: libc::tm = unsafe { mem::zeroed() };
+	if unsafe { libc::localtime_r(&secs, &mut tm) }.is_null() {
+		return String::new();
+	}
+
+	// Nothing is written if it doesn't fit, which can't be told from an empty result,
+	// so it's tried again with a larger buffer, up to a limit
+	let mut buf = vec![0u8; 256];
+	loop {
+		let n = unsafe { libc::strftime(buf.as_mut_ptr().cast(), buf.len(), format.as_ptr(), &tm) };
+		if n > 0 || buf.len() >= STRFTIME_MAX {
+			return String::from_utf8_lossy(&buf[..n]).into_owned();
+		}
+		buf.resize(buf.len() * 4, 0);
+	}
+}
+
+#[cfg(not(unix))]
+fn strftime(time: SystemTime, format: &str) -> String {
+	use std::fmt::Write;
+
+	// An invalid format is an error of the formatting, rather than a panic of `to_string`
+	let mut s = String::new();
+	write!(s, "{}", DateTime::<Local>::from(time).format(format)).ok();
+	s
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strftime_long() {
+		let s = strftime(SystemTime::now(), &"%Y".repeat(100));
+		assert_eq!(s.len(), 400);
+		assert!(s.bytes().all(|b| b.is_ascii_digit()));
+	}
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::term::Colors;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+	#[default]
+	Auto,
+	Truecolor,
+	#[serde(rename = "256")]
+	Ansi256,
+	#[serde(rename = "16")]
+	Ansi16,
+	None,
+}
+
+impl ColorMode {
+	/// The colors to degrade those of the theme to, or `None` to detect them.
+	#[inline]
+	pub fn colors(self) -> Option<Colors> {
+		match self {
+			Self::Auto => None,
+			Self::Truecolor => Some(Colors::TrueColor),
+			Self::Ansi256 => Some(Colors::Ansi256),
+			Self::Ansi16 => Some(Colors::Ansi16),
+			Self::None => Some(Colors::None),
+		}
+	}
+}
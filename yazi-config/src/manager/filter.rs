@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use yazi_shared::event::Cmd;
+
+use crate::MANAGER;
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FilterCase {
+	Smart,
+	#[default]
+	Sensitive,
+	Insensitive,
+}
+
+impl From<&Cmd> for FilterCase {
+	fn from(c: &Cmd) -> Self {
+		if c.named.contains_key("smart") {
+			Self::Smart
+		} else if c.named.contains_key("insensitive") {
+			Self::Insensitive
+		} else if c.named.contains_key("sensitive") {
+			Self::Sensitive
+		} else {
+			MANAGER.filter_case
+		}
+	}
+}
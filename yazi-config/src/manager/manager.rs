@@ -1,8 +1,11 @@
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError};
+use yazi_shared::fs::expand_path;
 
-use super::{ManagerRatio, SortBy};
-use crate::{validation::check_validation, MERGED_YAZI};
+use super::{ColorMode, Column, DirPreload, FilterCase, ManagerRatio, MouseEvents, SizeUnits, SortBy, View, WatchMode};
+use crate::{validation::check_validation, BOOT, MERGED_YAZI};
 
 #[derive(Debug, Deserialize, Serialize, Validate)]
 pub struct Manager {
@@ -15,11 +18,59 @@ pub struct Manager {
 	pub sort_dir_first: bool,
 
 	// Display
-	#[validate(length(min = 1, max = 20, message = "must be between 1 and 20 characters"))]
-	pub linemode:     String,
-	pub show_hidden:  bool,
-	pub show_symlink: bool,
-	pub scrolloff:    u8,
+	#[validate(custom = "validate_linemode")]
+	pub linemode:      String,
+	pub time_format:   String,
+	pub size_units:    SizeUnits,
+	pub size_raw:      bool,
+	pub show_hidden:   bool,
+	pub show_ignored:  bool,
+	pub show_symlink:  bool,
+	pub scrolloff:     u8,
+	pub smooth_scroll: bool,
+	pub colors:        ColorMode,
+
+	// Filtering and finding
+	pub filter_case:      FilterCase,
+	pub filter_normalize: bool,
+
+	// View
+	pub view:    View,
+	pub columns: Vec<Column>,
+
+	// Sidebar
+	pub sidebar:       bool,
+	#[validate(range(min = 8, message = "must be at least 8 columns"))]
+	pub sidebar_width: u16,
+
+	// Mouse
+	pub mouse_events: MouseEvents,
+
+	// Preloading
+	pub dir_preload:        DirPreload,
+	pub dir_preload_budget: usize,
+
+	// Watching
+	pub watch_mode:          WatchMode,
+	#[validate(range(min = 1, message = "must be at least 1 second"))]
+	pub watch_poll_interval: u64,
+
+	// Yanking
+	pub sync_yanked: bool,
+
+	// Creating
+	pub templates_dir: String,
+
+	// Opening
+	pub terminal:    String,
+	pub editor:      String,
+	pub recent_xbel: bool,
+
+	// Notifying
+	pub notify_bell: bool,
+
+	// Remote control
+	pub server: bool,
 }
 
 impl Default for Manager {
@@ -35,3 +86,30 @@ impl Default for Manager {
 		manager
 	}
 }
+
+impl Manager {
+	/// The skeletons for `create`, under the config directory unless configured.
+	#[inline]
+	pub fn templates_dir(&self) -> PathBuf {
+		if self.templates_dir.is_empty() {
+			BOOT.config_dir.join("templates")
+		} else {
+			expand_path(&self.templates_dir)
+		}
+	}
+}
+
+/// The longest a linemode can be, a built-in or plugin one being a short name,
+/// and a format of the fields, such as "{size:>8} {mtime:%Y-%m-%d}", longer.
+#[inline]
+pub fn linemode_max_len(mode: &str) -> usize { if mode.contains('{') { 200 } else { 20 } }
+
+fn validate_linemode(mode: &str) -> Result<(), ValidationError> {
+	let max = linemode_max_len(mode);
+	if mode.is_empty() || mode.len() > max {
+		let mut e = ValidationError::new("length");
+		e.message = Some(format!("must be between 1 and {max} characters").into());
+		return Err(e);
+	}
+	Ok(())
+}
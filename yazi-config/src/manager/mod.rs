@@ -1,7 +1,23 @@
+mod colors;
+mod column;
+mod filter;
 mod manager;
+mod mouse;
+mod preload;
 mod ratio;
+mod size;
 mod sorting;
+mod view;
+mod watch;
 
+pub use colors::*;
+pub use column::*;
+pub use filter::*;
 pub use manager::*;
+pub use mouse::*;
+pub use preload::*;
 pub use ratio::*;
+pub use size::*;
 pub use sorting::*;
+pub use view::*;
+pub use watch::*;
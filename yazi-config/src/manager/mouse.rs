@@ -0,0 +1,32 @@
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "Vec<String>")]
+pub struct MouseEvents {
+	pub click:  bool,
+	pub scroll: bool,
+	pub drag:   bool,
+}
+
+impl MouseEvents {
+	#[inline]
+	pub fn is_empty(&self) -> bool { !self.click && !self.scroll && !self.drag }
+}
+
+impl TryFrom<Vec<String>> for MouseEvents {
+	type Error = anyhow::Error;
+
+	fn try_from(events: Vec<String>) -> Result<Self, Self::Error> {
+		let mut me = Self::default();
+		for e in events {
+			match e.as_str() {
+				"click" => me.click = true,
+				"scroll" => me.scroll = true,
+				"drag" => me.drag = true,
+				_ => bail!("invalid mouse event: {e}"),
+			}
+		}
+		Ok(me)
+	}
+}
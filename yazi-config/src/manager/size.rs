@@ -0,0 +1,39 @@
+use std::{fmt::{self, Display}, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum SizeUnits {
+	#[default]
+	Binary,
+	Decimal,
+}
+
+impl FromStr for SizeUnits {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"binary" => Self::Binary,
+			"decimal" => Self::Decimal,
+			_ => bail!("invalid size_units value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for SizeUnits {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for SizeUnits {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Binary => "binary",
+			Self::Decimal => "decimal",
+		})
+	}
+}
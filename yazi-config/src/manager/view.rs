@@ -0,0 +1,42 @@
+use std::{fmt::{self, Display}, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum View {
+	#[default]
+	List,
+	Details,
+	Grid,
+}
+
+impl FromStr for View {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"list" => Self::List,
+			"details" => Self::Details,
+			"grid" => Self::Grid,
+			_ => bail!("invalid view value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for View {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for View {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::List => "list",
+			Self::Details => "details",
+			Self::Grid => "grid",
+		})
+	}
+}
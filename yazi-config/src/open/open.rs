@@ -5,7 +5,7 @@ use serde::{Deserialize, Deserializer};
 use yazi_shared::MIME_DIR;
 
 use super::Opener;
-use crate::{open::OpenRule, MERGED_YAZI};
+use crate::{open::{Env, OpenRule}, MERGED_YAZI};
 
 #[derive(Debug)]
 pub struct Open {
@@ -51,6 +51,12 @@ impl Open {
 		self.openers(path, mime).and_then(|o| o.into_iter().find(|o| o.block))
 	}
 
+	/// The opener of the `exec`, among all of them.
+	#[inline]
+	pub fn by_exec(&self, exec: &str) -> Option<&Opener> {
+		self.openers.values().flatten().find(|o| o.exec == exec)
+	}
+
 	pub fn common_openers(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> Vec<&Opener> {
 		let grouped: Vec<_> = targets.iter().filter_map(|(p, m)| self.openers(p, m)).collect();
 		let flat: IndexSet<_> = grouped.iter().flatten().copied().collect();
@@ -79,6 +85,9 @@ impl<'de> Deserialize<'de> for Open {
 			.into_iter()
 			.map(|(k, v)| (k, v.into_iter().filter_map(|o| o.take()).collect::<IndexSet<_>>()))
 			.collect();
-		Ok(Self { rules: outer.open.rules, openers })
+		// The environment doesn't change while running, so the rules not applying in it are left out
+		let env = Env::current();
+		let rules = outer.open.rules.into_iter().filter(|r| r.applies(&env)).collect();
+		Ok(Self { rules, openers })
 	}
 }
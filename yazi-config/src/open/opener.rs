@@ -2,12 +2,13 @@ use serde::{Deserialize, Deserializer};
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Opener {
-	pub exec:   String,
-	pub block:  bool,
-	pub orphan: bool,
-	pub desc:   String,
-	pub for_:   Option<String>,
-	pub spread: bool,
+	pub exec:    String,
+	pub block:   bool,
+	pub orphan:  bool,
+	pub capture: bool,
+	pub desc:    String,
+	pub for_:    Option<String>,
+	pub spread:  bool,
 }
 
 impl Opener {
@@ -32,14 +33,16 @@ impl<'de> Deserialize<'de> for Opener {
 	{
 		#[derive(Deserialize)]
 		pub struct Shadow {
-			exec:   String,
+			exec:    String,
 			#[serde(default)]
-			block:  bool,
+			block:   bool,
 			#[serde(default)]
-			orphan: bool,
-			desc:   Option<String>,
+			orphan:  bool,
+			#[serde(default)]
+			capture: bool,
+			desc:    Option<String>,
 			#[serde(rename = "for")]
-			for_:   Option<String>,
+			for_:    Option<String>,
 		}
 
 		let shadow = Shadow::deserialize(deserializer)?;
@@ -56,6 +59,7 @@ impl<'de> Deserialize<'de> for Opener {
 			exec: shadow.exec,
 			block: shadow.block,
 			orphan: shadow.orphan,
+			capture: shadow.capture,
 			desc,
 			for_: shadow.for_,
 			spread,
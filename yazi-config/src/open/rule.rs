@@ -1,6 +1,7 @@
 use std::fmt;
 
 use serde::{de::{self, Visitor}, Deserialize, Deserializer};
+use yazi_shared::{in_gui_session, in_ssh_connection};
 
 use crate::pattern::Pattern;
 
@@ -11,9 +12,58 @@ pub(super) struct OpenRule {
 	#[serde(rename = "use")]
 	#[serde(deserialize_with = "OpenRule::deserialize")]
 	pub(super) use_: Vec<String>,
+
+	// The environment it only applies in, if given
+	pub(super) ssh:     Option<bool>,
+	pub(super) gui:     Option<bool>,
+	pub(super) os:      Option<String>,
+	pub(super) desktop: Option<String>,
+}
+
+// The environment yazi runs in, as told by `Env::current()`
+pub(super) struct Env {
+	ssh:     bool,
+	gui:     bool,
+	os:      &'static str,
+	unix:    bool,
+	// The desktop sessions in `$XDG_CURRENT_DESKTOP`, separated by colons
+	desktop: String,
+}
+
+impl Env {
+	pub(super) fn current() -> Self {
+		Self {
+			ssh:     in_ssh_connection(),
+			gui:     in_gui_session(),
+			os:      std::env::consts::OS,
+			unix:    cfg!(unix),
+			desktop: std::env::var("XDG_CURRENT_DESKTOP").unwrap_or_default(),
+		}
+	}
 }
 
 impl OpenRule {
+	/// Whether it applies in the current environment: over SSH or not, with a graphical
+	/// session or not, on the OS (`unix`, or one of `std::env::consts::OS`, e.g. `macos`),
+	/// and in the desktop session, one of `$XDG_CURRENT_DESKTOP`, e.g. `GNOME`.
+	pub(super) fn applies(&self, env: &Env) -> bool {
+		if self.ssh.is_some_and(|b| b != env.ssh) {
+			return false;
+		}
+		if self.gui.is_some_and(|b| b != env.gui) {
+			return false;
+		}
+		if let Some(os) = &self.os {
+			if !(os == env.os || os == "unix" && env.unix) {
+				return false;
+			}
+		}
+		if let Some(desktop) = &self.desktop {
+			return env.desktop.split(':').any(|d| d.eq_ignore_ascii_case(desktop));
+		}
+		true
+	}
+
 	fn deserialize<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
 	where
 		D: Deserializer<'de>,
@@ -56,3 +106,43 @@ impl OpenRule {
 		deserializer.deserialize_any(UseVisitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn rule(s: &str) -> OpenRule { toml::from_str(&format!("use = \"edit\"\n{s}")).unwrap() }
+
+	fn env(ssh: bool, gui: bool, os: &'static str, desktop: &str) -> Env {
+		Env { ssh, gui, os, unix: os != "windows", desktop: desktop.to_owned() }
+	}
+
+	#[test]
+	fn test_applies() {
+		let linux = env(false, true, "linux", "ubuntu:GNOME");
+		let remote = env(true, false, "linux", "");
+		let windows = env(false, true, "windows", "");
+
+		let any = rule("");
+		assert!(any.applies(&linux) && any.applies(&remote) && any.applies(&windows));
+
+		let local = rule("ssh = false");
+		assert!(local.applies(&linux) && !local.applies(&remote));
+
+		let gui = rule("gui = true");
+		assert!(gui.applies(&linux) && !gui.applies(&remote));
+
+		let unix = rule(r#"os = "unix""#);
+		assert!(unix.applies(&linux) && !unix.applies(&windows));
+		assert!(rule(r#"os = "windows""#).applies(&windows));
+		assert!(!rule(r#"os = "macos""#).applies(&linux));
+
+		let gnome = rule(r#"desktop = "gnome""#);
+		assert!(gnome.applies(&linux) && !gnome.applies(&remote));
+		assert!(!rule(r#"desktop = "KDE""#).applies(&linux));
+
+		// All of them have to be met
+		let both = rule("os = \"linux\"\ndesktop = \"KDE\"");
+		assert!(!both.applies(&linux));
+	}
+}
@@ -6,10 +6,10 @@ use serde::Deserialize;
 #[derive(Debug, Deserialize)]
 #[serde(try_from = "String")]
 pub struct Pattern {
-	inner:     glob::Pattern,
-	sensitive: bool,
-	is_folder: bool,
-	full_path: bool,
+	pub(crate) inner:     glob::Pattern,
+	pub(crate) sensitive: bool,
+	pub(crate) is_folder: bool,
+	pub(crate) full_path: bool,
 }
 
 impl Pattern {
@@ -9,6 +9,7 @@ use crate::{pattern::Pattern, plugin::MAX_PRELOADERS, Preset, Priority, MERGED_Y
 pub struct Plugin {
 	pub preloaders: Vec<PluginRule>,
 	pub previewers: Vec<PluginRule>,
+	pub spotters:   Vec<PluginRule>,
 }
 
 #[derive(Deserialize)]
@@ -49,6 +50,12 @@ impl Default for Plugin {
 			prepend_previewers: Vec<PluginRule>,
 			#[serde(default)]
 			append_previewers:  Vec<PluginRule>,
+
+			spotters:           Vec<PluginRule>,
+			#[serde(default)]
+			prepend_spotters:   Vec<PluginRule>,
+			#[serde(default)]
+			append_spotters:    Vec<PluginRule>,
 		}
 
 		let mut shadow = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().plugin;
@@ -61,6 +68,7 @@ impl Default for Plugin {
 
 		Preset::mix(&mut shadow.preloaders, shadow.prepend_preloaders, shadow.append_preloaders);
 		Preset::mix(&mut shadow.previewers, shadow.prepend_previewers, shadow.append_previewers);
+		Preset::mix(&mut shadow.spotters, shadow.prepend_spotters, shadow.append_spotters);
 
 		if shadow.preloaders.len() > MAX_PRELOADERS as usize {
 			panic!("Too many preloaders");
@@ -73,7 +81,11 @@ impl Default for Plugin {
 			preloader.id = i as u8;
 		}
 
-		Self { preloaders: shadow.preloaders, previewers: shadow.previewers }
+		Self {
+			preloaders: shadow.preloaders,
+			previewers: shadow.previewers,
+			spotters:   shadow.spotters,
+		}
 	}
 }
 
@@ -91,14 +103,35 @@ impl Plugin {
 			.filter(|&rule| {
 				rule.cond.as_ref().and_then(|c| c.eval(f)) != Some(false)
 					&& (rule.name.as_ref().is_some_and(|n| n.match_path(path, is_folder))
-						|| rule.mime.as_ref().zip(mime).map_or(false, |(m, s)| m.matches(s)))
+						|| rule.mime.as_ref().zip(mime).is_some_and(|(m, s)| m.matches(s)))
 			})
 			.collect()
 	}
 
+	/// The spotters matching the file, each one a tab of its own in the spot.
+	pub fn spotters(&self, path: &Path, mime: Option<&str>) -> Vec<&PluginRule> {
+		let is_folder = mime == Some(MIME_DIR);
+		self
+			.spotters
+			.iter()
+			.filter(|&rule| {
+				rule.name.as_ref().is_some_and(|n| n.match_path(path, is_folder))
+					|| rule.mime.as_ref().zip(mime).is_some_and(|(m, s)| m.matches(s))
+			})
+			.collect()
+	}
+
+	#[inline]
 	pub fn previewer(&self, path: &Path, mime: &str) -> Option<&PluginRule> {
+		PluginRule::previewer(&self.previewers, path, mime)
+	}
+}
+
+impl PluginRule {
+	/// The first of the previewer rules matching the file.
+	pub(crate) fn previewer<'a>(rules: &'a [Self], path: &Path, mime: &str) -> Option<&'a Self> {
 		let is_folder = mime == MIME_DIR;
-		self.previewers.iter().find(|&rule| {
+		rules.iter().find(|&rule| {
 			rule.mime.as_ref().is_some_and(|m| m.matches(mime))
 				|| rule.name.as_ref().is_some_and(|n| n.match_path(path, is_folder))
 		})
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::{Offset, Origin};
+use super::{InputPopup, Offset, Origin, Popups};
 use crate::MERGED_YAZI;
 
 #[derive(Deserialize)]
@@ -59,6 +59,10 @@ pub struct Input {
 	pub quit_title:  String,
 	pub quit_origin: Origin,
 	pub quit_offset: Offset,
+
+	// the others, by their names
+	#[serde(default)]
+	pub popups: Popups<InputPopup>,
 }
 
 impl Default for Input {
@@ -68,7 +72,10 @@ impl Default for Input {
 			input: Input,
 		}
 
-		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().input
+		let mut input = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().input;
+		let preset = toml::from_str::<Outer>(include_str!("../../preset/yazi.toml")).unwrap();
+		input.popups.fill(preset.input.popups);
+		input
 	}
 }
 
@@ -2,6 +2,7 @@ mod input;
 mod offset;
 mod options;
 mod origin;
+mod popups;
 mod position;
 mod select;
 
@@ -9,5 +10,6 @@ pub use input::*;
 pub use offset::*;
 pub use options::*;
 pub use origin::*;
+pub use popups::*;
 pub use position::*;
 pub use select::*;
@@ -1,5 +1,7 @@
-use super::{Offset, Position};
-use crate::{INPUT, SELECT};
+use std::path::Path;
+
+use super::{InputPopup, Offset, Popup, Position, SelectPopup};
+use crate::{tr, tr_config, INPUT, SELECT};
 
 #[derive(Default)]
 pub struct InputCfg {
@@ -10,6 +12,7 @@ pub struct InputCfg {
 	pub realtime:   bool,
 	pub completion: bool,
 	pub highlight:  bool,
+	pub history:    Option<&'static str>,
 }
 
 #[derive(Default)]
@@ -17,15 +20,17 @@ pub struct SelectCfg {
 	pub title:    String,
 	pub items:    Vec<String>,
 	pub position: Position,
+	pub toggle:   Option<String>,
 }
 
 impl InputCfg {
 	#[inline]
 	pub fn cd() -> Self {
 		Self {
-			title: INPUT.cd_title.to_owned(),
+			title: tr_config!(&INPUT.cd_title).to_owned(),
 			position: Position::new(INPUT.cd_origin, INPUT.cd_offset),
 			completion: true,
+			history: Some("cd"),
 			..Default::default()
 		}
 	}
@@ -33,7 +38,7 @@ impl InputCfg {
 	#[inline]
 	pub fn create() -> Self {
 		Self {
-			title: INPUT.create_title.to_owned(),
+			title: tr_config!(&INPUT.create_title).to_owned(),
 			position: Position::new(INPUT.create_origin, INPUT.create_offset),
 			..Default::default()
 		}
@@ -42,17 +47,38 @@ impl InputCfg {
 	#[inline]
 	pub fn rename() -> Self {
 		Self {
-			title: INPUT.rename_title.to_owned(),
+			title: tr_config!(&INPUT.rename_title).to_owned(),
 			position: Position::new(INPUT.rename_origin, INPUT.rename_offset),
+			history: Some("rename"),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn rename_regex() -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::RenameRegex).title).to_owned(),
+			value: "s///".to_owned(),
+			cursor: Some(2),
+			position: INPUT.popups.get(InputPopup::RenameRegex).position(),
+			history: Some("rename_regex"),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn edit_link() -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::EditLink).title).to_owned(),
+			position: INPUT.popups.get(InputPopup::EditLink).position(),
 			..Default::default()
 		}
 	}
 
 	#[inline]
 	pub fn trash(n: usize) -> Self {
-		let title = INPUT.trash_title.replace("{n}", &n.to_string());
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
+			title: tr_config!(&INPUT.trash_title, n = n),
 			position: Position::new(INPUT.trash_origin, INPUT.trash_offset),
 			..Default::default()
 		}
@@ -60,20 +86,29 @@ impl InputCfg {
 
 	#[inline]
 	pub fn delete(n: usize) -> Self {
-		let title = INPUT.delete_title.replace("{n}", &n.to_string());
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
+			title: tr_config!(&INPUT.delete_title, n = n),
 			position: Position::new(INPUT.delete_origin, INPUT.delete_offset),
 			..Default::default()
 		}
 	}
 
+	#[inline]
+	pub fn no_trash(n: usize) -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::NoTrash).title, n = n),
+			position: INPUT.popups.get(InputPopup::NoTrash).position(),
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn filter() -> Self {
 		Self {
-			title: INPUT.filter_title.to_owned(),
+			title: tr_config!(&INPUT.filter_title).to_owned(),
 			position: Position::new(INPUT.filter_origin, INPUT.filter_offset),
 			realtime: true,
+			history: Some("filter"),
 			..Default::default()
 		}
 	}
@@ -81,18 +116,29 @@ impl InputCfg {
 	#[inline]
 	pub fn find(prev: bool) -> Self {
 		Self {
-			title: INPUT.find_title[prev as usize].to_owned(),
+			title: tr_config!(&INPUT.find_title[prev as usize]).to_owned(),
 			position: Position::new(INPUT.find_origin, INPUT.find_offset),
 			realtime: true,
 			..Default::default()
 		}
 	}
 
+	#[inline]
+	pub fn find_fuzzy() -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::FindFuzzy).title).to_owned(),
+			position: INPUT.popups.get(InputPopup::FindFuzzy).position(),
+			completion: true,
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn search(name: &str) -> Self {
 		Self {
-			title: INPUT.search_title.replace("{n}", name),
+			title: tr_config!(&INPUT.search_title, n = name),
 			position: Position::new(INPUT.search_origin, INPUT.search_offset),
+			history: Some("search"),
 			..Default::default()
 		}
 	}
@@ -100,9 +146,19 @@ impl InputCfg {
 	#[inline]
 	pub fn shell(block: bool) -> Self {
 		Self {
-			title: INPUT.shell_title[block as usize].to_owned(),
+			title: tr_config!(&INPUT.shell_title[block as usize]).to_owned(),
 			position: Position::new(INPUT.shell_origin, INPUT.shell_offset),
 			highlight: true,
+			history: Some("shell"),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn tab_rename() -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::TabRename).title).to_owned(),
+			position: INPUT.popups.get(InputPopup::TabRename).position(),
 			..Default::default()
 		}
 	}
@@ -110,7 +166,7 @@ impl InputCfg {
 	#[inline]
 	pub fn overwrite() -> Self {
 		Self {
-			title: INPUT.overwrite_title.to_owned(),
+			title: tr_config!(&INPUT.overwrite_title).to_owned(),
 			position: Position::new(INPUT.overwrite_origin, INPUT.overwrite_offset),
 			..Default::default()
 		}
@@ -118,14 +174,40 @@ impl InputCfg {
 
 	#[inline]
 	pub fn quit(n: usize) -> Self {
-		let title = INPUT.quit_title.replace("{n}", &n.to_string());
 		Self {
-			title: title.replace("{s}", if n > 1 { "s" } else { "" }),
+			title: tr_config!(&INPUT.quit_title, n = n),
 			position: Position::new(INPUT.quit_origin, INPUT.quit_offset),
 			..Default::default()
 		}
 	}
 
+	#[inline]
+	pub fn trust(path: &Path) -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::Trust).title, path = path.display()),
+			position: INPUT.popups.get(InputPopup::Trust).position(),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn escalate(n: usize) -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::Escalate).title, n = n),
+			position: INPUT.popups.get(InputPopup::Escalate).position(),
+			..Default::default()
+		}
+	}
+
+	#[inline]
+	pub fn protect(name: &str, action: &str) -> Self {
+		Self {
+			title: tr_config!(&INPUT.popups.get(InputPopup::Protect).title, name = name, action = action),
+			position: INPUT.popups.get(InputPopup::Protect).position(),
+			..Default::default()
+		}
+	}
+
 	#[inline]
 	pub fn with_value(mut self, value: impl Into<String>) -> Self {
 		self.value = value.into();
@@ -141,20 +223,70 @@ impl InputCfg {
 
 impl SelectCfg {
 	#[inline]
-	fn max_height(len: usize) -> u16 {
-		SELECT.open_offset.height.min(SELECT.border().saturating_add(len as u16))
+	fn max_height(offset: Offset, len: usize) -> u16 {
+		offset.height.min(SELECT.border().saturating_add(len as u16))
+	}
+
+	// Of the popup, as high as its items need, up to the height of its offset
+	fn popup(popup: &Popup, title: String, items: Vec<String>) -> Self {
+		let height = Self::max_height(popup.offset, items.len());
+		let position = Position::new(popup.origin, Offset { height, ..popup.offset });
+		Self { title, items, position, ..Default::default() }
 	}
 
 	#[inline]
 	pub fn open(items: Vec<String>) -> Self {
-		let max_height = Self::max_height(items.len());
+		let max_height = Self::max_height(SELECT.open_offset, items.len());
 		Self {
-			title: SELECT.open_title.to_owned(),
+			title: tr_config!(&SELECT.open_title).to_owned(),
 			items,
 			position: Position::new(SELECT.open_origin, Offset {
 				height: max_height,
 				..SELECT.open_offset
 			}),
+			..Default::default()
 		}
 	}
+
+	/// With a toggle to always use the chosen opener for the `ext` of the files.
+	#[inline]
+	pub fn with_remember(mut self, ext: &str) -> Self {
+		self.toggle = Some(tr!("Always use it for *.{ext}", ext = ext));
+		self
+	}
+
+	#[inline]
+	pub fn setup(title: &str, items: Vec<String>) -> Self {
+		Self::popup(SELECT.popups.get(SelectPopup::Setup), tr!(title).to_owned(), items)
+	}
+
+	#[inline]
+	pub fn template(items: Vec<String>) -> Self {
+		let popup = SELECT.popups.get(SelectPopup::Template);
+		Self::popup(popup, tr_config!(&popup.title).to_owned(), items)
+	}
+
+	#[inline]
+	pub fn link(n: usize, items: Vec<String>) -> Self {
+		let popup = SELECT.popups.get(SelectPopup::Link);
+		Self::popup(popup, tr_config!(&popup.title, n = n), items)
+	}
+
+	#[inline]
+	pub fn resume(items: Vec<String>) -> Self {
+		let popup = SELECT.popups.get(SelectPopup::Resume);
+		Self::popup(popup, tr_config!(&popup.title).to_owned(), items)
+	}
+
+	#[inline]
+	pub fn recover(items: Vec<String>) -> Self {
+		let popup = SELECT.popups.get(SelectPopup::Recover);
+		Self::popup(popup, tr_config!(&popup.title).to_owned(), items)
+	}
+
+	#[inline]
+	pub fn registers(items: Vec<String>) -> Self {
+		let popup = SELECT.popups.get(SelectPopup::Registers);
+		Self::popup(popup, tr_config!(&popup.title).to_owned(), items)
+	}
 }
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, hash::Hash};
+
+use serde::Deserialize;
+
+use super::{Offset, Origin, Position};
+
+/// A popup in `[input.popups]` or `[select.popups]`, e.g.
+/// `trust = { title = "Trust {path}? (y/N)", origin = "top-center", offset = [ 0, 2, 60, 3 ] }`.
+#[derive(Clone, Deserialize)]
+pub struct Popup {
+	#[serde(default)]
+	pub title:  String,
+	pub origin: Origin,
+	pub offset: Offset,
+}
+
+impl Popup {
+	#[inline]
+	pub fn position(&self) -> Position { Position::new(self.origin, self.offset) }
+}
+
+/// The names of the popups in `[input.popups]`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum InputPopup {
+	RenameRegex,
+	EditLink,
+	NoTrash,
+	FindFuzzy,
+	TabRename,
+	Trust,
+	Escalate,
+	Protect,
+}
+
+/// The names of the popups in `[select.popups]`.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectPopup {
+	Template,
+	Chmod,
+	Link,
+	Registers,
+	Resume,
+	Recover,
+	Spot,
+	Checksum,
+	Setup,
+}
+
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub struct Popups<K: Eq + Hash>(HashMap<K, Popup>);
+
+impl<K: Eq + Hash> Default for Popups<K> {
+	fn default() -> Self { Self(Default::default()) }
+}
+
+impl<K: Eq + Hash> Popups<K> {
+	/// The popup of the name, the preset one if it's not configured,
+	/// the preset has all of them, see `test_preset`.
+	#[inline]
+	pub fn get(&self, name: K) -> &Popup { &self.0[&name] }
+
+	// A `popups` table of the user replaces the preset one as a whole, as it's merged 2 levels deep,
+	// so the popups left out of it are taken from the preset
+	pub(super) fn fill(&mut self, preset: Self) {
+		for (name, popup) in preset.0 {
+			self.0.entry(name).or_insert(popup);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fill() {
+		let mut user: Popups<SelectPopup> = toml::from_str(
+			r#"link = { title = "Link?", origin = "center", offset = [ 1, 2, 30, 3 ] }"#,
+		)
+		.unwrap();
+		user.fill(
+			toml::from_str(
+				r#"
+				link = { title = "Link {n} file{s} as:", origin = "top-center", offset = [ 0, 2, 50, 6 ] }
+				spot = { origin = "center", offset = [ 0, 0, 72, 20 ] }
+				"#,
+			)
+			.unwrap(),
+		);
+
+		let link = user.get(SelectPopup::Link);
+		assert_eq!((link.title.as_str(), link.offset.x, link.offset.width), ("Link?", 1, 30));
+		assert!(link.origin == Origin::Center);
+
+		let spot = user.get(SelectPopup::Spot);
+		assert_eq!((spot.title.as_str(), spot.offset.height), ("", 20));
+	}
+
+	#[test]
+	fn test_preset() {
+		#[derive(Deserialize)]
+		struct Outer {
+			input:  Inner<InputPopup>,
+			select: Inner<SelectPopup>,
+		}
+		#[derive(Deserialize)]
+		struct Inner<K: Eq + Hash> {
+			popups: Popups<K>,
+		}
+
+		let preset: Outer = toml::from_str(include_str!("../../preset/yazi.toml")).unwrap();
+		for name in [
+			InputPopup::RenameRegex,
+			InputPopup::EditLink,
+			InputPopup::NoTrash,
+			InputPopup::FindFuzzy,
+			InputPopup::TabRename,
+			InputPopup::Trust,
+			InputPopup::Escalate,
+			InputPopup::Protect,
+		] {
+			assert!(preset.input.popups.0.contains_key(&name), "{name:?} is not in the preset");
+		}
+		for name in [
+			SelectPopup::Template,
+			SelectPopup::Chmod,
+			SelectPopup::Link,
+			SelectPopup::Registers,
+			SelectPopup::Resume,
+			SelectPopup::Recover,
+			SelectPopup::Spot,
+			SelectPopup::Checksum,
+			SelectPopup::Setup,
+		] {
+			assert!(preset.select.popups.0.contains_key(&name), "{name:?} is not in the preset");
+		}
+	}
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::{Offset, Origin};
+use super::{Offset, Origin, Popups, SelectPopup};
 use crate::MERGED_YAZI;
 
 #[derive(Deserialize)]
@@ -9,6 +9,10 @@ pub struct Select {
 	pub open_title:  String,
 	pub open_origin: Origin,
 	pub open_offset: Offset,
+
+	// the others, by their names
+	#[serde(default)]
+	pub popups: Popups<SelectPopup>,
 }
 
 impl Default for Select {
@@ -18,7 +22,10 @@ impl Default for Select {
 			select: Select,
 		}
 
-		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().select
+		let mut select = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().select;
+		let preset = toml::from_str::<Outer>(include_str!("../../preset/yazi.toml")).unwrap();
+		select.popups.fill(preset.select.popups);
+		select
 	}
 }
 
@@ -1,6 +1,6 @@
-use std::{fs, mem};
+use std::{fs, mem, path::{Path, PathBuf}};
 
-use toml::Table;
+use toml::{Table, Value};
 
 use crate::BOOT;
 
@@ -17,6 +17,22 @@ impl Preset {
 		Self::merge_str("theme.toml", include_str!("../preset/theme.toml"))
 	}
 
+	/// The `theme.toml` merged with the preset like on startup, or an error instead of a panic
+	/// if it or an overlay of it can't be parsed.
+	pub(crate) fn try_theme() -> anyhow::Result<String> {
+		let mut user = Self::layered("theme.toml")?;
+		Self::merge(&mut user, &include_str!("../preset/theme.toml").parse()?, 2);
+		Ok(user.to_string())
+	}
+
+	/// The theme at the path merged with the preset like `theme.toml` is, or an error
+	/// instead of a panic if it can't be read or parsed.
+	pub(crate) fn theme_at(path: &Path) -> anyhow::Result<String> {
+		let mut user = fs::read_to_string(path)?.parse::<Table>()?;
+		Self::merge(&mut user, &include_str!("../preset/theme.toml").parse()?, 2);
+		Ok(user.to_string())
+	}
+
 	#[inline]
 	pub(crate) fn yazi() -> String {
 		Self::merge_str("yazi.toml", include_str!("../preset/yazi.toml"))
@@ -27,7 +43,7 @@ impl Preset {
 		*a = b.into_iter().chain(mem::take(a)).chain(c).collect();
 	}
 
-	fn merge(a: &mut Table, b: &Table, max: u8) {
+	pub(crate) fn merge(a: &mut Table, b: &Table, max: u8) {
 		for (k, v) in b {
 			let Some(a) = a.get_mut(k) else {
 				a.insert(k.clone(), v.clone());
@@ -49,11 +65,85 @@ impl Preset {
 	}
 
 	fn merge_str(user: &str, base: &str) -> String {
-		let path = BOOT.config_dir.join(user);
-		let mut user = fs::read_to_string(path).unwrap_or_default().parse::<Table>().unwrap();
+		let mut user = Self::layered(user).unwrap();
 
 		let base = base.parse::<Table>().unwrap();
 		Self::merge(&mut user, &base, 2);
 		user.to_string()
 	}
+
+	/// The config file of the name, with the same one of each overlay laid onto it in turn.
+	fn layered(name: &str) -> anyhow::Result<Table> {
+		let read = |p: PathBuf| fs::read_to_string(p).unwrap_or_default().parse::<Table>();
+
+		let mut table = read(BOOT.config_dir.join(name))?;
+		for dir in &BOOT.overlays {
+			Self::overlay(&mut table, read(dir.join(name))?);
+		}
+		Ok(table)
+	}
+
+	/// Lays the overlay onto the config, replacing the keys of each section it has, except
+	/// for the `prepend_*` and `append_*` arrays, which add to those of the config.
+	fn overlay(a: &mut Table, b: Table) {
+		for (section, b) in b {
+			let Value::Table(b) = b else {
+				a.insert(section, b);
+				continue;
+			};
+			let Some(a) = a.get_mut(&section).and_then(|v| v.as_table_mut()) else {
+				a.insert(section, Value::Table(b));
+				continue;
+			};
+
+			for (k, v) in b {
+				match (a.get_mut(&k).and_then(|v| v.as_array_mut()), v) {
+					(Some(old), Value::Array(mut new)) if k.starts_with("prepend_") => {
+						new.append(old);
+						*old = new;
+					}
+					(Some(old), Value::Array(new)) if k.starts_with("append_") => old.extend(new),
+					(_, v) => _ = a.insert(k, v),
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_overlay() {
+		let mut base: Table = r#"
+			[manager]
+			show_hidden    = false
+			sort_by        = "natural"
+			prepend_keymap = [ "a" ]
+			append_keymap  = [ "b" ]
+		"#
+		.parse()
+		.unwrap();
+		let overlay = r#"
+			[manager]
+			show_hidden    = true
+			prepend_keymap = [ "c" ]
+			append_keymap  = [ "d" ]
+			[tasks]
+			micro_workers = 1
+		"#;
+
+		Preset::overlay(&mut base, overlay.parse().unwrap());
+		let expected = r#"
+			[manager]
+			show_hidden    = true
+			sort_by        = "natural"
+			prepend_keymap = [ "c", "a" ]
+			append_keymap  = [ "b", "d" ]
+			[tasks]
+			micro_workers = 1
+		"#;
+		assert_eq!(base, expected.parse().unwrap());
+	}
 }
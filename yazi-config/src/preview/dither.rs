@@ -0,0 +1,39 @@
+use std::{fmt::{self, Display}, str::FromStr};
+
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum SixelDither {
+	None,
+	#[default]
+	FloydSteinberg,
+}
+
+impl FromStr for SixelDither {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"none" => Self::None,
+			"floyd-steinberg" => Self::FloydSteinberg,
+			_ => bail!("invalid sixel_dither value: {s}"),
+		})
+	}
+}
+
+impl TryFrom<String> for SixelDither {
+	type Error = anyhow::Error;
+
+	fn try_from(s: String) -> Result<Self, Self::Error> { Self::from_str(&s) }
+}
+
+impl Display for SixelDither {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::None => "none",
+			Self::FloydSteinberg => "floyd-steinberg",
+		})
+	}
+}
@@ -1,3 +1,5 @@
+mod dither;
 mod preview;
 
+pub use dither::*;
 pub use preview::*;
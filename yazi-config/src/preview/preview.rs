@@ -1,9 +1,10 @@
-use std::{fs, path::PathBuf, process, time::{self, SystemTime}};
+use std::{collections::HashMap, fs, path::PathBuf, process, time::{self, SystemTime}};
 
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 use yazi_shared::fs::expand_path;
 
+use super::SixelDither;
 use crate::{validation::check_validation, xdg::Xdg, ARGS, MERGED_YAZI};
 
 #[derive(Debug, Serialize)]
@@ -11,39 +12,52 @@ pub struct Preview {
 	pub tab_size:   u8,
 	pub max_width:  u32,
 	pub max_height: u32,
+	pub follow:     bool,
 
 	pub cache_dir: PathBuf,
 
 	pub image_filter:   String,
 	pub image_quality:  u8,
 	pub sixel_fraction: u8,
+	pub sixel_colors:   u16,
+	pub sixel_dither:   SixelDither,
 
 	pub ueberzug_scale:  f32,
 	pub ueberzug_offset: (f32, f32, f32, f32),
+
+	pub backend: HashMap<String, String>,
+}
+
+// The `[preview]` as it's written, before the cache directory is resolved
+#[derive(Deserialize, Validate)]
+pub(crate) struct PreviewShadow {
+	tab_size:   u8,
+	max_width:  u32,
+	max_height: u32,
+	follow:     bool,
+
+	cache_dir: Option<String>,
+
+	image_filter:   String,
+	#[validate(range(min = 50, max = 90))]
+	image_quality:  u8,
+	#[validate(range(min = 10, max = 20))]
+	sixel_fraction: u8,
+	#[validate(range(min = 2, max = 256))]
+	sixel_colors:   u16,
+	sixel_dither:   SixelDither,
+
+	ueberzug_scale:  f32,
+	ueberzug_offset: (f32, f32, f32, f32),
+
+	backend: HashMap<String, String>,
 }
 
 impl Default for Preview {
 	fn default() -> Self {
 		#[derive(Deserialize)]
 		struct Outer {
-			preview: Shadow,
-		}
-		#[derive(Deserialize, Validate)]
-		struct Shadow {
-			tab_size:   u8,
-			max_width:  u32,
-			max_height: u32,
-
-			cache_dir: Option<String>,
-
-			image_filter:   String,
-			#[validate(range(min = 50, max = 90))]
-			image_quality:  u8,
-			#[validate(range(min = 10, max = 20))]
-			sixel_fraction: u8,
-
-			ueberzug_scale:  f32,
-			ueberzug_offset: (f32, f32, f32, f32),
+			preview: PreviewShadow,
 		}
 
 		let preview = toml::from_str::<Outer>(&MERGED_YAZI).unwrap().preview;
@@ -73,15 +87,20 @@ impl Default for Preview {
 			tab_size: preview.tab_size,
 			max_width: preview.max_width,
 			max_height: preview.max_height,
+			follow: preview.follow,
 
 			cache_dir,
 
 			image_filter: preview.image_filter,
 			image_quality: preview.image_quality,
 			sixel_fraction: preview.sixel_fraction,
+			sixel_colors: preview.sixel_colors,
+			sixel_dither: preview.sixel_dither,
 
 			ueberzug_scale: preview.ueberzug_scale,
 			ueberzug_offset: preview.ueberzug_offset,
+
+			backend: preview.backend,
 		}
 	}
 }
@@ -0,0 +1,3 @@
+mod protect;
+
+pub use protect::*;
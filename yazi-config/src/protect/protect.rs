@@ -0,0 +1,102 @@
+use std::path::Path;
+
+use serde::{Deserialize, Deserializer};
+use yazi_shared::fs::expand_path;
+
+use crate::{Pattern, MERGED_YAZI};
+
+/// How the files in a protected path are kept from being removed, moved away or renamed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protection {
+	/// Refused altogether
+	ReadOnly,
+	/// Asked to type the name of the protected path first
+	Confirm,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Protect {
+	#[serde(deserialize_with = "deserialize_patterns")]
+	read_only: Vec<Pattern>,
+	#[serde(deserialize_with = "deserialize_patterns")]
+	confirm:   Vec<Pattern>,
+}
+
+impl Default for Protect {
+	fn default() -> Self {
+		#[derive(Deserialize)]
+		struct Outer {
+			protect: Protect,
+		}
+
+		toml::from_str::<Outer>(&MERGED_YAZI).unwrap().protect
+	}
+}
+
+impl Protect {
+	/// The protection of the path, and the protected path it's in, i.e. itself or the closest
+	/// of its parents matching a pattern, with the read-only ones taking precedence.
+	pub fn of<'a>(&self, path: &'a Path) -> Option<(Protection, &'a Path)> {
+		let find = |patterns: &[Pattern]| {
+			path.ancestors().find(|&p| patterns.iter().any(|pat| pat.matches(p.to_string_lossy())))
+		};
+
+		find(&self.read_only)
+			.map(|p| (Protection::ReadOnly, p))
+			.or_else(|| find(&self.confirm).map(|p| (Protection::Confirm, p)))
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool { self.read_only.is_empty() && self.confirm.is_empty() }
+}
+
+// The `~` and environment variables in them expanded, for them to match the absolute paths
+fn deserialize_patterns<'de, D>(deserializer: D) -> Result<Vec<Pattern>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Vec::<String>::deserialize(deserializer)?
+		.into_iter()
+		.map(|s| Pattern::try_from(expand_path(s).to_string_lossy().as_ref()))
+		.collect::<Result<_, _>>()
+		.map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn protect(read_only: &[&str], confirm: &[&str]) -> Protect {
+		let patterns = |v: &[&str]| v.iter().map(|&s| Pattern::try_from(s).unwrap()).collect();
+		Protect { read_only: patterns(read_only), confirm: patterns(confirm) }
+	}
+
+	#[test]
+	fn test_of_ancestors() {
+		let p = protect(&[], &["/home/me/proj"]);
+		let zone = Some((Protection::Confirm, Path::new("/home/me/proj")));
+
+		assert_eq!(p.of(Path::new("/home/me/proj")), zone);
+		assert_eq!(p.of(Path::new("/home/me/proj/src/main.rs")), zone);
+		assert_eq!(p.of(Path::new("/home/me/project")), None);
+		assert_eq!(p.of(Path::new("/home/me")), None);
+	}
+
+	#[test]
+	fn test_of_glob() {
+		let p = protect(&[], &["/home/me/*"]);
+		let path = Path::new("/home/me/a/b/c");
+		assert_eq!(p.of(path), Some((Protection::Confirm, Path::new("/home/me/a"))));
+		assert_eq!(p.of(Path::new("/home/me")), None);
+	}
+
+	#[test]
+	fn test_of_read_only_first() {
+		let p = protect(&["/etc"], &["/etc/nginx", "/usr"]);
+		let path = Path::new("/etc/nginx/nginx.conf");
+		assert_eq!(p.of(path), Some((Protection::ReadOnly, Path::new("/etc"))));
+		assert_eq!(p.of(Path::new("/usr/bin")), Some((Protection::Confirm, Path::new("/usr"))));
+		assert!(!p.is_empty());
+		assert!(protect(&[], &[]).is_empty());
+	}
+}
@@ -1,3 +1,5 @@
+mod remove;
 mod tasks;
 
+pub use remove::*;
 pub use tasks::*;
@@ -0,0 +1,93 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::Pattern;
+
+/// Whether the files under a mount point, or on a type of filesystem, are trashed or deleted
+/// permanently by `remove`, e.g. deleted on a tmpfs, or a network mount where trashing is slow.
+#[derive(Debug, Deserialize)]
+pub struct RemoveRule {
+	mount:  Option<Pattern>,
+	fstype: Option<Pattern>,
+	action: RemoveAction,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum RemoveAction {
+	Trash,
+	Delete,
+}
+
+impl RemoveRule {
+	#[inline]
+	fn matches(&self, mount: &Path, fstype: &str) -> bool {
+		self.mount.as_ref().is_none_or(|p| p.matches(mount.to_string_lossy()))
+			&& self.fstype.as_ref().is_none_or(|p| p.matches(fstype))
+	}
+
+	fn permanently(rules: &[Self], mount: &Path, fstype: &str) -> bool {
+		rules.iter().find(|r| r.matches(mount, fstype)).map(|r| r.action) == Some(RemoveAction::Delete)
+	}
+}
+
+impl super::Tasks {
+	/// Whether the files on the mount point are deleted permanently rather than trashed,
+	/// by the first rule that matches it, or trashed if none does.
+	#[inline]
+	pub fn remove_permanently(&self, mount: &Path, fstype: &str) -> bool {
+		RemoveRule::permanently(&self.remove_rules, mount, fstype)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Deserialize)]
+	struct Outer {
+		tasks: Inner,
+	}
+
+	#[derive(Deserialize)]
+	struct Inner {
+		remove_rules: Vec<RemoveRule>,
+	}
+
+	fn rules(s: &str) -> Vec<RemoveRule> { toml::from_str::<Outer>(s).unwrap().tasks.remove_rules }
+
+	#[test]
+	fn test_remove_permanently() {
+		let rules = rules(
+			r#"
+			[tasks]
+			remove_rules = [
+				{ mount = "/mnt/keep*", action = "trash" },
+				{ fstype = "nfs*", action = "delete" },
+				{ mount = "/tmp", action = "delete" },
+			]
+			"#,
+		);
+		let is = |mount: &str, fstype: &str| RemoveRule::permanently(&rules, Path::new(mount), fstype);
+
+		assert!(is("/mnt/nas", "nfs4"));
+		assert!(!is("/mnt/keep-nas", "nfs4"));
+		assert!(is("/tmp", "ext4"));
+		assert!(!is("/tmp/a", "ext4"));
+		assert!(!is("/", "ext4"));
+	}
+
+	#[test]
+	fn test_remove_preset() {
+		let rules = rules(include_str!("../../preset/yazi.toml"));
+		let is = |fstype: &str| RemoveRule::permanently(&rules, Path::new("/mnt"), fstype);
+
+		assert!(is("tmpfs"));
+		assert!(is("nfs"));
+		assert!(is("smbfs"));
+		assert!(is("fuse.sshfs"));
+		assert!(!is("ext4"));
+		assert!(!is("apfs"));
+	}
+}
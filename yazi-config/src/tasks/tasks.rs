@@ -1,7 +1,8 @@
 use serde::Deserialize;
 use validator::Validate;
 
-use crate::{validation::check_validation, MERGED_YAZI};
+use super::RemoveRule;
+use crate::{validation::check_validation, Priority, MERGED_YAZI};
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct Tasks {
@@ -10,12 +11,32 @@ pub struct Tasks {
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub macro_workers: u8,
 	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub prework_workers: u8,
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
 	pub bizarre_retry: u8,
+	pub paste_prescan: u32,
 
-	pub image_alloc: u32,
-	pub image_bound: [u16; 2],
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub image_workers: u8,
+	pub image_alloc:   u32,
+	pub image_bound:   [u16; 2],
+	#[validate(range(min = 1, message = "Cannot be less than 1"))]
+	pub thumb_workers: u8,
 
 	pub suppress_preload: bool,
+	pub report_progress:  bool,
+	pub notify_after:     u16,
+
+	pub priority_paste:    Priority,
+	pub priority_link:     Priority,
+	pub priority_delete:   Priority,
+	pub priority_trash:    Priority,
+	pub priority_chmod:    Priority,
+	pub priority_checksum: Priority,
+	pub priority_preload:  Priority,
+	pub priority_plugin:   Priority,
+
+	pub remove_rules: Vec<RemoveRule>,
 }
 
 impl Default for Tasks {
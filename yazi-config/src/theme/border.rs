@@ -0,0 +1,227 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use ratatui::{style::Style, symbols::border, text::{Line, Span}, widgets::{Block, Padding}};
+use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
+
+// Drawn in place of the border without one, which keeps the space of the border that the
+// titles, and the sizes of the panels, such as the `TASKS_BORDER` of the tasks, count on
+const BLANK: border::Set = border::Set {
+	top_left:          " ",
+	top_right:         " ",
+	bottom_left:       " ",
+	bottom_right:      " ",
+	vertical_left:     " ",
+	vertical_right:    " ",
+	horizontal_top:    " ",
+	horizontal_bottom: " ",
+};
+
+/// A set of border characters, or no border at all, in which case the
+/// bordered area is separated from the others only by padding.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(try_from = "BorderShadow")]
+pub enum Border {
+	None,
+	Set {
+		set:       border::Set,
+		// Around the titles where they cut the border, such as "┤" and "├", none by the presets
+		junctions: [&'static str; 2],
+	},
+}
+
+impl Border {
+	/// A block with the border, or the padding taking its place, around the `padding`.
+	/// Without a border, the title takes the place of the top one.
+	pub fn block(self, padding: Padding) -> Block<'static> {
+		let set = match self {
+			Self::None => BLANK,
+			Self::Set { set, .. } => set,
+		};
+		Block::bordered().border_set(set).padding(padding)
+	}
+
+	/// The title in between the junctions of the border, in the style of the border.
+	pub fn title<'a>(self, title: impl Into<Line<'a>>, style: impl Into<Style>) -> Line<'a> {
+		let mut line = title.into();
+		let Self::Set { junctions: [left, right], .. } = self else {
+			return line;
+		};
+
+		let style = style.into();
+		if !left.is_empty() {
+			line.spans.insert(0, Span::styled(left, style));
+		}
+		if !right.is_empty() {
+			line.spans.push(Span::styled(right, style));
+		}
+		line
+	}
+
+	fn named(s: &str) -> Result<Self, String> {
+		let set = match s {
+			"none" => return Ok(Self::None),
+			"plain" => border::PLAIN,
+			"rounded" => border::ROUNDED,
+			"double" => border::DOUBLE,
+			"thick" => border::THICK,
+			_ => return Err(format!("unknown border `{s}`")),
+		};
+		Ok(Self::Set { set, junctions: ["", ""] })
+	}
+}
+
+impl Serialize for Border {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let Self::Set { set, junctions: [left, right] } = self else {
+			return serializer.serialize_str("none");
+		};
+
+		let mut map = serializer.serialize_map(Some(8))?;
+		map.serialize_entry("top_left", set.top_left)?;
+		map.serialize_entry("top_right", set.top_right)?;
+		map.serialize_entry("bottom_left", set.bottom_left)?;
+		map.serialize_entry("bottom_right", set.bottom_right)?;
+		map.serialize_entry("vertical", set.vertical_left)?;
+		map.serialize_entry("horizontal", set.horizontal_top)?;
+		map.serialize_entry("junction_left", left)?;
+		map.serialize_entry("junction_right", right)?;
+		map.end()
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BorderShadow {
+	Name(String),
+	Set(SetShadow),
+}
+
+#[derive(Deserialize)]
+struct SetShadow {
+	top_left:       String,
+	top_right:      String,
+	bottom_left:    String,
+	bottom_right:   String,
+	vertical:       String,
+	horizontal:     String,
+	#[serde(default)]
+	junction_left:  String,
+	#[serde(default)]
+	junction_right: String,
+}
+
+impl TryFrom<BorderShadow> for Border {
+	type Error = String;
+
+	fn try_from(value: BorderShadow) -> Result<Self, Self::Error> {
+		let set = match value {
+			BorderShadow::Name(s) => return Self::named(&s),
+			BorderShadow::Set(set) => set,
+		};
+
+		let (vertical, horizontal) = (intern(set.vertical), intern(set.horizontal));
+		Ok(Self::Set {
+			set:       border::Set {
+				top_left:          intern(set.top_left),
+				top_right:         intern(set.top_right),
+				bottom_left:       intern(set.bottom_left),
+				bottom_right:      intern(set.bottom_right),
+				vertical_left:     vertical,
+				vertical_right:    vertical,
+				horizontal_top:    horizontal,
+				horizontal_bottom: horizontal,
+			},
+			junctions: [intern(set.junction_left), intern(set.junction_right)],
+		})
+	}
+}
+
+// The characters of a border set are `&'static str`s, which are leaked once for each string,
+// so that loading the themes again and again doesn't take more memory than the first time
+fn intern(s: String) -> &'static str {
+	static INTERNED: Mutex<Option<HashSet<&'static str>>> = Mutex::new(None);
+
+	let mut interned = INTERNED.lock().unwrap();
+	let interned = interned.get_or_insert_with(Default::default);
+	if let Some(&s) = interned.get(s.as_str()) {
+		return s;
+	}
+
+	let s = Box::leak(s.into_boxed_str());
+	interned.insert(s);
+	s
+}
+
+/// How much padding there is around the items of the lists.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Density {
+	Compact,
+	#[default]
+	Normal,
+	Relaxed,
+}
+
+impl Density {
+	#[inline]
+	pub fn padding(self) -> Padding {
+		match self {
+			Self::Compact => Padding::zero(),
+			Self::Normal => Padding::symmetric(1, 1),
+			Self::Relaxed => Padding::symmetric(2, 1),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::{layout::Rect, widgets::block::{Position, Title}};
+
+	use super::*;
+
+	#[test]
+	fn test_block() {
+		let area = Rect::new(0, 0, 20, 10);
+		let inner = |border: Border| {
+			let block = border.block(Padding::symmetric(1, 1)).title("Tasks");
+			block.title(Title::from("lanes").position(Position::Bottom)).inner(area)
+		};
+
+		// The same space is left without a border as with one, titled at the bottom or not
+		assert_eq!(inner(Border::None), inner(Border::named("rounded").unwrap()));
+		assert_eq!(inner(Border::None), Rect::new(2, 2, 16, 6));
+	}
+
+	#[test]
+	fn test_title() {
+		let custom: Border = toml::from_str::<toml::Value>(
+			r#"
+			top_left     = "┌"
+			top_right    = "┐"
+			bottom_left  = "└"
+			bottom_right = "┘"
+			vertical     = "│"
+			horizontal   = "─"
+			junction_left  = "┤"
+			junction_right = "├"
+			"#,
+		)
+		.and_then(|v| v.try_into())
+		.unwrap();
+
+		let title = |border: Border| border.title("Tasks", Style::new()).to_string();
+		assert_eq!(title(custom), "┤Tasks├");
+		assert_eq!(title(Border::named("rounded").unwrap()), "Tasks");
+		assert_eq!(title(Border::None), "Tasks");
+
+		// Interned, for the same string not to be leaked again
+		let Border::Set { set, junctions } = custom else {
+			unreachable!();
+		};
+		assert!(std::ptr::eq(set.vertical_left, intern("│".to_owned())));
+		assert!(std::ptr::eq(junctions[0], intern("┤".to_owned())));
+	}
+}
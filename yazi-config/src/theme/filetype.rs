@@ -1,17 +1,18 @@
-use std::str::FromStr;
+use std::{cmp::Reverse, str::FromStr};
 
 use anyhow::bail;
 use serde::{Deserialize, Deserializer};
 use yazi_shared::fs::File;
 
 use super::{Color, Style, StyleShadow};
-use crate::Pattern;
+use crate::{Pattern, Priority};
 
 pub struct Filetype {
 	pub is:    FiletypeIs,
 	pub name:  Option<Pattern>,
 	pub mime:  Option<Pattern>,
 	pub style: Style,
+	pub icon:  Option<Style>,
 }
 
 impl Filetype {
@@ -31,8 +32,14 @@ impl Filetype {
 			return false;
 		}
 
-		self.name.as_ref().is_some_and(|n| n.match_path(&file.url, file.is_dir()))
-			|| self.mime.as_ref().zip(mime).map_or(false, |(m, s)| m.matches(s))
+		// Both have to match if both are given
+		let name = self.name.as_ref().map(|n| n.match_path(&file.url, file.is_dir()));
+		let mime = self.mime.as_ref().map(|m| mime.is_some_and(|s| m.matches(s)));
+		match (name, mime) {
+			(Some(a), Some(b)) => a && b,
+			(Some(a), None) | (None, Some(a)) => a,
+			(None, None) => false,
+		}
 	}
 }
 
@@ -51,6 +58,9 @@ impl Filetype {
 			is:   FiletypeIs,
 			name: Option<Pattern>,
 			mime: Option<Pattern>,
+			#[serde(default)]
+			prio: Priority,
+			icon: Option<Style>,
 
 			fg:          Option<Color>,
 			bg:          Option<Color>,
@@ -74,14 +84,18 @@ impl Filetype {
 			crossed:     bool,
 		}
 
+		// The rules of a higher priority come first, otherwise they're in the order given
+		let mut rules = FiletypeOuter::deserialize(deserializer)?.rules;
+		rules.sort_by_key(|r| Reverse(r.prio as u8));
+
 		Ok(
-			FiletypeOuter::deserialize(deserializer)?
-				.rules
+			rules
 				.into_iter()
 				.map(|r| Filetype {
 					is:    r.is,
 					name:  r.name,
 					mime:  r.mime,
+					icon:  r.icon,
 					style: StyleShadow {
 						fg:          r.fg,
 						bg:          r.bg,
@@ -1,3 +1,5 @@
+use std::{collections::HashMap, path::Path, sync::OnceLock};
+
 use serde::{Deserialize, Deserializer};
 
 use super::Style;
@@ -9,8 +11,79 @@ pub struct Icon {
 	pub style: Style,
 }
 
-impl Icon {
-	pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Icon>, D::Error>
+/// The icon rules, indexed by the literal names and extensions they match,
+/// so that finding the icon of a file doesn't go through all of them.
+#[derive(Default)]
+pub struct Icons {
+	rules: Vec<Icon>,
+	index: OnceLock<IconsIndex>,
+}
+
+#[derive(Default)]
+struct IconsIndex {
+	names: HashMap<(String, bool), usize>,
+	exts:  HashMap<(String, bool), usize>,
+	globs: Vec<usize>,
+}
+
+impl Icons {
+	pub fn matches(&self, path: &Path, is_dir: bool) -> Option<&Icon> {
+		// Go through the rules one by one until the index is ready
+		let Some(index) = self.index.get() else {
+			return self.rules.iter().find(|r| r.name.match_path(path, is_dir));
+		};
+
+		// The first matching rule wins, so take the earliest one of the indexed
+		let mut best = usize::MAX;
+		if let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_ascii_lowercase()) {
+			let mut key = (name.clone(), is_dir);
+			if let Some(&i) = index.names.get(&key) {
+				best = i;
+			}
+
+			for (i, _) in name.match_indices('.') {
+				key.0 = name[i + 1..].to_owned();
+				if let Some(&i) = index.exts.get(&key) {
+					best = best.min(i);
+				}
+			}
+		}
+
+		// and check the rest which come before it one by one
+		index
+			.globs
+			.iter()
+			.take_while(|&&i| i < best)
+			.find(|&&i| self.rules[i].name.match_path(path, is_dir))
+			.or(Some(&best))
+			.and_then(|&i| self.rules.get(i))
+	}
+
+	/// Builds the index, which is deferred until after the first frame to speed up the startup.
+	pub fn prepare(&self) {
+		self.index.get_or_init(|| {
+			let mut index = IconsIndex::default();
+			let literal = |s: &str| s.is_ascii() && !s.contains(['*', '?', '[', ']', '/']);
+			for (i, rule) in self.rules.iter().enumerate() {
+				let p = &rule.name;
+				let s = p.inner.as_str();
+				if p.sensitive || p.full_path {
+					index.globs.push(i);
+				} else if let Some(ext) = s.strip_prefix("*.").filter(|e| literal(e)) {
+					index.exts.entry((ext.to_ascii_lowercase(), p.is_folder)).or_insert(i);
+				} else if literal(s) {
+					index.names.entry((s.to_ascii_lowercase(), p.is_folder)).or_insert(i);
+				} else {
+					index.globs.push(i);
+				}
+			}
+			index
+		});
+	}
+}
+
+impl Icons {
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Icons, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
@@ -33,8 +106,8 @@ impl Icon {
 		let mut outer = IconOuter::deserialize(deserializer)?;
 		Preset::mix(&mut outer.rules, outer.prepend_rules, outer.append_rules);
 
-		Ok(
-			outer
+		Ok(Icons {
+			rules: outer
 				.rules
 				.into_iter()
 				.map(|r| Icon {
@@ -43,6 +116,32 @@ impl Icon {
 					style: StyleShadow { fg: r.fg, ..Default::default() }.into(),
 				})
 				.collect(),
-		)
+			..Default::default()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_icons_matches() {
+		#[derive(Deserialize)]
+		struct Outer {
+			#[serde(deserialize_with = "Icons::deserialize")]
+			icon: Icons,
+		}
+
+		let icons = toml::from_str::<Outer>(include_str!("../../preset/theme.toml")).unwrap().icon;
+		icons.prepare();
+		let names = ["a.rs", "Cargo.TOML", ".gitignore", "x.tar.gz", "README.md", "noext", "ünï.PY"];
+		for name in names {
+			for is_dir in [false, true] {
+				let linear = icons.rules.iter().find(|r| r.name.match_path(name, is_dir));
+				let indexed = icons.matches(Path::new(name), is_dir);
+				assert_eq!(indexed.map(|i| &i.text), linear.map(|i| &i.text), "{name}");
+			}
+		}
 	}
 }
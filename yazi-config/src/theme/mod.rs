@@ -1,9 +1,11 @@
+mod border;
 mod color;
 mod filetype;
 mod icon;
 mod style;
 mod theme;
 
+pub use border::*;
 pub use color::*;
 pub use filetype::*;
 pub use icon::*;
@@ -1,11 +1,12 @@
-use std::path::PathBuf;
+use std::path::{PathBuf, MAIN_SEPARATOR};
 
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
-use yazi_shared::fs::expand_path;
+use yazi_shared::fs::{expand_path, File};
 
-use super::{Filetype, Icon, Style};
-use crate::{validation::check_validation, MERGED_THEME};
+use super::{Border, Density, Filetype, Icons, Style};
+use crate::{validation::check_validation, Preset, BOOT, MERGED_THEME};
 
 #[derive(Deserialize, Serialize, Validate)]
 pub struct Manager {
@@ -28,14 +29,20 @@ pub struct Manager {
 	// Tab
 	tab_active:   Style,
 	tab_inactive: Style,
+	tab_overflow: Style,
 	#[validate(range(min = 1, message = "Must be greater than 0"))]
 	tab_width:    u8,
+	tab_format:   String,
 
 	// Selected counter
 	count_selected: Style,
 	count_copied:   Style,
 	count_cut:      Style,
 
+	// Details
+	column_header: Style,
+	column_sorted: Style,
+
 	// Border
 	pub border_symbol: String,
 	pub border_style:  Style,
@@ -44,12 +51,24 @@ pub struct Manager {
 	pub syntect_theme: PathBuf,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Ui {
+	pub border_popup: Border,
+	pub border_panel: Border,
+	pub density:      Density,
+}
+
 #[derive(Deserialize, Serialize)]
 struct Status {
 	pub separator_open:  String,
 	pub separator_close: String,
 	pub separator_style: Style,
 
+	// Segments
+	pub left:   Vec<String>,
+	pub center: Vec<String>,
+	pub right:  Vec<String>,
+
 	// Mode
 	pub mode_normal: Style,
 	pub mode_select: Style,
@@ -66,6 +85,15 @@ struct Status {
 	pub permissions_w: Style,
 	pub permissions_x: Style,
 	pub permissions_s: Style,
+
+	// Extra segments
+	pub selected:  Style,
+	pub view:      Style,
+	pub filter:    Style,
+	pub tasks:     Style,
+	pub disk:      Style,
+	pub disk_full: Style,
+	pub git:       Style,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -83,11 +111,43 @@ pub struct Select {
 	pub inactive: Style,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Chmod {
+	pub border: Style,
+	pub active: Style,
+	pub read:   Style,
+	pub write:  Style,
+	pub exec:   Style,
+	pub unset:  Style,
+	pub value:  Style,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Spot {
+	pub border: Style,
+	pub title:  Style,
+	pub key:    Style,
+	pub value:  Style,
+	pub active: Style,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Checksum {
+	pub border: Style,
+	pub title:  Style,
+	pub name:   Style,
+	pub hash:   Style,
+	pub active: Style,
+	pub pass:   Style,
+	pub fail:   Style,
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct Completion {
-	pub border:   Style,
-	pub active:   Style,
-	pub inactive: Style,
+	pub border:    Style,
+	pub active:    Style,
+	pub inactive:  Style,
+	pub highlight: Style,
 
 	pub icon_file:    String,
 	pub icon_folder:  String,
@@ -101,6 +161,38 @@ pub struct Tasks {
 	pub hovered: Style,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Mounts {
+	pub border:     Style,
+	pub title:      Style,
+	pub hovered:    Style,
+	pub gauge:      Style,
+	pub gauge_full: Style,
+	pub full:       u8,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Sidebar {
+	pub border:  Style,
+	pub title:   Style,
+	pub hovered: Style,
+	pub active:  Style,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Notify {
+	pub title_info:  Style,
+	pub title_warn:  Style,
+	pub title_error: Style,
+
+	pub icon_info:  String,
+	pub icon_warn:  String,
+	pub icon_error: String,
+
+	pub border:  Style,
+	pub hovered: Style,
+}
+
 #[derive(Deserialize, Serialize, Validate)]
 pub struct Which {
 	#[validate(range(min = 1, max = 3, message = "Must be between 1 and 3"))]
@@ -122,24 +214,32 @@ pub struct Help {
 
 	pub hovered: Style,
 	pub footer:  Style,
+	pub group:   Style,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct Theme {
 	pub manager:    Manager,
+	pub ui:         Ui,
 	status:         Status,
 	pub input:      Input,
 	pub select:     Select,
+	pub chmod:      Chmod,
+	pub spot:       Spot,
+	pub checksum:   Checksum,
 	pub completion: Completion,
 	pub tasks:      Tasks,
+	pub mounts:     Mounts,
+	pub sidebar:    Sidebar,
+	pub notify:     Notify,
 	pub which:      Which,
 	pub help:       Help,
 
 	// File-specific styles
 	#[serde(rename = "filetype", deserialize_with = "Filetype::deserialize", skip_serializing)]
 	pub filetypes: Vec<Filetype>,
-	#[serde(rename = "icon", deserialize_with = "Icon::deserialize", skip_serializing)]
-	pub icons:     Vec<Icon>,
+	#[serde(rename = "icon", deserialize_with = "Icons::deserialize", skip_serializing)]
+	pub icons:     Icons,
 }
 
 impl Default for Theme {
@@ -154,3 +254,30 @@ impl Default for Theme {
 		theme
 	}
 }
+
+impl Theme {
+	/// Loads another theme to switch to at runtime: `theme.toml` with its overlays for `None`, or by
+	/// its name, the one in the `themes` directory under the config directory, unless it's a path.
+	pub fn load(name: Option<&str>) -> Result<Self> {
+		let merged = match name {
+			None => Preset::try_theme()?,
+			Some(s) if s.contains(MAIN_SEPARATOR) || s.ends_with(".toml") => {
+				Preset::theme_at(&expand_path(s))?
+			}
+			Some(s) => Preset::theme_at(&BOOT.config_dir.join("themes").join(format!("{s}.toml")))?,
+		};
+		let mut theme: Self = toml::from_str(&merged)?;
+
+		theme.manager.validate()?;
+		theme.which.validate()?;
+
+		theme.manager.syntect_theme = expand_path(&theme.manager.syntect_theme);
+		Ok(theme)
+	}
+
+	/// The first of the filetype rules matching the file.
+	#[inline]
+	pub fn filetype(&self, file: &File, mime: Option<&str>) -> Option<&Filetype> {
+		self.filetypes.iter().find(|&f| f.matches(file, mime))
+	}
+}
@@ -0,0 +1,64 @@
+use yazi_config::popup::Position;
+use yazi_shared::fs::Url;
+
+pub const CHMOD_ROWS: [&str; 3] = ["Owner", "Group", "Other"];
+pub const CHMOD_COLS: [char; 3] = ['r', 'w', 'x'];
+
+/// The state of a cell in the permission matrix.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChmodBit {
+	On,
+	Off,
+	/// The bit differs among the targets, and hasn't been touched.
+	Mixed,
+}
+
+#[derive(Default)]
+pub struct Chmod {
+	pub(super) title:   String,
+	pub(super) targets: Vec<Url>,
+	pub position:       Position,
+
+	// The bits of the first target, the bits that differ among the targets,
+	// and the bits changed by the user, which are the only ones applied
+	pub(super) mode:    u32,
+	pub(super) mixed:   u32,
+	pub(super) changed: u32,
+
+	pub cursor:    usize,
+	pub recursive: bool,
+
+	pub visible: bool,
+}
+
+impl Chmod {
+	#[inline]
+	pub(super) fn mask(row: usize, col: usize) -> u32 { 0o400 >> (row * 3 + col) }
+
+	pub fn bit(&self, row: usize, col: usize) -> ChmodBit {
+		let mask = Self::mask(row, col);
+		if self.mixed & mask != 0 && self.changed & mask == 0 {
+			ChmodBit::Mixed
+		} else if self.mode & mask != 0 {
+			ChmodBit::On
+		} else {
+			ChmodBit::Off
+		}
+	}
+
+	/// The octal notation, with a `?` for the digits that differ among the targets.
+	pub fn octal(&self) -> String {
+		let mut s = String::from("0");
+		for row in 0..3 {
+			if (0..3).any(|col| self.bit(row, col) == ChmodBit::Mixed) {
+				s.push('?');
+			} else {
+				s.push(char::from(b'0' + (self.mode >> ((2 - row) * 3) & 0o7) as u8));
+			}
+		}
+		s
+	}
+
+	#[inline]
+	pub fn title(&self) -> &str { &self.title }
+}
@@ -0,0 +1,24 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::chmod::Chmod;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Chmod {
+	pub fn arrow(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let row = (self.cursor / 3).saturating_add_signed(opt.step).min(2);
+
+		let old = self.cursor;
+		self.cursor = row * 3 + self.cursor % 3;
+		render!(old != self.cursor);
+	}
+}
@@ -0,0 +1,34 @@
+use std::mem;
+
+use yazi_shared::{event::Cmd, render};
+
+use crate::{chmod::Chmod, tasks::Tasks};
+
+pub struct Opt {
+	submit: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { submit: c.named.contains_key("submit") } }
+}
+impl From<bool> for Opt {
+	fn from(submit: bool) -> Self { Self { submit } }
+}
+
+impl Chmod {
+	pub fn close(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let targets = mem::take(&mut self.targets);
+		if opt.submit && self.changed != 0 {
+			Tasks::_chmod(targets, self.mode, self.changed, self.recursive);
+		}
+
+		self.mode = 0;
+		self.mixed = 0;
+		self.changed = 0;
+		self.cursor = 0;
+		self.recursive = false;
+		self.visible = false;
+		render!();
+	}
+}
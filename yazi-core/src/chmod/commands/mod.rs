@@ -0,0 +1,6 @@
+mod arrow;
+mod close;
+mod move_;
+mod recursive;
+mod show;
+mod toggle;
@@ -0,0 +1,10 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::chmod::Chmod;
+
+impl Chmod {
+	pub fn recursive(&mut self, _: Cmd) {
+		self.recursive = !self.recursive;
+		render!();
+	}
+}
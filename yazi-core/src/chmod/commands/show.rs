@@ -0,0 +1,44 @@
+use yazi_config::{popup::SelectPopup, tr_config, SELECT};
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::chmod::Chmod;
+
+pub struct Opt {
+	targets: Vec<(Url, u32)>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { targets: c.take_data().ok_or(())? })
+	}
+}
+
+impl Chmod {
+	#[inline]
+	pub fn _show(targets: Vec<(Url, u32)>) {
+		emit!(Call(Cmd::new("show").with_data(targets), Layer::Chmod));
+	}
+
+	pub fn show(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+		let Some(&(_, first)) = opt.targets.first() else {
+			return;
+		};
+
+		self.close(false);
+		let popup = SELECT.popups.get(SelectPopup::Chmod);
+		self.title = tr_config!(&popup.title, n = opt.targets.len());
+		self.position = popup.position();
+
+		self.mode = first & 0o777;
+		self.mixed = opt.targets.iter().fold(0, |acc, &(_, m)| acc | ((m ^ first) & 0o777));
+		self.targets = opt.targets.into_iter().map(|(u, _)| u).collect();
+
+		self.visible = true;
+		render!();
+	}
+}
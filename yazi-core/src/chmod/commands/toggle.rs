@@ -0,0 +1,32 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::chmod::{Chmod, ChmodBit};
+
+pub struct Opt {
+	col: Option<usize>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self {
+		let col = ["read", "write", "exec"].iter().position(|&s| c.named.contains_key(s));
+		Self { col }
+	}
+}
+
+impl Chmod {
+	pub fn toggle(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let (row, col) = (self.cursor / 3, opt.col.unwrap_or(self.cursor % 3));
+
+		// A mixed bit gets set first, for all the targets
+		let mask = Self::mask(row, col);
+		if self.bit(row, col) == ChmodBit::On {
+			self.mode &= !mask;
+		} else {
+			self.mode |= mask;
+		}
+
+		self.changed |= mask;
+		render!();
+	}
+}
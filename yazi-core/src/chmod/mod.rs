@@ -0,0 +1,4 @@
+mod chmod;
+mod commands;
+
+pub use chmod::*;
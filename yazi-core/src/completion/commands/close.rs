@@ -20,10 +20,14 @@ impl Completion {
 		let opt = opt.into() as Opt;
 
 		if let Some(s) = self.selected().filter(|_| opt.submit) {
-			Input::_complete(s, self.ticket);
+			match self.bases.get(&self.name) {
+				Some(base) => Input::_complete(&format!("{base}{s}"), true, self.ticket),
+				None => Input::_complete(s, false, self.ticket),
+			}
 		}
 
 		self.caches.clear();
+		self.bases.clear();
 		self.visible = false;
 		render!();
 	}
@@ -0,0 +1,15 @@
+use yazi_shared::event::Cmd;
+
+use crate::completion::Completion;
+
+impl Completion {
+	pub fn hidden(&mut self, _: Cmd) {
+		self.hidden = Some(!self.show_hidden());
+		self.show(
+			Cmd::new("show")
+				.with("cache-name", self.name.clone())
+				.with("word", self.word.clone())
+				.with("ticket", self.ticket),
+		);
+	}
+}
@@ -1,4 +1,5 @@
 mod arrow;
 mod close;
+mod hidden;
 mod show;
 mod trigger;
@@ -1,15 +1,18 @@
-use std::{mem, ops::ControlFlow};
+use std::mem;
 
 use yazi_shared::{event::Cmd, render};
 
-use crate::completion::Completion;
+use crate::{completion::Completion, folder::{FilterCase, Fuzzy}};
 
 const LIMIT: usize = 30;
 
 pub struct Opt {
 	cache:      Vec<String>,
 	cache_name: String,
+	base:       Option<String>,
 	word:       String,
+	all:        bool,
+	ranked:     Option<FilterCase>,
 	ticket:     usize,
 }
 
@@ -18,57 +21,66 @@ impl From<Cmd> for Opt {
 		Self {
 			cache:      mem::take(&mut c.args),
 			cache_name: c.take_name("cache-name").unwrap_or_default(),
+			base:       c.take_name("base"),
 			word:       c.take_name("word").unwrap_or_default(),
+			all:        c.named.contains_key("all"),
+			ranked:     c.named.contains_key("ranked").then(|| FilterCase::from(&c)),
 			ticket:     c.take_name("ticket").and_then(|v| v.parse().ok()).unwrap_or(0),
 		}
 	}
 }
 
 impl Completion {
-	fn match_candidates(word: &str, cache: &[String]) -> Vec<String> {
-		let flow = cache.iter().try_fold(
-			(Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT)),
-			|(mut prefixed, mut fuzzy), s| {
-				if s.starts_with(word) {
-					if s != word {
-						prefixed.push(s);
-						if prefixed.len() >= LIMIT {
-							return ControlFlow::Break((prefixed, fuzzy));
-						}
+	fn match_candidates(word: &str, cache: &[String], hidden: bool) -> Vec<String> {
+		let hidden = hidden || word.starts_with('.');
+
+		let (mut prefixed, mut fuzzy) = (Vec::with_capacity(LIMIT), Vec::with_capacity(LIMIT));
+		for s in cache {
+			if !hidden && s.starts_with('.') {
+				continue;
+			} else if s.starts_with(word) {
+				if s != word {
+					prefixed.push(s);
+					if prefixed.len() >= LIMIT {
+						break;
 					}
-				} else if fuzzy.len() < LIMIT - prefixed.len() && s.contains(word) {
-					// here we don't break the control flow, since we want more exact matching.
-					fuzzy.push(s)
 				}
-				ControlFlow::Continue((prefixed, fuzzy))
-			},
-		);
-
-		let (mut prefixed, fuzzy) = match flow {
-			ControlFlow::Continue(v) => v,
-			ControlFlow::Break(v) => v,
-		};
-		if prefixed.len() < LIMIT {
-			prefixed.extend(fuzzy.into_iter().take(LIMIT - prefixed.len()))
+			} else if fuzzy.len() < LIMIT - prefixed.len() && s.contains(word) {
+				// here we don't break the loop, since we want more exact matching.
+				fuzzy.push(s);
+			}
 		}
-		prefixed.into_iter().map(ToOwned::to_owned).collect()
+
+		prefixed.into_iter().chain(fuzzy).take(LIMIT).map(ToOwned::to_owned).collect()
 	}
 
 	pub fn show(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		if self.ticket != opt.ticket {
+		if self.is_stale(opt.ticket) {
 			return;
 		}
 
-		if !opt.cache.is_empty() {
+		// Ranked candidates are replaced as a whole, even if nothing matches anymore
+		if !opt.cache.is_empty() || opt.ranked.is_some() {
 			self.caches.insert(opt.cache_name.to_owned(), opt.cache.clone());
 		}
+		if let Some(base) = opt.base {
+			self.bases.insert(opt.cache_name.to_owned(), base);
+		}
 		let Some(cache) = self.caches.get(&opt.cache_name) else {
 			return;
 		};
 
 		self.ticket = opt.ticket;
-		self.cands = Self::match_candidates(&opt.word, cache);
+		if let Some(case) = opt.ranked {
+			self.cands = cache.iter().take(LIMIT).cloned().collect();
+			self.fuzzy = Some(Fuzzy::new(&opt.word, case));
+		} else {
+			self.cands = Self::match_candidates(&opt.word, cache, opt.all || self.show_hidden());
+			self.fuzzy = None;
+		}
+		self.name = opt.cache_name;
+		self.word = opt.word;
 		if self.cands.is_empty() {
 			return render!(mem::replace(&mut self.visible, false));
 		}
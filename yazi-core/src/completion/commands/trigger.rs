@@ -1,7 +1,8 @@
-use std::{mem, path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR}};
+use std::{mem, path::{Component, Path, PathBuf, MAIN_SEPARATOR, MAIN_SEPARATOR_STR}};
 
+use anyhow::anyhow;
 use tokio::fs;
-use yazi_shared::{emit, event::Cmd, render, Layer};
+use yazi_shared::{emit, event::Cmd, fs::expand_path, render, Layer};
 
 use crate::completion::Completion;
 
@@ -30,7 +31,7 @@ impl Completion {
 
 	pub fn trigger(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		if opt.ticket < self.ticket {
+		if self.is_stale(opt.ticket) {
 			return;
 		}
 
@@ -45,7 +46,19 @@ impl Completion {
 
 		let ticket = self.ticket;
 		tokio::spawn(async move {
-			let mut dir = fs::read_dir(&parent).await?;
+			// `$VAR` and `~user` are expanded, and if that doesn't lead to a directory,
+			// each component is taken as the prefix of one, e.g. `/u/lo/` for `/usr/local/`
+			let expanded = expand_path(&parent);
+			let (real, base) = match fs::metadata(&expanded).await {
+				Ok(m) if m.is_dir() => (expanded, None),
+				_ => {
+					let real = Self::resolve(&expanded).await.ok_or_else(|| anyhow!("no such directory"))?;
+					let base = format!("{}{MAIN_SEPARATOR}", real.to_string_lossy().trim_end_matches(MAIN_SEPARATOR));
+					(real, Some(base))
+				}
+			};
+
+			let mut dir = fs::read_dir(&real).await?;
 			let mut cache = vec![];
 			while let Ok(Some(f)) = dir.next_entry().await {
 				let Ok(meta) = f.metadata().await else {
@@ -60,13 +73,12 @@ impl Completion {
 			}
 
 			if !cache.is_empty() {
-				emit!(Call(
-					Cmd::args("show", cache)
-						.with("cache-name", parent)
-						.with("word", child)
-						.with("ticket", ticket),
-					Layer::Completion
-				));
+				let mut cmd =
+					Cmd::args("show", cache).with("cache-name", parent).with("word", child).with("ticket", ticket);
+				if let Some(base) = base {
+					cmd = cmd.with("base", base);
+				}
+				emit!(Call(cmd, Layer::Completion));
 			}
 
 			Ok::<(), anyhow::Error>(())
@@ -75,6 +87,40 @@ impl Completion {
 		render!(mem::replace(&mut self.visible, false));
 	}
 
+	// Resolves each component of the path that doesn't exist to the only
+	// directory that it's a prefix of, or gives up if there's not exactly one.
+	async fn resolve(path: &Path) -> Option<PathBuf> {
+		let mut real = PathBuf::new();
+		for c in path.components() {
+			let Component::Normal(name) = c else {
+				real.push(c);
+				continue;
+			};
+
+			let next = real.join(name);
+			if fs::metadata(&next).await.is_ok_and(|m| m.is_dir()) {
+				real = next;
+				continue;
+			}
+
+			let (name, mut found) = (name.to_string_lossy(), None);
+			let mut dir = fs::read_dir(&real).await.ok()?;
+			while let Ok(Some(f)) = dir.next_entry().await {
+				if !f.file_name().to_string_lossy().starts_with(name.as_ref()) {
+					continue;
+				}
+				if !fs::metadata(f.path()).await.is_ok_and(|m| m.is_dir()) {
+					continue;
+				}
+				if found.replace(f.path()).is_some() {
+					return None;
+				}
+			}
+			real = found?;
+		}
+		Some(real)
+	}
+
 	#[inline]
 	fn split_path(s: &str) -> (String, String) {
 		match s.rsplit_once(MAIN_SEPARATOR) {
@@ -112,4 +158,17 @@ mod tests {
 		assert_eq!(Completion::split_path("C:\\foo\\"), ("C:\\foo\\".to_owned(), "".to_owned()));
 		assert_eq!(Completion::split_path("C:\\foo\\bar"), ("C:\\foo\\".to_owned(), "bar".to_owned()));
 	}
+
+	#[test]
+	fn test_stale() {
+		let mut c = Completion { ticket: 5, ..Default::default() };
+		assert!(c.is_stale(4));
+		assert!(!c.is_stale(5));
+		assert!(!c.is_stale(6));
+
+		// The input's ticket wrapped around, which is newer, not older
+		c.ticket = usize::MAX;
+		assert!(!c.is_stale(0));
+		assert!(c.is_stale(usize::MAX - 1));
+	}
 }
@@ -1,4 +1,8 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, ops::Range};
+
+use yazi_config::MANAGER;
+
+use crate::folder::Fuzzy;
 
 #[derive(Default)]
 pub struct Completion {
@@ -7,11 +11,31 @@ pub struct Completion {
 	pub(super) offset: usize,
 	pub cursor:        usize,
 
+	// The directories that fuzzy-matched parents were resolved to,
+	// which replace what was typed once a candidate is submitted
+	pub(super) bases: BTreeMap<String, String>,
+	pub(super) name:  String,
+	pub(super) word:  String,
+
+	// Candidates that come already ranked, with the matcher used to highlight them
+	pub(super) fuzzy: Option<Fuzzy>,
+
+	pub(super) hidden: Option<bool>,
 	pub(super) ticket: usize,
 	pub visible:       bool,
 }
 
 impl Completion {
+	#[inline]
+	pub(super) fn show_hidden(&self) -> bool { self.hidden.unwrap_or(MANAGER.show_hidden) }
+
+	/// Whether the ticket is older than the current one, as the input's tickets wrap around,
+	/// where one less than half the range behind is taken as older, not as far ahead.
+	#[inline]
+	pub(super) fn is_stale(&self, ticket: usize) -> bool {
+		(self.ticket.wrapping_sub(ticket) as isize) > 0
+	}
+
 	// --- Cands
 	#[inline]
 	pub fn window(&self) -> &[String] {
@@ -22,6 +46,11 @@ impl Completion {
 	#[inline]
 	pub fn limit(&self) -> usize { self.cands.len().min(10) }
 
+	#[inline]
+	pub fn highlighted(&self, s: &str) -> Vec<Range<usize>> {
+		self.fuzzy.as_ref().and_then(|f| f.matches(s)).map(|m| m.1).unwrap_or_default()
+	}
+
 	#[inline]
 	pub fn selected(&self) -> Option<&String> { self.cands.get(self.cursor) }
 
@@ -1,24 +1,30 @@
 use std::{collections::{BTreeMap, BTreeSet}, mem, ops::Deref, sync::atomic::Ordering};
 
 use anyhow::Result;
-use tokio::{fs::{self, DirEntry}, select, sync::mpsc::{self, UnboundedReceiver}};
+use futures::StreamExt;
+use tokio::{fs, pin, sync::mpsc::{self, UnboundedReceiver}};
 use yazi_config::{manager::SortBy, MANAGER};
 use yazi_shared::fs::{File, Url, FILES_TICKET};
 
-use super::{FilesSorter, Filter};
+use super::{FilesSorter, Filter, Ignore};
 
+#[derive(Clone)]
 pub struct Files {
 	hidden:              Vec<File>,
 	items:               Vec<File>,
 	ticket:              u64,
 	version:             u64,
 	pub(crate) revision: u64,
+	sorted:              usize,
+	// The number of the hidden files that are ignored
+	ignored:             usize,
 
 	pub sizes: BTreeMap<Url, u64>,
 
-	sorter:      FilesSorter,
-	filter:      Option<Filter>,
-	show_hidden: bool,
+	sorter:       FilesSorter,
+	filter:       Option<Filter>,
+	show_hidden:  bool,
+	show_ignored: bool,
 }
 
 impl Default for Files {
@@ -29,12 +35,15 @@ impl Default for Files {
 			ticket:   Default::default(),
 			version:  Default::default(),
 			revision: Default::default(),
+			sorted:   Default::default(),
+			ignored:  Default::default(),
 
 			sizes: Default::default(),
 
-			sorter:      Default::default(),
-			filter:      Default::default(),
-			show_hidden: MANAGER.show_hidden,
+			sorter:       Default::default(),
+			filter:       Default::default(),
+			show_hidden:  MANAGER.show_hidden,
+			show_ignored: MANAGER.show_ignored,
 		}
 	}
 }
@@ -47,51 +56,33 @@ impl Deref for Files {
 
 impl Files {
 	pub async fn from_dir(url: &Url) -> Result<UnboundedReceiver<File>> {
-		let mut it = fs::read_dir(url).await?;
+		Ignore::load(url).await;
+		let it = fs::read_dir(url).await?;
 		let (tx, rx) = mpsc::unbounded_channel();
 
 		tokio::spawn(async move {
-			while let Ok(Some(item)) = it.next_entry().await {
-				select! {
-					_ = tx.closed() => break,
-					result = item.metadata() => {
-						if let Ok(meta) = result {
-							tx.send(File::from_meta(Url::from(item.path()), meta).await).ok();
-						}
+			let entries = futures::stream::unfold(it, |mut it| async {
+				it.next_entry().await.ok().flatten().map(|entry| (entry, it))
+			});
+
+			let files = entries
+				.map(|entry| async move {
+					let meta = entry.metadata().await.ok()?;
+					Some(File::from_meta(Url::from(entry.path()), meta).await)
+				})
+				.buffer_unordered(8);
+
+			pin!(files);
+			while let Some(file) = files.next().await {
+				if let Some(file) = file {
+					if tx.send(file).is_err() {
+						break;
 					}
 				}
 			}
 		});
 		Ok(rx)
 	}
-
-	pub async fn from_dir_bulk(url: &Url) -> Result<Vec<File>> {
-		let mut it = fs::read_dir(url).await?;
-		let mut items = Vec::with_capacity(5000);
-		while let Ok(Some(item)) = it.next_entry().await {
-			items.push(item);
-		}
-
-		let (first, rest) = items.split_at(items.len() / 3);
-		let (second, third) = rest.split_at(items.len() / 3);
-		async fn go(entities: &[DirEntry]) -> Vec<File> {
-			let mut files = Vec::with_capacity(entities.len() / 3 + 1);
-			for entry in entities {
-				if let Ok(meta) = entry.metadata().await {
-					files.push(File::from_meta(Url::from(entry.path()), meta).await);
-				}
-			}
-			files
-		}
-
-		Ok(
-			futures::future::join_all([go(first), go(second), go(third)])
-				.await
-				.into_iter()
-				.flatten()
-				.collect(),
-		)
-	}
 }
 
 impl Files {
@@ -99,6 +90,8 @@ impl Files {
 		self.ticket = FILES_TICKET.fetch_add(1, Ordering::Relaxed);
 
 		(self.hidden, self.items) = self.split_files(files);
+		self.sorted = 0;
+		self.recount();
 		if !self.items.is_empty() {
 			self.revision += 1;
 		}
@@ -115,6 +108,7 @@ impl Files {
 				self.revision += 1;
 			}
 
+			self.ignored += self.count_ignored(&hidden);
 			self.hidden.extend(hidden);
 			self.items.extend(items);
 			return;
@@ -122,6 +116,7 @@ impl Files {
 
 		self.ticket = ticket;
 		self.hidden.clear();
+		(self.sorted, self.ignored) = (0, 0);
 		if !self.items.is_empty() {
 			self.revision += 1;
 			self.items.clear();
@@ -135,6 +130,7 @@ impl Files {
 
 		if self.sorter.by == SortBy::Size {
 			self.revision += 1;
+			self.sorted = 0;
 		}
 		self.sizes.extend(sizes);
 	}
@@ -165,6 +161,7 @@ impl Files {
 		}
 		if !hidden.is_empty() {
 			go!(self.hidden, hidden, 0);
+			self.recount();
 		}
 	}
 
@@ -186,7 +183,7 @@ impl Files {
 			};
 		}
 
-		let (hidden, items) = if let Some(filter) = &self.filter {
+		let (mut hidden, items): (Vec<_>, Vec<_>) = if let Some(filter) = &self.filter {
 			urls.into_iter().partition(|u| {
 				(!self.show_hidden && u.is_hidden()) || !u.file_name().is_some_and(|s| filter.matches(s))
 			})
@@ -196,11 +193,18 @@ impl Files {
 			urls.into_iter().partition(|u| u.is_hidden())
 		};
 
+		// Whether it was a directory is unknown, so it's removed from both if it might be ignored
+		let mut ignored = Ignore::matcher(!self.show_ignored);
+		hidden.extend(items.iter().filter(|u| ignored(u, None)).cloned());
+
 		if !items.is_empty() {
+			let full = self.sorted == self.items.len();
 			go!(self.items, items, 1);
+			self.sorted = if full { self.items.len() } else { 0 };
 		}
 		if !hidden.is_empty() {
 			go!(self.hidden, hidden, 0);
+			self.recount();
 		}
 	}
 
@@ -219,10 +223,13 @@ impl Files {
 
 		let mut urls: BTreeSet<_> = urls.into_iter().collect();
 		if !urls.is_empty() {
+			let full = self.sorted == self.items.len();
 			go!(self.items, urls, 1);
+			self.sorted = if full { self.items.len() } else { 0 };
 		}
 		if !urls.is_empty() {
 			go!(self.hidden, urls, 0);
+			self.recount();
 		}
 	}
 
@@ -251,22 +258,31 @@ impl Files {
 			};
 		}
 
+		let mut ignored = Ignore::matcher(!self.show_ignored);
 		let (mut hidden, mut items) = if let Some(filter) = &self.filter {
 			files.into_iter().partition(|(_, f)| {
 				(f.is_hidden() && !self.show_hidden)
+					|| ignored(&f.url, Some(f.is_dir()))
 					|| !f.url.file_name().is_some_and(|s| filter.matches(s))
 			})
-		} else if self.show_hidden {
+		} else if self.show_hidden && self.show_ignored {
 			(BTreeMap::new(), files)
 		} else {
-			files.into_iter().partition(|(_, f)| f.is_hidden())
+			files.into_iter().partition(|(_, f)| {
+				(f.is_hidden() && !self.show_hidden) || ignored(&f.url, Some(f.is_dir()))
+			})
 		};
 
 		if !items.is_empty() {
+			let len = items.len();
 			go!(self.items, items, 1);
+			if items.len() != len {
+				self.sorted = 0;
+			}
 		}
 		if !hidden.is_empty() {
 			go!(self.hidden, hidden, 0);
+			self.recount();
 		}
 		(hidden, items)
 	}
@@ -283,6 +299,7 @@ impl Files {
 
 		if !hidden.is_empty() {
 			self.hidden.extend(hidden.into_values());
+			self.recount();
 		}
 		if !items.is_empty() {
 			self.revision += 1;
@@ -296,20 +313,30 @@ impl Files {
 		}
 
 		self.version = self.revision;
-		self.sorter.sort(&mut self.items, &self.sizes);
+		if self.sorted == 0 {
+			self.sorter.sort(&mut self.items, &self.sizes);
+		} else {
+			self.sorter.merge(&mut self.items, self.sorted, &self.sizes);
+		}
+
+		self.sorted = self.items.len();
 		true
 	}
 
 	fn split_files(&self, files: impl IntoIterator<Item = File>) -> (Vec<File>, Vec<File>) {
+		let mut ignored = Ignore::matcher(!self.show_ignored);
 		if let Some(filter) = &self.filter {
 			files.into_iter().partition(|f| {
 				(f.is_hidden() && !self.show_hidden)
+					|| ignored(&f.url, Some(f.is_dir()))
 					|| !f.url.file_name().is_some_and(|s| filter.matches(s))
 			})
-		} else if self.show_hidden {
+		} else if self.show_hidden && self.show_ignored {
 			(vec![], files.into_iter().collect())
 		} else {
-			files.into_iter().partition(|f| f.is_hidden())
+			files.into_iter().partition(|f| {
+				(f.is_hidden() && !self.show_hidden) || ignored(&f.url, Some(f.is_dir()))
+			})
 		}
 	}
 }
@@ -331,6 +358,7 @@ impl Files {
 		if self.sorter != sorter {
 			self.sorter = sorter;
 			self.revision += 1;
+			self.sorted = 0;
 		}
 	}
 
@@ -349,40 +377,71 @@ impl Files {
 			let (hidden, items) = self.split_files(take);
 
 			self.hidden = hidden;
+			self.recount();
 			if !items.is_empty() {
 				self.items.extend(items);
 				self.sorter.sort(&mut self.items, &self.sizes);
+				self.sorted = self.items.len();
 			}
 			return true;
 		}
 
 		let it = mem::take(&mut self.items).into_iter().chain(mem::take(&mut self.hidden));
 		(self.hidden, self.items) = self.split_files(it);
+		self.recount();
 		self.sorter.sort(&mut self.items, &self.sizes);
+		self.sorted = self.items.len();
 		true
 	}
 
 	// --- Show hidden
 	pub fn set_show_hidden(&mut self, state: bool) {
-		if self.show_hidden == state {
-			return;
+		if self.show_hidden != state {
+			self.show_hidden = state;
+			self.refilter(state);
 		}
+	}
 
-		self.show_hidden = state;
-		if self.show_hidden && self.hidden.is_empty() {
-			return;
-		} else if !self.show_hidden && self.items.is_empty() {
+	// --- Show ignored
+	pub fn set_show_ignored(&mut self, state: bool) {
+		if self.show_ignored != state {
+			self.show_ignored = state;
+			self.refilter(state);
+		}
+	}
+
+	/// The number of files hidden since they're ignored.
+	#[inline]
+	pub fn ignored(&self) -> usize { self.ignored }
+
+	/// Splits the hidden files again after showing more, or the shown ones after hiding more.
+	fn refilter(&mut self, reveal: bool) {
+		if reveal && self.hidden.is_empty() {
 			return;
+		} else if !reveal && self.items.is_empty() {
+			return self.recount();
 		}
 
-		let take =
-			if self.show_hidden { mem::take(&mut self.hidden) } else { mem::take(&mut self.items) };
+		let full = self.sorted == self.items.len();
+		let take = if reveal { mem::take(&mut self.hidden) } else { mem::take(&mut self.items) };
 		let (hidden, items) = self.split_files(take);
+		if !reveal {
+			self.sorted = if full { items.len() } else { 0 };
+		}
 
 		self.hidden.extend(hidden);
+		self.recount();
 		if !items.is_empty() {
 			self.revision += 1;
 			self.items.extend(items);
 		}
 	}
+
+	#[inline]
+	fn recount(&mut self) { self.ignored = self.count_ignored(&self.hidden); }
+
+	fn count_ignored(&self, files: &[File]) -> usize {
+		let mut ignored = Ignore::matcher(!self.show_ignored);
+		files.iter().filter(|f| ignored(&f.url, Some(f.is_dir()))).count()
+	}
 }
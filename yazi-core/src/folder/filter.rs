@@ -1,54 +1,94 @@
-use std::{ffi::OsStr, ops::Range};
+use std::{borrow::Cow, ffi::OsStr, fmt::{self, Display}, ops::Range};
 
 use anyhow::Result;
 use regex::bytes::{Regex, RegexBuilder};
-use yazi_shared::event::Cmd;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+pub use yazi_config::manager::FilterCase;
+use yazi_config::MANAGER;
 
+#[derive(Clone)]
 pub struct Filter {
 	raw:   String,
 	regex: Regex,
+	fold:  bool,
 }
 
 impl PartialEq for Filter {
 	fn eq(&self, other: &Self) -> bool { self.raw == other.raw }
 }
 
+impl Display for Filter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(&self.raw) }
+}
+
 impl Filter {
 	pub fn new(s: &str, case: FilterCase) -> Result<Self> {
+		// With `filter_normalize`, both sides are stripped of diacritics, so "e" matches "é"
+		let fold = MANAGER.filter_normalize;
+		let pattern = if fold { Self::fold(s).0 } else { Cow::Borrowed(s) };
+
 		let regex = match case {
 			FilterCase::Smart => {
 				let uppercase = s.chars().any(|c| c.is_uppercase());
-				RegexBuilder::new(s).case_insensitive(!uppercase).build()?
+				RegexBuilder::new(&pattern).case_insensitive(!uppercase).build()?
 			}
-			FilterCase::Sensitive => Regex::new(s)?,
-			FilterCase::Insensitive => RegexBuilder::new(s).case_insensitive(true).build()?,
+			FilterCase::Sensitive => Regex::new(&pattern)?,
+			FilterCase::Insensitive => RegexBuilder::new(&pattern).case_insensitive(true).build()?,
 		};
-		Ok(Self { raw: s.to_owned(), regex })
+		Ok(Self { raw: s.to_owned(), regex, fold })
 	}
 
 	#[inline]
-	pub fn matches(&self, name: &OsStr) -> bool { self.regex.is_match(name.as_encoded_bytes()) }
+	pub fn matches(&self, name: &OsStr) -> bool {
+		match name.to_str().filter(|_| self.fold) {
+			Some(s) => self.regex.is_match(Self::fold(s).0.as_bytes()),
+			None => self.regex.is_match(name.as_encoded_bytes()),
+		}
+	}
 
 	#[inline]
 	pub fn highlighted(&self, name: &OsStr) -> Option<Vec<Range<usize>>> {
-		self.regex.find(name.as_encoded_bytes()).map(|m| vec![m.range()])
+		let Some(s) = name.to_str().filter(|_| self.fold) else {
+			return self.regex.find(name.as_encoded_bytes()).map(|m| vec![m.range()]);
+		};
+
+		// Map the match in the folded name back to the original one
+		let (folded, map) = Self::fold(s);
+		let m = self.regex.find(folded.as_bytes())?;
+		Some(vec![map.map_or(m.range(), |map| map[m.start()]..map[m.end()])])
 	}
-}
 
-#[derive(Default, PartialEq, Eq)]
-pub enum FilterCase {
-	Smart,
-	#[default]
-	Sensitive,
-	Insensitive,
-}
+	// Decomposes the string and drops the combining marks, returning the offset
+	// in the original string of each byte in the new one, if they differ.
+	fn fold(s: &str) -> (Cow<'_, str>, Option<Vec<usize>>) {
+		if s.is_ascii() {
+			return (Cow::Borrowed(s), None);
+		}
 
-impl From<&Cmd> for FilterCase {
-	fn from(c: &Cmd) -> Self {
-		match (c.named.contains_key("smart"), c.named.contains_key("insensitive")) {
-			(true, _) => Self::Smart,
-			(_, false) => Self::Sensitive,
-			(_, true) => Self::Insensitive,
+		let (mut folded, mut map) = (String::with_capacity(s.len()), Vec::with_capacity(s.len() + 1));
+		for (i, c) in s.char_indices() {
+			for d in c.nfd().filter(|&d| !is_combining_mark(d)) {
+				folded.push(d);
+				map.resize(folded.len(), i);
+			}
 		}
+
+		map.push(s.len());
+		(Cow::Owned(folded), Some(map))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fold() {
+		assert_eq!(Filter::fold("abc").0, "abc");
+		assert_eq!(Filter::fold("Café Ångström").0, "Cafe Angstrom");
+
+		let (folded, map) = Filter::fold("née.txt");
+		assert_eq!(folded, "nee.txt");
+		assert_eq!(map.unwrap(), [0, 1, 3, 4, 5, 6, 7, 8]);
 	}
 }
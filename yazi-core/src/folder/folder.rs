@@ -7,7 +7,7 @@ use yazi_shared::fs::{File, FilesOp, Url};
 use super::FolderStage;
 use crate::{folder::Files, manager::Manager, Step};
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Folder {
 	pub cwd:   Url,
 	pub files: Files,
@@ -16,6 +16,8 @@ pub struct Folder {
 
 	pub offset: usize,
 	pub cursor: usize,
+	// The offset shown while the list glides to `offset`, with `smooth_scroll`
+	pub glide:  Option<usize>,
 
 	pub page:    usize,
 	pub tracing: bool,
@@ -92,7 +94,7 @@ impl Folder {
 	}
 
 	pub fn sync_page(&mut self, force: bool) {
-		let limit = LAYOUT.load().current.height as usize;
+		let limit = LAYOUT.load().current_limit;
 		if limit == 0 {
 			return;
 		}
@@ -107,7 +109,7 @@ impl Folder {
 		let old = (self.cursor, self.offset);
 		let len = self.files.len();
 
-		let limit = LAYOUT.load().current.height as usize;
+		let limit = LAYOUT.load().current_limit;
 		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(len.saturating_sub(1));
@@ -124,7 +126,7 @@ impl Folder {
 		let old = (self.cursor, self.offset);
 		let max = self.files.len().saturating_sub(1);
 
-		let limit = LAYOUT.load().current.height as usize;
+		let limit = LAYOUT.load().current_limit;
 		let scrolloff = (limit / 2).min(MANAGER.scrolloff as usize);
 
 		self.cursor = step.add(self.cursor, limit).min(max);
@@ -142,9 +144,35 @@ impl Folder {
 	#[inline]
 	pub fn hovered(&self) -> Option<&File> { self.files.get(self.cursor) }
 
+	/// The offset the list is shown at, which lags behind `offset` while it glides there.
+	#[inline]
+	pub fn shown_offset(&self) -> usize {
+		self.glide.map_or(self.offset, |o| o.min(self.files.len().saturating_sub(1)))
+	}
+
+	/// Moves the shown offset a step closer to `offset`, returning whether it's still not there.
+	pub fn glide_step(&mut self) -> bool {
+		let Some(shown) = self.glide else {
+			return false;
+		};
+
+		self.glide = Self::glided(shown, self.offset);
+		self.glide.is_some()
+	}
+
+	// A third of the way left each time, for it to slow down towards the end
+	fn glided(shown: usize, offset: usize) -> Option<usize> {
+		if shown == offset {
+			return None;
+		}
+		let step = (shown.abs_diff(offset) / 3).max(1);
+		let shown = if shown < offset { shown + step } else { shown.saturating_sub(step) };
+		Some(shown).filter(|&s| s != offset)
+	}
+
 	pub fn paginate(&self, page: usize) -> &[File] {
 		let len = self.files.len();
-		let limit = LAYOUT.load().current.height as usize;
+		let limit = LAYOUT.load().current_limit;
 
 		let start = (page.saturating_sub(1) * limit).min(len.saturating_sub(1));
 		let end = ((page + 2) * limit).min(len);
@@ -160,3 +188,24 @@ impl Folder {
 		Some(rect)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_glided() {
+		let mut shown = vec![];
+		let mut glide = Some(0);
+		while let Some(s) = glide {
+			shown.push(s);
+			glide = Folder::glided(s, 10);
+		}
+		assert_eq!(shown, [0, 3, 5, 6, 7, 8, 9]);
+
+		// Back up the list too, and not at all once it's there
+		assert_eq!(Folder::glided(20, 10), Some(17));
+		assert_eq!(Folder::glided(11, 10), None);
+		assert_eq!(Folder::glided(10, 10), None);
+	}
+}
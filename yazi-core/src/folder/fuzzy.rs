@@ -0,0 +1,136 @@
+use std::ops::Range;
+
+use super::FilterCase;
+
+const SCORE_MATCH: i32 = 16;
+const SCORE_GAP_START: i32 = -3;
+const SCORE_GAP_EXTENSION: i32 = -1;
+
+const BONUS_FIRST: i32 = 10;
+const BONUS_BOUNDARY: i32 = 8;
+const BONUS_CAMEL: i32 = 7;
+const BONUS_CONSECUTIVE: i32 = 6;
+
+/// A subsequence matcher in the spirit of fzf, which prefers matches that are
+/// consecutive, or that start a word or a path component.
+#[derive(Clone)]
+pub struct Fuzzy {
+	pattern:   Vec<char>,
+	sensitive: bool,
+}
+
+impl Fuzzy {
+	pub fn new(s: &str, case: FilterCase) -> Self {
+		let sensitive = match case {
+			FilterCase::Smart => s.chars().any(|c| c.is_uppercase()),
+			FilterCase::Sensitive => true,
+			FilterCase::Insensitive => false,
+		};
+		Self { pattern: s.chars().filter(|c| !c.is_whitespace()).collect(), sensitive }
+	}
+
+	/// Returns the score of the string, and the byte ranges of the matched characters.
+	pub fn matches(&self, s: &str) -> Option<(i32, Vec<Range<usize>>)> {
+		if self.pattern.is_empty() {
+			return Some((0, vec![]));
+		}
+
+		let chars: Vec<_> = s.char_indices().collect();
+		let eq = |a: char, b: char| {
+			a == b || (!self.sensitive && a.to_lowercase().eq(b.to_lowercase()))
+		};
+
+		// Find the first window containing the whole pattern,
+		// then shrink it from the end to get a tighter match.
+		let mut p = 0;
+		let mut end = None;
+		for (i, &(_, c)) in chars.iter().enumerate() {
+			if eq(c, self.pattern[p]) {
+				p += 1;
+				if p == self.pattern.len() {
+					end = Some(i);
+					break;
+				}
+			}
+		}
+
+		let mut positions = Vec::with_capacity(self.pattern.len());
+		let mut p = self.pattern.len();
+		for i in (0..=end?).rev() {
+			if eq(chars[i].1, self.pattern[p - 1]) {
+				positions.push(i);
+				p -= 1;
+				if p == 0 {
+					break;
+				}
+			}
+		}
+		positions.reverse();
+
+		let mut score = 0;
+		for (k, &i) in positions.iter().enumerate() {
+			score += SCORE_MATCH + Self::bonus(&chars, i);
+			if k == 0 {
+				continue;
+			}
+
+			let gap = i - positions[k - 1] - 1;
+			if gap == 0 {
+				score += BONUS_CONSECUTIVE;
+			} else {
+				score += SCORE_GAP_START + SCORE_GAP_EXTENSION * (gap as i32 - 1);
+			}
+		}
+
+		Some((score, Self::ranges(&chars, &positions, s.len())))
+	}
+
+	fn bonus(chars: &[(usize, char)], i: usize) -> i32 {
+		let Some(&(_, prev)) = i.checked_sub(1).and_then(|i| chars.get(i)) else {
+			return BONUS_FIRST;
+		};
+
+		let cur = chars[i].1;
+		if matches!(prev, '/' | '\\' | '_' | '-' | '.' | ' ') {
+			BONUS_BOUNDARY
+		} else if prev.is_lowercase() && cur.is_uppercase() {
+			BONUS_CAMEL
+		} else {
+			0
+		}
+	}
+
+	fn ranges(chars: &[(usize, char)], positions: &[usize], len: usize) -> Vec<Range<usize>> {
+		let mut ranges: Vec<Range<usize>> = vec![];
+		for &i in positions {
+			let start = chars[i].0;
+			let end = chars.get(i + 1).map_or(len, |&(b, _)| b);
+			match ranges.last_mut() {
+				Some(r) if r.end == start => r.end = end,
+				_ => ranges.push(start..end),
+			}
+		}
+		ranges
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fuzzy() {
+		let fuzzy = Fuzzy::new("fb", FilterCase::Smart);
+		assert_eq!(fuzzy.matches("foo_bar").map(|m| m.1), Some(vec![0..1, 4..5]));
+		assert_eq!(fuzzy.matches("bf"), None);
+
+		// Boundaries and consecutive characters score higher
+		let (a, _) = fuzzy.matches("foo_bar").unwrap();
+		let (b, _) = fuzzy.matches("foobar").unwrap();
+		let (c, _) = fuzzy.matches("fbox").unwrap();
+		assert!(a > b && c > a);
+
+		assert_eq!(Fuzzy::new("Fb", FilterCase::Smart).matches("foo_bar"), None);
+		assert_eq!(Fuzzy::new("éa", FilterCase::Smart).matches("xéxa").map(|m| m.1), Some(vec![1..3, 4..5]));
+	}
+}
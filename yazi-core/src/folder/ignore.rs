@@ -0,0 +1,351 @@
+use std::{collections::HashMap, path::{Path, PathBuf}, sync::Arc, time::SystemTime};
+
+use parking_lot::Mutex;
+use regex::{Regex, RegexSet};
+use tokio::fs;
+use yazi_config::BOOT;
+use yazi_shared::RoCell;
+
+// The files the rules came from, with their mtimes
+type Stamp = Vec<(PathBuf, Option<SystemTime>)>;
+
+struct Entry {
+	stamp:  Stamp,
+	ignore: Option<Arc<Ignore>>,
+	// When it was last used, by the `tick` of the cache
+	used:   u64,
+}
+
+#[derive(Default)]
+struct Cache {
+	entries: HashMap<PathBuf, Entry>,
+	tick:    u64,
+}
+
+// The ignore rules of each loaded directory
+static CACHE: RoCell<Mutex<Cache>> = RoCell::new();
+
+const CACHE_LIMIT: usize = 1000;
+
+/// The `.gitignore` rules that apply to the entries of a directory.
+#[derive(Debug)]
+pub struct Ignore {
+	set:   RegexSet,
+	rules: Vec<Rule>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Rule {
+	negate:   bool,
+	dir_only: bool,
+}
+
+impl Ignore {
+	pub(crate) fn init() { CACHE.with(Default::default); }
+
+	/// Loads the rules of the directory into the cache, unless they're cached and
+	/// none of the files they came from have changed since.
+	///
+	/// The rules are, from the lowest precedence to the highest, those of the global
+	/// `ignore` file under the config directory, `.git/info/exclude` of the repository,
+	/// and the `.gitignore` of each directory from the repository root down to it.
+	pub async fn load(dir: &Path) {
+		let sources = Self::sources(dir).await;
+		let mut stamp = Vec::with_capacity(sources.len());
+		for (path, _) in &sources {
+			stamp.push((path.clone(), fs::metadata(path).await.and_then(|m| m.modified()).ok()));
+		}
+
+		if CACHE.lock().entries.get(dir).is_some_and(|e| e.stamp == stamp) {
+			return;
+		}
+
+		let ignore = Self::parse(&sources).await;
+		CACHE.lock().insert(dir.to_owned(), stamp, ignore);
+	}
+
+	/// The rules of the directory read afresh, without the cache, for a walk of a tree
+	/// that takes each directory once, not to evict those of the directories shown.
+	pub async fn read(dir: &Path) -> Option<Arc<Self>> { Self::parse(&Self::sources(dir).await).await }
+
+	async fn parse(sources: &[(PathBuf, PathBuf)]) -> Option<Arc<Self>> {
+		let (mut patterns, mut rules) = (vec![], vec![]);
+		for (path, base) in sources {
+			let Ok(s) = fs::read_to_string(path).await else {
+				continue;
+			};
+
+			let base = Self::normalize(base);
+			for (pattern, rule) in s.lines().filter_map(|l| Self::translate(l, &base)) {
+				if Regex::new(&pattern).is_ok() {
+					patterns.push(pattern);
+					rules.push(rule);
+				}
+			}
+		}
+
+		if rules.is_empty() {
+			return None;
+		}
+		RegexSet::new(patterns).ok().map(|set| Arc::new(Self { set, rules }))
+	}
+
+	/// The rules of the patterns, as if they're in a `.gitignore` of the `base` directory.
+	pub fn from_patterns(patterns: &[String], base: &Path) -> Option<Self> {
+		let base = Self::normalize(base);
+		let (patterns, rules): (Vec<_>, Vec<_>) = patterns
+			.iter()
+			.filter_map(|l| Self::translate(l, &base))
+			.filter(|(p, _)| Regex::new(p).is_ok())
+			.unzip();
+
+		if rules.is_empty() {
+			return None;
+		}
+		RegexSet::new(patterns).ok().map(|set| Self { set, rules })
+	}
+
+	/// The cached rules of the directory, if it has been loaded and has any.
+	#[inline]
+	pub fn get(dir: &Path) -> Option<Arc<Self>> {
+		let mut cache = CACHE.lock();
+		cache.tick += 1;
+		let tick = cache.tick;
+
+		let entry = cache.entries.get_mut(dir)?;
+		entry.used = tick;
+		entry.ignore.clone()
+	}
+
+	/// Whether the path is ignored, by the last rule matching it. A path of unknown type
+	/// is matched by the rules for directories too.
+	///
+	/// Only the path itself is matched, not its parents, so the entries of an ignored
+	/// directory aren't ignored when listing it.
+	pub fn matches(&self, path: &Path, is_dir: Option<bool>) -> bool {
+		let s = Self::normalize(path);
+		let matched = self.set.matches(&s);
+
+		let mut it = matched.iter().rev().map(|i| self.rules[i]);
+		it.find(|r| !r.dir_only || is_dir != Some(false)).is_some_and(|r| !r.negate)
+	}
+
+	/// Tells whether a path is ignored by the rules of the directory it's in, looking them
+	/// up only once for each sibling in a row. Always `false` unless `enabled`.
+	pub fn matcher(enabled: bool) -> impl FnMut(&Path, Option<bool>) -> bool {
+		let mut last: Option<(PathBuf, Option<Arc<Self>>)> = None;
+		move |path, is_dir| {
+			let Some(parent) = path.parent().filter(|_| enabled) else {
+				return false;
+			};
+
+			if last.as_ref().map(|(p, _)| p.as_path()) != Some(parent) {
+				last = Some((parent.to_owned(), Self::get(parent)));
+			}
+			last.as_ref().and_then(|(_, i)| i.as_ref()).is_some_and(|i| i.matches(path, is_dir))
+		}
+	}
+
+	/// Each file the rules of the directory come from, with the directory its patterns are
+	/// relative to, in the order of precedence.
+	async fn sources(dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+		let mut sources = vec![(BOOT.config_dir.join("ignore"), dir.to_owned())];
+
+		let mut root = None;
+		for p in dir.ancestors() {
+			if fs::symlink_metadata(p.join(".git")).await.is_ok() {
+				root = Some(p);
+				break;
+			}
+		}
+		let Some(root) = root else {
+			return sources;
+		};
+
+		sources.push((root.join(".git").join("info").join("exclude"), root.to_owned()));
+		sources.push((root.join(".gitignore"), root.to_owned()));
+
+		let mut d = root.to_owned();
+		for c in dir.strip_prefix(root).unwrap_or(Path::new("")).components() {
+			d.push(c);
+			sources.push((d.join(".gitignore"), d.clone()));
+		}
+		sources
+	}
+
+	/// Translates a line of a `.gitignore` into a regex matching the absolute paths.
+	fn translate(line: &str, base: &str) -> Option<(String, Rule)> {
+		let mut s = line.trim_end_matches('\r');
+		while s.ends_with(' ') && !s.ends_with("\\ ") {
+			s = &s[..s.len() - 1];
+		}
+		if s.is_empty() || s.starts_with('#') {
+			return None;
+		}
+
+		let negate = s.starts_with('!');
+		s = s.strip_prefix('!').unwrap_or(s);
+
+		let dir_only = s.ends_with('/') && !s.ends_with("\\/");
+		s = s.strip_suffix('/').filter(|_| dir_only).unwrap_or(s);
+
+		// A slash at the beginning or in the middle anchors the pattern to the base
+		let anchored = s.contains('/');
+		s = s.strip_prefix('/').unwrap_or(s);
+		if s.is_empty() {
+			return None;
+		}
+
+		let p: Vec<_> = s.chars().collect();
+		let mut re = format!("^{}/", regex::escape(base.trim_end_matches('/')));
+		if !anchored {
+			re.push_str("(?:.*/)?");
+		}
+
+		let mut i = 0;
+		while i < p.len() {
+			match p[i] {
+				'*' if p.get(i + 1) == Some(&'*') => {
+					let leading = i == 0 || p[i - 1] == '/';
+					if leading && p.get(i + 2) == Some(&'/') {
+						re.push_str("(?:.*/)?");
+						i += 3;
+					} else if leading && i + 2 == p.len() {
+						re.push_str(".*");
+						i += 2;
+					} else {
+						re.push_str("[^/]*");
+						i += 2;
+					}
+					continue;
+				}
+				'*' => re.push_str("[^/]*"),
+				'?' => re.push_str("[^/]"),
+				'[' => {
+					let start = i + 1 + matches!(p.get(i + 1), Some('!' | '^')) as usize;
+					let Some(end) = p.iter().skip(start + 1).position(|&c| c == ']').map(|n| start + 1 + n)
+					else {
+						re.push_str("\\[");
+						i += 1;
+						continue;
+					};
+
+					re.push('[');
+					if start != i + 1 {
+						re.push('^');
+					}
+					for &c in &p[start..end] {
+						match c {
+							'[' | '\\' | '&' | '~' => re.push_str(&format!("\\{c}")),
+							c => re.push(c),
+						}
+					}
+					re.push(']');
+					i = end;
+				}
+				'\\' => {
+					if let Some(c) = p.get(i + 1) {
+						re.push_str(&regex::escape(&c.to_string()));
+						i += 1;
+					}
+				}
+				c => re.push_str(&regex::escape(&c.to_string())),
+			}
+			i += 1;
+		}
+
+		re.push('$');
+		Some((re, Rule { negate, dir_only }))
+	}
+
+	#[inline]
+	fn normalize(path: &Path) -> String {
+		#[cfg(windows)]
+		return path.to_string_lossy().replace('\\', "/");
+		#[cfg(not(windows))]
+		path.to_string_lossy().into_owned()
+	}
+}
+
+impl Cache {
+	// Makes room by dropping the least recently used one, if it's full and the directory is new
+	fn insert(&mut self, dir: PathBuf, stamp: Stamp, ignore: Option<Arc<Ignore>>) {
+		self.tick += 1;
+		if self.entries.len() >= CACHE_LIMIT && !self.entries.contains_key(&dir) {
+			let lru = self.entries.iter().min_by_key(|(_, e)| e.used).map(|(p, _)| p.clone());
+			lru.map(|p| self.entries.remove(&p));
+		}
+		self.entries.insert(dir, Entry { stamp, ignore, used: self.tick });
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn ignore(lines: &[&str]) -> Ignore {
+		let (patterns, rules): (Vec<_>, Vec<_>) =
+			lines.iter().filter_map(|l| Ignore::translate(l, "/repo")).unzip();
+		Ignore { set: RegexSet::new(patterns).unwrap(), rules }
+	}
+
+	#[test]
+	fn test_matches() {
+		let ig = ignore(&[
+			"# comment",
+			"*.log",
+			"!keep.log",
+			"/build",
+			"target/",
+			"docs/*.html",
+			"**/tmp/**",
+			"[!a]bc",
+			"\\#lit",
+		]);
+		let is = |p: &str, d: Option<bool>| ig.matches(Path::new(p), d);
+
+		assert!(is("/repo/a.log", Some(false)));
+		assert!(is("/repo/sub/b.log", Some(false)));
+		assert!(!is("/repo/sub/keep.log", Some(false)));
+
+		assert!(is("/repo/build", Some(true)));
+		assert!(!is("/repo/sub/build", Some(true)));
+
+		assert!(is("/repo/target", Some(true)));
+		assert!(is("/repo/sub/target", None));
+		assert!(!is("/repo/target", Some(false)));
+
+		assert!(is("/repo/docs/index.html", Some(false)));
+		assert!(!is("/repo/docs/api/index.html", Some(false)));
+
+		assert!(is("/repo/x/tmp/y", Some(false)));
+		assert!(!is("/repo/x/tmp", Some(true)));
+
+		assert!(is("/repo/xbc", Some(false)));
+		assert!(!is("/repo/abc", Some(false)));
+
+		assert!(is("/repo/#lit", Some(false)));
+		assert!(!is("/other/a.log", Some(false)));
+	}
+
+	#[test]
+	fn test_evict() {
+		let mut cache = Cache::default();
+		for i in 0..CACHE_LIMIT {
+			cache.insert(PathBuf::from(i.to_string()), vec![], None);
+		}
+
+		// The first one is used again, so the second one is evicted for the new one
+		cache.entries.get_mut(Path::new("0")).unwrap().used = cache.tick + 1;
+		cache.tick += 1;
+		cache.insert(PathBuf::from("new"), vec![], None);
+		assert_eq!(cache.entries.len(), CACHE_LIMIT);
+		assert!(cache.entries.contains_key(Path::new("0")));
+		assert!(!cache.entries.contains_key(Path::new("1")));
+
+		// Loading one of them again doesn't evict any
+		cache.insert(PathBuf::from("2"), vec![], None);
+		assert_eq!(cache.entries.len(), CACHE_LIMIT);
+		assert!(cache.entries.contains_key(Path::new("3")));
+	}
+}
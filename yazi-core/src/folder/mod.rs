@@ -1,11 +1,15 @@
 mod files;
 mod filter;
 mod folder;
+mod fuzzy;
+mod ignore;
 mod sorter;
 mod stage;
 
 pub use files::*;
 pub use filter::*;
 pub use folder::*;
+pub use fuzzy::*;
+pub use ignore::*;
 pub use sorter::*;
 pub use stage::*;
@@ -12,12 +12,36 @@ pub struct FilesSorter {
 }
 
 impl FilesSorter {
-	pub(super) fn sort(&self, items: &mut Vec<File>, sizes: &BTreeMap<Url, u64>) {
-		if items.is_empty() {
+	pub(super) fn sort(&self, items: &mut [File], sizes: &BTreeMap<Url, u64>) {
+		if self.by != SortBy::None {
+			items.sort_unstable_by(|a, b| self.compare(a, b, sizes));
+		}
+	}
+
+	/// Sorts the items after `at`, and merges them into the already sorted ones.
+	pub(super) fn merge(&self, items: &mut Vec<File>, at: usize, sizes: &BTreeMap<Url, u64>) {
+		if self.by == SortBy::None || at >= items.len() {
 			return;
 		}
 
-		let by_alphabetical = |a: &File, b: &File| {
+		let mut tail = items.split_off(at);
+		self.sort(&mut tail, sizes);
+		if items.last().zip(tail.first()).is_some_and(|(a, b)| self.compare(a, b, sizes).is_le()) {
+			return items.append(&mut tail);
+		}
+
+		let mut merged = Vec::with_capacity(items.len() + tail.len());
+		let (mut a, mut b) = (mem::take(items).into_iter().peekable(), tail.into_iter().peekable());
+		while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+			merged.push(if self.compare(x, y, sizes).is_le() { a.next() } else { b.next() }.unwrap());
+		}
+
+		merged.extend(a.chain(b));
+		*items = merged;
+	}
+
+	fn compare(&self, a: &File, b: &File, sizes: &BTreeMap<Url, u64>) -> Ordering {
+		let by_alphabetical = || {
 			if self.sensitive {
 				return self.cmp(&*a.url, &*b.url, self.promote(a, b));
 			}
@@ -29,58 +53,36 @@ impl FilesSorter {
 			)
 		};
 
-		match self.by {
-			SortBy::None => {}
-			SortBy::Modified => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.modified, b.modified, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Created => items.sort_unstable_by(|a, b| {
-				let ord = self.cmp(a.created, b.created, self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Extension => items.sort_unstable_by(|a, b| {
-				let ord = if self.sensitive {
-					self.cmp(a.url.extension(), b.url.extension(), self.promote(a, b))
-				} else {
-					self.cmp(
-						a.url.extension().map(|s| s.to_ascii_lowercase()),
-						b.url.extension().map(|s| s.to_ascii_lowercase()),
-						self.promote(a, b),
-					)
-				};
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-			SortBy::Alphabetical => items.sort_unstable_by(by_alphabetical),
-			SortBy::Natural => self.sort_naturally(items),
-			SortBy::Size => items.sort_unstable_by(|a, b| {
+		let ord = match self.by {
+			SortBy::None => return Ordering::Equal,
+			SortBy::Modified => self.cmp(a.modified, b.modified, self.promote(a, b)),
+			SortBy::Created => self.cmp(a.created, b.created, self.promote(a, b)),
+			SortBy::Extension if self.sensitive => {
+				self.cmp(a.url.extension(), b.url.extension(), self.promote(a, b))
+			}
+			SortBy::Extension => self.cmp(
+				a.url.extension().map(|s| s.to_ascii_lowercase()),
+				b.url.extension().map(|s| s.to_ascii_lowercase()),
+				self.promote(a, b),
+			),
+			SortBy::Alphabetical => return by_alphabetical(),
+			SortBy::Natural => {
+				let promote = self.promote(a, b);
+				if promote != Ordering::Equal {
+					return promote;
+				}
+
+				let (a, b) = (a.url.as_os_str().as_encoded_bytes(), b.url.as_os_str().as_encoded_bytes());
+				let ordering = natsort(a, b, !self.sensitive);
+				return if self.reverse { ordering.reverse() } else { ordering };
+			}
+			SortBy::Size => {
 				let aa = if a.is_dir() { sizes.get(&a.url).copied() } else { None };
 				let bb = if b.is_dir() { sizes.get(&b.url).copied() } else { None };
-				let ord = self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b));
-				if ord == Ordering::Equal { by_alphabetical(a, b) } else { ord }
-			}),
-		}
-	}
-
-	fn sort_naturally(&self, items: &mut Vec<File>) {
-		let mut indices = Vec::with_capacity(items.len());
-		let mut entities = Vec::with_capacity(items.len());
-		for (i, file) in items.iter().enumerate() {
-			indices.push(i);
-			entities.push(file.url.as_os_str().as_encoded_bytes());
-		}
-
-		indices.sort_unstable_by(|&a, &b| {
-			let promote = self.promote(&items[a], &items[b]);
-			if promote != Ordering::Equal {
-				return promote;
+				self.cmp(aa.unwrap_or(a.len), bb.unwrap_or(b.len), self.promote(a, b))
 			}
-
-			let ordering = natsort(entities[a], entities[b], !self.sensitive);
-			if self.reverse { ordering.reverse() } else { ordering }
-		});
-
-		*items = indices.into_iter().map(|i| mem::take(&mut items[i])).collect();
+		};
+		if ord == Ordering::Equal { by_alphabetical() } else { ord }
 	}
 
 	#[inline(always)]
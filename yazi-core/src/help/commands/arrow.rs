@@ -1,6 +1,6 @@
 use yazi_shared::{event::Cmd, render};
 
-use crate::help::Help;
+use crate::help::{Help, HelpRow};
 
 pub struct Opt {
 	step: isize,
@@ -37,11 +37,11 @@ impl Help {
 		}
 
 		let old = self.cursor;
-		self.cursor = (self.cursor + step).min(len - 1);
+		self.cursor = control_at(&self.bindings, (self.cursor + step).min(len - 1), true);
 
 		let limit = Self::limit();
 		if self.cursor >= (self.offset + limit).min(len).saturating_sub(5) {
-			self.offset = len.saturating_sub(limit).min(self.offset + self.cursor - old);
+			self.offset = len.saturating_sub(limit).min(self.offset + self.cursor.saturating_sub(old));
 		}
 
 		render!(old != self.cursor);
@@ -49,12 +49,45 @@ impl Help {
 
 	fn prev(&mut self, step: usize) {
 		let old = self.cursor;
-		self.cursor = self.cursor.saturating_sub(step);
+		self.cursor = control_at(&self.bindings, self.cursor.saturating_sub(step), false);
 
 		if self.cursor < self.offset + 5 {
-			self.offset = self.offset.saturating_sub(old - self.cursor);
+			self.offset = self.offset.saturating_sub(old.saturating_sub(self.cursor));
 		}
 
 		render!(old != self.cursor);
 	}
 }
+
+// The keybinding at `i`, or the nearest one past the titles of the categories in the direction,
+// or back the other way if there's none, for the cursor never to be on a title
+fn control_at(rows: &[HelpRow], i: usize, forward: bool) -> usize {
+	let is_control = |&j: &usize| matches!(rows.get(j), Some(HelpRow::Control(_)));
+	let (mut ahead, mut behind) = ((i..rows.len()).find(is_control), (0..=i).rev().find(is_control));
+	if !forward {
+		(ahead, behind) = (behind, ahead);
+	}
+	ahead.or(behind).unwrap_or(i)
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_config::keymap::Control;
+
+	use super::*;
+
+	#[test]
+	fn test_control_at() {
+		let control: &'static Control = Box::leak(Box::default());
+		let group = || HelpRow::Group(String::new());
+		let rows = [group(), HelpRow::Control(control), group(), group(), HelpRow::Control(control)];
+
+		assert_eq!(control_at(&rows, 0, true), 1);
+		assert_eq!(control_at(&rows, 0, false), 1);
+		assert_eq!(control_at(&rows, 2, true), 4);
+		assert_eq!(control_at(&rows, 3, false), 1);
+		assert_eq!(control_at(&rows, 4, true), 4);
+		assert_eq!(control_at(&rows[..4], 3, true), 1);
+		assert_eq!(control_at(&[group()], 0, true), 0);
+	}
+}
@@ -1,3 +1,4 @@
 mod arrow;
 mod escape;
 mod filter;
+mod switch;
@@ -0,0 +1,18 @@
+use yazi_shared::event::Cmd;
+
+use crate::help::Help;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(1) }
+	}
+}
+
+impl Help {
+	#[inline]
+	pub fn switch(&mut self, opt: impl Into<Opt>) { self.switch_scope(opt.into().step); }
+}
@@ -1,16 +1,43 @@
 use crossterm::event::KeyCode;
 use unicode_width::UnicodeWidthStr;
-use yazi_config::{keymap::{Control, Key}, KEYMAP};
+use yazi_config::{keymap::{Control, Key}, tr, KEYMAP};
 use yazi_shared::{render, term::Term, Layer};
 
 use super::HELP_MARGIN;
 use crate::input::Input;
 
+// The layers with keybindings, in the order they're switched through
+const LAYERS: [Layer; 11] = [
+	Layer::Manager,
+	Layer::Tasks,
+	Layer::Mounts,
+	Layer::Sidebar,
+	Layer::Notify,
+	Layer::Select,
+	Layer::Chmod,
+	Layer::Spot,
+	Layer::Input,
+	Layer::Completion,
+	Layer::Help,
+];
+
+// The categories the keybindings are grouped into, in order
+const GROUPS: [&str; 8] =
+	["General", "Navigation", "Selection", "Operations", "Editing", "Search", "View", "Tabs"];
+
+/// A row of the help, either a keybinding, or the title of the category it's in.
+pub enum HelpRow {
+	Group(String),
+	Control(&'static Control),
+}
+
 #[derive(Default)]
 pub struct Help {
 	pub visible:         bool,
 	pub layer:           Layer,
-	pub(super) bindings: Vec<&'static Control>,
+	// The layer whose keybindings are shown, or `None` for all of them
+	pub(super) scope:    Option<Layer>,
+	pub(super) bindings: Vec<HelpRow>,
 
 	// Filter
 	keyword:              Option<String>,
@@ -27,6 +54,7 @@ impl Help {
 	pub fn toggle(&mut self, layer: Layer) {
 		self.visible = !self.visible;
 		self.layer = layer;
+		self.scope = Some(layer);
 
 		self.keyword = Some(String::new());
 		self.in_filter = None;
@@ -70,15 +98,82 @@ impl Help {
 			return;
 		}
 
-		if let Some(kw) = kw {
-			self.bindings = KEYMAP.get(self.layer).iter().filter(|&c| c.contains(kw)).collect();
-		} else {
-			self.bindings = KEYMAP.get(self.layer).iter().collect();
+		let layers = match self.scope {
+			Some(l) => std::slice::from_ref(&LAYERS[Self::index(l)]),
+			None => &LAYERS[..],
+		};
+		self.bindings.clear();
+		for &layer in layers {
+			let mut groups: Vec<(&str, Vec<&'static Control>)> = vec![];
+			for c in KEYMAP.get(layer).iter().filter(|&c| kw.is_none_or(|kw| c.contains(kw))) {
+				let group = Self::group(layer, c);
+				match groups.iter_mut().find(|(g, _)| *g == group) {
+					Some((_, v)) => v.push(c),
+					None => groups.push((group, vec![c])),
+				}
+			}
+
+			// The built-in categories first, then the ones given by the user
+			groups.sort_by_key(|&(g, _)| GROUPS.iter().position(|&s| s == g).unwrap_or(GROUPS.len()));
+			for (group, controls) in groups {
+				let title = if GROUPS.contains(&group) { tr!(group) } else { group };
+				self.bindings.push(HelpRow::Group(if self.scope.is_some() {
+					title.to_owned()
+				} else {
+					format!("{layer} › {title}")
+				}));
+				self.bindings.extend(controls.into_iter().map(HelpRow::Control));
+			}
 		}
 
 		self.keyword = kw.map(|s| s.to_owned());
 		self.arrow(0);
 	}
+
+	/// Switches to the keybindings of the next or previous layer, with all of them
+	/// after the last.
+	pub(super) fn switch_scope(&mut self, step: isize) {
+		let len = LAYERS.len() as isize + 1;
+		let now = self.scope.map_or(LAYERS.len(), Self::index) as isize;
+		self.scope = LAYERS.get((now + step).rem_euclid(len) as usize).copied();
+
+		self.keyword = Some(String::new());
+		self.filter_apply();
+		render!();
+	}
+
+	#[inline]
+	fn index(layer: Layer) -> usize { LAYERS.iter().position(|&l| l == layer).unwrap_or(0) }
+
+	/// The category of a keybinding, by the command it runs unless set by the user.
+	fn group(layer: Layer, c: &'static Control) -> &'static str {
+		if let Some(g) = &c.group {
+			return g;
+		}
+
+		// The last command, as the others are usually to prepare for it, like `escape --visual`
+		let name = c.exec.last().map(|e| e.name.as_str()).unwrap_or_default();
+		if matches!(layer, Layer::Input | Layer::Completion) {
+			return match name {
+				"escape" | "close" | "help" | "plugin" => "General",
+				"complete" | "arrow" => "Navigation",
+				_ => "Editing",
+			};
+		}
+
+		match name {
+			"escape" | "quit" | "close" | "suspend" | "help" | "theme" | "plugin" | "shell" => "General",
+			"arrow" | "leap" | "seek" | "enter" | "leave" | "back" | "forward" | "cd" | "jump"
+			| "peek" | "find_arrow" | "history" => "Navigation",
+			"select" | "select_all" | "visual_mode" | "toggle" | "unyank" => "Selection",
+			"find" | "search" | "filter" => "Search",
+			"sort" | "linemode" | "hidden" | "ignored" | "ratio" | "view" | "expand" | "preview"
+			| "size_format" | "inspect" | "recursive" | "refresh" | "spot" | "tasks_show" | "mounts_show"
+			| "notify_show" | "sidebar" => "View",
+			s if s.starts_with("tab_") => "Tabs",
+			_ => "Operations",
+		}
+	}
 }
 
 impl Help {
@@ -93,9 +188,13 @@ impl Help {
 			.map(|s| format!("/{}", s))
 	}
 
+	// --- Scope
+	#[inline]
+	pub fn scope(&self) -> String { self.scope.map_or_else(|| "all".to_owned(), |l| l.to_string()) }
+
 	// --- Bindings
 	#[inline]
-	pub fn window(&self) -> &[&Control] {
+	pub fn window(&self) -> &[HelpRow] {
 		let end = (self.offset + Self::limit()).min(self.bindings.len());
 		&self.bindings[self.offset..end]
 	}
@@ -17,12 +17,15 @@ impl Input {
 	pub fn close(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 
-		if self.completion {
+		if self.completion || self.completing || self.history.searching {
 			Completion::_close();
 		}
 
 		if let Some(cb) = self.callback.take() {
 			let value = self.snap_mut().value.clone();
+			if opt.submit {
+				self.history.save(&value);
+			}
 			_ = cb.send(if opt.submit { Ok(value) } else { Err(InputError::Canceled(value)) });
 		}
 
@@ -6,6 +6,8 @@ use crate::input::Input;
 
 pub struct Opt {
 	word:   String,
+	whole:  bool,
+	show:   bool,
 	ticket: usize,
 }
 
@@ -13,6 +15,8 @@ impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
 		Self {
 			word:   c.take_first().unwrap_or_default(),
+			whole:  c.named.contains_key("whole"),
+			show:   c.named.contains_key("show"),
 			ticket: c.take_name("ticket").and_then(|s| s.parse().ok()).unwrap_or(0),
 		}
 	}
@@ -20,19 +24,34 @@ impl From<Cmd> for Opt {
 
 impl Input {
 	#[inline]
-	pub fn _complete(word: &str, ticket: usize) {
-		emit!(Call(Cmd::args("complete", vec![word.to_owned()]).with("ticket", ticket), Layer::Input));
+	pub fn _complete(word: &str, whole: bool, ticket: usize) {
+		emit!(Call(
+			Cmd::args("complete", vec![word.to_owned()]).with_bool("whole", whole).with("ticket", ticket),
+			Layer::Input
+		));
 	}
 
 	pub fn complete(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		if self.ticket != opt.ticket {
+		if opt.show {
+			return self.request_completion();
+		} else if self.ticket != opt.ticket {
 			return;
 		}
 
+		// Shell commands complete their last argument
 		let [before, after] = self.partition();
-		let new = if let Some((prefix, _)) = before.rsplit_once(MAIN_SEPARATOR) {
+		let arg = if self.highlight { before.rfind(' ').map_or(0, |i| i + 1) } else { 0 };
+
+		let new = if self.history.searching {
+			self.history.searching = false;
+			opt.word
+		} else if opt.whole {
+			format!("{}{}{after}", &before[..arg], opt.word)
+		} else if let Some((prefix, _)) = before.rsplit_once(MAIN_SEPARATOR) {
 			format!("{prefix}/{}{after}", opt.word)
+		} else if arg > 0 {
+			format!("{}{}{after}", &before[..arg], opt.word)
 		} else {
 			format!("{}{after}", opt.word)
 		};
@@ -46,6 +65,7 @@ impl Input {
 		snap.value = new;
 
 		self.move_(delta);
+		self.completing = false;
 		self.flush_value();
 		render!();
 	}
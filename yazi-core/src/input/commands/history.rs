@@ -0,0 +1,44 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::input::Input;
+
+pub struct Opt {
+	step:   isize,
+	search: bool,
+	// The entries of a kind, once the history is read
+	loaded: Option<(String, Vec<String>)>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			step:   c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0),
+			search: c.named.contains_key("search"),
+			loaded: c.take_name("loaded").zip(c.take_data()),
+		}
+	}
+}
+
+impl Input {
+	pub fn history(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if let Some((kind, entries)) = opt.loaded {
+			return self.history.loaded(&kind, entries);
+		}
+		if opt.search {
+			return self.search_history();
+		}
+
+		let Some(value) = self.history.recall(opt.step, &self.snaps.current().value) else {
+			return;
+		};
+
+		let snap = self.snap_mut();
+		snap.cursor = value.chars().count();
+		snap.value = value;
+
+		self.move_(0);
+		self.flush_value();
+		render!();
+	}
+}
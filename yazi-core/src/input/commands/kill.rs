@@ -4,6 +4,8 @@ use yazi_shared::{event::Cmd, render, CharKind};
 
 use crate::input::Input;
 
+const KILL_RING: usize = 16;
+
 pub struct Opt {
 	kind: String,
 }
@@ -14,17 +16,23 @@ impl From<Cmd> for Opt {
 
 impl Input {
 	fn kill_range(&mut self, range: impl RangeBounds<usize>) {
+		self.snaps.tag(self.limit());
 		let snap = self.snap_mut();
 		snap.cursor = match range.start_bound() {
 			std::ops::Bound::Included(i) => *i,
 			std::ops::Bound::Excluded(_) => unreachable!(),
 			std::ops::Bound::Unbounded => 0,
 		};
-		if snap.value.drain(range).next().is_none() {
+		let killed: String = snap.value.drain(range).collect();
+		if killed.is_empty() {
 			return;
 		}
 
+		self.kill_ring.push_front(killed);
+		self.kill_ring.truncate(KILL_RING);
+
 		self.move_(0);
+		self.snaps.tag(self.limit());
 		self.flush_value();
 		render!();
 	}
@@ -5,6 +5,7 @@ mod complete;
 mod delete;
 mod escape;
 mod forward;
+mod history;
 mod insert;
 mod kill;
 mod move_;
@@ -4,14 +4,27 @@ use crate::{input::{op::InputOp, Input}, CLIPBOARD};
 
 pub struct Opt {
 	before: bool,
+	ring:   bool,
+	pop:    bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { before: c.named.contains_key("before") } }
+	fn from(c: Cmd) -> Self {
+		Self {
+			before: c.named.contains_key("before"),
+			ring:   c.named.contains_key("ring"),
+			pop:    c.named.contains_key("pop"),
+		}
+	}
 }
 
 impl Input {
 	pub fn paste(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.ring {
+			return self.paste_ring(opt.pop);
+		}
+
 		if let Some(start) = self.snap().op.start() {
 			self.snap_mut().op = InputOp::Delete(false, false, start);
 			self.handle_op(self.snap().cursor, true);
@@ -22,10 +35,38 @@ impl Input {
 			return;
 		}
 
-		let opt = opt.into() as Opt;
 		self.insert(!opt.before);
 		self.type_str(&s.to_string_lossy());
 		self.escape(());
 		render!();
 	}
 }
+
+impl Input {
+	// Yanks the latest killed text at the cursor, or with `pop`, replaces the text
+	// just yanked with the next older entry of the kill ring.
+	fn paste_ring(&mut self, pop: bool) {
+		let (start, idx) = if pop {
+			let Some((range, idx)) = self.ring_pasted.take() else { return };
+			let snap = self.snap();
+			if range.end > snap.count() || snap.slice(range.clone()) != self.kill_ring[idx] {
+				return;
+			}
+
+			let (start, end) = (snap.idx(range.start).unwrap(), snap.idx(range.end).unwrap_or(snap.len()));
+			let snap = self.snap_mut();
+			snap.value.drain(start..end);
+			snap.cursor = range.start;
+			(range.start, (idx + 1) % self.kill_ring.len())
+		} else {
+			self.snaps.tag(self.limit());
+			(self.snap().cursor, 0)
+		};
+
+		let Some(s) = self.kill_ring.get(idx).cloned() else { return };
+		self.type_str(&s);
+		self.snaps.tag(self.limit());
+		self.ring_pasted = Some((start..start + s.chars().count(), idx));
+		render!();
+	}
+}
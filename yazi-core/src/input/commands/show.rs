@@ -2,7 +2,7 @@ use tokio::sync::mpsc;
 use yazi_config::popup::InputCfg;
 use yazi_shared::{emit, event::Cmd, render, InputError, Layer};
 
-use crate::input::Input;
+use crate::input::{Input, InputHistory};
 
 pub struct Opt {
 	cfg: InputCfg,
@@ -36,6 +36,8 @@ impl Input {
 		self.callback = Some(opt.tx);
 		self.realtime = opt.cfg.realtime;
 		self.completion = opt.cfg.completion;
+		self.completing = false;
+		self.history = InputHistory::load(opt.cfg.history);
 
 		// Shell
 		self.highlight = opt.cfg.highlight;
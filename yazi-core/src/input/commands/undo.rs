@@ -4,10 +4,18 @@ use crate::input::{Input, InputMode};
 
 impl Input {
 	pub fn undo(&mut self, _: Cmd) {
+		// Undoing in insert mode keeps typing, after saving what has been typed so far
+		let insert = self.snap().mode == InputMode::Insert;
+		if insert {
+			self.snaps.tag(self.limit());
+		}
+
 		if !self.snaps.undo() {
 			return;
 		}
-		if self.snap().mode == InputMode::Insert {
+		if insert {
+			self.snap_mut().mode = InputMode::Insert;
+		} else if self.snap().mode == InputMode::Insert {
 			self.escape(());
 		}
 		render!();
@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+use tokio::fs;
+use yazi_config::BOOT;
+use yazi_shared::{emit, event::Cmd, Layer};
+
+use super::Input;
+use crate::folder::{FilterCase, Fuzzy};
+
+const HISTORY_LIMIT: usize = 200;
+const SEARCH_LIMIT: usize = 30;
+
+/// The history of one kind of prompt, kept in the state directory, so
+/// that it's shared with the other instances and survives restarts.
+#[derive(Default)]
+pub(super) struct InputHistory {
+	kind:    Option<&'static str>,
+	entries: Vec<String>,
+
+	// Browsing with Up/Down: the index of the recalled entry,
+	// and the value that was being typed before recalling
+	cursor: Option<usize>,
+	draft:  String,
+
+	pub(super) searching: bool,
+}
+
+impl InputHistory {
+	/// An empty history of the kind, whose entries are read in the background,
+	/// and handed back to the input by the `history` command once they're read.
+	pub(super) fn load(kind: Option<&'static str>) -> Self {
+		if let Some(kind) = kind {
+			tokio::spawn(async move {
+				let entries = Self::read(kind).await;
+				emit!(Call(Cmd::new("history").with("loaded", kind).with_data(entries), Layer::Input));
+			});
+		}
+		Self { kind, ..Default::default() }
+	}
+
+	#[inline]
+	pub(super) fn loaded(&mut self, kind: &str, entries: Vec<String>) {
+		if self.kind == Some(kind) {
+			self.entries = entries;
+		}
+	}
+
+	pub(super) fn save(&self, value: &str) {
+		let Some(kind) = self.kind else { return };
+		if value.is_empty() || value.contains('\n') {
+			return;
+		}
+
+		let value = value.to_owned();
+		tokio::spawn(async move {
+			// Re-read it, to keep what the other instances have added in the meantime
+			let mut entries = Self::read(kind).await;
+			entries.retain(|s| *s != value);
+			entries.push(value);
+			if entries.len() > HISTORY_LIMIT {
+				entries.drain(..entries.len() - HISTORY_LIMIT);
+			}
+
+			// A temporary file of our own, for two instances saving at once not to mix theirs up
+			let path = Self::path(kind);
+			let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+			if fs::write(&tmp, entries.join("\n") + "\n").await.is_ok() {
+				fs::rename(tmp, path).await.ok();
+			}
+		});
+	}
+
+	/// Steps through the entries, where a negative step goes back in time,
+	/// returning the value that should replace the current one.
+	pub(super) fn recall(&mut self, step: isize, current: &str) -> Option<String> {
+		let len = self.entries.len();
+		let cursor = match self.cursor {
+			None if step >= 0 || len == 0 => return None,
+			None => {
+				self.draft = current.to_owned();
+				len.saturating_sub(step.unsigned_abs())
+			}
+			Some(i) if step < 0 => i.saturating_sub(step.unsigned_abs()),
+			Some(i) if i + step as usize >= len => {
+				self.cursor = None;
+				return Some(std::mem::take(&mut self.draft));
+			}
+			Some(i) => i + step as usize,
+		};
+
+		self.cursor = Some(cursor);
+		Some(self.entries[cursor].clone())
+	}
+
+	/// The entries matching the word, the better matches first, then the newer ones.
+	pub(super) fn search(&self, word: &str) -> Vec<String> {
+		let fuzzy = Fuzzy::new(word, FilterCase::Smart);
+		let mut ranked: Vec<_> =
+			self.entries.iter().rev().filter_map(|s| Some((fuzzy.matches(s)?.0, s))).collect();
+
+		ranked.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+		ranked.into_iter().take(SEARCH_LIMIT).map(|(_, s)| s.to_owned()).collect()
+	}
+
+	#[inline]
+	pub(super) fn is_empty(&self) -> bool { self.entries.is_empty() }
+
+	async fn read(kind: &str) -> Vec<String> {
+		let Ok(s) = fs::read_to_string(Self::path(kind)).await else {
+			return vec![];
+		};
+		s.lines().filter(|l| !l.is_empty()).map(ToOwned::to_owned).collect()
+	}
+
+	#[inline]
+	fn path(kind: &str) -> PathBuf { BOOT.state_dir.join(format!("{kind}.history")) }
+}
+
+impl Input {
+	/// The history of a kind of prompt, from the newest to the oldest.
+	#[inline]
+	pub async fn history_of(kind: &str) -> Vec<String> {
+		InputHistory::read(kind).await.into_iter().rev().collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_search() {
+		let entries = ["~/src/yazi", "~/Downloads", "~/src/ya"].map(ToOwned::to_owned).to_vec();
+		let history = InputHistory { entries, ..Default::default() };
+
+		assert_eq!(history.search("ya"), ["~/src/ya", "~/src/yazi"]);
+		assert_eq!(history.search("sy"), ["~/src/ya", "~/src/yazi"]);
+		assert_eq!(history.search("dl"), ["~/Downloads"]);
+		assert_eq!(history.search(""), ["~/src/ya", "~/Downloads", "~/src/yazi"]);
+	}
+}
@@ -1,11 +1,11 @@
-use std::ops::Range;
+use std::{collections::VecDeque, ops::Range};
 
 use tokio::sync::mpsc::UnboundedSender;
 use unicode_width::UnicodeWidthStr;
 use yazi_config::{popup::Position, INPUT};
-use yazi_shared::{render, InputError};
+use yazi_shared::{emit, event::Cmd, render, InputError, Layer};
 
-use super::{mode::InputMode, op::InputOp, InputSnap, InputSnaps};
+use super::{mode::InputMode, op::InputOp, InputHistory, InputSnap, InputSnaps};
 use crate::CLIPBOARD;
 
 #[derive(Default)]
@@ -21,9 +21,15 @@ pub struct Input {
 	pub(super) callback:   Option<UnboundedSender<Result<String, InputError>>>,
 	pub(super) realtime:   bool,
 	pub(super) completion: bool,
+	pub(super) completing: bool,
+	pub(super) history:    InputHistory,
 
 	// Shell
 	pub highlight: bool,
+
+	// Kill ring, and the range of the last text pasted from it
+	pub(super) kill_ring:   VecDeque<String>,
+	pub(super) ring_pasted: Option<(Range<usize>, usize)>,
 }
 
 impl Input {
@@ -45,6 +51,18 @@ impl Input {
 		render!();
 	}
 
+	/// Types the text of a bracketed paste, with multiple lines collapsed into one.
+	pub fn type_paste(&mut self, s: &str) {
+		let s = s.split(['\r', '\n']).filter(|l| !l.is_empty()).collect::<Vec<_>>().join(" ");
+		if s.is_empty() {
+			return;
+		}
+
+		self.snaps.tag(self.limit());
+		self.type_str(&s);
+		self.snaps.tag(self.limit());
+	}
+
 	pub(super) fn handle_op(&mut self, cursor: usize, include: bool) -> bool {
 		let old = self.snap().clone();
 		let snap = self.snaps.current_mut();
@@ -86,6 +104,25 @@ impl Input {
 		true
 	}
 
+	// Opens the completion popup with the entries fuzzy-matching what's typed,
+	// which keeps narrowing down as we type.
+	pub(super) fn search_history(&mut self) {
+		if self.history.is_empty() {
+			return;
+		}
+
+		self.history.searching = true;
+		emit!(Call(
+			Cmd::args("show", self.history.search(&self.snap().value))
+				.with("cache-name", "history")
+				.with("word", &self.snap().value)
+				.with_bool("ranked", true)
+				.with_bool("smart", true)
+				.with("ticket", self.ticket),
+			Layer::Completion
+		));
+	}
+
 	#[inline]
 	pub(super) fn flush_value(&mut self) {
 		self.ticket = self.ticket.wrapping_add(1);
@@ -95,11 +132,23 @@ impl Input {
 			self.callback.as_ref().unwrap().send(Err(InputError::Typed(value))).ok();
 		}
 
-		if self.completion {
+		if self.history.searching {
+			self.search_history();
+		} else if self.completion || self.completing {
 			let before = self.partition()[0].to_owned();
 			self.callback.as_ref().unwrap().send(Err(InputError::Completed(before, self.ticket))).ok();
 		}
 	}
+
+	// Asks for the completion of a prompt which doesn't complete as we type,
+	// and keeps refining it until a candidate is submitted.
+	pub(super) fn request_completion(&mut self) {
+		let Some(cb) = &self.callback else { return };
+
+		self.completing = true;
+		self.ticket = self.ticket.wrapping_add(1);
+		cb.send(Err(InputError::Completed(self.partition()[0].to_owned(), self.ticket))).ok();
+	}
 }
 
 impl Input {
@@ -1,4 +1,5 @@
 mod commands;
+mod history;
 mod input;
 mod mode;
 mod op;
@@ -7,6 +8,7 @@ mod snaps;
 
 pub use input::*;
 pub use mode::*;
+use history::*;
 use op::*;
 use snap::*;
 use snaps::*;
@@ -7,23 +7,31 @@
 )]
 
 mod clipboard;
+pub mod chmod;
 pub mod completion;
 pub mod folder;
 pub mod help;
 pub mod input;
 pub mod manager;
+pub mod mounts;
 pub mod notify;
 pub mod select;
+pub mod sidebar;
+mod snapshot;
+pub mod spot;
 mod step;
 pub mod tab;
 pub mod tasks;
 pub mod which;
 
 pub use clipboard::*;
+pub(crate) use snapshot::*;
 pub use step::*;
 
 pub fn init() {
 	CLIPBOARD.with(Default::default);
+	folder::Ignore::init();
+	manager::Yanked::init();
 
 	yazi_scheduler::init();
 }
@@ -0,0 +1,67 @@
+use yazi_config::tr;
+use yazi_plugin::external::Checksum;
+use yazi_shared::{emit, event::Cmd, fs::path_relative_to};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	algo:   Option<String>,
+	verify: bool,
+	write:  bool,
+	copy:   bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			algo:   c.take_first(),
+			verify: c.named.contains_key("verify"),
+			write:  c.named.contains_key("write"),
+			copy:   c.named.contains_key("copy"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn checksum(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		let algo = match opt.algo.as_deref().map(str::parse::<Checksum>).transpose() {
+			Ok(algo) => algo,
+			Err(e) => return emit!(Notify(tr!("Checksum"), e, "warn", 5)),
+		};
+
+		if opt.verify {
+			return self.checksum_verify(algo, tasks);
+		}
+
+		let algo = algo.unwrap_or_default();
+		let cwd = self.cwd().clone();
+		let targets: Vec<_> = self
+			.selected_or_hovered()
+			.into_iter()
+			.filter(|u| !u.is_dir())
+			.map(|u| {
+				let name = if u.is_absolute() { path_relative_to(u, &cwd) } else { u.as_path().into() };
+				(u.clone(), name.to_string_lossy().into_owned(), None)
+			})
+			.collect();
+
+		if targets.is_empty() {
+			let content = tr!("No files to calculate the checksums of");
+			return emit!(Notify(tr!("Checksum"), content, "warn", 5));
+		}
+
+		let write = opt.write.then(|| cwd.join(algo.sums_name()));
+		tasks.file_checksum(algo, targets, write, opt.copy);
+	}
+
+	fn checksum_verify(&self, algo: Option<Checksum>, tasks: &Tasks) {
+		match self.hovered().filter(|h| !h.is_dir()) {
+			Some(h) => tasks.file_checksum_verify(algo, h.url.clone()),
+			None => {
+				let content = tr!("Hover on a sums file to verify against");
+				emit!(Notify(tr!("Checksum"), content, "warn", 5));
+			}
+		}
+	}
+}
@@ -0,0 +1,63 @@
+use yazi_config::tr;
+use yazi_shared::{emit, event::Cmd, fs::Url};
+
+use crate::{chmod::Chmod, folder::Folder, manager::Manager, tasks::Tasks};
+
+pub struct Opt {
+	mode:        Option<u32>,
+	interactive: bool,
+	recursive:   bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			mode:        c.take_first().and_then(|s| u32::from_str_radix(&s, 8).ok()),
+			interactive: c.named.contains_key("interactive"),
+			recursive:   c.named.contains_key("recursive"),
+		}
+	}
+}
+
+impl Manager {
+	pub fn chmod(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if cfg!(not(unix)) {
+			let content = tr!("Changing permissions is not supported on this platform");
+			return emit!(Notify(tr!("Chmod"), content, "warn", 5));
+		}
+
+		let targets: Vec<_> = self.selected_or_hovered().into_iter().cloned().collect();
+		if targets.is_empty() {
+			return;
+		}
+
+		if opt.interactive {
+			Chmod::_show(targets.into_iter().filter_map(|u| self.mode_of(&u).map(|m| (u, m))).collect());
+		} else if let Some(mode) = opt.mode {
+			Tasks::_chmod(targets, mode, 0o7777, opt.recursive);
+		}
+	}
+
+	// The mode from the cached file in any of the folders loaded, rather than stat-ing it again.
+	// The cast is needed where mode_t is u16, as on macOS
+	#[cfg(unix)]
+	#[allow(clippy::unnecessary_cast)]
+	fn mode_of(&self, url: &Url) -> Option<u32> {
+		let p = url.parent_url()?;
+		let find = |folder: Option<&Folder>| {
+			folder
+				.filter(|folder| folder.cwd == p)
+				.and_then(|folder| folder.files.iter().find(|f| f.url == *url))
+				.map(|f| f.cha.permissions as u32)
+		};
+
+		find(Some(self.current()))
+			.or_else(|| find(self.parent()))
+			.or_else(|| find(self.hovered_folder()))
+			.or_else(|| find(self.active().history.get(&p)))
+	}
+
+	#[cfg(not(unix))]
+	fn mode_of(&self, _: &Url) -> Option<u32> { None }
+}
@@ -0,0 +1,312 @@
+use std::{collections::{BTreeMap, VecDeque}, path::{Path, PathBuf}};
+
+use anyhow::{bail, Result};
+use ratatui::{style::{Color, Modifier, Style}, text::{Line, Span}};
+use tokio::{fs, io::AsyncReadExt};
+use yazi_config::{tr, LAYOUT};
+use yazi_plugin::{bindings::Window, elements::Paragraph, utils::PreviewLock};
+use yazi_shared::{diff::{diff, Edit}, emit, event::Cmd, fs::Url, Layer};
+
+use crate::manager::Manager;
+
+// Files larger than this are only told apart, not diffed
+const MAX_TEXT: u64 = 1 << 20;
+
+// At most how many lines, or characters within a line, can differ before giving up on the diff
+const MAX_EDITS: usize = 4096;
+const MAX_INLINE_EDITS: usize = 64;
+
+// At most how many lines of a change are tried to be paired up
+const MAX_PAIRED: usize = 32;
+
+// The unchanged lines shown around each change
+const CONTEXT: usize = 3;
+
+// At most how many entries of the two directories are compared
+const MAX_ENTRIES: usize = 10000;
+
+impl Manager {
+	pub fn compare(&mut self, _: Cmd) {
+		let Some(hovered) = self.hovered().cloned() else {
+			return;
+		};
+		let selected: Vec<_> = self.active().selected.iter().cloned().collect();
+		let Ok([a, b]) = <[Url; 2]>::try_from(selected) else {
+			return emit!(Notify(tr!("Compare"), tr!("Select exactly two files to compare"), "warn", 5));
+		};
+
+		self.active_mut().preview.reset_image();
+		tokio::spawn(async move {
+			let lines = match Self::compare_do(&a, &b).await {
+				Ok(Some(lines)) => lines,
+				Ok(None) => return,
+				Err(e) => return emit!(Notify(tr!("Compare"), e, "warn", 5)),
+			};
+
+			let area = LAYOUT.load().preview;
+			let height = area.height as usize;
+			let mut lines = lines;
+			if lines.len() > height && height > 0 {
+				let more = lines.len() - height + 1;
+				lines.truncate(height - 1);
+				lines.push(Line::styled(tr!("… {n} more lines", n = more), Modifier::DIM));
+			}
+
+			let lock = PreviewLock {
+				url:    hovered.url,
+				cha:    hovered.cha,
+				skip:   0,
+				window: Window::default(),
+				data:   vec![Box::new(Paragraph { area, text: lines.into(), ..Default::default() })],
+			};
+			emit!(Call(Cmd::new("preview").with_data(lock), Layer::Manager));
+		});
+	}
+
+	async fn compare_do(a: &Url, b: &Url) -> Result<Option<Vec<Line<'static>>>> {
+		let (ma, mb) = (fs::metadata(a).await?, fs::metadata(b).await?);
+		match (ma.is_dir(), mb.is_dir()) {
+			(true, true) => {
+				let lines = Self::compare_dirs(a, b).await;
+				if lines.is_none() {
+					let content = tr!("The directories have the same files, of the same sizes");
+					emit!(Notify(tr!("Compare"), content, "info", 5));
+				}
+				return Ok(lines);
+			}
+			(false, false) => {}
+			_ => bail!("Can't compare a file with a directory"),
+		}
+
+		let Some(offset) = Self::first_difference(a, b).await? else {
+			emit!(Notify(tr!("Compare"), tr!("The files are identical"), "info", 5));
+			return Ok(None);
+		};
+
+		let binary = |content: &[u8]| content.contains(&0);
+		let (ca, cb) = if ma.len() > MAX_TEXT || mb.len() > MAX_TEXT {
+			(None, None)
+		} else {
+			(Some(fs::read(a).await?), Some(fs::read(b).await?))
+		};
+
+		let (Some(ca), Some(cb)) = (ca.filter(|c| !binary(c)), cb.filter(|c| !binary(c))) else {
+			let content = tr!("The files differ, starting at byte {offset}", offset = offset);
+			emit!(Notify(tr!("Compare"), content, "info", 5));
+			return Ok(None);
+		};
+
+		// Diffing is all computation, which can take a while, and shouldn't hold up a worker
+		let (na, nb) = (a.display().to_string(), b.display().to_string());
+		let lines = tokio::task::spawn_blocking(move || {
+			let (ta, tb) = (String::from_utf8_lossy(&ca), String::from_utf8_lossy(&cb));
+			Self::compare_text(&na, &nb, &ta, &tb)
+		})
+		.await?;
+
+		match lines {
+			Some(lines) => Ok(Some(lines)),
+			None => bail!("The files differ in too many lines to show"),
+		}
+	}
+
+	/// The offset of the first byte that differs, or `None` if the files are identical.
+	async fn first_difference(a: &Path, b: &Path) -> Result<Option<u64>> {
+		let (mut fa, mut fb) = (fs::File::open(a).await?, fs::File::open(b).await?);
+		let (mut ba, mut bb) = (vec![0; 1 << 16], vec![0; 1 << 16]);
+
+		let mut offset = 0;
+		loop {
+			let (na, nb) = (Self::fill(&mut fa, &mut ba).await?, Self::fill(&mut fb, &mut bb).await?);
+			if let Some(i) = ba[..na].iter().zip(&bb[..nb]).position(|(x, y)| x != y) {
+				return Ok(Some(offset + i as u64));
+			} else if na != nb {
+				return Ok(Some(offset + na.min(nb) as u64));
+			} else if na == 0 {
+				return Ok(None);
+			}
+			offset += na as u64;
+		}
+	}
+
+	/// Reads until the buffer is full or the end of the file, returning how much was read.
+	async fn fill(f: &mut fs::File, buf: &mut [u8]) -> Result<usize> {
+		let mut n = 0;
+		while n < buf.len() {
+			match f.read(&mut buf[n..]).await? {
+				0 => break,
+				k => n += k,
+			}
+		}
+		Ok(n)
+	}
+
+	fn compare_text(name_a: &str, name_b: &str, a: &str, b: &str) -> Option<Vec<Line<'static>>> {
+		let (la, lb): (Vec<_>, Vec<_>) = (a.lines().collect(), b.lines().collect());
+		let edits = diff(&la, &lb, MAX_EDITS)?;
+
+		// Each edit, with the line of `a` and `b` it's at
+		let (mut i, mut j) = (0, 0);
+		let ops: Vec<_> = edits
+			.into_iter()
+			.map(|e| {
+				let op = (e, i, j);
+				i += (e != Edit::Insert) as usize;
+				j += (e != Edit::Delete) as usize;
+				op
+			})
+			.collect();
+
+		// The ranges of the edits to show, as the changes with their context around
+		let mut hunks: Vec<(usize, usize)> = vec![];
+		for (n, _) in ops.iter().enumerate().filter(|(_, op)| op.0 != Edit::Equal) {
+			let (start, end) = (n.saturating_sub(CONTEXT), (n + CONTEXT + 1).min(ops.len()));
+			match hunks.last_mut() {
+				Some(last) if last.1 >= start => last.1 = end,
+				_ => hunks.push((start, end)),
+			}
+		}
+
+		let bold = Style::new().add_modifier(Modifier::BOLD);
+		let mut lines = vec![
+			Line::styled(format!("--- {name_a}"), bold.fg(Color::Red)),
+			Line::styled(format!("+++ {name_b}"), bold.fg(Color::Green)),
+		];
+
+		for (start, end) in hunks {
+			let ops = &ops[start..end];
+			let dels = ops.iter().filter(|op| op.0 != Edit::Insert).count();
+			let adds = ops.iter().filter(|op| op.0 != Edit::Delete).count();
+			lines.push(Line::styled(
+				format!("@@ -{},{dels} +{},{adds} @@", ops[0].1 + 1, ops[0].2 + 1),
+				Style::new().fg(Color::Cyan),
+			));
+
+			let mut n = 0;
+			while n < ops.len() {
+				if ops[n].0 == Edit::Equal {
+					lines.push(Line::from(format!(" {}", la[ops[n].1])));
+					n += 1;
+					continue;
+				}
+
+				// A run of deletions followed by insertions, pairing the lines up to highlight within them
+				let del_end = n + ops[n..].iter().take_while(|op| op.0 == Edit::Delete).count();
+				let ins_end = del_end + ops[del_end..].iter().take_while(|op| op.0 == Edit::Insert).count();
+				let (dels, ins) = (&ops[n..del_end], &ops[del_end..ins_end]);
+
+				// Each deleted line is paired with the next similar inserted one, to highlight within them
+				let mut old: Vec<_> = dels.iter().map(|op| Self::compare_whole('-', la[op.1])).collect();
+				let mut new: Vec<_> = ins.iter().map(|op| Self::compare_whole('+', lb[op.2])).collect();
+				let mut next = 0;
+				for (k, d) in dels.iter().enumerate().take(MAX_PAIRED) {
+					for (p, i) in ins.iter().enumerate().skip(next).take(MAX_PAIRED) {
+						if let Some((o, n)) = Self::compare_pair(la[d.1], lb[i.2]) {
+							(old[k], new[p], next) = (o, n, p + 1);
+							break;
+						}
+					}
+				}
+				lines.extend(old);
+				lines.extend(new);
+				n = ins_end;
+			}
+		}
+		Some(lines)
+	}
+
+	#[inline]
+	fn compare_whole(sign: char, s: &str) -> Line<'static> {
+		let color = if sign == '-' { Color::Red } else { Color::Green };
+		Line::styled(format!("{sign}{s}"), Style::new().fg(color))
+	}
+
+	/// Renders a changed line and its counterpart, highlighting the characters that differ,
+	/// or `None` if they're too different to be the same line changed.
+	fn compare_pair(old: &str, new: &str) -> Option<(Line<'static>, Line<'static>)> {
+		let (ca, cb): (Vec<_>, Vec<_>) = (old.chars().collect(), new.chars().collect());
+		let edits = diff(&ca, &cb, MAX_INLINE_EDITS.min((ca.len() + cb.len()) / 2))?;
+
+		let spans = |chars: &[char], skip: Edit, color: Color, sign: char| {
+			let style = Style::new().fg(color);
+			let mut spans = vec![Span::styled(sign.to_string(), style)];
+
+			let mut it = edits.iter().filter(|&&e| e != skip).zip(chars).peekable();
+			while let Some((&e, &c)) = it.next() {
+				let mut s = String::from(c);
+				while let Some((_, &c)) = it.next_if(|(&next, _)| next == e) {
+					s.push(c);
+				}
+				let style = if e == Edit::Equal { style } else { style.add_modifier(Modifier::REVERSED) };
+				spans.push(Span::styled(s, style));
+			}
+			Line::from(spans)
+		};
+
+		Some((spans(&ca, Edit::Insert, Color::Red, '-'), spans(&cb, Edit::Delete, Color::Green, '+')))
+	}
+
+	/// Lists the entries only in either directory, or of a different type or size,
+	/// or `None` if there are none.
+	async fn compare_dirs(a: &Path, b: &Path) -> Option<Vec<Line<'static>>> {
+		let ((ea, more_a), (eb, more_b)) = (Self::walk(a).await, Self::walk(b).await);
+		if more_a || more_b {
+			let content = tr!("Too many entries, only the first {n} of each compared", n = MAX_ENTRIES);
+			emit!(Notify(tr!("Compare"), content, "warn", 5));
+		}
+
+		let mut lines = vec![Line::styled(
+			format!("{} ↔ {}", a.display(), b.display()),
+			Style::new().add_modifier(Modifier::BOLD),
+		)];
+		let mut all: Vec<_> = ea.keys().chain(eb.keys()).collect();
+		all.sort_unstable();
+		all.dedup();
+
+		for rel in all {
+			let (sign, color) = match (ea.get(rel), eb.get(rel)) {
+				(Some(_), None) => ('-', Color::Red),
+				(None, Some(_)) => ('+', Color::Green),
+				(Some(x), Some(y)) if x != y => ('~', Color::Yellow),
+				_ => continue,
+			};
+			lines.push(Line::styled(format!("{sign} {}", rel.display()), Style::new().fg(color)));
+		}
+
+		if lines.len() > 1 { Some(lines) } else { None }
+	}
+
+	/// Each entry under the directory, relative to it, with whether it's a directory and its size,
+	/// and whether there were more than `MAX_ENTRIES` of them, of which only the first are taken.
+	async fn walk(root: &Path) -> (BTreeMap<PathBuf, (bool, u64)>, bool) {
+		let mut entries = BTreeMap::new();
+		let mut dirs = VecDeque::from([root.to_owned()]);
+
+		while let Some(dir) = dirs.pop_front() {
+			let Ok(mut it) = fs::read_dir(&dir).await else { continue };
+
+			// In the order of their names, not of the filesystem, for the same entries
+			// to be taken from both directories if there are too many
+			let mut children = vec![];
+			while let Ok(Some(entry)) = it.next_entry().await {
+				if let Ok(meta) = entry.metadata().await {
+					children.push((entry.path(), meta));
+				}
+			}
+			children.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+			for (path, meta) in children {
+				if entries.len() >= MAX_ENTRIES {
+					return (entries, true);
+				}
+				if meta.is_dir() {
+					dirs.push_back(path.clone());
+				}
+
+				let rel = path.strip_prefix(root).unwrap_or(&path).to_owned();
+				entries.insert(rel, (meta.is_dir(), if meta.is_dir() { 0 } else { meta.len() }));
+			}
+		}
+		(entries, false)
+	}
+}
@@ -1,17 +1,21 @@
-use std::path::{PathBuf, MAIN_SEPARATOR};
+use std::{fs as std_fs, io, path::{Path, PathBuf, MAIN_SEPARATOR}};
 
+use anyhow::Result;
 use tokio::fs;
-use yazi_config::popup::InputCfg;
+use yazi_config::{popup::{InputCfg, SelectCfg}, tr, MANAGER};
 use yazi_shared::{event::Cmd, fs::{File, FilesOp, Url}};
 
-use crate::{input::Input, manager::Manager};
+use crate::{input::Input, manager::Manager, select::Select};
 
 pub struct Opt {
-	force: bool,
+	force:    bool,
+	template: bool,
 }
 
 impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { force: c.named.contains_key("force") } }
+	fn from(c: Cmd) -> Self {
+		Self { force: c.named.contains_key("force"), template: !c.named.contains_key("no-template") }
+	}
 }
 
 impl Manager {
@@ -32,11 +36,29 @@ impl Manager {
 				}
 			}
 
-			if name.ends_with(MAIN_SEPARATOR) {
+			let dir = name.ends_with(MAIN_SEPARATOR);
+			let template = if opt.template {
+				let Ok(template) = Self::pick_template(&path, dir).await else {
+					return Ok(());
+				};
+				template
+			} else {
+				None
+			};
+
+			if dir {
 				fs::create_dir_all(&path).await?;
 			} else {
 				fs::create_dir_all(&path.parent().unwrap()).await.ok();
-				fs::File::create(&path).await?;
+			}
+			match template {
+				Some(from) => {
+					let to = path.clone();
+					tokio::task::spawn_blocking(move || Self::apply_template(&from, &to, &Vars::new(&to)))
+						.await??;
+				}
+				None if !dir => _ = fs::File::create(&path).await?,
+				None => {}
 			}
 
 			let child =
@@ -48,4 +70,77 @@ impl Manager {
 			Ok::<(), anyhow::Error>(())
 		});
 	}
+
+	// The templates for files with the same extension, or the directory templates,
+	// letting the user choose one of them or none, and failing if they cancel.
+	async fn pick_template(path: &Path, dir: bool) -> Result<Option<PathBuf>> {
+		let mut templates = vec![];
+		let Ok(mut it) = fs::read_dir(MANAGER.templates_dir()).await else {
+			return Ok(None);
+		};
+
+		let ext = path.extension().map(|s| s.to_ascii_lowercase());
+		while let Ok(Some(entry)) = it.next_entry().await {
+			let p = entry.path();
+			if entry.file_type().await.is_ok_and(|t| t.is_dir()) != dir {
+				continue;
+			}
+			if dir || (ext.is_some() && p.extension().map(|s| s.to_ascii_lowercase()) == ext) {
+				templates.push(p);
+			}
+		}
+
+		templates.sort_unstable();
+		if templates.is_empty() {
+			return Ok(None);
+		}
+
+		let empty = if dir { tr!("Empty directory") } else { tr!("Empty file") };
+		let names = templates.iter().map(|p| p.file_name().unwrap().to_string_lossy().into_owned());
+		let names = names.chain([empty.to_owned()]).collect();
+		let choice = Select::_show(SelectCfg::template(names)).await?;
+		Ok((choice < templates.len()).then(|| templates.swap_remove(choice)))
+	}
+
+	fn apply_template(from: &Path, to: &Path, vars: &Vars) -> io::Result<()> {
+		let meta = std_fs::metadata(from)?;
+		if meta.is_dir() {
+			std_fs::create_dir_all(to)?;
+			for entry in std_fs::read_dir(from)? {
+				let entry = entry?;
+				Self::apply_template(&entry.path(), &to.join(entry.file_name()), vars)?;
+			}
+			return Ok(());
+		}
+
+		// Only text files get the substitutions, the others are copied as is
+		match std_fs::read_to_string(from) {
+			Ok(s) => std_fs::write(to, vars.apply(&s))?,
+			Err(_) => _ = std_fs::copy(from, to)?,
+		}
+		std_fs::set_permissions(to, meta.permissions())
+	}
+}
+
+struct Vars {
+	filename: String,
+	name:     String,
+	date:     String,
+}
+
+impl Vars {
+	fn new(path: &Path) -> Self {
+		let lossy = |s: Option<&std::ffi::OsStr>| s.map(|s| s.to_string_lossy().into_owned());
+		Self {
+			filename: lossy(path.file_name()).unwrap_or_default(),
+			name:     lossy(path.file_stem()).unwrap_or_default(),
+			date:     chrono::Local::now().format("%Y-%m-%d").to_string(),
+		}
+	}
+
+	fn apply(&self, s: &str) -> String {
+		s.replace("{{filename}}", &self.filename)
+			.replace("{{name}}", &self.name)
+			.replace("{{date}}", &self.date)
+	}
 }
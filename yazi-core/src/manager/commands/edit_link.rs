@@ -0,0 +1,94 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}};
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::{popup::InputCfg, tr};
+use yazi_shared::{emit, event::Cmd, fs::{File, FilesOp, Url}};
+
+use crate::{input::Input, manager::Manager};
+
+pub struct Opt {
+	target: Option<String>,
+	force:  bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { target: c.take_first(), force: c.named.contains_key("force") }
+	}
+}
+
+impl Manager {
+	pub fn edit_link(&self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(url) = self.hovered().filter(|h| h.is_link()).map(|h| h.url()) else {
+			return;
+		};
+
+		tokio::spawn(async move {
+			let old = fs::read_link(&url).await?;
+			let target = match opt.target {
+				Some(t) => t,
+				None => {
+					let value = old.to_string_lossy().into_owned();
+					match Input::_show(InputCfg::edit_link().with_value(value)).recv().await {
+						Some(Ok(t)) => t,
+						_ => return Ok(()),
+					}
+				}
+			};
+
+			let target = PathBuf::from(target);
+			if target.as_os_str().is_empty() || target == old {
+				return Ok(());
+			}
+
+			// A relative target is relative to the directory of the link, not to the cwd
+			let parent = url.parent().unwrap_or(Path::new(""));
+			if !opt.force && fs::metadata(parent.join(&target)).await.is_err() {
+				let content = tr!(
+					"The target {target} doesn't exist, use `--force` to link to it anyway",
+					target = target.display()
+				);
+				return Ok(emit!(Notify(tr!("Edit link"), content, "warn", 5)));
+			}
+
+			if let Err(e) = Self::replace_link(&url, &target).await {
+				let content = tr!("Failed to replace the link: {error}", error = e);
+				return Ok(emit!(Notify(tr!("Edit link"), content, "error", 5)));
+			}
+
+			let file = File::from(url.clone()).await?;
+			FilesOp::Upserting(file.parent().unwrap(), BTreeMap::from_iter([(url.clone(), file)])).emit();
+			Ok::<(), anyhow::Error>(Self::_hover(Some(url)))
+		});
+	}
+
+	// Links the new target at a temporary name next to the link, and renames it over the
+	// link, for there to be no moment the link is missing, or left missing if it fails.
+	async fn replace_link(link: &Url, target: &Path) -> Result<()> {
+		let name = link.file_name().unwrap_or_default().to_string_lossy();
+		let tmp = link.with_file_name(format!(".{name}.yazi-link-{}", std::process::id()));
+		fs::remove_file(&tmp).await.ok();
+
+		#[cfg(unix)]
+		{
+			fs::symlink(target, &tmp).await?;
+		}
+		#[cfg(windows)]
+		{
+			let resolved = link.parent().unwrap_or(Path::new("")).join(target);
+			if fs::metadata(resolved).await.is_ok_and(|m| m.is_dir()) {
+				fs::symlink_dir(target, &tmp).await?;
+			} else {
+				fs::symlink_file(target, &tmp).await?;
+			}
+		}
+
+		if let Err(e) = fs::rename(&tmp, link).await {
+			fs::remove_file(&tmp).await.ok();
+			Err(e)?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use yazi_shared::{emit, event::Cmd, render, Layer};
+
+use crate::manager::Manager;
+
+// The time between the steps of the smooth scrolling, about a frame of a 60Hz display
+const FRAME: Duration = Duration::from_millis(16);
+
+impl Manager {
+	#[inline]
+	pub fn _glide() {
+		tokio::spawn(async {
+			tokio::time::sleep(FRAME).await;
+			emit!(Call(Cmd::new("glide"), Layer::Manager));
+		});
+	}
+
+	pub fn glide(&mut self, _: Cmd) {
+		let mut b = false;
+		for tab in self.tabs.iter_mut() {
+			b |= tab.current.glide_step();
+		}
+
+		if b {
+			Self::_glide();
+		}
+		render!();
+	}
+}
@@ -38,8 +38,9 @@ impl Manager {
 		// Re-peek
 		self.peek(false);
 
-		// Refresh watcher
-		let mut to_watch = BTreeSet::new();
+		// Refresh watcher, which also keeps track of the yanked files
+		let yanked = self.yanked.parents();
+		let mut to_watch: BTreeSet<_> = yanked.iter().collect();
 		for tab in self.tabs.iter() {
 			to_watch.insert(&tab.current.cwd);
 			if let Some(ref p) = tab.parent {
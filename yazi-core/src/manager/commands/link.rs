@@ -1,25 +1,82 @@
-use yazi_shared::event::Cmd;
+use yazi_config::{popup::SelectCfg, tr};
+use yazi_shared::{emit, event::Cmd, render, Layer};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, select::Select, tasks::Tasks};
+
+// The choices of the interactive mode, with whether the links are relative, and resolved
+const CHOICES: [(&str, bool, bool); 4] = [
+	("Absolute paths", false, false),
+	("Relative paths", true, false),
+	("Absolute paths, of the resolved symlinks", false, true),
+	("Relative paths, of the resolved symlinks", true, true),
+];
 
 pub struct Opt {
-	relative: bool,
-	force:    bool,
+	relative:    bool,
+	resolve:     bool,
+	force:       bool,
+	hovered:     bool,
+	interactive: bool,
+	register:    Option<char>,
 }
 
-impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self {
-		Self { relative: c.named.contains_key("relative"), force: c.named.contains_key("force") }
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			relative:    c.named.contains_key("relative"),
+			resolve:     c.named.contains_key("resolve"),
+			force:       c.named.contains_key("force"),
+			hovered:     c.named.contains_key("hovered"),
+			interactive: c.named.contains_key("interactive"),
+			register:    Manager::take_register(&mut c)?,
+		})
 	}
 }
 
 impl Manager {
-	pub fn link(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
-		if self.yanked.cut {
+	pub fn link(&mut self, opt: impl TryInto<Opt>, tasks: &Tasks) {
+		render!(self.yanked.catchup());
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		let Some((cut, urls)) = self.yanked.get(opt.register) else {
+			return Self::register_empty(opt.register);
+		};
+		if cut || urls.is_empty() {
 			return;
+		} else if opt.interactive {
+			return Self::link_ask(urls.len(), opt);
 		}
 
-		let opt = opt.into() as Opt;
-		tasks.file_link(&self.yanked, self.cwd(), opt.relative, opt.force);
+		// Into the hovered directory, or the cwd if it's not one
+		let dest = match self.hovered() {
+			Some(h) if opt.hovered && h.is_dir() => h.url(),
+			_ => self.cwd().to_owned(),
+		};
+		tasks.file_link(urls, &dest, opt.relative, opt.resolve, opt.force);
+	}
+
+	// Asks how the links are made, once for all of the yanked files
+	fn link_ask(n: usize, opt: Opt) {
+		tokio::spawn(async move {
+			let items = CHOICES.iter().map(|&(s, ..)| tr!(s).to_owned()).collect();
+			let Ok(choice) = Select::_show(SelectCfg::link(n, items)).await else {
+				return;
+			};
+
+			let (_, relative, resolve) = CHOICES[choice];
+			let mut cmd = Cmd::new("link")
+				.with_bool("relative", relative)
+				.with_bool("resolve", resolve)
+				.with_bool("force", opt.force)
+				.with_bool("hovered", opt.hovered);
+			if let Some(name) = opt.register {
+				cmd = cmd.with("register", name);
+			}
+			emit!(Call(cmd, Layer::Manager));
+		});
 	}
 }
@@ -0,0 +1,62 @@
+use tokio::fs;
+use yazi_config::{local::Local, tr};
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::{manager::{Manager, Trusted}, tab::Overrides};
+
+pub struct Opt {
+	cwd:   Url,
+	found: Option<(Url, String, Local)>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_data().ok_or(()) }
+}
+
+impl Manager {
+	/// Looks for the nearest `.yazi.toml` of the directory, to apply it to the tab if trusted.
+	pub(super) fn _local(cwd: &Url) {
+		if !cwd.is_regular() {
+			return;
+		}
+
+		let cwd = cwd.clone();
+		tokio::spawn(async move {
+			let mut found = None;
+			for dir in cwd.ancestors() {
+				let path = dir.join(Local::NAME);
+				let Ok(s) = fs::read_to_string(&path).await else { continue };
+
+				let local = match Local::parse(&s) {
+					Ok(local) => local,
+					Err(e) => {
+						let path = path.display();
+						let content = tr!("Failed to parse {path}: {error}", path = path, error = e);
+						emit!(Notify(tr!("Local settings"), content, "warn", 5));
+						break;
+					}
+				};
+
+				let id = Trusted::id(&s);
+				if Trusted::ask(&path, &id).await {
+					found = Some((Url::from(dir), id, local));
+				}
+				break;
+			}
+			emit!(Call(Cmd::new("local").with_data(Opt { cwd, found }), Layer::Manager));
+		});
+	}
+
+	pub fn local(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		if *self.cwd() == opt.cwd {
+			let found = opt.found.map(|(root, id, local)| Overrides::new(root, id, local));
+			self.active_mut().set_overrides(found);
+		}
+	}
+}
@@ -1,22 +1,43 @@
+mod checksum;
+mod chmod;
 mod close;
+mod compare;
 mod create;
+mod edit_link;
+mod glide;
 mod hover;
 mod link;
+mod local;
 mod open;
+mod open_editor;
+mod open_terminal;
 mod paste;
 mod peek;
+mod qr;
 mod quit;
+mod ratio;
 mod refresh;
+mod registers;
 mod remove;
 mod rename;
 mod seek;
+mod size_format;
+mod spot;
 mod suspend;
 mod tab_close;
 mod tab_create;
+mod tab_move;
+mod tab_pin;
+mod tab_rename;
 mod tab_swap;
 mod tab_switch;
+mod tab_sync;
 mod unyank;
+mod update_branch;
+mod update_fields;
 mod update_files;
 mod update_mimetype;
 mod update_paged;
+mod update_sizing;
+mod update_yanked;
 mod yank;
@@ -5,7 +5,7 @@ use yazi_config::{popup::SelectCfg, ARGS, OPEN};
 use yazi_plugin::isolate;
 use yazi_shared::{emit, event::{Cmd, EventQuit}, fs::{File, Url}, Layer, MIME_DIR};
 
-use crate::{folder::Folder, manager::Manager, select::Select, tasks::Tasks};
+use crate::{folder::Folder, manager::{Learned, Manager, Recent}, select::Select, tasks::Tasks};
 
 pub struct Opt {
 	targets:     Vec<(Url, String)>,
@@ -41,7 +41,7 @@ impl Manager {
 
 		let (mut done, mut todo) = (Vec::with_capacity(selected.len()), vec![]);
 		for u in selected {
-			if self.mimetype.get(u).is_some() {
+			if self.mimetype.contains_key(u) {
 				done.push((u.clone(), String::new()));
 			} else if self.guess_folder(u) {
 				done.push((u.clone(), MIME_DIR.to_owned()));
@@ -96,7 +96,11 @@ impl Manager {
 
 		if targets.is_empty() {
 			return;
-		} else if !opt.interactive {
+		}
+
+		let files = targets.iter().filter(|(_, m)| m != MIME_DIR).map(|(u, _)| u.to_path_buf());
+		Recent::push(files.collect());
+		if !opt.interactive {
 			tasks.file_open(&targets);
 			return;
 		}
@@ -106,12 +110,21 @@ impl Manager {
 			return;
 		}
 
-		let urls = targets.into_iter().map(|(u, _)| u).collect();
+		let urls: Vec<_> = targets.into_iter().map(|(u, _)| u).collect();
+		let ext = Learned::ext(urls.iter().map(|u| u.as_path()));
 		tokio::spawn(async move {
-			let result = Select::_show(SelectCfg::open(openers.iter().map(|o| o.desc.clone()).collect()));
-			if let Ok(choice) = result.await {
-				Tasks::_open(urls, openers[choice].clone());
+			let mut cfg = SelectCfg::open(openers.iter().map(|o| o.desc.clone()).collect());
+			if let Some(ext) = &ext {
+				cfg = cfg.with_remember(ext);
+			}
+
+			let Ok((choice, remember)) = Select::_show_toggle(cfg).await else {
+				return;
+			};
+			if let Some(ext) = ext.filter(|_| remember) {
+				Learned::remember(&ext, &openers[choice]).await;
 			}
+			Tasks::_open(urls, openers[choice].clone());
 		});
 	}
 
@@ -0,0 +1,65 @@
+use std::{env, path::Path};
+
+use yazi_config::{open::Opener, MANAGER};
+use yazi_shared::event::Cmd;
+
+use crate::{manager::Manager, tasks::Tasks};
+
+// Editors with a window of their own, which are detached instead of taking over the terminal
+const GUI_EDITORS: [&str; 14] = [
+	"code",
+	"codium",
+	"cursor",
+	"zed",
+	"subl",
+	"gedit",
+	"gnome-text-editor",
+	"kate",
+	"mousepad",
+	"gvim",
+	"mvim",
+	"neovide",
+	"notepad",
+	"notepad++",
+];
+
+impl Manager {
+	pub fn open_editor(&mut self, _: Cmd, tasks: &Tasks) {
+		let Some(hovered) = self.hovered() else {
+			return;
+		};
+
+		let target = hovered.url.clone();
+		let dir = if hovered.is_dir() {
+			target.clone()
+		} else {
+			target.parent_url().unwrap_or_else(|| self.cwd().clone())
+		};
+
+		let editor = Some(MANAGER.editor.clone())
+			.into_iter()
+			.chain(["VISUAL", "EDITOR"].map(|k| env::var(k).unwrap_or_default()))
+			.find(|s| !s.is_empty())
+			.unwrap_or_else(|| if cfg!(windows) { "notepad" } else { "vi" }.to_owned());
+
+		let program = editor.split_whitespace().next().map(Path::new).and_then(|p| p.file_stem());
+		let gui = program.and_then(|p| p.to_str()).is_some_and(|p| GUI_EDITORS.contains(&p));
+
+		let exec = if cfg!(windows) {
+			format!(r#"cd /d "%1" && {editor} "%2""#)
+		} else {
+			format!(r#"cd -- "$1" && exec {editor} "$2""#)
+		};
+
+		let opener = Opener {
+			exec,
+			block: !gui,
+			orphan: gui,
+			capture: false,
+			desc: editor,
+			for_: None,
+			spread: true,
+		};
+		tasks.file_open_with(&opener, &[dir, target]);
+	}
+}
@@ -0,0 +1,96 @@
+use std::env;
+
+use yazi_config::{open::Opener, tr, MANAGER};
+use yazi_shared::{emit, event::Cmd, in_path};
+
+use crate::{manager::Manager, tasks::Tasks};
+
+const WAYLAND_TERMINALS: [&str; 9] = [
+	"foot",
+	"kitty",
+	"wezterm",
+	"alacritty",
+	"ghostty",
+	"gnome-terminal",
+	"konsole",
+	"xfce4-terminal",
+	"xterm",
+];
+
+const X11_TERMINALS: [&str; 10] = [
+	"x-terminal-emulator",
+	"kitty",
+	"wezterm",
+	"alacritty",
+	"ghostty",
+	"gnome-terminal",
+	"konsole",
+	"xfce4-terminal",
+	"urxvt",
+	"xterm",
+];
+
+pub struct Opt {
+	cwd: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { cwd: c.named.contains_key("cwd") } }
+}
+
+impl Manager {
+	pub fn open_terminal(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+		let opt = opt.into() as Opt;
+		let dir = match self.hovered() {
+			Some(h) if h.is_dir() && !opt.cwd => h.url.clone(),
+			_ => self.cwd().clone(),
+		};
+
+		let Some(exec) = Self::terminal() else {
+			let content = tr!("No terminal found, set `terminal` under `[manager]` in yazi.toml");
+			return emit!(Notify(tr!("Open terminal"), content, "warn", 5));
+		};
+
+		let opener = Opener {
+			exec,
+			block: false,
+			orphan: true,
+			capture: false,
+			desc: "Terminal".to_owned(),
+			for_: None,
+			spread: true,
+		};
+		tasks.file_open_with(&opener, &[dir]);
+	}
+
+	// The command starting a terminal in the directory `$1`
+	fn terminal() -> Option<String> {
+		let configured = [MANAGER.terminal.clone(), env::var("TERMINAL").unwrap_or_default()]
+			.into_iter()
+			.find(|s| !s.is_empty());
+
+		if cfg!(windows) {
+			return Some(match configured {
+				Some(t) => format!(r#"start "" /D "%1" {t}"#),
+				None if in_path("wt.exe") => r#"wt -d "%1""#.to_owned(),
+				None => r#"start "" /D "%1" cmd"#.to_owned(),
+			});
+		} else if let Some(t) = configured {
+			return Some(format!(r#"cd -- "$1" && exec {t}"#));
+		} else if cfg!(target_os = "macos") {
+			let iterm = env::var("TERM_PROGRAM").is_ok_and(|s| s == "iTerm.app");
+			return Some(format!(r#"open -a {} "$1""#, if iterm { "iTerm" } else { "Terminal" }));
+		}
+
+		// The ones native to the display server come first
+		let candidates: &[&str] = if env::var_os("WAYLAND_DISPLAY").is_some() {
+			&WAYLAND_TERMINALS
+		} else if env::var_os("DISPLAY").is_some() {
+			&X11_TERMINALS
+		} else {
+			&[]
+		};
+
+		candidates.iter().find(|&&t| in_path(t)).map(|t| format!(r#"cd -- "$1" && exec {t}"#))
+	}
+}
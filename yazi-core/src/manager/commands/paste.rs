@@ -1,27 +1,49 @@
-use yazi_shared::event::Cmd;
+use yazi_shared::{event::Cmd, render};
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, tasks::{Exclusion, Tasks}};
 
 pub struct Opt {
-	force:  bool,
-	follow: bool,
+	force:     bool,
+	follow:    bool,
+	exclusion: Exclusion,
+	register:  Option<char>,
 }
 
-impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self {
-		Self { force: c.named.contains_key("force"), follow: c.named.contains_key("follow") }
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		// `--exclude=node_modules --exclude=target/`, of the patterns as in a `.gitignore`
+		let patterns = c.take_names("exclude");
+
+		Ok(Self {
+			force:     c.named.contains_key("force"),
+			follow:    c.named.contains_key("follow"),
+			exclusion: Exclusion::new(patterns, c.named.contains_key("gitignore")),
+			register:  Manager::take_register(&mut c)?,
+		})
 	}
 }
 
 impl Manager {
-	pub fn paste(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
-		let opt = opt.into() as Opt;
+	pub fn paste(&mut self, opt: impl TryInto<Opt>, tasks: &Tasks) {
+		render!(self.yanked.catchup());
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		let Some((cut, urls)) = self.yanked.get(opt.register) else {
+			return Self::register_empty(opt.register);
+		};
 
 		let dest = self.cwd();
-		if self.yanked.cut {
-			tasks.file_cut(&self.yanked, dest, opt.force);
+		if cut {
+			// Those moved away can't be pasted again, and are left out of the register as they're
+			// gone, for the rest to be kept if e.g. the task fails, or the protected ones are declined
+			tasks.file_cut(urls, dest, opt.force, &opt.exclusion);
+			self.yanked.moving(opt.register);
 		} else {
-			tasks.file_copy(&self.yanked, dest, opt.force, opt.follow);
+			tasks.file_copy(urls, dest, opt.force, opt.follow, &opt.exclusion);
 		}
 	}
 }
@@ -37,25 +37,34 @@ impl Manager {
 
 		let folder = self.active().hovered_folder().map(|f| (f.offset, f.mtime));
 		if !self.active().preview.same_url(&hovered.url) {
-			self.active_mut().preview.skip = folder.map(|f| f.0).unwrap_or_default();
-			render!(self.active_mut().preview.reset());
+			let preview = &mut self.active_mut().preview;
+			preview.switch_to(&hovered.url);
+			if let Some((offset, _)) = folder {
+				preview.skip = offset;
+			}
+			render!(preview.reset());
 		}
 
 		let opt = opt.into() as Opt;
 		if matches!(opt.only_if, Some(ref u) if *u != hovered.url) {
 			return;
 		}
+		self.active_mut().preload_dirs();
 
 		if let Some(skip) = opt.skip {
 			let preview = &mut self.active_mut().preview;
 			if opt.upper_bound {
+				// Settled on the last page, where following resumes
 				preview.skip = preview.skip.min(skip);
+				preview.paused = false;
 			} else {
+				preview.paused |= preview.follow && skip < preview.skip;
 				preview.skip = skip;
 			}
 		}
 
 		if hovered.is_dir() {
+			self.active_mut().apply_files_attrs();
 			self.active_mut().preview.go_folder(hovered, folder.and_then(|f| f.1), opt.force);
 			return;
 		}
@@ -0,0 +1,128 @@
+use qrcode::{EcLevel, QrCode};
+use ratatui::{layout::Rect, style::{Color, Style}, text::{Line, Span, Text}};
+use yazi_config::{tr, LAYOUT};
+use yazi_plugin::{bindings::Window, elements::Paragraph, utils::PreviewLock};
+use yazi_shared::{emit, event::Cmd, Layer};
+
+use crate::manager::Manager;
+
+// The most bytes a QR code can hold, with the low error correction
+const MAX_BYTES: u64 = 2953;
+
+// The light modules around the code, which scanners need to find it
+const QUIET_ZONE: usize = 2;
+
+pub struct Opt {
+	path: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { path: c.named.contains_key("path") } }
+}
+
+impl Manager {
+	pub fn qr(&mut self, opt: impl Into<Opt>) {
+		let Some(hovered) = self.hovered().cloned() else {
+			return;
+		};
+
+		let opt = opt.into() as Opt;
+		let selected: Vec<_> = self.active().selected.iter().map(|u| u.to_string()).collect();
+
+		self.active_mut().preview.reset_image();
+		tokio::spawn(async move {
+			let data = if !selected.is_empty() {
+				selected.join("\n").into_bytes()
+			} else if opt.path || hovered.is_dir() || hovered.cha.len > MAX_BYTES {
+				hovered.url.to_string().into_bytes()
+			} else {
+				match tokio::fs::read(&hovered.url).await {
+					Ok(b) => b,
+					Err(e) => {
+						let content = tr!("Failed to read the file: {error}", error = e);
+						return emit!(Notify(tr!("QR code"), content, "warn", 5));
+					}
+				}
+			};
+
+			let Some(qr) = Self::qr_encode(&data) else {
+				let content = tr!("Too long to encode, {n} bytes", n = data.len());
+				return emit!(Notify(tr!("QR code"), content, "warn", 5));
+			};
+
+			let area = LAYOUT.load().preview;
+			let n = qr.width() + QUIET_ZONE * 2;
+			if n > area.width as usize || n.div_ceil(2) > area.height as usize {
+				let (w, h) = (n, n.div_ceil(2));
+				let content = tr!("Needs a preview of {w}x{h} cells to fit", w = w, h = h);
+				return emit!(Notify(tr!("QR code"), content, "warn", 5));
+			}
+
+			let area = Rect {
+				x:      area.x + (area.width - n as u16) / 2,
+				y:      area.y + (area.height - n.div_ceil(2) as u16) / 2,
+				width:  n as u16,
+				height: n.div_ceil(2) as u16,
+			};
+
+			let lock = PreviewLock {
+				url:    hovered.url.clone(),
+				cha:    hovered.cha,
+				skip:   0,
+				window: Window::default(),
+				data:   vec![Box::new(Paragraph { area, text: Self::qr_text(&qr), ..Default::default() })],
+			};
+			emit!(Call(Cmd::new("preview").with_data(lock), Layer::Manager));
+		});
+	}
+
+	// In the smallest version that fits, with the medium error correction if it still fits in there
+	fn qr_encode(data: &[u8]) -> Option<QrCode> {
+		let low = QrCode::with_error_correction_level(data, EcLevel::L).ok()?;
+		QrCode::with_version(data, low.version(), EcLevel::M).ok().or(Some(low))
+	}
+
+	/// Draws two rows of modules per line with half blocks, the upper one as the
+	/// foreground and the lower one as the background.
+	fn qr_text(qr: &QrCode) -> Text<'static> {
+		let (w, n) = (qr.width(), qr.width() + QUIET_ZONE * 2);
+		let dark = |x: usize, y: usize| {
+			let (x, y) = (x.wrapping_sub(QUIET_ZONE), y.wrapping_sub(QUIET_ZONE));
+			x < w && y < w && qr[(x, y)] == qrcode::Color::Dark
+		};
+		let color = |dark: bool| if dark { Color::Black } else { Color::White };
+
+		let lines: Vec<_> = (0..n)
+			.step_by(2)
+			.map(|y| {
+				Line::from(
+					(0..n)
+						.map(|x| {
+							let style = Style::new().fg(color(dark(x, y))).bg(color(dark(x, y + 1)));
+							Span::styled("▀", style)
+						})
+						.collect::<Vec<_>>(),
+				)
+			})
+			.collect();
+		Text::from(lines)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use qrcode::Version;
+
+	use super::*;
+
+	#[test]
+	fn test_qr_encode() {
+		let qr = Manager::qr_encode(b"/home/me/a.txt").unwrap();
+		assert_eq!((qr.version(), qr.error_correction_level()), (Version::Normal(1), EcLevel::M));
+
+		let data = vec![0xff; MAX_BYTES as usize];
+		let qr = Manager::qr_encode(&data).unwrap();
+		assert_eq!((qr.version(), qr.error_correction_level()), (Version::Normal(40), EcLevel::L));
+		assert!(Manager::qr_encode(&[data, vec![0xff]].concat()).is_none());
+	}
+}
@@ -0,0 +1,136 @@
+use std::{fs, path::PathBuf};
+
+use yazi_config::{manager::ManagerRatio, BOOT, MANAGER};
+use yazi_shared::{emit, event::Cmd};
+
+use crate::{manager::Manager, Snapshot};
+
+static SAVED: Snapshot = Snapshot::new();
+
+pub enum Opt {
+	Set(ManagerRatio),
+	Grow(String, bool),
+	Single,
+	Reset,
+	None,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		if let Some(name) = c.take_name("grow") {
+			return Self::Grow(name, true);
+		} else if let Some(name) = c.take_name("shrink") {
+			return Self::Grow(name, false);
+		} else if c.named.contains_key("single") {
+			return Self::Single;
+		} else if c.named.contains_key("reset") {
+			return Self::Reset;
+		}
+
+		let ratio: Option<Vec<u16>> = c.args.iter().map(|s| s.parse().ok()).collect();
+		ratio.and_then(|r| ManagerRatio::try_from(r).ok()).map_or(Self::None, Self::Set)
+	}
+}
+
+impl Manager {
+	pub fn ratio(&mut self, opt: impl Into<Opt>) {
+		let ratio = match opt.into() {
+			Opt::Set(ratio) => ratio,
+			Opt::Grow(name, grow) => {
+				let Some(ratio) = Self::grow_ratio(self.ratio, &name, grow) else {
+					return;
+				};
+				ratio
+			}
+			Opt::Single => {
+				if let Some(ratio) = self.ratio_saved.take() {
+					ratio
+				} else {
+					self.ratio_saved = Some(self.ratio);
+					ManagerRatio { parent: 0, current: 1, preview: 0, all: 1 }
+				}
+			}
+			Opt::Reset => MANAGER.ratio,
+			Opt::None => return,
+		};
+
+		// Leaving the single-column mode in any other way forgets the saved ratio
+		if ratio.parent != 0 || ratio.preview != 0 {
+			self.ratio_saved = None;
+		}
+		if ratio == self.ratio {
+			return;
+		}
+
+		// Treat it as a resize, so the preview is redrawn to fit its new area
+		self.ratio = ratio;
+		self.save_ratio();
+		emit!(Resize);
+	}
+
+	/// The ratio last set, kept across launches unless the one configured has changed since.
+	pub(crate) fn saved_ratio() -> ManagerRatio {
+		let s = fs::read_to_string(Self::ratio_path()).unwrap_or_default();
+		Self::parse_saved(&s, MANAGER.ratio).unwrap_or(MANAGER.ratio)
+	}
+
+	// Saved along with the one configured, and in the single-column mode as the one it's left to,
+	// for the next launch not to be stuck in it
+	fn save_ratio(&self) {
+		let ratio = self.ratio_saved.unwrap_or(self.ratio);
+		SAVED.save(Self::ratio_path(), format!("{}\n{}\n", fmt(MANAGER.ratio), fmt(ratio)));
+	}
+
+	fn parse_saved(s: &str, configured: ManagerRatio) -> Option<ManagerRatio> {
+		let parse = |l: &str| -> Option<ManagerRatio> {
+			let ratio: Option<Vec<u16>> = l.split(' ').map(|s| s.parse().ok()).collect();
+			ManagerRatio::try_from(ratio?).ok()
+		};
+
+		let mut lines = s.lines();
+		if parse(lines.next()?)? != configured {
+			return None;
+		}
+		parse(lines.next()?)
+	}
+
+	#[inline]
+	fn ratio_path() -> PathBuf { BOOT.state_dir.join("ratio") }
+
+	fn grow_ratio(mut ratio: ManagerRatio, name: &str, grow: bool) -> Option<ManagerRatio> {
+		// Step by an eighth of the whole, so ratios set by mouse drags move at a similar pace
+		let step = (ratio.all / 8).max(1);
+		let value = match name {
+			"parent" => &mut ratio.parent,
+			"current" => &mut ratio.current,
+			"preview" => &mut ratio.preview,
+			_ => return None,
+		};
+
+		*value = if grow { value.saturating_add(step) } else { value.saturating_sub(step) };
+		ManagerRatio::try_from(vec![ratio.parent, ratio.current, ratio.preview]).ok()
+	}
+}
+
+#[inline]
+fn fmt(r: ManagerRatio) -> String { format!("{} {} {}", r.parent, r.current, r.preview) }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_saved() {
+		let configured = ManagerRatio::try_from(vec![1, 4, 3]).unwrap();
+		let set = ManagerRatio::try_from(vec![2, 3, 3]).unwrap();
+
+		assert_eq!(Manager::parse_saved("1 4 3\n2 3 3\n", configured), Some(set));
+		assert_eq!(fmt(set), "2 3 3");
+
+		// Not if the one configured has changed since, nor if it's missing or malformed
+		assert_eq!(Manager::parse_saved("1 3 4\n2 3 3\n", configured), None);
+		assert_eq!(Manager::parse_saved("", configured), None);
+		assert_eq!(Manager::parse_saved("1 4 3\n0 0 0\n", configured), None);
+		assert_eq!(Manager::parse_saved("1 4 3\n2 x 3\n", configured), None);
+	}
+}
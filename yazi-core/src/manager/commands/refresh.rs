@@ -10,19 +10,26 @@ impl Manager {
 		emit!(Call(Cmd::new("refresh"), Layer::Manager));
 	}
 
-	pub fn refresh(&mut self, _: Cmd, tasks: &Tasks) {
+	pub fn refresh(&mut self, c: Cmd, tasks: &Tasks) {
+		let force = c.named.contains_key("force");
+
 		env::set_current_dir(self.cwd()).ok();
 		env::set_var("PWD", self.cwd());
 
 		self.active_mut().apply_files_attrs();
+		self.active_mut().read_branch();
+		Self::_local(self.cwd());
 
 		if let Some(p) = self.parent() {
-			self.watcher.trigger_dirs(&[self.current(), p]);
+			self.watcher.trigger_dirs(&[self.current(), p], force);
 		} else {
-			self.watcher.trigger_dirs(&[self.current()]);
+			self.watcher.trigger_dirs(&[self.current()], force);
 		}
 
 		self.hover(None);
+		if force {
+			self.peek(true);
+		}
 		self.update_paged((), tasks);
 
 		tasks.preload_sorted(&self.current().files);
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use yazi_config::{popup::SelectCfg, tr};
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::{manager::{register_name, Manager}, select::Select};
+
+impl Manager {
+	/// Shows what's in each of the registers, to paste from the chosen one.
+	pub fn registers(&mut self, _: Cmd) {
+		self.yanked.catchup();
+
+		let mut names = vec![];
+		let mut items = vec![];
+		if !self.yanked.is_empty() {
+			names.push(None);
+			items.push(Self::register_item('"', self.yanked.cut, &self.yanked));
+		}
+		for (&c, r) in self.yanked.named() {
+			names.push(Some(c));
+			items.push(Self::register_item(c, r.cut, &r.urls));
+		}
+		if items.is_empty() {
+			return Self::register_empty(None);
+		}
+
+		tokio::spawn(async move {
+			let Ok(choice) = Select::_show(SelectCfg::registers(items)).await else {
+				return;
+			};
+
+			let mut cmd = Cmd::new("paste");
+			if let Some(name) = names[choice] {
+				cmd = cmd.with("register", name);
+			}
+			emit!(Call(cmd, Layer::Manager));
+		});
+	}
+
+	/// The register of the `--register` of the command, none for the unnamed one if it's not
+	/// given, or an error that's notified of if it's not a valid name.
+	pub(super) fn take_register(c: &mut Cmd) -> Result<Option<char>, ()> {
+		let Some(s) = c.take_name("register") else {
+			return Ok(None);
+		};
+		register_name(&s).map(Some).map_err(|e| {
+			emit!(Notify(tr!("Registers"), e, "error", 5));
+		})
+	}
+
+	pub(super) fn register_empty(name: Option<char>) {
+		let content = match name {
+			Some(c) => tr!("The register \"{name} is empty", name = c),
+			None => tr!("Nothing is yanked").to_owned(),
+		};
+		emit!(Notify(tr!("Registers"), content, "warn", 5));
+	}
+
+	fn register_item(name: char, cut: bool, urls: &HashSet<Url>) -> String {
+		let mut files: Vec<_> =
+			urls.iter().filter_map(|u| u.file_name()).map(|s| s.to_string_lossy()).collect();
+		files.sort_unstable();
+
+		let n = urls.len();
+		let kind = if cut { tr!("{n} cut", n = n) } else { tr!("{n} copied", n = n) };
+		format!("\"{name}  {kind}: {}", files.join(", "))
+	}
+}
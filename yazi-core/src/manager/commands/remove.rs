@@ -1,6 +1,6 @@
 use yazi_shared::event::Cmd;
 
-use crate::{manager::Manager, tasks::Tasks};
+use crate::{manager::Manager, mounts::Mounts, tasks::Tasks};
 
 pub struct Opt {
 	force:       bool,
@@ -17,9 +17,9 @@ impl From<Cmd> for Opt {
 }
 
 impl Manager {
-	pub fn remove(&mut self, opt: impl Into<Opt>, tasks: &Tasks) {
+	pub fn remove(&mut self, opt: impl Into<Opt>, tasks: &Tasks, mounts: &Mounts) {
 		let opt = opt.into() as Opt;
 		let targets = self.selected_or_hovered().into_iter().cloned().collect();
-		tasks.file_remove(targets, opt.force, opt.permanently);
+		tasks.file_remove(targets, opt.force, opt.permanently, mounts);
 	}
 }
@@ -1,30 +1,92 @@
-use std::{collections::BTreeMap, ffi::OsStr, io::{stdout, BufWriter, Write}, path::PathBuf};
+use std::{collections::{BTreeMap, HashSet}, ffi::OsStr, io::{stdout, BufWriter, Write}, path::PathBuf};
 
-use anyhow::{anyhow, bail, Result};
+use anyhow::{bail, Result};
+use regex::{Regex, RegexBuilder};
 use tokio::{fs::{self, OpenOptions}, io::{stdin, AsyncReadExt, AsyncWriteExt}};
-use yazi_config::{popup::InputCfg, OPEN, PREVIEW};
+use yazi_config::{popup::InputCfg, tr, OPEN, PREVIEW};
 use yazi_plugin::external::{self, ShellOpt};
 use yazi_scheduler::{Scheduler, BLOCKER};
-use yazi_shared::{event::Cmd, fs::{max_common_root, File, FilesOp, Url}, term::Term, Defer};
+use yazi_shared::{emit, event::Cmd, fs::{max_common_root, rename_file, same_file, File, FilesOp, Url}, term::Term, Defer};
 
-use crate::{input::Input, manager::Manager};
+use crate::{input::Input, manager::{Manager, Transform}, tasks::Tasks};
 
 pub struct Opt {
-	force:  bool,
-	empty:  String,
-	cursor: String,
+	force:     bool,
+	empty:     String,
+	cursor:    String,
+	regex:     Option<Option<String>>,
+	transform: Option<String>,
+	undo:      bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
 		Self {
-			force:  c.named.contains_key("force"),
-			empty:  c.take_name("empty").unwrap_or_default(),
-			cursor: c.take_name("cursor").unwrap_or_default(),
+			force:     c.named.contains_key("force"),
+			empty:     c.take_name("empty").unwrap_or_default(),
+			cursor:    c.take_name("cursor").unwrap_or_default(),
+			regex:     c.named.contains_key("regex").then(|| c.take_first()),
+			transform: c.take_name("transform"),
+			undo:      c.named.contains_key("undo"),
 		}
 	}
 }
 
+// A sed-like `s/pattern/replacement/flags`, where the flags are `g` to replace
+// all the matches instead of the first one, and `i` to ignore the case.
+struct Substitution {
+	regex:       Regex,
+	replacement: String,
+	global:      bool,
+}
+
+impl Substitution {
+	fn parse(expr: &str) -> Result<Self> {
+		let mut chars = expr.chars();
+		let (Some('s'), Some(delim)) = (chars.next(), chars.next()) else {
+			bail!(tr!("Expected `s/pattern/replacement/`"));
+		};
+
+		// Split by the delimiter, unless it's escaped
+		let mut parts = vec![String::new()];
+		let mut escaped = false;
+		for c in chars {
+			match c {
+				_ if escaped => {
+					if c != delim {
+						parts.last_mut().unwrap().push('\\');
+					}
+					parts.last_mut().unwrap().push(c);
+					escaped = false;
+				}
+				'\\' => escaped = true,
+				_ if c == delim => parts.push(String::new()),
+				_ => parts.last_mut().unwrap().push(c),
+			}
+		}
+
+		let [pattern, replacement, flags] = &parts[..] else {
+			bail!(tr!("Expected `s/pattern/replacement/`"));
+		};
+		if let Some(c) = flags.chars().find(|c| !matches!(c, 'g' | 'i')) {
+			bail!(tr!("Unknown flag `{flag}`", flag = c));
+		}
+
+		// Take the `\1` of sed as the `${1}` of the regex crate
+		let replacement = Regex::new(r"\\(\d)").unwrap().replace_all(replacement, "$${$1}");
+		Ok(Self {
+			regex:       RegexBuilder::new(pattern).case_insensitive(flags.contains('i')).build()?,
+			replacement: replacement.into_owned(),
+			global:      flags.contains('g'),
+		})
+	}
+
+	fn apply(&self, name: &str) -> String {
+		let limit = if self.global { 0 } else { 1 };
+		self.regex.replacen(name, limit, &self.replacement).into_owned()
+	}
+}
+
 impl Manager {
 	fn empty_url_part(url: &Url, by: &str) -> String {
 		if by == "all" {
@@ -41,7 +103,7 @@ impl Manager {
 	}
 
 	async fn rename_and_hover(old: Url, new: Url) -> Result<()> {
-		fs::rename(&old, &new).await?;
+		rename_file(&old, &new).await?;
 		if old.parent() != new.parent() {
 			return Ok(());
 		}
@@ -53,6 +115,15 @@ impl Manager {
 	}
 
 	pub fn rename(&self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.undo {
+			return Tasks::_rename_undo();
+		} else if let Some(expr) = opt.regex {
+			return self.rename_regex(expr);
+		} else if let Some(transform) = opt.transform {
+			return self.rename_transform(&transform);
+		}
+
 		if !self.active().selected.is_empty() {
 			return self.bulk_rename();
 		}
@@ -61,7 +132,6 @@ impl Manager {
 			return;
 		};
 
-		let opt = opt.into() as Opt;
 		let name = Self::empty_url_part(&hovered, &opt.empty);
 		let cursor = match opt.cursor.as_str() {
 			"start" => Some(0),
@@ -75,13 +145,18 @@ impl Manager {
 		};
 
 		tokio::spawn(async move {
+			if !Tasks::guard(tr!("rename"), [&hovered]).await {
+				return;
+			}
+
 			let mut result = Input::_show(InputCfg::rename().with_value(name).with_cursor(cursor));
 			let Some(Ok(name)) = result.recv().await else {
 				return;
 			};
 
+			// A case-only rename on a case-insensitive filesystem finds the file itself
 			let new = hovered.parent().unwrap().join(name);
-			if opt.force || fs::symlink_metadata(&new).await.is_err() {
+			if opt.force || fs::symlink_metadata(&new).await.is_err() || same_file(&hovered, &new).await {
 				Self::rename_and_hover(hovered, Url::from(new)).await.ok();
 				return;
 			}
@@ -97,14 +172,19 @@ impl Manager {
 
 	fn bulk_rename(&self) {
 		let old: Vec<_> = self.selected_or_hovered();
+		let targets: Vec<_> = old.iter().map(|&u| u.clone()).collect();
 
 		let root = max_common_root(&old);
 		let old: Vec<_> = old.into_iter().map(|p| p.strip_prefix(&root).unwrap().to_owned()).collect();
 
 		let tmp = PREVIEW.tmpfile("bulk");
 		tokio::spawn(async move {
+			// Only the read-only ones are refused before editing, the rest are guarded by the task
+			if Tasks::read_only(tr!("rename"), &targets) {
+				return Ok(());
+			}
 			let Some(opener) = OPEN.block_opener("bulk.txt", "text/plain") else {
-				bail!("No opener for bulk rename");
+				bail!(tr!("No opener for bulk rename"));
 			};
 
 			{
@@ -126,10 +206,11 @@ impl Manager {
 			Scheduler::app_stop().await;
 
 			let mut child = external::shell(ShellOpt {
-				cmd:    (*opener.exec).into(),
-				args:   vec![tmp.to_owned().into()],
-				piped:  false,
-				orphan: false,
+				cmd:     (*opener.exec).into(),
+				args:    vec![tmp.to_owned().into()],
+				piped:   false,
+				orphan:  false,
+				capture: false,
 			})?;
 			child.wait().await?;
 
@@ -138,60 +219,156 @@ impl Manager {
 		});
 	}
 
-	async fn bulk_rename_do(root: PathBuf, old: Vec<PathBuf>, new: Vec<PathBuf>) -> Result<()> {
-		Term::clear(&mut stdout())?;
-		if old.len() != new.len() {
-			println!("Number of old and new differ, press ENTER to exit");
-			stdin().read_exact(&mut [0]).await?;
-			return Ok(());
+	fn rename_regex(&self, expr: Option<String>) {
+		let old: Vec<_> = self.selected_or_hovered().into_iter().cloned().collect();
+		if old.is_empty() {
+			return;
 		}
 
-		let todo: Vec<_> = old.into_iter().zip(new).filter(|(o, n)| o != n).collect();
-		if todo.is_empty() {
+		tokio::spawn(async move {
+			let expr = match expr {
+				Some(expr) => expr,
+				None => match Input::_show(InputCfg::rename_regex()).recv().await {
+					Some(Ok(expr)) => expr,
+					_ => return Ok(()),
+				},
+			};
+
+			match Substitution::parse(&expr) {
+				Ok(sub) => Self::rename_batch(old, |s| sub.apply(s)).await,
+				Err(e) => {
+					let content = tr!("Invalid substitution `{expr}`: {error}", expr = expr, error = e);
+					Ok(emit!(Notify(tr!("Rename"), content, "error", 5)))
+				}
+			}
+		});
+	}
+
+	fn rename_transform(&self, transform: &str) {
+		let transform = match transform.parse::<Transform>() {
+			Ok(t) => t,
+			Err(e) => return emit!(Notify(tr!("Rename"), e, "error", 5)),
+		};
+
+		let old: Vec<_> = self.selected_or_hovered().into_iter().cloned().collect();
+		if !old.is_empty() {
+			tokio::spawn(Self::rename_batch(old, move |s| transform.apply(s)));
+		}
+	}
+
+	// Renames each of the files to what `f` makes of its name, after a preview
+	async fn rename_batch(old: Vec<Url>, f: impl Fn(&str) -> String) -> Result<()> {
+		let todo: Vec<_> = old
+			.into_iter()
+			.filter_map(|u| {
+				let name = u.file_name()?.to_str()?;
+				let new = f(name);
+				let changed = new != name && !new.is_empty();
+				changed.then(|| {
+					let new = Url::from(u.parent().unwrap().join(new));
+					(u, new)
+				})
+			})
+			.collect();
+		if todo.is_empty() || Tasks::read_only(tr!("rename"), todo.iter().map(|(o, _)| o)) {
 			return Ok(());
 		}
 
+		let _guard = BLOCKER.acquire().await.unwrap();
+		let _defer = Defer::new(Scheduler::app_resume);
+		Scheduler::app_stop().await;
+
+		let todo = Self::rename_batch_preview(todo).await?;
+		if !todo.is_empty() {
+			Tasks::_rename(todo);
+		}
+		Ok(())
+	}
+
+	// Shows the old and new names side by side, with the conflicting ones marked,
+	// which are left out, and returns the renames to do once confirmed. A name taken
+	// by another file of the batch isn't a conflict, since that one is moved away first.
+	async fn rename_batch_preview(todo: Vec<(Url, Url)>) -> Result<Vec<(Url, Url)>> {
+		let sources: HashSet<_> = todo.iter().map(|(o, _)| o.clone()).collect();
+		let mut seen = HashSet::new();
+		let mut conflicts = vec![];
+		for (o, n) in &todo {
+			let dup = !seen.insert(n.clone());
+			conflicts.push(if dup {
+				Some(tr!("duplicate"))
+			} else if !sources.contains(n)
+				&& fs::symlink_metadata(n).await.is_ok()
+				&& !same_file(o, n).await
+			{
+				Some(tr!("already exists"))
+			} else {
+				None
+			});
+		}
+
+		let name = |u: &Url| u.file_name().unwrap().to_string_lossy().into_owned();
+		let width = todo.iter().map(|(o, _)| name(o).chars().count()).max().unwrap_or(0);
+
+		Term::clear(&mut stdout())?;
 		{
 			let mut stdout = BufWriter::new(stdout().lock());
-			for (o, n) in &todo {
-				writeln!(stdout, "{} -> {}", o.display(), n.display())?;
+			for ((o, n), conflict) in todo.iter().zip(&conflicts) {
+				let old = format!("{:width$}", name(o));
+				match conflict {
+					Some(reason) => writeln!(stdout, "\x1b[31m{old}  ->  {} ({reason})\x1b[0m", name(n))?,
+					None => writeln!(stdout, "{old}  ->  {}", name(n))?,
+				}
+			}
+
+			let n = conflicts.iter().filter(|c| c.is_none()).count();
+			if n == 0 {
+				write!(stdout, "\n{}", tr!("Nothing to rename, press ENTER to exit"))?;
+			} else {
+				write!(stdout, "\n{}", tr!("Continue to rename {n} file{s}? (y/N): ", n = n))?;
 			}
-			write!(stdout, "Continue to rename? (y/N): ")?;
 			stdout.flush()?;
 		}
 
 		let mut buf = [0; 10];
 		_ = stdin().read(&mut buf).await?;
 		if buf[0] != b'y' && buf[0] != b'Y' {
-			return Ok(());
+			return Ok(vec![]);
 		}
 
-		let mut failed = vec![];
-		for (o, n) in todo {
-			if fs::symlink_metadata(&n).await.is_ok() {
-				failed.push((o, n, anyhow!("Destination already exists")));
-				continue;
-			}
-			if let Err(e) = fs::rename(root.join(&o), root.join(&n)).await {
-				failed.push((o, n, e.into()));
-			}
+		Ok(todo.into_iter().zip(conflicts).filter(|(_, c)| c.is_none()).map(|(t, _)| t).collect())
+	}
+
+	async fn bulk_rename_do(root: PathBuf, old: Vec<PathBuf>, new: Vec<PathBuf>) -> Result<()> {
+		Term::clear(&mut stdout())?;
+		if old.len() != new.len() {
+			println!("{}", tr!("Number of old and new differ, press ENTER to exit"));
+			stdin().read_exact(&mut [0]).await?;
+			return Ok(());
 		}
-		if failed.is_empty() {
+
+		let todo: Vec<_> = old.into_iter().zip(new).filter(|(o, n)| o != n).collect();
+		if todo.is_empty() {
 			return Ok(());
 		}
 
-		Term::clear(&mut stdout())?;
 		{
 			let mut stdout = BufWriter::new(stdout().lock());
-			writeln!(stdout, "Failed to rename:")?;
-			for (o, n, e) in failed {
-				writeln!(stdout, "{} -> {}: {e}", o.display(), n.display())?;
+			for (o, n) in &todo {
+				writeln!(stdout, "{} -> {}", o.display(), n.display())?;
 			}
-			writeln!(stdout, "\nPress ENTER to exit")?;
+			write!(stdout, "{}", tr!("Continue to rename? (y/N): "))?;
 			stdout.flush()?;
 		}
 
-		stdin().read_exact(&mut [0]).await?;
+		let mut buf = [0; 10];
+		_ = stdin().read(&mut buf).await?;
+		if buf[0] != b'y' && buf[0] != b'Y' {
+			return Ok(());
+		}
+
+		// Done as a task, which orders the swaps and chains, and rolls back on failure
+		let todo = todo.into_iter().map(|(o, n)| (Url::from(root.join(o)), Url::from(root.join(n))));
+		Tasks::_rename(todo.collect());
 		Ok(())
 	}
 }
@@ -1,4 +1,4 @@
-use yazi_config::PLUGIN;
+use yazi_config::{LAYOUT, PLUGIN};
 use yazi_plugin::isolate;
 use yazi_shared::{event::Cmd, render, MIME_DIR};
 
@@ -11,12 +11,20 @@ pub struct Opt {
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
+		if c.named.contains_key("end") {
+			return Self { units: isolate::SEEK_END };
+		}
 		Self { units: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
 	}
 }
 
 impl Manager {
 	pub fn seek(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if let Some(stream) = &mut self.active_mut().preview.stream {
+			return render!(stream.seek(opt.units, LAYOUT.load().preview.height as usize));
+		}
+
 		let Some(hovered) = self.hovered() else {
 			return render!(self.active_mut().preview.reset());
 		};
@@ -29,11 +37,11 @@ impl Manager {
 			return render!(self.active_mut().preview.reset());
 		};
 
-		let Some(previewer) = PLUGIN.previewer(&hovered.url, mime) else {
+		let local = self.active().preview.local.as_ref().and_then(|l| l.previewer(&hovered.url, mime));
+		let Some(previewer) = local.or_else(|| PLUGIN.previewer(&hovered.url, mime)) else {
 			return render!(self.active_mut().preview.reset());
 		};
 
-		let opt = opt.into() as Opt;
 		isolate::seek_sync(&previewer.cmd, hovered.clone(), opt.units);
 	}
 }
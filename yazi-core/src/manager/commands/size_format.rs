@@ -0,0 +1,17 @@
+use std::sync::atomic::Ordering;
+
+use yazi_shared::{event::Cmd, fs::SIZE_RAW, render};
+
+use crate::manager::Manager;
+
+impl Manager {
+	/// Shows the sizes human-readable, or as the exact bytes, everywhere they're shown.
+	pub fn size_format(&mut self, c: Cmd) {
+		let raw = match c.args.first().map(|s| s.as_str()) {
+			Some("human") => false,
+			Some("raw") => true,
+			_ => !SIZE_RAW.load(Ordering::Relaxed),
+		};
+		render!(SIZE_RAW.swap(raw, Ordering::Relaxed) != raw);
+	}
+}
@@ -0,0 +1,12 @@
+use yazi_shared::{event::Cmd, MIME_DIR};
+
+use crate::{manager::Manager, spot::Spot};
+
+impl Manager {
+	pub fn spot(&mut self, _: Cmd) {
+		if let Some(h) = self.hovered() {
+			let mime = if h.is_dir() { Some(MIME_DIR) } else { self.mimetype.get(&h.url).map(|s| &**s) };
+			Spot::_show(h.url.clone(), mime);
+		}
+	}
+}
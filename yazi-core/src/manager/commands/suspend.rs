@@ -1,14 +1,73 @@
-use yazi_scheduler::Scheduler;
-use yazi_shared::event::Cmd;
+use std::{ffi::OsString, process::Stdio};
+
+use tokio::process::Command;
+use yazi_config::tr;
+use yazi_scheduler::{Scheduler, BLOCKER};
+use yazi_shared::{emit, event::Cmd, Defer};
 
 use crate::manager::Manager;
 
+pub struct Opt {
+	shell: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { shell: c.named.contains_key("shell") } }
+}
+
 impl Manager {
-	pub fn suspend(&mut self, _: Cmd) {
+	pub fn suspend(&mut self, opt: impl Into<Opt>) {
+		if opt.into().shell {
+			return self.suspend_shell();
+		}
+
 		#[cfg(unix)]
 		tokio::spawn(async move {
 			Scheduler::app_stop().await;
 			unsafe { libc::raise(libc::SIGTSTP) };
 		});
 	}
+
+	fn suspend_shell(&self) {
+		let cwd = self.cwd().clone();
+		let hover = self.hovered().map(|f| f.url.as_os_str().to_owned()).unwrap_or_default();
+		let selection = self.active().selected.iter().fold(OsString::new(), |mut s, u| {
+			if !s.is_empty() {
+				s.push("\n");
+			}
+			s.push(u.as_os_str());
+			s
+		});
+
+		let shell = if cfg!(windows) {
+			std::env::var_os("COMSPEC").unwrap_or_else(|| "cmd".into())
+		} else {
+			std::env::var_os("SHELL").filter(|s| !s.is_empty()).unwrap_or_else(|| "sh".into())
+		};
+
+		tokio::spawn(async move {
+			let _guard = BLOCKER.acquire().await.unwrap();
+			let _defer = Defer::new(Scheduler::app_resume);
+			Scheduler::app_stop().await;
+
+			let result = Command::new(&shell)
+				.current_dir(&cwd)
+				.env("YAZI_HOVER", hover)
+				.env("YAZI_SELECTION", selection)
+				.stdin(Stdio::inherit())
+				.stdout(Stdio::inherit())
+				.stderr(Stdio::inherit())
+				.kill_on_drop(true)
+				.spawn();
+
+			match result {
+				Ok(mut child) => _ = child.wait().await,
+				Err(e) => {
+					let content =
+						tr!("Failed to spawn `{shell}`: {error}", shell = shell.display(), error = e);
+					emit!(Notify(tr!("Suspend"), content, "error", 5));
+				}
+			}
+		});
+	}
 }
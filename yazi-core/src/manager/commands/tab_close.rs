@@ -3,22 +3,29 @@ use yazi_shared::{event::Cmd, render};
 use crate::manager::Tabs;
 
 pub struct Opt {
-	idx: usize,
+	idx:    usize,
+	others: bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		Self { idx: c.take_first().and_then(|i| i.parse().ok()).unwrap_or(0) }
+		Self {
+			idx:    c.take_first().and_then(|i| i.parse().ok()).unwrap_or(0),
+			others: c.named.contains_key("all-but-current"),
+		}
 	}
 }
 
 impl From<usize> for Opt {
-	fn from(idx: usize) -> Self { Self { idx } }
+	fn from(idx: usize) -> Self { Self { idx, others: false } }
 }
 
 impl Tabs {
 	pub fn close(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
+		if opt.others {
+			return self.close_others();
+		}
 
 		let len = self.items.len();
 		if len < 2 || opt.idx >= len {
@@ -32,4 +39,20 @@ impl Tabs {
 
 		render!();
 	}
+
+	// Closes all the tabs except the active one and the pinned ones.
+	fn close_others(&mut self) {
+		let (mut i, mut idx) = (0, 0);
+		self.items.retain(|tab| {
+			let keep = i == self.idx || tab.pinned;
+			if keep && i < self.idx {
+				idx += 1;
+			}
+			i += 1;
+			keep
+		});
+
+		self.idx = idx;
+		render!();
+	}
 }
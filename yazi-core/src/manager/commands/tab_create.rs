@@ -1,19 +1,24 @@
 use yazi_shared::{event::Cmd, fs::Url, render};
 
-use crate::{manager::Tabs, tab::Tab};
-
-const MAX_TABS: usize = 9;
+use crate::{manager::{Tabs, MAX_TABS}, tab::Tab};
 
 pub struct Opt {
-	url:     Option<Url>,
-	current: bool,
+	url:        Option<Url>,
+	current:    bool,
+	duplicate:  bool,
+	background: bool,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		let mut opt = Self { url: None, current: c.named.contains_key("current") };
-
-		if !opt.current {
+		let mut opt = Self {
+			url:        None,
+			current:    c.named.contains_key("current"),
+			duplicate:  c.named.contains_key("duplicate"),
+			background: c.named.contains_key("background"),
+		};
+
+		if !opt.current && !opt.duplicate {
 			opt.url = Some(c.take_first().map_or_else(|| Url::from("."), Url::from));
 		}
 		opt
@@ -27,14 +32,20 @@ impl Tabs {
 		}
 
 		let opt = opt.into() as Opt;
-		let url = if opt.current { self.active().current.cwd.to_owned() } else { opt.url.unwrap() };
-
-		let mut tab = Tab::from(url);
-		tab.conf = self.active().conf.clone();
-		tab.apply_files_attrs();
+		let tab = if opt.duplicate {
+			self.active().duplicate()
+		} else {
+			let url = if opt.current { self.active().current.cwd.to_owned() } else { opt.url.unwrap() };
+			let mut tab = Tab::from(url);
+			tab.conf = self.active().conf.clone();
+			tab.apply_files_attrs();
+			tab
+		};
 
 		self.items.insert(self.idx + 1, tab);
-		self.set_idx(self.idx + 1);
+		if !opt.background {
+			self.set_idx(self.idx + 1);
+		}
 		render!();
 	}
 }
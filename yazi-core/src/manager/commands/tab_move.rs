@@ -0,0 +1,29 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Tabs;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Tabs {
+	/// Moves the active tab by `step` positions, shifting the tabs in between,
+	/// while [`Tabs::swap`] exchanges it with the one at the destination.
+	pub fn move_(&mut self, opt: impl Into<Opt>) {
+		let idx = self.absolute(opt.into().step);
+		if idx == self.idx {
+			return;
+		}
+
+		let tab = self.items.remove(self.idx);
+		self.items.insert(idx, tab);
+		self.idx = idx;
+		render!();
+	}
+}
@@ -0,0 +1,29 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Tabs;
+
+pub struct Opt {
+	state: Option<bool>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self {
+			state: match c.take_first().as_deref() {
+				Some("on") => Some(true),
+				Some("off") => Some(false),
+				_ => None,
+			},
+		}
+	}
+}
+
+impl Tabs {
+	pub fn pin(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+
+		let tab = self.active_mut();
+		tab.pinned = opt.state.unwrap_or(!tab.pinned);
+		render!();
+	}
+}
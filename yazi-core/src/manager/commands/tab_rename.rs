@@ -0,0 +1,32 @@
+use yazi_config::popup::InputCfg;
+use yazi_shared::{emit, event::Cmd, render, Layer};
+
+use crate::{input::Input, manager::Tabs};
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first() } }
+}
+
+impl Tabs {
+	pub fn rename(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+
+		// An empty name gives the tab back its default title
+		if let Some(name) = opt.name {
+			self.active_mut().label = Some(name).filter(|s| !s.is_empty());
+			return render!();
+		}
+
+		let value = self.active().label.clone().unwrap_or_default();
+		tokio::spawn(async move {
+			let mut result = Input::_show(InputCfg::tab_rename().with_value(value));
+			if let Some(Ok(name)) = result.recv().await {
+				emit!(Call(Cmd::args("tab_rename", vec![name]), Layer::Manager));
+			}
+		});
+	}
+}
@@ -0,0 +1,16 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Tabs;
+
+impl Tabs {
+	/// Applies the view settings of the active tab, i.e. sorting, linemode and
+	/// the visibility of hidden files, to all the other tabs.
+	pub fn sync(&mut self, _: Cmd) {
+		let conf = self.active().conf.clone();
+		for tab in self.items.iter_mut().filter(|t| t.conf != conf) {
+			tab.conf = conf.clone();
+			tab.apply_files_attrs();
+		}
+		render!();
+	}
+}
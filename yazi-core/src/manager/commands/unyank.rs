@@ -6,6 +6,6 @@ impl Manager {
 	pub fn unyank(&mut self, _: Cmd) {
 		render!(!self.yanked.is_empty());
 
-		self.yanked = Default::default();
+		self.yanked.set(false, Default::default());
 	}
 }
@@ -0,0 +1,33 @@
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	cwd:  Url,
+	name: String,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_data().ok_or(()) }
+}
+
+impl Manager {
+	#[inline]
+	pub(crate) fn _update_branch(cwd: Url, name: String) {
+		emit!(Call(Cmd::new("update_branch").with_data(Opt { cwd, name }), Layer::Manager));
+	}
+
+	pub fn update_branch(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		// Of the tabs still in the directory it was read for
+		for tab in self.tabs.items.iter_mut().filter(|t| t.branch.of.as_ref() == Some(&opt.cwd)) {
+			tab.branch.name = Some(opt.name.clone());
+			render!();
+		}
+	}
+}
@@ -0,0 +1,38 @@
+use yazi_plugin::ValueSendable;
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	name: String,
+	data: ValueSendable,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { name: c.take_first().ok_or(())?, data: c.take_data().ok_or(())? })
+	}
+}
+
+impl Manager {
+	/// Sets a field of the files for the linemode formats, as fetched by a preloader with e.g.
+	/// `ya.manager_emit("update_fields", { "git" }, { [tostring(url)] = "M" })`.
+	pub fn update_fields(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		let linked = self.watcher.linked.read();
+		let values = self.fields.entry(opt.name).or_default();
+		for (url, value) in opt.data.into_table_string() {
+			let url = Url::from(url);
+			for u in linked.from_file(&url) {
+				values.insert(u, value.clone());
+			}
+			values.insert(url, value);
+		}
+		render!();
+	}
+}
@@ -97,6 +97,8 @@ impl Manager {
 			return;
 		};
 
+		self.track_yanked(&opt.op);
+
 		let mut ops = vec![opt.op];
 		for u in self.watcher.linked.read().from_dir(ops[0].url()) {
 			ops.push(ops[0].chroot(u));
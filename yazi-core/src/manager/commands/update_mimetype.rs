@@ -51,6 +51,10 @@ impl Manager {
 			.cloned()
 			.collect();
 
+		for f in &affected {
+			self.mime_cache.put(f, &updates[&f.url]);
+		}
+
 		self.mimetype.extend(updates);
 		self.peek(false);
 
@@ -44,7 +44,24 @@ impl Manager {
 			return;
 		}
 
-		let targets = self.current().paginate(opt.page.unwrap_or(self.current().page));
+		let current = &self.tabs.active().current;
+		let targets = current.paginate(opt.page.unwrap_or(current.page));
+		let mut cached = false;
+		for f in targets {
+			if f.is_dir() || self.mimetype.contains_key(&f.url) {
+				continue;
+			}
+			if let Some(mime) = self.mime_cache.get(f) {
+				self.mimetype.insert(f.url.clone(), mime.clone());
+				cached = true;
+			}
+		}
+
 		tasks.preload_paged(targets, &self.mimetype);
+
+		// No `update_mimetype` will follow for the cached ones, so peek here instead
+		if cached {
+			self.peek(false);
+		}
 	}
 }
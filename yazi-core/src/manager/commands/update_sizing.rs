@@ -0,0 +1,35 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	total:  u64,
+	ticket: usize,
+	done:   bool,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			total:  c.take_first().and_then(|s| s.parse().ok()).ok_or(())?,
+			ticket: c.take_name("ticket").and_then(|s| s.parse().ok()).ok_or(())?,
+			done:   c.named.contains_key("done"),
+		})
+	}
+}
+
+impl Manager {
+	pub fn update_sizing(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		// Of the tab the selection was sized for, unless it has changed since
+		if let Some(tab) = self.tabs.items.iter_mut().find(|t| t.sizing.ticket == opt.ticket) {
+			(tab.sizing.total, tab.sizing.done) = (Some(opt.total), opt.done);
+			render!();
+		}
+	}
+}
@@ -0,0 +1,58 @@
+use yazi_config::tr;
+use yazi_shared::{emit, event::Cmd, fs::{FilesOp, Url}, render, Layer};
+
+use crate::manager::Manager;
+
+pub struct Opt {
+	from: Url,
+	to:   Url,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		let (from, to) = c.take_data().ok_or(())?;
+		Ok(Self { from, to })
+	}
+}
+
+impl Manager {
+	#[inline]
+	pub fn _update_yanked(from: Url, to: Url) {
+		emit!(Call(Cmd::new("update_yanked").with_data((from, to)), Layer::Manager));
+	}
+
+	pub fn update_yanked(&mut self, opt: impl TryInto<Opt>) {
+		if let Ok(opt) = opt.try_into() {
+			render!(self.yanked.rename(&opt.from, &opt.to));
+		}
+	}
+
+	// Follows the yanked files to where they're renamed, and leaves out those deleted,
+	// for a paste not to fail on them later.
+	pub(super) fn track_yanked(&mut self, op: &FilesOp) {
+		match op {
+			FilesOp::Upserting(_, files) => {
+				for (from, file) in files.iter().filter(|(from, file)| **from != file.url) {
+					render!(self.yanked.rename(from, &file.url));
+				}
+			}
+			FilesOp::Deleting(_, urls) => {
+				let n = self.yanked.forget(urls);
+				if n == 0 {
+					return;
+				}
+
+				let content = if self.yanked.is_all_empty() {
+					tr!("The yanked files are deleted, there's nothing left to paste").to_owned()
+				} else {
+					tr!("{n} of the yanked files are deleted, and left out of the paste", n = n)
+				};
+				emit!(Notify(tr!("Yank"), content, "warn", 5));
+				render!();
+			}
+			_ => {}
+		}
+	}
+}
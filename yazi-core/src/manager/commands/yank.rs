@@ -2,25 +2,40 @@ use std::collections::HashSet;
 
 use yazi_shared::{event::Cmd, render};
 
-use crate::manager::{Manager, Yanked};
+use crate::manager::Manager;
 
 pub struct Opt {
-	cut: bool,
+	cut:      bool,
+	register: Option<char>,
 }
 
-impl From<Cmd> for Opt {
-	fn from(c: Cmd) -> Self { Self { cut: c.named.contains_key("cut") } }
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self {
+			cut:      c.named.contains_key("cut"),
+			register: Manager::take_register(&mut c)?,
+		})
+	}
 }
 
 impl Manager {
-	pub fn yank(&mut self, opt: impl Into<Opt>) {
+	pub fn yank(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
 		let selected: HashSet<_> = self.selected_or_hovered().into_iter().cloned().collect();
 		if selected.is_empty() {
 			return;
 		}
-
-		self.yanked = Yanked { cut: opt.into().cut, urls: selected };
+		match opt.register {
+			Some(name) => self.yanked.set_named(name, opt.cut, selected),
+			None => self.yanked.set(opt.cut, selected),
+		}
 		self.active_mut().escape_select();
+		Self::_hover(None); // For the watcher to pick up the yanked files
 		render!();
 	}
 }
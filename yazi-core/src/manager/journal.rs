@@ -0,0 +1,136 @@
+use std::{fs, io, path::PathBuf, sync::Mutex, time::SystemTime};
+
+use serde::{Deserialize, Serialize};
+use yazi_config::{popup::SelectCfg, tr, BOOT};
+use yazi_shared::{emit, event::Cmd, pid_alive, Layer};
+
+use super::Manager;
+use crate::select::Select;
+
+/// The tabs as they are, journaled while the app runs, and removed at a clean exit,
+/// for them to be restored if it's left over by a crash.
+///
+/// Each instance has its own, named after its pid, and only the one of an instance
+/// no longer running is offered, to the first of the instances started after it.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct Journal {
+	pub tabs: Vec<JournalTab>,
+	pub idx:  usize,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct JournalTab {
+	pub cwd:     PathBuf,
+	pub hovered: Option<PathBuf>,
+	pub label:   Option<String>,
+}
+
+// Whether it's cleared, for a write still going on at the exit not to bring it back
+static CLEARED: Mutex<bool> = Mutex::new(false);
+
+impl Journal {
+	pub fn write(&self) -> io::Result<()> {
+		let json = serde_json::to_string(self)?;
+
+		let cleared = CLEARED.lock().unwrap();
+		if *cleared {
+			return Ok(());
+		}
+
+		// Written to a temporary file first, for a crash in between not to leave half of it
+		let tmp = Self::path(std::process::id()).with_extension("tmp");
+		fs::write(&tmp, json)?;
+		fs::rename(tmp, Self::path(std::process::id()))
+	}
+
+	pub fn clear() {
+		let mut cleared = CLEARED.lock().unwrap();
+		*cleared = true;
+		fs::remove_file(Self::path(std::process::id())).ok();
+	}
+
+	/// Offers to restore the tabs journaled by the last run of an instance that didn't exit
+	/// cleanly, which is forgotten either way, not to be asked again.
+	pub fn offer() {
+		let Some(s) = Self::claim() else {
+			return;
+		};
+
+		let Ok(journal) = serde_json::from_str::<Self>(&s) else {
+			return;
+		};
+		if journal.tabs.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let items = vec![
+				tr!("Restore {n} tab{s}", n = journal.tabs.len()),
+				tr!("Start afresh").to_owned(),
+			];
+			if Select::_show(SelectCfg::recover(items)).await.ok() == Some(0) {
+				journal.restore();
+			}
+		});
+	}
+
+	fn restore(self) {
+		for (i, tab) in self.tabs.into_iter().enumerate() {
+			let cwd = tab.cwd.to_string_lossy().into_owned();
+			if i > 0 {
+				emit!(Call(Cmd::args("tab_create", vec![cwd.clone()]), Layer::Manager));
+			}
+
+			let cmd = match tab.hovered {
+				Some(h) => Cmd::args("reveal", vec![h.to_string_lossy().into_owned()]),
+				None => Cmd::args("cd", vec![cwd]),
+			};
+			emit!(Call(cmd, Layer::Manager));
+			if let Some(label) = tab.label {
+				emit!(Call(Cmd::args("tab_rename", vec![label]), Layer::Manager));
+			}
+		}
+		emit!(Call(Cmd::args("tab_switch", vec![self.idx.to_string()]), Layer::Manager));
+	}
+
+	// Takes the latest journal of the instances no longer running, by renaming it to one of
+	// our own, which only one of the instances started at once can do.
+	fn claim() -> Option<String> {
+		let latest = fs::read_dir(&BOOT.state_dir)
+			.ok()?
+			.flatten()
+			.filter_map(|e| {
+				let pid = e.file_name().to_str()?.strip_prefix("journal-")?.parse::<u32>().ok()?;
+				let mtime = e.metadata().and_then(|m| m.modified()).unwrap_or(SystemTime::UNIX_EPOCH);
+				(pid != std::process::id() && !pid_alive(pid)).then_some((mtime, e.path()))
+			})
+			.max_by_key(|(mtime, _)| *mtime)?;
+
+		let claimed = Self::path(std::process::id()).with_extension("claimed");
+		fs::rename(latest.1, &claimed).ok()?;
+
+		let s = fs::read_to_string(&claimed).ok();
+		fs::remove_file(claimed).ok();
+		s
+	}
+
+	#[inline]
+	fn path(pid: u32) -> PathBuf { BOOT.state_dir.join(format!("journal-{pid}")) }
+}
+
+impl Manager {
+	pub fn journal(&self) -> Journal {
+		Journal {
+			tabs: self
+				.tabs
+				.iter()
+				.map(|tab| JournalTab {
+					cwd:     tab.current.cwd.to_path_buf(),
+					hovered: tab.current.hovered().map(|f| f.url.to_path_buf()),
+					label:   tab.label.clone(),
+				})
+				.collect(),
+			idx:  self.tabs.idx,
+		}
+	}
+}
@@ -0,0 +1,70 @@
+use std::{collections::HashMap, path::{Path, PathBuf}};
+
+use parking_lot::Mutex;
+use tokio::{fs, sync::{Mutex as AsyncMutex, OnceCell}};
+use yazi_config::{open::Opener, BOOT, OPEN};
+
+// The extensions and the `exec` of their openers, loaded on startup
+static LEARNED: OnceCell<Mutex<HashMap<String, String>>> = OnceCell::const_new();
+
+// Held over each write, for the last one remembered to be the last one written
+static WRITING: AsyncMutex<()> = AsyncMutex::const_new(());
+
+/// The openers chosen in the picker to always be used for an extension,
+/// which are kept in the state, and take precedence over the rules of the config.
+pub struct Learned;
+
+impl Learned {
+	/// Loads the learned openers in the background, on startup.
+	#[inline]
+	pub fn preload() {
+		tokio::spawn(Self::map());
+	}
+
+	/// The lowercase extension the files share, if any.
+	pub fn ext<'a>(mut paths: impl Iterator<Item = &'a Path>) -> Option<String> {
+		let ext = Self::ext_of(paths.next()?)?;
+		paths.all(|p| Self::ext_of(p).as_ref() == Some(&ext)).then_some(ext)
+	}
+
+	/// The opener learned for the extension of the file, none until they're loaded.
+	pub fn get(path: &Path) -> Option<&'static Opener> {
+		let ext = Self::ext_of(path)?;
+		let learned = LEARNED.get()?.lock();
+		OPEN.by_exec(learned.get(&ext)?)
+	}
+
+	pub async fn remember(ext: &str, opener: &Opener) {
+		let _guard = WRITING.lock().await;
+		let s = {
+			let mut map = Self::map().await.lock();
+			if map.get(ext) == Some(&opener.exec) {
+				return;
+			}
+			map.insert(ext.to_owned(), opener.exec.clone());
+			serde_json::to_string(&*map)
+		};
+		if let Ok(s) = s {
+			fs::write(Self::path(), s).await.ok();
+		}
+	}
+
+	// As a JSON object, for an `exec` of several lines to be kept as it is
+	async fn map() -> &'static Mutex<HashMap<String, String>> {
+		LEARNED
+			.get_or_init(|| async {
+				let b = fs::read(Self::path()).await.unwrap_or_default();
+				Mutex::new(serde_json::from_slice(&b).unwrap_or_default())
+			})
+			.await
+	}
+
+	#[inline]
+	fn ext_of(path: &Path) -> Option<String> {
+		let ext = path.extension()?.to_str()?;
+		Some(ext.to_lowercase())
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("openers") }
+}
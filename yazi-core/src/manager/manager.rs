@@ -1,16 +1,22 @@
 use std::collections::HashMap;
 
+use yazi_config::manager::ManagerRatio;
 use yazi_shared::fs::{File, Url};
 
-use super::{Tabs, Watcher, Yanked};
+use super::{MimeCache, Tabs, Watcher, Yanked};
 use crate::{folder::Folder, tab::Tab};
 
 pub struct Manager {
 	pub tabs:   Tabs,
 	pub yanked: Yanked,
+	pub ratio:  ManagerRatio,
 
-	pub(super) watcher: Watcher,
-	pub mimetype:       HashMap<Url, String>,
+	pub(super) watcher:     Watcher,
+	pub(super) ratio_saved: Option<ManagerRatio>,
+	pub mimetype:           HashMap<Url, String>,
+	pub mime_cache:         MimeCache,
+	// The fields fetched by the preloaders for the linemode formats, by their names
+	pub fields:             HashMap<String, HashMap<Url, String>>,
 }
 
 impl Manager {
@@ -18,9 +24,13 @@ impl Manager {
 		Self {
 			tabs:   Tabs::make(),
 			yanked: Default::default(),
+			ratio:  Self::saved_ratio(),
 
-			watcher:  Watcher::start(),
-			mimetype: Default::default(),
+			watcher:     Watcher::start(),
+			ratio_saved: None,
+			mimetype:    Default::default(),
+			mime_cache:  MimeCache::load(),
+			fields:      Default::default(),
 		}
 	}
 }
@@ -0,0 +1,156 @@
+use std::{fs, num::NonZeroUsize, path::PathBuf, time::{Duration, Instant, UNIX_EPOCH}};
+
+use lru::LruCache;
+use tokio::sync::oneshot;
+use yazi_config::BOOT;
+use yazi_shared::fs::File;
+
+use crate::Snapshot;
+
+const CAPACITY: usize = 100_000;
+
+// Saved at most this often as it changes, besides on quit, for a crash not to lose it all
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+static SAVED: Snapshot = Snapshot::new();
+
+/// Mimetypes detected in the previous sessions, keyed by the identity and
+/// the state of the files, so that the unchanged ones don't get detected again.
+pub struct MimeCache {
+	lru:      LruCache<String, String>,
+	dirty:    bool,
+	saved_at: Instant,
+	// The one of the previous sessions, being read in the background
+	loading:  Option<oneshot::Receiver<LruCache<String, String>>>,
+}
+
+impl MimeCache {
+	pub(super) fn load() -> Self {
+		let (tx, rx) = oneshot::channel();
+		tokio::task::spawn_blocking(move || {
+			let mut lru = LruCache::new(NonZeroUsize::new(CAPACITY).unwrap());
+			for line in fs::read_to_string(Self::path()).unwrap_or_default().lines() {
+				if let Some((key, mime)) = line.rsplit_once(' ') {
+					lru.put(key.to_owned(), mime.to_owned());
+				}
+			}
+			tx.send(lru).ok();
+		});
+
+		Self {
+			lru:      LruCache::new(NonZeroUsize::new(CAPACITY).unwrap()),
+			dirty:    false,
+			saved_at: Instant::now(),
+			loading:  Some(rx),
+		}
+	}
+
+	/// Writes it on quit, unless it's still being read, as what's read would be lost otherwise.
+	pub fn save(&mut self) {
+		self.sync_loaded();
+		if !self.dirty || self.loading.is_some() {
+			return;
+		}
+
+		// Through a temporary file, for a periodic save still going not to tear it
+		let (path, tmp) = (Self::path(), Self::path().with_extension("cache.tmp"));
+		self.dirty = fs::write(&tmp, self.serialize()).and_then(|_| fs::rename(tmp, path)).is_err();
+	}
+
+	pub fn get(&mut self, file: &File) -> Option<&String> {
+		self.sync_loaded();
+		self.lru.get(&Self::key(file)?)
+	}
+
+	pub fn put(&mut self, file: &File, mime: &str) {
+		let Some(key) = Self::key(file) else {
+			return;
+		};
+
+		self.sync_loaded();
+		if self.lru.get(&key).map(|s| s.as_str()) != Some(mime) {
+			self.lru.put(key, mime.to_owned());
+			self.dirty = true;
+		}
+
+		if self.dirty && self.loading.is_none() && self.saved_at.elapsed() >= SAVE_INTERVAL {
+			SAVED.save(Self::path(), self.serialize());
+			(self.dirty, self.saved_at) = (false, Instant::now());
+		}
+	}
+
+	// Takes in the one read once it's done, under the ones detected since, as they're newer
+	fn sync_loaded(&mut self) {
+		let Some(rx) = self.loading.as_mut() else {
+			return;
+		};
+
+		match rx.try_recv() {
+			Ok(mut loaded) => {
+				for (key, mime) in self.lru.iter().rev() {
+					loaded.put(key.clone(), mime.clone());
+				}
+				self.lru = loaded;
+				self.loading = None;
+			}
+			Err(oneshot::error::TryRecvError::Empty) => {}
+			Err(oneshot::error::TryRecvError::Closed) => self.loading = None,
+		}
+	}
+
+	// From the least recently used, so that the order is restored on load
+	fn serialize(&self) -> String {
+		let mut s = String::with_capacity(self.lru.len() * 64);
+		for (key, mime) in self.lru.iter().rev() {
+			s.push_str(key);
+			s.push(' ');
+			s.push_str(mime);
+			s.push('\n');
+		}
+		s
+	}
+
+	fn key(file: &File) -> Option<String> {
+		let mtime = file.modified?.duration_since(UNIX_EPOCH).ok()?.as_nanos();
+
+		#[cfg(unix)]
+		return Some(format!("{}:{}:{}:{}", file.dev, file.ino, mtime, file.len));
+
+		#[cfg(windows)]
+		return Some(format!("{}:{}:{}", file.url.display(), mtime, file.len));
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("mime.cache") }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_sync_loaded() {
+		let lru = |pairs: &[(&str, &str)]| {
+			let mut lru = LruCache::new(NonZeroUsize::new(CAPACITY).unwrap());
+			pairs.iter().for_each(|&(k, v)| _ = lru.put(k.to_owned(), v.to_owned()));
+			lru
+		};
+
+		let (tx, rx) = oneshot::channel();
+		let mut cache = MimeCache {
+			lru:      lru(&[("b", "text/x-new")]),
+			dirty:    true,
+			saved_at: Instant::now(),
+			loading:  Some(rx),
+		};
+
+		cache.sync_loaded();
+		assert!(cache.loading.is_some());
+
+		// The ones detected since the start are newer than those read
+		tx.send(lru(&[("a", "image/png"), ("b", "text/plain")])).unwrap();
+		cache.sync_loaded();
+		assert!(cache.loading.is_none());
+		assert_eq!(cache.serialize(), "a image/png\nb text/x-new\n");
+	}
+}
@@ -1,12 +1,30 @@
 mod commands;
+mod journal;
+mod learned;
 mod linked;
 mod manager;
+mod mime_cache;
+mod recent;
+mod setup;
+mod state;
 mod tabs;
+mod transform;
+mod trusted;
 mod watcher;
 mod yanked;
 
+pub use journal::*;
+pub use learned::*;
 pub use linked::*;
 pub use manager::*;
+pub use mime_cache::*;
+pub use recent::*;
+pub use setup::*;
+pub use state::*;
 pub use tabs::*;
+pub use transform::*;
+pub use trusted::*;
 pub use watcher::*;
 pub use yanked::*;
+
+pub const MAX_TABS: usize = 9;
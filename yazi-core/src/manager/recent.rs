@@ -0,0 +1,166 @@
+use std::{cmp::Reverse, collections::HashSet, env, path::PathBuf, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use percent_encoding::percent_decode_str;
+use quick_xml::{events::Event, Reader};
+use serde::{Deserialize, Serialize};
+use tokio::{fs, sync::Mutex};
+use yazi_config::{BOOT, MANAGER};
+use yazi_shared::fs::{lossless, File, FilesOp, Url};
+
+const CAPACITY: usize = 1000;
+
+// Held over each read-modify-write of the file, for the tasks of them not to undo each other
+static LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Deserialize, Serialize)]
+struct Entry {
+	#[serde(with = "lossless")]
+	path: PathBuf,
+	at:   u64,
+}
+
+/// The files opened through yazi, newest first, listed in the `recent://` directory.
+pub struct Recent;
+
+impl Recent {
+	/// Records the files as opened just now.
+	pub fn push(paths: Vec<PathBuf>) {
+		if paths.is_empty() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let _guard = LOCK.lock().await;
+			let now = SystemTime::now();
+			let mut entries = Self::read().await;
+			entries.retain(|(p, _)| !paths.contains(p));
+			entries.splice(0..0, paths.into_iter().map(|p| (p, now)));
+			entries.truncate(CAPACITY);
+			Self::write(&entries).await;
+		});
+	}
+
+	/// Loads the files into the `recent://` directory, dropping those no longer existing.
+	pub fn _load(cwd: Url) {
+		tokio::spawn(async move {
+			let _guard = LOCK.lock().await;
+			let mut entries = Self::read().await;
+			let mut all = entries.clone();
+			if MANAGER.recent_xbel {
+				all.extend(Self::xbel().await);
+				all.sort_by_key(|&(_, t)| Reverse(t));
+			}
+
+			let (mut seen, mut files) = (HashSet::new(), Vec::with_capacity(all.len()));
+			for (path, _) in all {
+				if !seen.insert(path.clone()) {
+					continue;
+				}
+				match File::from(Url::from(path.clone())).await {
+					Ok(f) if !f.is_dir() => files.push(f),
+					_ => _ = seen.remove(&path),
+				}
+			}
+
+			let len = entries.len();
+			entries.retain(|(p, _)| seen.contains(p));
+			if entries.len() != len {
+				Self::write(&entries).await;
+			}
+			FilesOp::Full(cwd, files, None).emit();
+		});
+	}
+
+	async fn read() -> Vec<(PathBuf, SystemTime)> {
+		let b = fs::read(Self::path()).await.unwrap_or_default();
+		let entries: Vec<Entry> = serde_json::from_slice(&b).unwrap_or_default();
+		entries.into_iter().map(|e| (e.path, UNIX_EPOCH + Duration::from_secs(e.at))).collect()
+	}
+
+	// Written to a temporary file renamed over it, for another instance not to read a partial one
+	async fn write(entries: &[(PathBuf, SystemTime)]) {
+		let entries: Vec<_> = entries
+			.iter()
+			.filter_map(|(p, t)| {
+				Some(Entry { path: p.clone(), at: t.duration_since(UNIX_EPOCH).ok()?.as_secs() })
+			})
+			.collect();
+		let Ok(s) = serde_json::to_vec(&entries) else {
+			return;
+		};
+
+		let tmp = Self::path().with_extension(format!("{}.tmp", std::process::id()));
+		if fs::write(&tmp, s).await.is_err() || fs::rename(&tmp, Self::path()).await.is_err() {
+			fs::remove_file(&tmp).await.ok();
+		}
+	}
+
+	/// The files in the `recently-used.xbel` of the other apps, with the time each is visited.
+	async fn xbel() -> Vec<(PathBuf, SystemTime)> {
+		let Some(dir) = env::var_os("XDG_DATA_HOME")
+			.map(PathBuf::from)
+			.filter(|p| p.is_absolute())
+			.or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+		else {
+			return vec![];
+		};
+
+		let s = fs::read_to_string(dir.join("recently-used.xbel")).await.unwrap_or_default();
+		Self::parse_xbel(&s)
+	}
+
+	fn parse_xbel(s: &str) -> Vec<(PathBuf, SystemTime)> {
+		let mut reader = Reader::from_str(s);
+		let mut bookmarks = vec![];
+		loop {
+			let e = match reader.read_event() {
+				Ok(Event::Start(e) | Event::Empty(e)) if e.name().as_ref() == b"bookmark" => e,
+				Ok(Event::Eof) | Err(_) => break,
+				Ok(_) => continue,
+			};
+
+			let attr = |name: &str| {
+				let value = e.try_get_attribute(name).ok()??.unescape_value().ok()?;
+				Some(value.into_owned())
+			};
+			let bookmark = || {
+				let href = attr("href")?;
+				let path = percent_decode_str(href.strip_prefix("file://")?).collect();
+				let time = attr("visited").or_else(|| attr("modified"))?;
+				let time = chrono::DateTime::parse_from_rfc3339(&time).ok()?;
+				Some((lossless::path_from_bytes(path), time.into()))
+			};
+			bookmarks.extend(bookmark());
+		}
+		bookmarks
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("recent") }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_xbel() {
+		let s = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xbel version="1.0">
+	<bookmark href="file:///tmp/a%20%26%20b.txt" added="2024-01-01T00:00:00Z" visited="2024-01-02T00:00:00Z"/>
+	<bookmark href="file:///tmp/%FF.txt" modified="2024-01-03T00:00:00Z">
+		<info><metadata owner="http://freedesktop.org"/></info>
+	</bookmark>
+	<bookmark href="https://example.com" visited="2024-01-04T00:00:00Z"/>
+	<bookmark href="file:///tmp/&amp;.txt" visited="2024-01-05T00:00:00+08:00"></bookmark>
+</xbel>"#;
+
+		let time = |s| chrono::DateTime::parse_from_rfc3339(s).unwrap().into();
+		let bookmarks = Recent::parse_xbel(s);
+		assert_eq!(bookmarks, [
+			(PathBuf::from("/tmp/a & b.txt"), time("2024-01-02T00:00:00Z")),
+			(lossless::path_from_bytes(b"/tmp/\xff.txt".to_vec()), time("2024-01-03T00:00:00Z")),
+			(PathBuf::from("/tmp/&.txt"), time("2024-01-05T00:00:00+08:00")),
+		]);
+	}
+}
@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::{popup::SelectCfg, tr, BOOT};
+use yazi_shared::{emit, event::Cmd, in_path, Layer};
+
+use crate::select::Select;
+
+// The editors offered for text files, if installed, after `$EDITOR`
+const EDITORS: [&str; 7] = ["nvim", "vim", "hx", "nano", "micro", "emacs", "code"];
+
+const YAZI: &str = r#"# The settings override those of the preset, which are listed with their explanations at
+# https://github.com/sxyazi/yazi/blob/main/yazi-config/preset/yazi.toml
+
+[manager]
+# show_hidden = true
+# sort_by     = "natural"
+"#;
+
+const NO_IMAGES: &str = r#"# Images are previewed as files, remove these to preview them as images
+prepend_preloaders = [ { mime = "image/*", exec = "noop" } ]
+prepend_previewers = [ { mime = "image/*", exec = "file" } ]
+"#;
+
+const NO_IMAGES_COMMENTED: &str = r#"# Uncomment these to preview images as files
+# prepend_preloaders = [ { mime = "image/*", exec = "noop" } ]
+# prepend_previewers = [ { mime = "image/*", exec = "file" } ]
+"#;
+
+const KEYMAP: &str = r#"# The keybindings are added to those of the preset, listed at
+# https://github.com/sxyazi/yazi/blob/main/yazi-config/preset/keymap.toml
+
+[manager]
+"#;
+
+const PERMANENT: &str = r#"# `d` deletes permanently, and `D` moves to the trash
+prepend_keymap = [
+	{ on = [ "d" ], exec = [ "escape --visual", "remove --permanently" ], desc = "Permanently delete the files" },
+	{ on = [ "D" ], exec = [ "escape --visual", "remove" ],               desc = "Move the files to the trash" },
+]
+"#;
+
+const PERMANENT_COMMENTED: &str = r#"# Uncomment these for `d` to delete permanently, and `D` to move to the trash
+# prepend_keymap = [
+# 	{ on = [ "d" ], exec = [ "escape --visual", "remove --permanently" ], desc = "Permanently delete the files" },
+# 	{ on = [ "D" ], exec = [ "escape --visual", "remove" ],               desc = "Move the files to the trash" },
+# ]
+"#;
+
+const THEME: &str = r#"# The styles override those of the preset, listed at
+# https://github.com/sxyazi/yazi/blob/main/yazi-config/preset/theme.toml
+"#;
+
+const LIGHT: &str = r#"
+# A flavor for the terminals with a light background
+[manager]
+hovered      = { fg = "white", bg = "blue" }
+tab_active   = { fg = "white", bg = "black" }
+tab_inactive = { fg = "black", bg = "gray" }
+border_style = { fg = "darkgray" }
+
+[status]
+separator_style = { fg = "gray", bg = "gray" }
+progress_normal = { fg = "blue", bg = "gray" }
+progress_error  = { fg = "red", bg = "gray" }
+
+[which]
+mask = { bg = "white" }
+
+[help]
+hovered = { bg = "gray", bold = true }
+footer  = { fg = "white", bg = "black" }
+"#;
+
+const LIGHT_COMMENTED: &str = r#"
+# [manager]
+# hovered = { fg = "black", bg = "lightblue" }
+"#;
+
+/// The wizard of the first run, writing a starter config from a few questions.
+pub struct Setup;
+
+#[derive(Default)]
+struct Answers {
+	light:     bool,
+	no_images: bool,
+	permanent: bool,
+	editor:    Option<String>,
+}
+
+impl Setup {
+	/// Offers the wizard, if there's no config directory yet.
+	pub fn offer() {
+		if BOOT.config_dir.exists() {
+			return;
+		}
+
+		tokio::spawn(async move {
+			let dir = &BOOT.config_dir;
+			let Some(answers) = Self::ask().await else {
+				// Declined, so only the directory is created, for it not to be asked again
+				return fs::create_dir_all(dir).await.unwrap_or(());
+			};
+
+			let (level, content) = match Self::write(dir, &answers).await {
+				// Only the theme can be reloaded, the rest of the config is read once on startup
+				Ok(()) => ("info", tr!(
+					"A starter config is written to {dir}, restart yazi for all but the theme to take effect",
+					dir = dir.display()
+				)),
+				Err(e) => ("error", tr!("Failed to write the config: {error}", error = e)),
+			};
+			emit!(Notify(tr!("Setup"), content, level, 5));
+			emit!(Call(Cmd::new("theme"), Layer::Manager));
+		});
+	}
+
+	/// The answers to the questions, or `None` if the wizard is declined or canceled.
+	async fn ask() -> Option<Answers> {
+		let choose = |title: &'static str, items: &[&str]| {
+			let items = items.iter().map(|&s| tr!(s).to_owned()).collect();
+			Select::_show(SelectCfg::setup(title, items))
+		};
+
+		let start = ["Set up a starter config", "Skip, and use the defaults"];
+		if choose("Welcome to yazi! This seems to be the first run", &start).await.ok()? != 0 {
+			return None;
+		}
+
+		let mut answers = Answers {
+			light: choose("Theme flavor:", &["Dark", "Light"]).await.ok()? == 1,
+			no_images: choose("Image previews:", &["Enabled", "Disabled"]).await.ok()? == 1,
+			permanent: choose("Delete files by default:", &["Move to the trash", "Delete permanently"])
+				.await
+				.ok()? == 1,
+			..Default::default()
+		};
+
+		let mut editors = vec!["$EDITOR"];
+		editors.extend(EDITORS.into_iter().filter(|&e| in_path(e)));
+		let i = choose("Open text files with:", &editors).await.ok()?;
+		answers.editor = Some(editors[i]).filter(|&e| e != "$EDITOR").map(ToOwned::to_owned);
+
+		Some(answers)
+	}
+
+	async fn write(dir: &Path, answers: &Answers) -> Result<()> {
+		fs::create_dir_all(dir).await?;
+		fs::write(dir.join("yazi.toml"), Self::yazi(answers)).await?;
+		fs::write(dir.join("keymap.toml"), Self::keymap(answers)).await?;
+		fs::write(dir.join("theme.toml"), Self::theme(answers)).await?;
+		Ok(())
+	}
+
+	fn yazi(answers: &Answers) -> String {
+		let args = if cfg!(windows) { "%*" } else { r#""$@""# };
+		let edit = match answers.editor.as_deref() {
+			Some(e @ "code") => {
+				format!(r#"edit = [ {{ exec = '{e} {args}', orphan = true, desc = "{e}" }} ]"#)
+			}
+			Some(e) => format!(r#"edit = [ {{ exec = '{e} {args}', block = true, desc = "{e}" }} ]"#),
+			None => r#"# edit = [ { exec = '${EDITOR:=vi} "$@"', block = true } ]"#.to_owned(),
+		};
+		let images = if answers.no_images { NO_IMAGES } else { NO_IMAGES_COMMENTED };
+		format!("{YAZI}\n[opener]\n{edit}\n\n[plugin]\n{images}")
+	}
+
+	fn keymap(answers: &Answers) -> String {
+		format!("{KEYMAP}{}", if answers.permanent { PERMANENT } else { PERMANENT_COMMENTED })
+	}
+
+	fn theme(answers: &Answers) -> String {
+		format!("{THEME}{}", if answers.light { LIGHT } else { LIGHT_COMMENTED })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use toml::Table;
+
+	use super::*;
+
+	#[test]
+	fn test_configs() {
+		for (light, no_images, permanent) in [(false, false, false), (true, true, true)] {
+			for editor in [None, Some("nvim"), Some("code")] {
+				let answers = Answers { light, no_images, permanent, editor: editor.map(Into::into) };
+
+				let yazi: Table = Setup::yazi(&answers).parse().unwrap();
+				assert_eq!(yazi["plugin"].get("prepend_previewers").is_some(), no_images);
+				let edit = yazi["opener"].get("edit").and_then(|v| v.as_array());
+				let edit = edit.map(|a| a[0].as_table().unwrap());
+				assert_eq!(edit.and_then(|t| t["desc"].as_str()), editor);
+				assert_eq!(edit.is_some_and(|t| t.contains_key("orphan")), editor == Some("code"));
+
+				let keymap: Table = Setup::keymap(&answers).parse().unwrap();
+				assert_eq!(keymap["manager"].get("prepend_keymap").is_some(), permanent);
+
+				let theme: Table = Setup::theme(&answers).parse().unwrap();
+				assert_eq!(theme.contains_key("manager"), light);
+			}
+		}
+	}
+}
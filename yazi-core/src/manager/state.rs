@@ -0,0 +1,95 @@
+use std::{ffi::OsString, fs, io, path::{Path, PathBuf}};
+
+use serde::{Serialize, Serializer};
+use yazi_shared::fs::lossless::{self, Lossless};
+
+use super::Manager;
+
+/// What shell wrappers and editor integrations want to know about the
+/// instance, written as JSON by `--state-file` and the `export_state` call.
+///
+/// Paths that aren't valid UTF-8 are written as their bytes.
+#[derive(Debug, Serialize)]
+pub struct State {
+	#[serde(serialize_with = "lossless::serialize")]
+	pub cwd:      PathBuf,
+	#[serde(serialize_with = "serialize_hovered")]
+	pub hovered:  Option<PathBuf>,
+	#[serde(serialize_with = "serialize_selected")]
+	pub selected: Vec<PathBuf>,
+	pub tab:      usize,
+}
+
+impl State {
+	/// Writes the JSON of a state to a temporary file of the instance first, then moves it
+	/// into place, so readers never see a half-written one, nor one mixed with another's.
+	pub fn write(path: &Path, json: &str) -> io::Result<()> {
+		let mut tmp = OsString::from(path);
+		tmp.push(format!(".{}.tmp", std::process::id()));
+		let tmp = PathBuf::from(tmp);
+
+		if let Err(e) = fs::write(&tmp, format!("{json}\n")).and_then(|_| fs::rename(&tmp, path)) {
+			fs::remove_file(tmp).ok();
+			return Err(e);
+		}
+		Ok(())
+	}
+}
+
+impl Manager {
+	pub fn state(&self) -> State {
+		let tab = self.active();
+		State {
+			cwd:      tab.current.cwd.to_path_buf(),
+			hovered:  tab.current.hovered().map(|f| f.url.to_path_buf()),
+			selected: tab.selected.iter().map(|u| u.to_path_buf()).collect(),
+			tab:      self.tabs.idx,
+		}
+	}
+}
+
+fn serialize_hovered<S: Serializer>(p: &Option<PathBuf>, s: S) -> Result<S::Ok, S::Error> {
+	p.as_deref().map(Lossless).serialize(s)
+}
+
+fn serialize_selected<S: Serializer>(v: &[PathBuf], s: S) -> Result<S::Ok, S::Error> {
+	s.collect_seq(v.iter().map(|p| Lossless(p)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_write() {
+		let dir = std::env::temp_dir().join(format!("yazi-state-{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let state = State {
+			cwd:      "/a".into(),
+			hovered:  Some("/a/ü b".into()),
+			selected: vec!["/a/c".into()],
+			tab:      1,
+		};
+		let path = dir.join("state.json");
+		State::write(&path, &serde_json::to_string(&state).unwrap()).unwrap();
+
+		let v: serde_json::Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+		assert_eq!(v["hovered"], "/a/ü b");
+		assert_eq!(v["selected"][0], "/a/c");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1, "the temporary file is left behind");
+		fs::remove_dir_all(dir).ok();
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_non_utf8() {
+		use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+		let path = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff"));
+		let state = State { cwd: path.clone(), hovered: None, selected: vec![path], tab: 0 };
+		let v = serde_json::to_value(&state).unwrap();
+		assert_eq!(v["cwd"], serde_json::json!(b"/tmp/\xff"));
+		assert_eq!(v["selected"][0], v["cwd"]);
+	}
+}
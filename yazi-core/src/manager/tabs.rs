@@ -1,8 +1,9 @@
 use std::ops::{Deref, DerefMut};
 
-use yazi_config::BOOT;
-use yazi_shared::fs::Url;
+use yazi_config::{tr, BOOT};
+use yazi_shared::{emit, fs::{File, FilesOp, Url}};
 
+use super::MAX_TABS;
 use crate::{manager::Manager, tab::Tab};
 
 pub struct Tabs {
@@ -12,9 +13,27 @@ pub struct Tabs {
 
 impl Tabs {
 	pub fn make() -> Self {
-		let mut tabs = Self { idx: 0, items: vec![Tab::from(Url::from(&BOOT.cwd))] };
-		if let Some(file) = &BOOT.file {
-			tabs.items[0].reveal(Url::from(BOOT.cwd.join(file)));
+		let mut tabs = Self { idx: 0, items: Vec::with_capacity(BOOT.cwds.len()) };
+		for (cwd, file) in BOOT.cwds.iter().zip(&BOOT.files).take(MAX_TABS) {
+			let mut tab = Tab::from(Url::from(cwd));
+			if let Some(file) = file {
+				// Hovered right away, not by a `hover` that'd be of the active tab only
+				let url = Url::from(cwd.join(file));
+				let dummy = File::from_dummy(&url);
+				tab.current.update(FilesOp::Creating(tab.current.cwd.clone(), vec![dummy]));
+				tab.current.hover(&url);
+				tab.current.tracing = true;
+			}
+			tabs.items.push(tab);
+		}
+
+		if BOOT.cwds.len() > MAX_TABS {
+			let content = tr!(
+				"Only the first {max} of the {n} entries are opened, as tabs",
+				max = MAX_TABS,
+				n = BOOT.cwds.len()
+			);
+			emit!(Notify(tr!("Tabs"), content, "warn", 5));
 		}
 
 		Manager::_refresh();
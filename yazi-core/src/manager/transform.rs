@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Error};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use yazi_config::tr;
+
+/// The transformations of `rename --transform`, where the case ones
+/// leave the extension alone, except for lowercase and UPPERCASE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+	Lower,
+	Upper,
+	Title,
+	Snake,
+	Kebab,
+	Ascii,
+	Spaces,
+}
+
+impl FromStr for Transform {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"lower" => Self::Lower,
+			"upper" => Self::Upper,
+			"title" => Self::Title,
+			"snake" => Self::Snake,
+			"kebab" => Self::Kebab,
+			"ascii" => Self::Ascii,
+			"spaces" => Self::Spaces,
+			_ => bail!(tr!("Unknown transform `{name}`", name = s)),
+		})
+	}
+}
+
+impl Transform {
+	pub fn apply(self, name: &str) -> String {
+		let (stem, ext) = match name.rfind('.') {
+			Some(i) if i > 0 => name.split_at(i),
+			_ => (name, ""),
+		};
+
+		match self {
+			Self::Lower => name.to_lowercase(),
+			Self::Upper => name.to_uppercase(),
+			Self::Title => Self::title(stem) + ext,
+			Self::Snake => Self::words(stem).join("_") + ext,
+			Self::Kebab => Self::words(stem).join("-") + ext,
+			Self::Ascii => name.nfd().filter(|&c| !is_combining_mark(c)).collect(),
+			Self::Spaces => stem.split_whitespace().collect::<Vec<_>>().join("_") + ext,
+		}
+	}
+
+	fn title(s: &str) -> String {
+		let mut start = true;
+		s.chars()
+			.flat_map(|c| {
+				let upper = start;
+				start = matches!(c, ' ' | '_' | '-');
+				if upper { c.to_uppercase().collect::<Vec<_>>() } else { c.to_lowercase().collect() }
+			})
+			.collect()
+	}
+
+	// Splits into lowercase words, at spaces, underscores, dashes and camelCase humps
+	fn words(s: &str) -> Vec<String> {
+		let mut words = vec![String::new()];
+		let mut prev_lower = false;
+		for c in s.chars() {
+			if matches!(c, ' ' | '_' | '-') {
+				words.push(String::new());
+				prev_lower = false;
+				continue;
+			}
+			if prev_lower && c.is_uppercase() {
+				words.push(String::new());
+			}
+			prev_lower = c.is_lowercase() || c.is_ascii_digit();
+			words.last_mut().unwrap().extend(c.to_lowercase());
+		}
+
+		words.retain(|w| !w.is_empty());
+		words
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_transform() {
+		let t = |name, s: &str| s.parse::<Transform>().unwrap().apply(name);
+		assert_eq!(t("My Photo.JPG", "lower"), "my photo.jpg");
+		assert_eq!(t("my photo.jpg", "title"), "My Photo.jpg");
+		assert_eq!(t("myPhoto-2024 final.jpg", "snake"), "my_photo_2024_final.jpg");
+		assert_eq!(t("my_photo_2024.tar", "kebab"), "my-photo-2024.tar");
+		assert_eq!(t("Crème brûlée.txt", "ascii"), "Creme brulee.txt");
+		assert_eq!(t("a  b c.txt", "spaces"), "a_b_c.txt");
+		assert_eq!(t(".bashrc", "upper"), ".BASHRC");
+	}
+}
@@ -0,0 +1,47 @@
+use std::{collections::BTreeSet, path::{Path, PathBuf}};
+
+use parking_lot::{const_mutex, Mutex};
+use sha2::{Digest, Sha256};
+use tokio::{fs::{self, OpenOptions}, io::AsyncWriteExt};
+use yazi_config::{popup::InputCfg, BOOT};
+
+use crate::input::Input;
+
+// The settings files asked about in this session, so that a declined one isn't asked again
+static ASKED: Mutex<BTreeSet<(PathBuf, String)>> = const_mutex(BTreeSet::new());
+
+/// The `.yazi.toml` files the user trusts, each with the hash of its content,
+/// so that a changed one has to be trusted again.
+pub struct Trusted;
+
+impl Trusted {
+	/// The SHA-256 of the content, which can't be made to collide with that of a trusted one.
+	#[inline]
+	pub fn id(content: &str) -> String {
+		Sha256::digest(content).iter().map(|b| format!("{b:02x}")).collect()
+	}
+
+	/// Whether the settings file is trusted, asking the user the first time it's seen.
+	pub async fn ask(path: &Path, id: &str) -> bool {
+		let line = format!("{id} {}", path.display());
+		if fs::read_to_string(Self::path()).await.unwrap_or_default().lines().any(|l| l == line) {
+			return true;
+		}
+		if !ASKED.lock().insert((path.to_owned(), id.to_owned())) {
+			return false;
+		}
+
+		match Input::_show(InputCfg::trust(path)).recv().await {
+			Some(Ok(c)) if c == "y" || c == "Y" => {}
+			_ => return false,
+		}
+
+		if let Ok(mut f) = OpenOptions::new().create(true).append(true).open(Self::path()).await {
+			f.write_all(format!("{line}\n").as_bytes()).await.ok();
+		}
+		true
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("trusted") }
+}
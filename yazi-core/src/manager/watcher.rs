@@ -1,20 +1,32 @@
-use std::{collections::{BTreeMap, BTreeSet}, sync::Arc, time::{Duration, SystemTime}};
+use std::{collections::{BTreeMap, BTreeSet}, mem, path::PathBuf, sync::{atomic::{AtomicUsize, Ordering}, Arc}, time::{Duration, SystemTime}};
 
 use anyhow::Result;
-use notify::{event::{MetadataKind, ModifyKind}, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
-use parking_lot::RwLock;
-use tokio::{fs, pin, sync::mpsc::{self, UnboundedReceiver}};
+use notify::{event::{MetadataKind, ModifyKind, RenameMode}, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _Watcher};
+use parking_lot::{Mutex, RwLock};
+use tokio::{fs, pin, sync::mpsc::{self, UnboundedReceiver}, time::{self, Instant, MissedTickBehavior}};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use tracing::error;
+use yazi_config::{manager::WatchMode, MANAGER};
 use yazi_plugin::isolate;
-use yazi_shared::fs::{File, FilesOp, Url};
+use yazi_shared::{fs::{File, FilesOp, Url}, is_remote_fs};
 
-use super::Linked;
+use super::{Linked, Manager};
 use crate::folder::{Files, Folder};
 
+// How long to wait for more events after the last one, and at most after the first one
+const DEBOUNCE: Duration = Duration::from_millis(30);
+const DEBOUNCE_MAX: Duration = Duration::from_millis(500);
+
+// Number of changes in a directory within a batch, beyond which it's reloaded entirely
+const DEBOUNCE_THRESHOLD: usize = 1000;
+
+/// The number of the changes reported by the watcher so far, for the debug overlay to rate.
+pub static WATCHER_EVENTS: AtomicUsize = AtomicUsize::new(0);
+
 pub struct Watcher {
-	watcher:    RecommendedWatcher,
+	watcher:    Arc<Mutex<RecommendedWatcher>>,
 	watched:    Arc<RwLock<BTreeSet<Url>>>,
+	polled:     Arc<RwLock<BTreeMap<Url, Option<SystemTime>>>>,
 	pub linked: Arc<RwLock<Linked>>,
 }
 
@@ -24,11 +36,17 @@ impl Watcher {
 		let watcher = RecommendedWatcher::new(
 			{
 				let tx = tx.clone();
+				let mut renames = Renames::default();
 				move |res: Result<notify::Event, notify::Error>| {
 					let Ok(event) = res else {
 						return;
 					};
 
+					// For the yanked files to follow a rename, once both ends of it are known
+					if let Some((from, to)) = renames.pair(&event) {
+						Manager::_update_yanked(Url::from(from), Url::from(to));
+					}
+
 					match event.kind {
 						EventKind::Create(_) => {}
 						EventKind::Modify(kind) => match kind {
@@ -46,6 +64,7 @@ impl Watcher {
 						_ => return,
 					}
 
+					WATCHER_EVENTS.fetch_add(event.paths.len(), Ordering::Relaxed);
 					for path in event.paths {
 						tx.send(Url::from(path)).ok();
 					}
@@ -54,12 +73,36 @@ impl Watcher {
 			Default::default(),
 		);
 
-		let instance =
-			Self { watcher: watcher.unwrap(), watched: Default::default(), linked: Default::default() };
+		let instance = Self {
+			watcher: Arc::new(Mutex::new(watcher.unwrap())),
+			watched: Default::default(),
+			polled:  Default::default(),
+			linked:  Default::default(),
+		};
 		tokio::spawn(Self::on_changed(rx));
+		tokio::spawn(Self::on_polled(instance.polled.clone()));
 		instance
 	}
 
+	// Tells whether it's on a network filesystem off the main thread, as `statfs` can hang on one
+	// that's unreachable, then watches it natively, or polls it if it is or can't be watched
+	fn watch_auto(&self, url: Url) {
+		let (watcher, watched) = (self.watcher.clone(), self.watched.clone());
+		let polled = self.polled.clone();
+		tokio::spawn(async move {
+			let u = url.clone();
+			let remote = tokio::task::spawn_blocking(move || is_remote_fs(&u)).await.unwrap_or(true);
+
+			let mut polled = polled.write();
+			if !watched.read().contains(&url) {
+				return;
+			}
+			if remote || watcher.lock().watch(&url, RecursiveMode::NonRecursive).is_err() {
+				polled.insert(url, None);
+			}
+		});
+	}
+
 	pub(super) fn watch(&mut self, mut new: BTreeSet<&Url>) {
 		new.retain(|&u| u.is_regular());
 		let (to_unwatch, to_watch): (BTreeSet<_>, BTreeSet<_>) = {
@@ -71,48 +114,81 @@ impl Watcher {
 			)
 		};
 
+		// Held until the watched ones are updated, for a check of `watch_auto()` not to go between
+		let mut polled = self.polled.write();
 		for u in to_unwatch {
-			self.watcher.unwatch(&u).ok();
+			if polled.remove(&u).is_none() {
+				self.watcher.lock().unwatch(&u).ok();
+			}
 		}
 		for u in to_watch {
-			if self.watcher.watch(&u, RecursiveMode::NonRecursive).is_err() {
-				new.remove(&u);
+			match MANAGER.watch_mode {
+				WatchMode::Auto => self.watch_auto(u),
+				WatchMode::Native => {
+					if self.watcher.lock().watch(&u, RecursiveMode::NonRecursive).is_err() {
+						new.remove(&u);
+					}
+				}
+				WatchMode::Poll => _ = polled.insert(u, None),
 			}
 		}
 
 		*self.watched.write() = new.into_iter().cloned().collect();
+		drop(polled);
 		self.sync_linked();
 	}
 
-	pub(super) fn trigger_dirs(&self, folders: &[&Folder]) {
-		let todo: Vec<_> =
-			folders.iter().filter(|&f| f.cwd.is_regular()).map(|&f| (f.cwd.clone(), f.mtime)).collect();
+	pub(super) fn trigger_dirs(&self, folders: &[&Folder], force: bool) {
+		let todo: Vec<_> = folders
+			.iter()
+			.filter(|&f| f.cwd.is_regular())
+			.map(|&f| (f.cwd.clone(), if force { None } else { f.mtime }))
+			.collect();
 		if todo.is_empty() {
 			return;
 		}
 
-		async fn go(url: Url, mtime: Option<SystemTime>) {
-			let Ok(meta) = fs::metadata(&url).await else {
-				if let Ok(m) = fs::symlink_metadata(&url).await {
-					FilesOp::Full(url, vec![], m.modified().ok()).emit();
-				} else if let Some(p) = url.parent_url() {
-					FilesOp::Deleting(p, vec![url]).emit();
-				}
-				return;
-			};
+		tokio::spawn(async move {
+			futures::future::join_all(todo.into_iter().map(|(url, mtime)| Self::reload(url, mtime)))
+				.await;
+		});
+	}
 
-			if meta.modified().ok() == mtime {
-				return;
+	async fn reload(url: Url, mtime: Option<SystemTime>) {
+		let Ok(meta) = fs::metadata(&url).await else {
+			if let Ok(m) = fs::symlink_metadata(&url).await {
+				FilesOp::Full(url, vec![], m.modified().ok()).emit();
+			} else if let Some(p) = url.parent_url() {
+				FilesOp::Deleting(p, vec![url]).emit();
 			}
+			return;
+		};
 
-			if let Ok(files) = Files::from_dir_bulk(&url).await {
-				FilesOp::Full(url, files, meta.modified().ok()).emit();
-			}
+		if meta.modified().ok() == mtime {
+			return;
 		}
 
-		tokio::spawn(async move {
-			futures::future::join_all(todo.into_iter().map(|(url, mtime)| go(url, mtime))).await;
-		});
+		let Ok(rx) = Files::from_dir(&url).await else {
+			return;
+		};
+
+		let stream = UnboundedReceiverStream::new(rx).chunks_timeout(50000, Duration::from_millis(500));
+		pin!(stream);
+
+		// Small directories are replaced in one go, and only the large ones are
+		// streamed in chunks, to avoid flashing an empty list on every refresh.
+		let first = stream.next().await.unwrap_or_default();
+		let Some(second) = stream.next().await else {
+			return FilesOp::Full(url, first, meta.modified().ok()).emit();
+		};
+
+		let ticket = FilesOp::prepare(&url);
+		FilesOp::Part(url.clone(), first, ticket).emit();
+		FilesOp::Part(url.clone(), second, ticket).emit();
+		while let Some(chunk) = stream.next().await {
+			FilesOp::Part(url.clone(), chunk, ticket).emit();
+		}
+		FilesOp::Done(url, meta.modified().ok(), ticket).emit();
 	}
 
 	fn sync_linked(&self) {
@@ -141,37 +217,168 @@ impl Watcher {
 		});
 	}
 
-	async fn on_changed(rx: UnboundedReceiver<Url>) {
-		// TODO: revert this once a new notification is implemented
-		let rx = UnboundedReceiverStream::new(rx).chunks_timeout(100, Duration::from_millis(20));
-		pin!(rx);
-
-		while let Some(urls) = rx.next().await {
-			let urls: BTreeSet<_> = urls.into_iter().collect();
-			let mut reload = Vec::with_capacity(urls.len());
+	async fn on_changed(mut rx: UnboundedReceiver<Url>) {
+		while let Some(first) = rx.recv().await {
+			// Coalesce the events until things calm down, so that a storm of them
+			// (e.g. extracting an archive) is processed in a few batches.
+			let mut urls = BTreeSet::from([first]);
+			let deadline = Instant::now() + DEBOUNCE_MAX;
+			while let Ok(Some(u)) =
+				time::timeout_at(deadline.min(Instant::now() + DEBOUNCE), rx.recv()).await
+			{
+				urls.insert(u);
+			}
 
+			let mut parents: BTreeMap<_, Vec<_>> = BTreeMap::new();
 			for u in urls {
-				let Some(parent) = u.parent_url() else {
-					continue;
-				};
+				if let Some(parent) = u.parent_url() {
+					parents.entry(parent).or_default().push(u);
+				}
+			}
 
-				let Ok(file) = File::from(u.clone()).await else {
-					FilesOp::Deleting(parent, vec![u]).emit();
+			for (parent, urls) in parents {
+				Self::on_changed_in(parent, urls).await;
+			}
+		}
+	}
+
+	async fn on_changed_in(parent: Url, urls: Vec<Url>) {
+		// Too many changes, it's cheaper to reload the whole directory, as it is
+		// if its `.gitignore` has changed, to load the rules again and split by them
+		let gitignore = urls.iter().any(|u| u.file_name() == Some(".gitignore".as_ref()));
+		if urls.len() > DEBOUNCE_THRESHOLD || gitignore {
+			return Self::reload(parent, None).await;
+		}
+
+		let (mut upserting, mut deleting) = (BTreeMap::new(), vec![]);
+		let mut reload = Vec::with_capacity(urls.len());
+		for u in urls {
+			let Ok(file) = File::from(u.clone()).await else {
+				deleting.push(u);
+				continue;
+			};
+
+			if !file.is_dir() {
+				reload.push(file.clone());
+			}
+			upserting.insert(u, file);
+		}
+
+		if !deleting.is_empty() {
+			FilesOp::Deleting(parent.clone(), deleting).emit();
+		}
+		if !upserting.is_empty() {
+			FilesOp::Upserting(parent, upserting).emit();
+		}
+
+		if reload.is_empty() {
+			return;
+		}
+		if let Err(e) = isolate::preload("mime", reload, true).await {
+			error!("preload in watcher failed: {e}");
+		}
+	}
+
+	async fn on_polled(polled: Arc<RwLock<BTreeMap<Url, Option<SystemTime>>>>) {
+		let mut interval = time::interval(Duration::from_secs(MANAGER.watch_poll_interval));
+		interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+		loop {
+			interval.tick().await;
+
+			let urls: Vec<_> = polled.read().keys().cloned().collect();
+			for url in urls {
+				let mtime = fs::metadata(&url).await.and_then(|m| m.modified()).ok();
+				let Some(old) = polled.write().get_mut(&url).map(|t| mem::replace(t, mtime)) else {
 					continue;
 				};
 
-				if !file.is_dir() {
-					reload.push(file.clone());
+				// The first poll only records the mtime, which has just been loaded
+				if old.is_some() && old != mtime {
+					Self::reload(url, None).await;
 				}
-				FilesOp::Upserting(parent, BTreeMap::from_iter([(u, file)])).emit();
 			}
+		}
+	}
+}
 
-			if reload.is_empty() {
-				continue;
+// Pairs up the ends of a rename, which inotify reports as one event, and the others as two in a
+// row: Windows as one from the path and one to it, and FSEvents as two alike, the gone one first.
+#[derive(Default)]
+struct Renames {
+	from: Option<PathBuf>,
+}
+
+impl Renames {
+	fn pair(&mut self, event: &notify::Event) -> Option<(PathBuf, PathBuf)> {
+		let EventKind::Modify(ModifyKind::Name(mode)) = event.kind else {
+			self.from = None;
+			return None;
+		};
+
+		match (mode, &event.paths[..]) {
+			(RenameMode::Both, [from, to]) => Some((from.clone(), to.clone())),
+			(RenameMode::From, [from]) => {
+				self.from = Some(from.clone());
+				None
 			}
-			if let Err(e) = isolate::preload("mime", reload, true).await {
-				error!("preload in watcher failed: {e}");
+			(RenameMode::To, [to]) => Some((self.from.take()?, to.clone())),
+			(RenameMode::Any | RenameMode::Other, [path]) => {
+				if path.symlink_metadata().is_err() {
+					self.from = Some(path.clone());
+					None
+				} else {
+					Some((self.from.take()?, path.clone()))
+				}
+			}
+			_ => {
+				self.from = None;
+				None
 			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use notify::{event::RemoveKind, Event};
+
+	use super::*;
+
+	fn event(mode: RenameMode, paths: &[&str]) -> Event {
+		let event = Event::new(EventKind::Modify(ModifyKind::Name(mode)));
+		paths.iter().fold(event, |e, &p| e.add_path(p.into()))
+	}
+
+	#[test]
+	fn test_pair() {
+		let mut renames = Renames::default();
+		let pair = Some(("/a".into(), "/b".into()));
+		assert_eq!(renames.pair(&event(RenameMode::Both, &["/a", "/b"])), pair);
+
+		assert_eq!(renames.pair(&event(RenameMode::From, &["/a"])), None);
+		assert_eq!(renames.pair(&event(RenameMode::To, &["/b"])), pair);
+		assert_eq!(renames.pair(&event(RenameMode::To, &["/c"])), None);
+
+		// Not a rename once anything else comes in between
+		assert_eq!(renames.pair(&event(RenameMode::From, &["/a"])), None);
+		let removed = Event::new(EventKind::Remove(RemoveKind::File)).add_path("/a".into());
+		renames.pair(&removed);
+		assert_eq!(renames.pair(&event(RenameMode::To, &["/b"])), None);
+	}
+
+	#[test]
+	fn test_pair_any() {
+		let dir = std::env::temp_dir().join(format!("yazi-renames-{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let (from, to) = (dir.join("a"), dir.join("b"));
+		std::fs::write(&to, "").unwrap();
+
+		let mut renames = Renames::default();
+		let e = |p: &PathBuf| event(RenameMode::Any, &[p.to_str().unwrap()]);
+		assert_eq!(renames.pair(&e(&to)), None);
+		assert_eq!(renames.pair(&e(&from)), None);
+		assert_eq!(renames.pair(&e(&to)), Some((from, to)));
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+}
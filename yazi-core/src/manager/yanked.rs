@@ -1,11 +1,50 @@
-use std::{collections::HashSet, ops::Deref};
+use std::{collections::{BTreeMap, BTreeSet, HashSet}, ops::Deref, path::{Path, PathBuf}, time::{Duration, SystemTime}};
 
-use yazi_shared::fs::Url;
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use tokio::fs;
+use yazi_config::{BOOT, MANAGER};
+use yazi_shared::{fs::Url, RoCell};
 
+/// The yank register, which is shared with the other running instances
+/// through a file in the state directory when `sync_yanked` is enabled.
+///
+/// Whoever yanks last wins: a local yank always overwrites the shared one,
+/// and a shared one is only picked up if it's newer than what we have.
+/// The file is read and written in the background, off the event loop.
 #[derive(Default)]
 pub struct Yanked {
 	pub cut:         bool,
 	pub(super) urls: HashSet<Url>,
+
+	named: BTreeMap<char, Register>,
+
+	// The cut files being pasted, which are left out of the registers as they're moved away
+	moving: HashSet<Url>,
+}
+
+// The shared register, as last read from the file or written to it
+struct Shared {
+	revision: SystemTime,
+	// Yanked by another instance, and yet to be picked up
+	fresh:    Option<(bool, HashSet<Url>)>,
+	// Our own yank yet to be written
+	latest:   Option<Vec<u8>>,
+}
+
+static SHARED: RoCell<Mutex<Shared>> = RoCell::new();
+
+// Our own writes, one at a time, during which the file isn't read, as it may be ours
+static WRITING: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+// How often the file is checked for a yank of another instance
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A named register, `"a` to `"z`, for several sets of files to be held at once.
+#[derive(Clone, Debug)]
+pub struct Register {
+	pub cut:  bool,
+	pub urls: HashSet<Url>,
 }
 
 impl Deref for Yanked {
@@ -13,3 +52,291 @@ impl Deref for Yanked {
 
 	fn deref(&self) -> &Self::Target { &self.urls }
 }
+
+impl Yanked {
+	pub(crate) fn init() {
+		SHARED.init(Mutex::new(Shared { revision: SystemTime::now(), fresh: None, latest: None }));
+		if MANAGER.sync_yanked {
+			tokio::spawn(async {
+				let mut interval = tokio::time::interval(POLL_INTERVAL);
+				loop {
+					interval.tick().await;
+					Self::poll().await;
+				}
+			});
+		}
+	}
+
+	pub(super) fn set(&mut self, cut: bool, urls: HashSet<Url>) {
+		self.cut = cut;
+		self.urls = urls;
+		self.moving.clear();
+		self.share();
+	}
+
+	/// Yanks into a named register, which is kept to this instance, unlike the unnamed one.
+	pub(super) fn set_named(&mut self, name: char, cut: bool, urls: HashSet<Url>) {
+		if urls.is_empty() {
+			self.named.remove(&name);
+		} else {
+			self.named.insert(name, Register { cut, urls });
+		}
+	}
+
+	#[inline]
+	pub fn named(&self) -> &BTreeMap<char, Register> { &self.named }
+
+	/// Whether the files of the register, or the unnamed one if `None`, are cut, and which they are.
+	pub(super) fn get(&self, name: Option<char>) -> Option<(bool, &HashSet<Url>)> {
+		match name {
+			Some(c) => self.named.get(&c).map(|r| (r.cut, &r.urls)),
+			None => Some((self.cut, &self.urls)),
+		}
+	}
+
+	/// Marks the files of the register as being moved by a paste, for them to be left out of it
+	/// as each one is gone, rather than followed or warned of, and kept if it's never moved.
+	pub(super) fn moving(&mut self, name: Option<char>) {
+		if let Some((_, urls)) = self.get(name) {
+			let urls = urls.clone();
+			self.moving.extend(urls);
+		}
+	}
+
+	/// Follows the yanked files, or those inside a yanked directory, to where they're renamed,
+	/// returning whether any of them is.
+	pub(super) fn rename(&mut self, from: &Path, to: &Url) -> bool {
+		let mut b = false;
+		for r in self.named.values_mut() {
+			b |= Self::rename_in(&mut r.urls, &self.moving, from, to);
+		}
+		self.named.retain(|_, r| !r.urls.is_empty());
+
+		if Self::rename_in(&mut self.urls, &self.moving, from, to) {
+			self.share();
+			b = true;
+		}
+		self.moving.retain(|u| !u.starts_with(from));
+		b
+	}
+
+	/// Leaves out the yanked files that are deleted, or inside a deleted directory,
+	/// returning how many of them are.
+	/// Those being moved are left out as well, but not counted.
+	pub(super) fn forget(&mut self, deleted: &[Url]) -> usize {
+		let mut n = 0;
+		for r in self.named.values_mut() {
+			n += Self::forget_in(&mut r.urls, &self.moving, deleted);
+		}
+		self.named.retain(|_, r| !r.urls.is_empty());
+
+		let len = self.urls.len();
+		n += Self::forget_in(&mut self.urls, &self.moving, deleted);
+		if self.urls.len() != len {
+			self.share();
+		}
+		self.moving.retain(|u| !deleted.iter().any(|d| u.starts_with(d)));
+		n
+	}
+
+	/// Whether there's nothing yanked, in any of the registers.
+	#[inline]
+	pub(super) fn is_all_empty(&self) -> bool { self.urls.is_empty() && self.named.is_empty() }
+
+	/// The parent directories of the yanked files, for the watcher to keep track of them.
+	pub(super) fn parents(&self) -> BTreeSet<Url> {
+		let named = self.named.values().flat_map(|r| &r.urls);
+		self.urls.iter().chain(named).filter_map(|u| u.parent_url()).collect()
+	}
+
+	fn rename_in(urls: &mut HashSet<Url>, moving: &HashSet<Url>, from: &Path, to: &Url) -> bool {
+		let renamed: Vec<_> = urls.iter().filter(|u| u.starts_with(from)).cloned().collect();
+		for u in &renamed {
+			urls.remove(u);
+			if moving.contains(u) {
+				continue;
+			}
+			let rest = u.strip_prefix(from).unwrap();
+			urls.insert(if rest.as_os_str().is_empty() { to.clone() } else { to.join(rest) });
+		}
+		!renamed.is_empty()
+	}
+
+	fn forget_in(urls: &mut HashSet<Url>, moving: &HashSet<Url>, deleted: &[Url]) -> usize {
+		let mut n = 0;
+		urls.retain(|u| {
+			let gone = deleted.iter().any(|d| u.starts_with(d));
+			n += (gone && !moving.contains(u)) as usize;
+			!gone
+		});
+		n
+	}
+
+	fn share(&self) {
+		if !MANAGER.sync_yanked {
+			return;
+		}
+
+		// Separated by NULs, which no path has, and the urls kept as bytes, whatever their names
+		let mut b = Vec::from(if self.cut { &b"cut"[..] } else { &b"copy"[..] });
+		for url in &self.urls {
+			b.push(0);
+			b.extend(url.to_bytes());
+		}
+
+		let mut shared = SHARED.lock();
+		shared.fresh = None;
+		shared.latest = Some(b);
+		drop(shared);
+
+		// Whichever write gets to it first writes the latest yank, and those after it have none left
+		tokio::spawn(async {
+			let _guard = WRITING.lock().await;
+			let Some(b) = SHARED.lock().latest.take() else { return };
+
+			// Write to a temporary file of our own first, so others never read a partial one
+			let tmp = Self::path().with_extension(format!("{}.tmp", std::process::id()));
+			if fs::write(&tmp, b).await.is_ok() && fs::rename(&tmp, Self::path()).await.is_ok() {
+				let revision = Self::modified().await.unwrap_or_else(SystemTime::now);
+				SHARED.lock().revision = revision;
+			}
+		});
+	}
+
+	// Reads the file if another instance has yanked since, for it to be picked up by `catchup`
+	async fn poll() {
+		let busy = || SHARED.lock().latest.is_some() || WRITING.try_lock().is_err();
+		let Some(m) = Self::modified().await else { return };
+		if busy() || m <= SHARED.lock().revision {
+			return;
+		}
+
+		let Ok(b) = fs::read(Self::path()).await else {
+			return;
+		};
+
+		let mut it = b.split(|&c| c == 0);
+		let cut = it.next() == Some(b"cut");
+		let urls = it.filter_map(Url::from_bytes).collect();
+
+		let mut shared = SHARED.lock();
+		if shared.latest.is_none() && WRITING.try_lock().is_ok() && m > shared.revision {
+			shared.revision = m;
+			shared.fresh = Some((cut, urls));
+		}
+	}
+
+	/// Picks up the shared register if another instance has yanked since,
+	/// returning whether it was changed.
+	pub(super) fn catchup(&mut self) -> bool {
+		if !MANAGER.sync_yanked {
+			return false;
+		}
+
+		let Some((cut, urls)) = SHARED.lock().fresh.take() else {
+			return false;
+		};
+		self.cut = cut;
+		self.urls = urls;
+		true
+	}
+
+	#[inline]
+	fn path() -> PathBuf { BOOT.state_dir.join("yanked") }
+
+	#[inline]
+	async fn modified() -> Option<SystemTime> {
+		fs::metadata(Self::path()).await.and_then(|m| m.modified()).ok()
+	}
+}
+
+/// The name of a register, e.g. `a` of `--register=a`, which is a lowercase letter.
+pub(super) fn register_name(s: &str) -> Result<char> {
+	let mut it = s.chars();
+	match (it.next(), it.next()) {
+		(Some(c @ 'a'..='z'), None) => Ok(c),
+		_ => bail!("Invalid register `{s}`, not one of a-z"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Once;
+
+	use serde::Deserialize;
+
+	use super::*;
+
+	// The preset, but not shared with the other instances, for no state directory to be written
+	fn yanked(urls: &[&str]) -> Yanked {
+		static INIT: Once = Once::new();
+		INIT.call_once(|| {
+			#[derive(Deserialize)]
+			struct Outer {
+				manager: yazi_config::manager::Manager,
+			}
+			let s = include_str!("../../../yazi-config/preset/yazi.toml");
+			let mut manager = toml::from_str::<Outer>(s).unwrap().manager;
+			manager.sync_yanked = false;
+			MANAGER.init(manager);
+		});
+
+		let mut yanked = Yanked::default();
+		yanked.set(true, urls.iter().map(|&u| Url::from(u)).collect());
+		yanked
+	}
+
+	fn urls(yanked: &Yanked) -> BTreeSet<&str> {
+		yanked.iter().map(|u| u.to_str().unwrap()).collect()
+	}
+
+	#[test]
+	fn test_rename() {
+		let mut yanked = yanked(&["/a/b", "/a/c/d", "/e"]);
+		yanked.set_named('x', false, [Url::from("/a/c")].into());
+
+		assert!(yanked.rename(Path::new("/a/c"), &Url::from("/f")));
+		assert_eq!(urls(&yanked), BTreeSet::from(["/a/b", "/f/d", "/e"]));
+		assert_eq!(yanked.get(Some('x')).unwrap().1, &HashSet::from([Url::from("/f")]));
+
+		assert!(!yanked.rename(Path::new("/g"), &Url::from("/h")));
+	}
+
+	#[test]
+	fn test_forget() {
+		let mut yanked = yanked(&["/a/b", "/a/c/d", "/e"]);
+		yanked.set_named('x', false, [Url::from("/a/c")].into());
+
+		assert_eq!(yanked.forget(&[Url::from("/a/c")]), 2);
+		assert_eq!(urls(&yanked), BTreeSet::from(["/a/b", "/e"]));
+		assert!(yanked.get(Some('x')).is_none());
+
+		assert_eq!(yanked.forget(&[Url::from("/a"), Url::from("/e")]), 2);
+		assert!(yanked.is_all_empty());
+	}
+
+	// Those cut and pasted are left out as they're moved away, the rest kept as they were
+	#[test]
+	fn test_moving() {
+		let mut yanked = yanked(&["/a/b", "/a/c", "/a/d"]);
+		yanked.moving(None);
+
+		assert!(yanked.rename(Path::new("/a/b"), &Url::from("/e/b")));
+		assert_eq!(yanked.forget(&[Url::from("/a/c")]), 0);
+		assert_eq!(urls(&yanked), BTreeSet::from(["/a/d"]));
+
+		// No longer being moved after a new yank
+		yanked.set(true, [Url::from("/a/b")].into());
+		assert!(yanked.rename(Path::new("/a/b"), &Url::from("/e/b")));
+		assert_eq!(urls(&yanked), BTreeSet::from(["/e/b"]));
+	}
+
+	#[test]
+	fn test_register_name() {
+		assert_eq!(register_name("a").unwrap(), 'a');
+		assert_eq!(register_name("z").unwrap(), 'z');
+		for s in ["", "A", "ab", "1", "\"", "ä"] {
+			assert!(register_name(s).is_err(), "{s:?} isn't a register");
+		}
+	}
+}
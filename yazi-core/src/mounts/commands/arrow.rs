@@ -0,0 +1,24 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::mounts::Mounts;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Mounts {
+	pub fn arrow(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let old = self.cursor;
+
+		self.cursor = self.cursor.saturating_add_signed(opt.step);
+		self.cursor = self.cursor.min(self.items.len().saturating_sub(1));
+		render!(self.cursor != old);
+	}
+}
@@ -0,0 +1,14 @@
+use yazi_shared::{event::Cmd, fs::Url};
+
+use crate::{mounts::Mounts, tab::Tab};
+
+impl Mounts {
+	pub fn enter(&mut self, _: Cmd) {
+		let Some(mount) = self.hovered() else {
+			return;
+		};
+
+		Tab::_cd(&Url::from(&mount.path));
+		self.toggle(());
+	}
+}
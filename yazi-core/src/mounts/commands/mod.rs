@@ -0,0 +1,5 @@
+mod arrow;
+mod enter;
+mod refresh;
+mod toggle;
+mod update;
@@ -0,0 +1,39 @@
+use std::{sync::atomic::Ordering, time::Instant};
+
+use yazi_shared::{event::Cmd, fs::Url};
+
+use crate::mounts::{Disk, Mounts, MOUNTS_INTERVAL};
+
+impl Mounts {
+	/// Re-states the filesystem of the cwd, and lists them all if they're shown, here or in the
+	/// sidebar, if the cwd has changed, it's been a while, or with `--force`.
+	pub fn refresh(&mut self, c: Cmd, cwd: &Url, sidebar: bool) {
+		let fresh = self.synced.is_some_and(|t| t.elapsed() < MOUNTS_INTERVAL);
+		if fresh && self.cwd.as_ref() == Some(cwd) && !c.named.contains_key("force") {
+			return;
+		}
+
+		// A stale network filesystem could hang `statvfs`, which isn't called again till it's back,
+		// not to take up another blocking thread each time, and tried again on a tick after that
+		if self.busy.swap(true, Ordering::Relaxed) {
+			self.synced = None;
+			return;
+		}
+
+		self.cwd = Some(cwd.clone());
+		self.synced = Some(Instant::now());
+
+		let (cwd, all, busy) = (cwd.clone(), self.visible || sidebar, self.busy.clone());
+		tokio::task::spawn_blocking(move || {
+			#[cfg(unix)]
+			let disk = yazi_shared::disk_space(&cwd).ok().map(|(free, total)| Disk { free, total });
+			#[cfg(not(unix))]
+			let disk = None;
+
+			let table = Self::table();
+			let items = all.then(|| Self::list(&table));
+			Self::_update(cwd, disk, table, items);
+			busy.store(false, Ordering::Relaxed);
+		});
+	}
+}
@@ -0,0 +1,26 @@
+use yazi_shared::{emit, event::Cmd, render, Layer};
+
+use crate::mounts::Mounts;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Mounts {
+	pub fn toggle(&mut self, _: impl Into<Opt>) {
+		self.visible = !self.visible;
+
+		// List the mounts right away, instead of waiting for the next refresh
+		if self.visible {
+			self.synced = None;
+			emit!(Call(Cmd::new("refresh"), Layer::Mounts));
+		}
+
+		render!();
+	}
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::mounts::{Disk, Mount, Mounts};
+
+pub struct Opt {
+	cwd:   Url,
+	disk:  Option<Disk>,
+	table: Vec<(String, PathBuf, String)>,
+	items: Option<Vec<Mount>>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_data().ok_or(()) }
+}
+
+impl Mounts {
+	#[inline]
+	pub(super) fn _update(
+		cwd: Url,
+		disk: Option<Disk>,
+		table: Vec<(String, PathBuf, String)>,
+		items: Option<Vec<Mount>>,
+	) {
+		emit!(Call(Cmd::new("update").with_data(Opt { cwd, disk, table, items }), Layer::Mounts));
+	}
+
+	pub fn update(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		// The cwd has changed since then
+		if self.cwd.as_ref() != Some(&opt.cwd) {
+			return;
+		}
+
+		let mut b = self.disk != opt.disk;
+		self.disk = opt.disk;
+		self.table = opt.table;
+
+		if let Some(items) = opt.items.filter(|i| *i != self.items) {
+			// The sidebar lists the mount points as well
+			let paths: Vec<_> = items.iter().map(|m| m.path.clone()).collect();
+			emit!(Call(Cmd::new("update").with_data(paths), Layer::Sidebar));
+
+			b |= self.visible;
+			self.items = items;
+			self.cursor = self.cursor.min(self.items.len().saturating_sub(1));
+		}
+
+		render!(b);
+	}
+}
@@ -0,0 +1,6 @@
+mod commands;
+mod mounts;
+
+pub use mounts::*;
+
+pub const MOUNTS_PERCENT: u16 = 80;
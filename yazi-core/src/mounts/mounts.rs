@@ -0,0 +1,192 @@
+use std::{path::{Path, PathBuf}, sync::{atomic::AtomicBool, Arc}, time::{Duration, Instant}};
+
+use serde::Serialize;
+use tokio::time::sleep;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+// How often the space of the filesystems is refreshed
+pub(super) const MOUNTS_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mount {
+	pub path:   PathBuf,
+	pub device: String,
+	pub fstype: String,
+	pub free:   u64,
+	pub total:  u64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Disk {
+	pub free:  u64,
+	pub total: u64,
+}
+
+#[derive(Default)]
+pub struct Mounts {
+	pub items:   Vec<Mount>,
+	pub cursor:  usize,
+	pub visible: bool,
+
+	// The filesystem containing the cwd, shown in the status bar,
+	// and when it was last refreshed
+	pub disk:          Option<Disk>,
+	// The mount points with their devices and filesystem types, refreshed along with them
+	pub(crate) table:  Vec<(String, PathBuf, String)>,
+	pub(super) cwd:    Option<Url>,
+	pub(super) synced: Option<Instant>,
+	// Still stating the last time, which a stale network filesystem can hang
+	pub(super) busy:   Arc<AtomicBool>,
+}
+
+impl Mounts {
+	pub fn start() -> Self {
+		// Checks frequently whether the cwd has changed, re-stating only if so,
+		// or once every `MOUNTS_INTERVAL`
+		tokio::spawn(async move {
+			loop {
+				emit!(Call(Cmd::new("refresh"), Layer::Mounts));
+				sleep(Duration::from_secs(1)).await;
+			}
+		});
+
+		Self::default()
+	}
+
+	/// The mounted filesystems with a size, i.e. without the pseudo ones.
+	#[cfg(unix)]
+	pub(crate) fn list(table: &[(String, PathBuf, String)]) -> Vec<Mount> {
+		let mut mounts: Vec<Mount> = vec![];
+		for (device, path, fstype) in table {
+			if mounts.iter().any(|m| m.path == *path) {
+				continue;
+			}
+			match yazi_shared::disk_space(path) {
+				Ok((free, total)) if total > 0 => {
+					let (path, device, fstype) = (path.clone(), device.clone(), fstype.clone());
+					mounts.push(Mount { path, device, fstype, free, total });
+				}
+				_ => {}
+			}
+		}
+		mounts
+	}
+
+	#[cfg(not(unix))]
+	pub(crate) fn list(_: &[(String, PathBuf, String)]) -> Vec<Mount> { vec![] }
+
+	/// The mount point and the type of the filesystem the path is on, in the `table`,
+	/// i.e. the longest mount point it's under, or the last of them if mounted over.
+	pub(crate) fn filesystem_of<'a>(
+		table: &'a [(String, PathBuf, String)],
+		path: &Path,
+	) -> Option<(&'a Path, &'a str)> {
+		table
+			.iter()
+			.filter(|(_, p, _)| path.starts_with(p))
+			.max_by_key(|(_, p, _)| p.components().count())
+			.map(|(_, p, t)| (p.as_path(), t.as_str()))
+	}
+
+	#[cfg(target_os = "linux")]
+	pub(crate) fn table() -> Vec<(String, PathBuf, String)> {
+		// Spaces and such are escaped in octal, e.g. "\040"
+		fn unescape(s: &str) -> String {
+			let mut out = Vec::with_capacity(s.len());
+			let mut it = s.bytes();
+			while let Some(b) = it.next() {
+				if b != b'\\' {
+					out.push(b);
+					continue;
+				}
+				let oct: Vec<_> = it.by_ref().take(3).collect();
+				match std::str::from_utf8(&oct).ok().and_then(|o| u8::from_str_radix(o, 8).ok()) {
+					Some(c) => out.push(c),
+					None => out.extend([b'\\'].iter().chain(&oct)),
+				}
+			}
+			String::from_utf8_lossy(&out).into_owned()
+		}
+
+		let s = std::fs::read_to_string("/proc/self/mounts").unwrap_or_default();
+		s.lines()
+			.filter_map(|l| {
+				let mut it = l.split(' ');
+				let (device, path, fstype) = (it.next()?, it.next()?, it.next()?);
+				Some((unescape(device), PathBuf::from(unescape(path)), fstype.to_owned()))
+			})
+			.collect()
+	}
+
+	#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "openbsd"))]
+	pub(crate) fn table() -> Vec<(String, PathBuf, String)> {
+		use std::{ffi::{CStr, OsStr}, os::unix::ffi::OsStrExt};
+
+		fn cstr(s: &[libc::c_char]) -> &CStr { unsafe { CStr::from_ptr(s.as_ptr()) } }
+
+		let mut buf: *mut libc::statfs = std::ptr::null_mut();
+		// Of what's known already, not to wait for a stale network filesystem
+		let n = unsafe { libc::getmntinfo(&mut buf, libc::MNT_NOWAIT) };
+		if n <= 0 || buf.is_null() {
+			return vec![];
+		}
+
+		unsafe { std::slice::from_raw_parts(buf, n as usize) }
+			.iter()
+			.map(|s| {
+				let path = PathBuf::from(OsStr::from_bytes(cstr(&s.f_mntonname).to_bytes()));
+				let device = cstr(&s.f_mntfromname).to_string_lossy().into_owned();
+				(device, path, cstr(&s.f_fstypename).to_string_lossy().into_owned())
+			})
+			.collect()
+	}
+
+	#[cfg(all(
+		unix,
+		not(any(
+			target_os = "linux",
+			target_os = "macos",
+			target_os = "freebsd",
+			target_os = "openbsd"
+		))
+	))]
+	pub(crate) fn table() -> Vec<(String, PathBuf, String)> {
+		vec![(String::new(), PathBuf::from("/"), String::new())]
+	}
+
+	#[cfg(not(unix))]
+	pub(crate) fn table() -> Vec<(String, PathBuf, String)> { vec![] }
+}
+
+impl Mounts {
+	#[inline]
+	pub fn hovered(&self) -> Option<&Mount> { self.items.get(self.cursor) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(unix)]
+	#[test]
+	fn test_filesystem_of() {
+		let t = |p: &str, fstype: &str| (String::new(), PathBuf::from(p), fstype.to_owned());
+		let table = [
+			t("/", "ext4"),
+			t("/home", "btrfs"),
+			t("/home/me/nas", "nfs4"),
+			t("/tmp", "ext4"),
+			t("/tmp", "tmpfs"),
+		];
+		let of = |p: &str| {
+			Mounts::filesystem_of(&table, Path::new(p)).map(|(m, t)| (m.to_str().unwrap(), t))
+		};
+
+		assert_eq!(of("/etc/hosts"), Some(("/", "ext4")));
+		assert_eq!(of("/home/me/a"), Some(("/home", "btrfs")));
+		assert_eq!(of("/home/me/nas/a"), Some(("/home/me/nas", "nfs4")));
+		assert_eq!(of("/home/me/nasty"), Some(("/home", "btrfs")));
+		assert_eq!(of("/tmp/a"), Some(("/tmp", "tmpfs")));
+		assert_eq!(of("a"), None);
+	}
+}
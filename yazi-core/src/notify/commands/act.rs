@@ -0,0 +1,14 @@
+use yazi_shared::{emit, event::Cmd, Layer};
+
+use crate::notify::Notify;
+
+impl Notify {
+	pub fn act(&mut self, _: Cmd) {
+		let Some(action) = self.history.get(self.cursor).and_then(|r| r.action.as_ref()) else {
+			return;
+		};
+
+		emit!(Call(action.clone_without_data(), Layer::Manager));
+		self.toggle(());
+	}
+}
@@ -0,0 +1,28 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::notify::Notify;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Notify {
+	pub fn arrow(&mut self, opt: impl Into<Opt>) {
+		let old = self.cursor;
+		let step = opt.into().step;
+		if step > 0 {
+			self.cursor = self.cursor.saturating_add(step.unsigned_abs());
+		} else {
+			self.cursor = self.cursor.saturating_sub(step.unsigned_abs());
+		}
+
+		self.cursor = self.cursor.min(self.history.len().saturating_sub(1));
+		render!(self.cursor != old);
+	}
+}
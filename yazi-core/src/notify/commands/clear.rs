@@ -0,0 +1,12 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::notify::Notify;
+
+impl Notify {
+	pub fn clear(&mut self, _: Cmd) {
+		render!(!self.history.is_empty());
+
+		self.history.clear();
+		self.cursor = 0;
+	}
+}
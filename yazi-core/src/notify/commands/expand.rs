@@ -0,0 +1,16 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::notify::Notify;
+
+impl Notify {
+	pub fn expand(&mut self, _: Cmd) {
+		let Some(r) = self.history.get_mut(self.cursor) else {
+			return;
+		};
+
+		if !r.details.is_empty() {
+			r.expanded = !r.expanded;
+			render!();
+		}
+	}
+}
@@ -1,2 +1,7 @@
+mod act;
+mod arrow;
+mod clear;
+mod expand;
 mod push;
 mod tick;
+mod toggle;
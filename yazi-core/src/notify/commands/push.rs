@@ -1,8 +1,9 @@
 use std::time::Instant;
 
-use yazi_shared::{emit, event::Cmd, Layer};
+use yazi_config::MANAGER;
+use yazi_shared::{emit, event::Cmd, render, term::Term, Layer};
 
-use crate::notify::{Message, Notify};
+use crate::notify::{Level, Message, Notify, NOTIFY_HISTORY};
 
 impl Notify {
 	pub fn push(&mut self, msg: impl TryInto<Message>) {
@@ -10,10 +11,87 @@ impl Notify {
 			return;
 		};
 
+		if let Some(id) = msg.id.clone() {
+			return self.update(&id, msg);
+		}
+
+		if msg.level == Level::Error {
+			Self::bell();
+		}
+		self.record(&msg);
+
+		let instant = Instant::now();
+		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
+
+		if let Some(m) = self.messages.iter_mut().find(|m| m.same(&msg) || m.same_group(&msg)) {
+			m.count += 1;
+			m.timeout = m.timeout.max(msg.timeout);
+			if m.content != msg.content {
+				m.lines = msg.lines;
+				m.content = msg.content;
+			}
+		} else {
+			self.messages.push(msg);
+		}
+
+		emit!(Call(Cmd::args("update_notify", vec![0.to_string()]), Layer::App));
+	}
+
+	/// Replaces the message with the same `id` in place, so that a long
+	/// running job can keep a single notification up to date,
+	/// or shows it as a new one if there's none.
+	pub fn update(&mut self, id: &str, mut msg: Message) {
+		msg.id = Some(id.to_owned());
+		self.record(&msg);
+
 		let instant = Instant::now();
 		msg.timeout += instant - self.messages.first().map_or(instant, |m| m.instant);
-		self.messages.push(msg);
+
+		if let Some(m) = self.messages.iter_mut().find(|m| m.id.as_deref() == Some(id)) {
+			if msg.level == Level::Error && m.level != Level::Error {
+				Self::bell();
+			}
+
+			m.title = msg.title;
+			m.content = msg.content;
+			m.level = msg.level;
+			m.lines = msg.lines;
+			m.timeout = msg.timeout;
+			m.progress = msg.progress;
+			m.action = msg.action;
+		} else {
+			if msg.level == Level::Error {
+				Self::bell();
+			}
+			self.messages.push(msg);
+		}
 
 		emit!(Call(Cmd::args("update_notify", vec![0.to_string()]), Layer::App));
 	}
+
+	#[inline]
+	fn bell() {
+		if MANAGER.notify_bell {
+			Term::bell();
+		}
+	}
+
+	fn record(&mut self, msg: &Message) {
+		if let Some(i) = self.history.iter().position(|r| r.owns(msg)) {
+			let mut r = self.history.remove(i).unwrap();
+			r.absorb(msg);
+			self.history.push_front(r);
+		} else if matches!(self.history.front(), Some(r) if r.same(msg)) {
+			let r = self.history.front_mut().unwrap();
+			r.count += 1;
+			r.instant = msg.instant;
+		} else {
+			self.history.push_front(msg.into());
+			self.history.truncate(NOTIFY_HISTORY);
+			if self.cursor > 0 {
+				self.cursor = (self.cursor + 1).min(self.history.len() - 1);
+			}
+		}
+		render!(self.visible);
+	}
 }
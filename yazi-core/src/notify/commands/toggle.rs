@@ -0,0 +1,23 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::notify::Notify;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Notify {
+	pub fn toggle(&mut self, _: impl Into<Opt>) {
+		self.visible = !self.visible;
+		if self.visible {
+			self.cursor = 0;
+		}
+
+		render!();
+	}
+}
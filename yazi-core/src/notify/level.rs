@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Level {
 	Info,
 	Warn,
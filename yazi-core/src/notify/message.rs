@@ -14,6 +14,15 @@ pub struct Message {
 
 	pub lines:   usize,
 	pub percent: u8,
+
+	pub count:  usize,
+	pub action: Option<Cmd>,
+
+	// Messages with the same `id` replace each other,
+	// and the ones with the same `group` are merged into one.
+	pub id:       Option<String>,
+	pub group:    Option<String>,
+	pub progress: Option<u8>,
 }
 
 impl TryFrom<Cmd> for Message {
@@ -25,6 +34,7 @@ impl TryFrom<Cmd> for Message {
 			return Err(());
 		}
 
+		// Translated by where they come from, as the ones with anything filled in can only be there
 		let content = c.take_name("content").ok_or(())?;
 		let lines = content.lines().count();
 		Ok(Self {
@@ -37,6 +47,13 @@ impl TryFrom<Cmd> for Message {
 
 			lines,
 			percent: 0,
+
+			count:  1,
+			action: c.take_name("action").and_then(|s| s.parse().ok()),
+
+			id:       c.take_name("id"),
+			group:    c.take_name("group"),
+			progress: c.take_name("progress").and_then(|s| s.parse::<u8>().ok()).map(|p| p.min(100)),
 		})
 	}
 }
@@ -44,4 +61,25 @@ impl TryFrom<Cmd> for Message {
 impl Message {
 	#[inline]
 	pub fn height(&self) -> usize { self.lines + NOTIFY_BORDER as usize }
+
+	#[inline]
+	pub(super) fn same(&self, other: &Self) -> bool {
+		self.level == other.level && self.title == other.title && self.content == other.content
+	}
+
+	#[inline]
+	pub(super) fn same_group(&self, other: &Self) -> bool {
+		other.group.is_some() && self.group == other.group && self.level == other.level
+	}
+
+	pub fn title(&self) -> String {
+		let mut s = self.title.clone();
+		if let Some(p) = self.progress {
+			s.push_str(&format!(" {p}%"));
+		}
+		if self.count > 1 {
+			s.push_str(&format!(" ({})", self.count));
+		}
+		s
+	}
 }
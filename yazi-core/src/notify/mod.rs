@@ -2,10 +2,13 @@ mod commands;
 mod level;
 mod message;
 mod notify;
+mod record;
 
 pub use level::*;
 pub use message::*;
 pub use notify::*;
+pub use record::*;
 
 pub const NOTIFY_BORDER: u16 = 2;
 pub const NOTIFY_SPACING: u16 = 1;
+pub const NOTIFY_HISTORY: usize = 100;
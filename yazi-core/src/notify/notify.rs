@@ -1,14 +1,19 @@
-use std::ops::ControlFlow;
+use std::{collections::VecDeque, ops::ControlFlow};
 
 use tokio::task::JoinHandle;
 use yazi_shared::term::Term;
 
-use super::{Message, NOTIFY_SPACING};
+use super::{Message, Record, NOTIFY_SPACING};
 
 #[derive(Default)]
 pub struct Notify {
 	pub(super) tick_handle: Option<JoinHandle<()>>,
 	pub messages:           Vec<Message>,
+
+	// History, the latest one comes first
+	pub history: VecDeque<Record>,
+	pub visible: bool,
+	pub cursor:  usize,
 }
 
 impl Notify {
@@ -0,0 +1,77 @@
+use std::time::Instant;
+
+use yazi_shared::event::Cmd;
+
+use super::{Level, Message, NOTIFY_HISTORY};
+
+/// A notification kept in the history, after its toast has gone.
+pub struct Record {
+	pub title:   String,
+	pub content: String,
+	pub level:   Level,
+
+	pub instant: Instant,
+	pub count:   usize,
+	pub action:  Option<Cmd>,
+
+	pub id:       Option<String>,
+	pub group:    Option<String>,
+	pub progress: Option<u8>,
+
+	// Contents of the grouped ones, shown when expanded
+	pub details:  Vec<String>,
+	pub expanded: bool,
+}
+
+impl From<&Message> for Record {
+	fn from(m: &Message) -> Self {
+		Self {
+			title:   m.title.clone(),
+			content: m.content.clone(),
+			level:   m.level,
+
+			instant: m.instant,
+			count:   1,
+			action:  m.action.as_ref().map(|c| c.clone_without_data()),
+
+			id:       m.id.clone(),
+			group:    m.group.clone(),
+			progress: m.progress,
+
+			details:  if m.group.is_some() { vec![m.content.clone()] } else { vec![] },
+			expanded: false,
+		}
+	}
+}
+
+impl Record {
+	#[inline]
+	pub(super) fn same(&self, m: &Message) -> bool {
+		self.level == m.level && self.title == m.title && self.content == m.content
+	}
+
+	/// Whether the message belongs to this record, either by having the
+	/// same `id` or the same `group`.
+	#[inline]
+	pub(super) fn owns(&self, m: &Message) -> bool {
+		(m.id.is_some() && self.id == m.id)
+			|| (m.group.is_some() && self.group == m.group && self.level == m.level)
+	}
+
+	pub(super) fn absorb(&mut self, m: &Message) {
+		self.instant = m.instant;
+		if m.id.is_some() && self.id == m.id {
+			self.title.clone_from(&m.title);
+			self.content.clone_from(&m.content);
+			self.level = m.level;
+			self.progress = m.progress;
+		} else {
+			self.count += 1;
+			self.content.clone_from(&m.content);
+			self.details.push(m.content.clone());
+			if self.details.len() > NOTIFY_HISTORY {
+				self.details.remove(0);
+			}
+		}
+	}
+}
@@ -18,7 +18,8 @@ impl Select {
 	pub fn close(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		if let Some(cb) = self.callback.take() {
-			_ = cb.send(if opt.submit { Ok(self.cursor) } else { Err(anyhow!("canceled")) });
+			let on = self.toggle.as_ref().is_some_and(|t| t.1);
+			_ = cb.send(if opt.submit { Ok((self.cursor, on)) } else { Err(anyhow!("canceled")) });
 		}
 
 		self.cursor = 0;
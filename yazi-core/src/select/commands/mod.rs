@@ -1,3 +1,4 @@
 mod arrow;
 mod close;
 mod show;
+mod toggle;
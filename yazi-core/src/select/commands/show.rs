@@ -7,7 +7,7 @@ use crate::select::Select;
 
 pub struct Opt {
 	cfg: SelectCfg,
-	tx:  oneshot::Sender<Result<usize>>,
+	tx:  oneshot::Sender<Result<(usize, bool)>>,
 }
 
 impl TryFrom<Cmd> for Opt {
@@ -17,7 +17,11 @@ impl TryFrom<Cmd> for Opt {
 }
 
 impl Select {
-	pub async fn _show(cfg: SelectCfg) -> Result<usize> {
+	#[inline]
+	pub async fn _show(cfg: SelectCfg) -> Result<usize> { Ok(Self::_show_toggle(cfg).await?.0) }
+
+	/// The choice, and whether the toggle of the `cfg` is on when it's made.
+	pub async fn _show_toggle(cfg: SelectCfg) -> Result<(usize, bool)> {
 		let (tx, rx) = oneshot::channel();
 		emit!(Call(Cmd::new("show").with_data(Opt { cfg, tx }), Layer::Select));
 		rx.await.unwrap_or_else(|_| Term::goodbye(|| false))
@@ -32,6 +36,7 @@ impl Select {
 		self.title = opt.cfg.title;
 		self.items = opt.cfg.items;
 		self.position = opt.cfg.position;
+		self.toggle = opt.cfg.toggle.map(|s| (s, false));
 
 		self.callback = Some(opt.tx);
 		self.visible = true;
@@ -0,0 +1,12 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::select::Select;
+
+impl Select {
+	pub fn toggle(&mut self, _: Cmd) {
+		if let Some((_, on)) = &mut self.toggle {
+			*on = !*on;
+			render!();
+		}
+	}
+}
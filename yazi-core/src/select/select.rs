@@ -8,9 +8,12 @@ pub struct Select {
 	pub(super) items: Vec<String>,
 	pub position:     Position,
 
+	// The label of the toggle shown under the items, and whether it's on
+	pub(super) toggle: Option<(String, bool)>,
+
 	pub(super) offset:   usize,
 	pub(super) cursor:   usize,
-	pub(super) callback: Option<Sender<Result<usize>>>,
+	pub(super) callback: Option<Sender<Result<(usize, bool)>>>,
 
 	pub visible: bool,
 }
@@ -32,6 +35,11 @@ impl Select {
 	#[inline]
 	pub fn title(&self) -> String { self.title.clone() }
 
+	#[inline]
+	pub fn toggle_state(&self) -> Option<(&str, bool)> {
+		self.toggle.as_ref().map(|(s, b)| (s.as_str(), *b))
+	}
+
 	#[inline]
 	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
 }
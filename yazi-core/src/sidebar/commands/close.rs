@@ -0,0 +1,27 @@
+use yazi_shared::{emit, event::Cmd, render};
+
+use crate::sidebar::Sidebar;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Sidebar {
+	pub fn close(&mut self, _: impl Into<Opt>) {
+		self.focused = false;
+
+		// Hidden again if it's only shown to be focused
+		if self.transient {
+			self.transient = false;
+			self.visible = false;
+			emit!(Resize);
+		}
+
+		render!();
+	}
+}
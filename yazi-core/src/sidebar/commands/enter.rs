@@ -0,0 +1,14 @@
+use yazi_shared::{event::Cmd, fs::Url};
+
+use crate::{sidebar::Sidebar, tab::Tab};
+
+impl Sidebar {
+	pub fn enter(&mut self, _: Cmd) {
+		let Some((_, path)) = self.hovered() else {
+			return;
+		};
+
+		Tab::_cd(&Url::from(path));
+		self.close(());
+	}
+}
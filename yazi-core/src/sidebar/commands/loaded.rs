@@ -0,0 +1,39 @@
+use std::{collections::HashMap, mem, path::PathBuf};
+
+use yazi_shared::{event::Cmd, render};
+
+use crate::sidebar::Sidebar;
+
+impl Sidebar {
+	pub fn loaded(&mut self, mut c: Cmd) {
+		let Some((pinned, visits)) = c.take_data::<(Vec<PathBuf>, HashMap<PathBuf, (u32, u64)>)>()
+		else {
+			return;
+		};
+
+		// Kept along with them, what's been pinned or visited while they were being read
+		let pins = mem::replace(&mut self.pinned, pinned);
+		let seen = mem::replace(&mut self.visits, visits);
+		self.loading = false;
+
+		if !pins.is_empty() {
+			for p in pins {
+				if !self.pinned.contains(&p) {
+					self.pinned.push(p);
+				}
+			}
+			self.save_pinned();
+		}
+		if !seen.is_empty() {
+			for (p, (count, last)) in seen {
+				let visit = self.visits.entry(p).or_default();
+				*visit = (visit.0.saturating_add(count), visit.1.max(last));
+			}
+			self.save_visits();
+		}
+
+		self.rank();
+		self.cursor = self.cursor.min(self.entries().len().saturating_sub(1));
+		render!();
+	}
+}
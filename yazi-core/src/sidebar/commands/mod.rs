@@ -0,0 +1,10 @@
+mod arrow;
+mod close;
+mod enter;
+mod loaded;
+mod move_;
+mod pin;
+mod remove;
+mod toggle;
+mod update;
+mod visit;
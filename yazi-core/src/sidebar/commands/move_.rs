@@ -0,0 +1,36 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::sidebar::{Sidebar, SidebarKind};
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Sidebar {
+	/// Moves the hovered pinned directory up or down in the pinned ones.
+	pub fn move_(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if !matches!(self.hovered(), Some((SidebarKind::Pinned, _))) {
+			return;
+		}
+
+		// The pinned are listed first, so the cursor is the index of it
+		let new = self.cursor.saturating_add_signed(opt.step).min(self.pinned.len() - 1);
+		if new == self.cursor {
+			return;
+		}
+
+		let path = self.pinned.remove(self.cursor);
+		self.pinned.insert(new, path);
+		self.cursor = new;
+
+		self.save_pinned();
+		render!();
+	}
+}
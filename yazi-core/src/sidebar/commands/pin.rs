@@ -0,0 +1,22 @@
+use yazi_shared::{fs::Url, render};
+
+use crate::sidebar::Sidebar;
+
+impl Sidebar {
+	/// Pins the directory, or unpins it if it's already pinned.
+	pub fn pin(&mut self, cwd: &Url) {
+		if !cwd.is_regular() {
+			return;
+		}
+
+		if let Some(i) = self.pinned.iter().position(|p| p == &**cwd) {
+			self.pinned.remove(i);
+		} else {
+			self.pinned.push(cwd.to_path_buf());
+		}
+
+		self.cursor = self.cursor.min(self.entries().len().saturating_sub(1));
+		self.save_pinned();
+		render!();
+	}
+}
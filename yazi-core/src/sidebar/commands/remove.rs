@@ -0,0 +1,29 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::sidebar::{Sidebar, SidebarKind};
+
+impl Sidebar {
+	/// Unpins the hovered directory, or forgets the visits of a frecent one.
+	pub fn remove(&mut self, _: Cmd) {
+		let Some((kind, path)) = self.hovered() else {
+			return;
+		};
+
+		let path = path.to_owned();
+		match kind {
+			SidebarKind::Pinned => {
+				self.pinned.retain(|p| *p != path);
+				self.save_pinned();
+			}
+			SidebarKind::Mount => return,
+			SidebarKind::Frecent => {
+				self.visits.remove(&path);
+				self.rank();
+				self.save_visits();
+			}
+		}
+
+		self.cursor = self.cursor.min(self.entries().len().saturating_sub(1));
+		render!();
+	}
+}
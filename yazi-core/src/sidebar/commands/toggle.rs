@@ -0,0 +1,40 @@
+use yazi_shared::{emit, event::Cmd, render, Layer};
+
+use crate::sidebar::Sidebar;
+
+pub struct Opt {
+	focus: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { focus: c.named.contains_key("focus") } }
+}
+
+impl Sidebar {
+	/// Shows or hides the sidebar, or with `--focus`, moves the focus to it, or back.
+	pub fn toggle(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.focus && self.focused {
+			return self.close(());
+		}
+
+		let visible = self.visible;
+		if opt.focus {
+			(self.focused, self.transient) = (true, !visible);
+			self.visible = true;
+		} else {
+			self.visible = !visible;
+			self.focused &= self.visible;
+			self.transient = false;
+		}
+
+		if self.visible != visible {
+			// The mounts are listed right away, and the panes resized to make room
+			if self.visible {
+				emit!(Call(Cmd::new("refresh").with_bool("force", true), Layer::Mounts));
+			}
+			emit!(Resize);
+		}
+		render!();
+	}
+}
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+use yazi_shared::{event::Cmd, render};
+
+use crate::sidebar::Sidebar;
+
+impl Sidebar {
+	pub fn update(&mut self, mut c: Cmd) {
+		let Some(mounts) = c.take_data::<Vec<PathBuf>>() else {
+			return;
+		};
+
+		if mounts != self.mounts {
+			self.mounts = mounts;
+			self.cursor = self.cursor.min(self.entries().len().saturating_sub(1));
+			render!();
+		}
+	}
+}
@@ -0,0 +1,21 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use yazi_shared::{event::Cmd, fs::Url, render};
+
+use crate::sidebar::Sidebar;
+
+impl Sidebar {
+	pub fn visit(&mut self, mut c: Cmd) {
+		let Some(url) = c.take_data::<Url>().filter(|u| u.is_regular()) else {
+			return;
+		};
+
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+		let visit = self.visits.entry(url.to_path_buf()).or_default();
+		*visit = (visit.0.saturating_add(1), now);
+
+		self.rank();
+		self.save_visits();
+		render!(self.visible);
+	}
+}
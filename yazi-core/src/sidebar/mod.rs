@@ -0,0 +1,4 @@
+mod commands;
+mod sidebar;
+
+pub use sidebar::*;
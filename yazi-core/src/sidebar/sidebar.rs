@@ -0,0 +1,178 @@
+use std::{collections::HashMap, env, path::{Path, PathBuf, MAIN_SEPARATOR}, str, time::{SystemTime, UNIX_EPOCH}};
+
+use tokio::fs;
+use yazi_config::{BOOT, MANAGER};
+use yazi_shared::{emit, event::Cmd, fs::{lossless, Url}, Layer};
+
+use crate::Snapshot;
+
+// How many of the most frecent directories are listed, and how many are remembered
+const FRECENT_LIMIT: usize = 8;
+const FRECENT_CAPACITY: usize = 500;
+
+// The content last saved of each file, for a write that's run later not to be overtaken by
+// an earlier one still going, and the writing of it, one at a time
+static PINNED: Snapshot = Snapshot::new();
+static VISITS: Snapshot = Snapshot::new();
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SidebarKind {
+	Pinned,
+	Mount,
+	Frecent,
+}
+
+/// The directories pinned by the user, the mount points, and those visited the most often
+/// and recently, the frecent ones, docked at the left of the panes.
+#[derive(Default)]
+pub struct Sidebar {
+	pub visible: bool,
+	pub focused: bool,
+	pub cursor:  usize,
+
+	pub pinned:  Vec<PathBuf>,
+	pub mounts:  Vec<PathBuf>,
+	pub frecent: Vec<PathBuf>,
+
+	// The number of visits of each directory, and the last one in seconds since the epoch
+	pub(super) visits: HashMap<PathBuf, (u32, u64)>,
+
+	// Whether it's only shown while focused
+	pub(super) transient: bool,
+	// Whether the pinned and the visits are still being read, not to be saved over until then
+	pub(super) loading:   bool,
+}
+
+impl Sidebar {
+	/// An empty sidebar, whose pinned directories and visits are read in the background,
+	/// and handed back to it by the `loaded` command once they're read.
+	pub fn start() -> Self {
+		tokio::spawn(async {
+			let pinned = fs::read(Self::pinned_path()).await.unwrap_or_default();
+			let visits = fs::read(Self::visits_path()).await.unwrap_or_default();
+
+			let pinned: Vec<_> = pinned
+				.split(|&b| b == b'\n')
+				.filter(|l| !l.is_empty())
+				.map(|l| lossless::path_from_bytes(l.to_vec()))
+				.collect();
+			let visits: HashMap<_, _> = visits
+				.split(|&b| b == b'\n')
+				.filter_map(|l| {
+					let mut it = l.splitn(3, |&b| b == b' ');
+					let count: u32 = str::from_utf8(it.next()?).ok()?.parse().ok()?;
+					let last: u64 = str::from_utf8(it.next()?).ok()?.parse().ok()?;
+					Some((lossless::path_from_bytes(it.next()?.to_vec()), (count, last)))
+				})
+				.collect();
+			emit!(Call(Cmd::new("loaded").with_data((pinned, visits)), Layer::Sidebar));
+		});
+
+		Self { visible: MANAGER.sidebar, loading: true, ..Default::default() }
+	}
+
+	/// The entries in the order they're listed, the pinned first, then the mounts and the frecent
+	/// ones that aren't pinned.
+	pub fn entries(&self) -> Vec<(SidebarKind, &Path)> {
+		let mut entries: Vec<_> =
+			self.pinned.iter().map(|p| (SidebarKind::Pinned, p.as_path())).collect();
+		for p in &self.mounts {
+			if !self.pinned.contains(p) {
+				entries.push((SidebarKind::Mount, p));
+			}
+		}
+		for p in &self.frecent {
+			if !self.pinned.contains(p) && !self.mounts.contains(p) {
+				entries.push((SidebarKind::Frecent, p));
+			}
+		}
+		entries
+	}
+
+	#[inline]
+	pub fn hovered(&self) -> Option<(SidebarKind, &Path)> { self.entries().get(self.cursor).copied() }
+
+	/// The path, with the home directory shortened to `~`.
+	pub fn readable(path: &Path) -> String {
+		match env::var_os("HOME").and_then(|h| path.strip_prefix(h).ok().map(ToOwned::to_owned)) {
+			Some(p) if p.as_os_str().is_empty() => "~".to_owned(),
+			Some(p) => format!("~{MAIN_SEPARATOR}{}", p.display()),
+			None => path.display().to_string(),
+		}
+	}
+
+	/// Ranks the visited directories by their frecency, the number of visits weighted by
+	/// how recent the last one is.
+	pub(super) fn rank(&mut self) {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+		let score = |&(count, last): &(u32, u64)| {
+			let weight = match now.saturating_sub(last) {
+				0..=3600 => 4.0,
+				3601..=86400 => 2.0,
+				86401..=604800 => 0.5,
+				_ => 0.25,
+			};
+			count as f64 * weight
+		};
+
+		let mut ranked: Vec<_> = self.visits.iter().map(|(p, v)| (p, score(v))).collect();
+		ranked.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+		self.frecent = ranked.into_iter().take(FRECENT_LIMIT).map(|(p, _)| p.clone()).collect();
+	}
+
+	/// Counts a visit of the directory, entered by the user.
+	#[inline]
+	pub fn _visit(url: &Url) {
+		emit!(Call(Cmd::new("visit").with_data(url.clone()), Layer::Sidebar));
+	}
+
+	pub(super) fn save_pinned(&self) {
+		if self.loading {
+			return;
+		}
+
+		let mut s = vec![];
+		for p in self.pinned.iter().filter_map(|p| Self::line(p)) {
+			s.extend_from_slice(p);
+			s.push(b'\n');
+		}
+		PINNED.save(Self::pinned_path(), s);
+	}
+
+	pub(super) fn save_visits(&mut self) {
+		if self.loading {
+			return;
+		}
+
+		// The least recently visited are forgotten, once there are too many
+		if self.visits.len() > FRECENT_CAPACITY {
+			let mut all: Vec<_> = self.visits.iter().map(|(p, &(_, last))| (last, p.clone())).collect();
+			all.sort_unstable();
+			for (_, p) in all.into_iter().take(self.visits.len() - FRECENT_CAPACITY) {
+				self.visits.remove(&p);
+			}
+		}
+
+		let mut s = vec![];
+		for (p, (count, last)) in &self.visits {
+			if let Some(p) = Self::line(p) {
+				s.extend_from_slice(format!("{count} {last} ").as_bytes());
+				s.extend_from_slice(p);
+				s.push(b'\n');
+			}
+		}
+		VISITS.save(Self::visits_path(), s);
+	}
+
+	// The path as it is, the bytes of it if it's not UTF-8, unless it has a newline to break the line
+	#[inline]
+	fn line(p: &Path) -> Option<&[u8]> {
+		Some(p.as_os_str().as_encoded_bytes()).filter(|b| !b.contains(&b'\n'))
+	}
+
+	#[inline]
+	fn pinned_path() -> PathBuf { BOOT.state_dir.join("pinned") }
+
+	#[inline]
+	fn visits_path() -> PathBuf { BOOT.state_dir.join("visits") }
+}
@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use parking_lot::{const_mutex, Mutex};
+
+/// The content last saved of a file, written in the background, one write at a time.
+pub(crate) struct Snapshot {
+	latest:  Mutex<Option<Vec<u8>>>,
+	writing: tokio::sync::Mutex<()>,
+}
+
+impl Snapshot {
+	pub(crate) const fn new() -> Self {
+		Self { latest: const_mutex(None), writing: tokio::sync::Mutex::const_new(()) }
+	}
+
+	// Whichever write gets to it first writes the latest content, and those after it have none left
+	pub(crate) fn save(&'static self, path: PathBuf, s: impl Into<Vec<u8>>) {
+		*self.latest.lock() = Some(s.into());
+		tokio::spawn(async move {
+			let _guard = self.writing.lock().await;
+			let Some(s) = self.latest.lock().take() else { return };
+			tokio::fs::write(path, s).await.ok();
+		});
+	}
+}
@@ -0,0 +1,70 @@
+use yazi_config::{popup::{Offset, Position, SelectPopup}, SELECT};
+use yazi_scheduler::ChecksumReport;
+use yazi_shared::{event::Cmd, render};
+
+use crate::{spot::Spot, CLIPBOARD};
+
+pub struct Opt {
+	report: ChecksumReport,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { report: c.take_data().ok_or(())? })
+	}
+}
+
+impl Spot {
+	pub fn checksums(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		self.close(());
+		let ChecksumReport { algo, entries, copy } = opt.report;
+		let verify = entries.iter().any(|e| e.expected.is_some());
+
+		self.label = Some(if verify {
+			let passed = entries.iter().filter(|e| e.passed() == Some(true)).count();
+			format!("{algo}: {passed} of {} passed", entries.len())
+		} else {
+			format!("{algo} checksums")
+		});
+
+		if copy && !verify {
+			let s = entries
+				.iter()
+				.filter_map(|e| e.actual.as_ref().ok().map(|a| format!("{a}  {}", e.name)))
+				.collect::<Vec<_>>()
+				.join("\n");
+			futures::executor::block_on(CLIPBOARD.set(s));
+		}
+
+		for e in entries {
+			let verdict = if e.actual.is_err() { Some(false) } else { e.passed() };
+			let value = match (verdict, e.actual) {
+				(_, Err(err)) => err,
+				(Some(true), _) => "OK".to_owned(),
+				(Some(false), _) => "FAILED".to_owned(),
+				(None, Ok(hash)) => hash,
+			};
+			self.rows.push((e.name, value));
+			self.verdicts.push(verdict);
+		}
+
+		let width = self.rows.iter().map(|(k, v)| k.len().min(24) + v.len() + 5).max().unwrap_or(0);
+		let popup = SELECT.popups.get(SelectPopup::Checksum);
+		let height = popup.offset.height.min(SELECT.border() + self.rows.len() as u16);
+		self.position = Position::new(popup.origin, Offset {
+			width: popup.offset.width.max(width as u16),
+			height,
+			..popup.offset
+		});
+
+		self.checksum = true;
+		self.visible = true;
+		render!();
+	}
+}
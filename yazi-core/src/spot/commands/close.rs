@@ -0,0 +1,32 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::spot::Spot;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+impl From<()> for Opt {
+	fn from(_: ()) -> Self { Self }
+}
+
+impl Spot {
+	pub fn close(&mut self, _: impl Into<Opt>) {
+		if let Some(ct) = self.ct.take() {
+			ct.cancel();
+		}
+		self.url = None;
+		self.label = None;
+		self.rows.clear();
+		self.verdicts.clear();
+		self.checksum = false;
+		self.tabs.clear();
+		self.tab = 0;
+
+		self.cursor = 0;
+		self.offset = 0;
+		self.visible = false;
+		render!();
+	}
+}
@@ -0,0 +1,27 @@
+use yazi_shared::event::Cmd;
+
+use crate::{spot::Spot, CLIPBOARD};
+
+pub struct Opt {
+	all: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { all: c.named.contains_key("all") } }
+}
+
+impl Spot {
+	pub fn copy(&self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+
+		let s = if opt.all {
+			self.rows().iter().map(|(k, v)| format!("{k}: {v}")).collect::<Vec<_>>().join("\n")
+		} else if let Some((_, v)) = self.rows().get(self.cursor) {
+			v.clone()
+		} else {
+			return;
+		};
+
+		futures::executor::block_on(CLIPBOARD.set(s));
+	}
+}
@@ -0,0 +1,46 @@
+use yazi_shared::{emit, event::Cmd, fs::Url, render, Layer};
+
+use crate::spot::Spot;
+
+pub struct Opt {
+	url:   Url,
+	tab:   usize,
+	title: Option<String>,
+	rows:  Vec<(String, String)>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_data().ok_or(()) }
+}
+
+impl Spot {
+	#[inline]
+	pub fn _fill(url: Url, tab: usize, title: Option<String>, rows: Vec<(String, String)>) {
+		emit!(Call(Cmd::new("fill").with_data(Opt { url, tab, title, rows }), Layer::Spot));
+	}
+
+	pub fn fill(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+		if self.url.as_ref() != Some(&opt.url) {
+			return;
+		}
+
+		if opt.tab == 0 {
+			self.rows = opt.rows;
+		} else if let Some(tab) = self.tabs.get_mut(opt.tab - 1) {
+			if let Some(title) = opt.title {
+				tab.title = title;
+			}
+			tab.rows = Some(opt.rows);
+		} else {
+			return;
+		}
+
+		self.resize();
+		render!();
+	}
+}
@@ -0,0 +1,7 @@
+mod arrow;
+mod checksums;
+mod close;
+mod copy;
+mod fill;
+mod show;
+mod tab;
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use tokio::{select, time::sleep};
+use tokio_util::sync::CancellationToken;
+use yazi_config::PLUGIN;
+use yazi_plugin::isolate;
+use yazi_shared::{emit, event::Cmd, fs::{File, Url}, render, Layer};
+
+use crate::spot::{metadata, Spot, SpotTab};
+
+// How long a spotter may take, before it's stopped, for a hung one not to be left running
+const SPOT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct Opt {
+	url:  Url,
+	mime: Option<String>,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
+		Ok(Self { url: c.take_data().ok_or(())?, mime: c.take_name("mime") })
+	}
+}
+
+impl Spot {
+	#[inline]
+	pub fn _show(url: Url, mime: Option<&str>) {
+		let mut cmd = Cmd::new("show").with_data(url);
+		if let Some(mime) = mime {
+			cmd = cmd.with("mime", mime);
+		}
+		emit!(Call(cmd, Layer::Spot));
+	}
+
+	pub fn show(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		self.close(());
+
+		// Of the syscalls and lookups of the users and groups, which may block, so off the event loop
+		let url = opt.url.clone();
+		tokio::task::spawn_blocking(move || {
+			let rows = metadata::collect(&url);
+			Self::_fill(url, 0, None, rows);
+		});
+
+		// Cancelled once the spot is closed, or each of them on its own if it takes too long
+		let ct = self.ct.insert(CancellationToken::new());
+		for (i, rule) in PLUGIN.spotters(&opt.url, opt.mime.as_deref()).into_iter().enumerate() {
+			let name = rule.cmd.name.clone();
+			self.tabs.push(SpotTab { title: name.clone(), rows: None });
+
+			let (url, ct) = (opt.url.clone(), ct.child_token());
+			tokio::spawn(async move {
+				let result = match File::from(url.clone()).await {
+					Ok(file) => select! {
+						r = isolate::spot(&name, file, ct.clone()) => r.map_err(|e| e.to_string()),
+						_ = sleep(SPOT_TIMEOUT) => {
+							ct.cancel();
+							Err(format!("Timed out after {}s", SPOT_TIMEOUT.as_secs()))
+						}
+					},
+					Err(e) => Err(e.to_string()),
+				};
+				let (title, rows) = result.unwrap_or_else(|e| (None, vec![("Error".to_owned(), e)]));
+				Self::_fill(url, i + 1, title, rows);
+			});
+		}
+
+		self.url = Some(opt.url);
+		self.resize();
+
+		self.visible = true;
+		render!();
+	}
+}
@@ -0,0 +1,30 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::spot::Spot;
+
+pub struct Opt {
+	step: isize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or(0) }
+	}
+}
+
+impl Spot {
+	pub fn tab(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+
+		let len = self.tabs.len() as isize + 1;
+		let tab = (self.tab as isize + opt.step).rem_euclid(len) as usize;
+		if tab == self.tab {
+			return;
+		}
+
+		self.tab = tab;
+		self.cursor = 0;
+		self.offset = 0;
+		render!();
+	}
+}
@@ -0,0 +1,249 @@
+use std::{fs, sync::atomic::Ordering, time::SystemTime};
+
+use yazi_config::format_time;
+use yazi_shared::fs::{readable_size, Url, SIZE_RAW};
+
+/// Collects the metadata of a file, as the rows of the spot popup.
+pub(super) fn collect(url: &Url) -> Vec<(String, String)> {
+	let mut rows = vec![];
+	macro_rules! push {
+		($k:expr, $v:expr) => {
+			rows.push(($k.to_owned(), $v))
+		};
+	}
+
+	push!("Path", url.display().to_string());
+	let meta = match fs::symlink_metadata(url) {
+		Ok(m) => m,
+		Err(e) => {
+			push!("Error", e.to_string());
+			return rows;
+		}
+	};
+
+	push!("Type", kind(&meta.file_type()).to_owned());
+	if SIZE_RAW.load(Ordering::Relaxed) {
+		push!("Size", format!("{} bytes", meta.len()));
+	} else {
+		push!("Size", format!("{} ({} bytes)", readable_size(meta.len()), meta.len()));
+	}
+	if meta.is_symlink() {
+		let target = fs::read_link(url).map_or_else(|e| e.to_string(), |p| p.display().to_string());
+		let broken = if fs::metadata(url).is_err() { " (broken)" } else { "" };
+		push!("Link target", format!("{target}{broken}"));
+	}
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::MetadataExt;
+
+		use uzers::{get_group_by_gid, get_user_by_uid};
+
+		let mode = meta.mode();
+		let perm = yazi_shared::fs::permissions(mode as _);
+		push!("Permissions", format!("{perm} ({:04o})", mode & 0o7777));
+
+		let user = get_user_by_uid(meta.uid()).map(|u| u.name().to_string_lossy().into_owned());
+		push!("Owner", format!("{} ({})", user.as_deref().unwrap_or("?"), meta.uid()));
+		let group = get_group_by_gid(meta.gid()).map(|g| g.name().to_string_lossy().into_owned());
+		push!("Group", format!("{} ({})", group.as_deref().unwrap_or("?"), meta.gid()));
+	}
+
+	let times = [meta.modified(), meta.accessed(), meta.created()];
+	for (k, time) in ["Modified", "Accessed", "Created"].into_iter().zip(times) {
+		if let Ok(time) = time {
+			push!(k, datetime(time));
+		}
+	}
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::MetadataExt;
+		push!("Inode", meta.ino().to_string());
+		push!("Hard links", meta.nlink().to_string());
+	}
+
+	#[cfg(target_os = "linux")]
+	for (name, value) in xattrs(url) {
+		match name.as_str() {
+			"security.capability" => {
+				push!("Capabilities", capabilities(&value).unwrap_or_else(|| xattr_value(&value)))
+			}
+			"security.selinux" => push!("SELinux context", xattr_value(&value)),
+			_ => push!(&name, xattr_value(&value)),
+		}
+	}
+
+	rows
+}
+
+fn kind(ft: &fs::FileType) -> &'static str {
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::FileTypeExt;
+		if ft.is_block_device() {
+			return "Block device";
+		} else if ft.is_char_device() {
+			return "Character device";
+		} else if ft.is_fifo() {
+			return "FIFO";
+		} else if ft.is_socket() {
+			return "Socket";
+		}
+	}
+
+	if ft.is_symlink() {
+		"Symbolic link"
+	} else if ft.is_dir() {
+		"Directory"
+	} else if ft.is_file() {
+		"Regular file"
+	} else {
+		"Unknown"
+	}
+}
+
+#[inline]
+fn datetime(time: SystemTime) -> String {
+	format!("{} ({})", format_time(time, "%Y-%m-%d %H:%M:%S"), format_time(time, "relative"))
+}
+
+// The extended attributes of a file, without following symlinks
+#[cfg(target_os = "linux")]
+fn xattrs(url: &Url) -> Vec<(String, Vec<u8>)> {
+	use std::{ffi::CString, os::unix::ffi::OsStrExt};
+
+	fn read(f: impl Fn(*mut libc::c_void, usize) -> libc::ssize_t) -> Option<Vec<u8>> {
+		let len = f(std::ptr::null_mut(), 0);
+		let mut buf = vec![0u8; usize::try_from(len).ok()?];
+		let len = f(buf.as_mut_ptr().cast(), buf.len());
+		buf.truncate(usize::try_from(len).ok()?);
+		Some(buf)
+	}
+
+	let Ok(path) = CString::new(url.as_os_str().as_bytes()) else {
+		return vec![];
+	};
+	let Some(names) = read(|b, n| unsafe { libc::llistxattr(path.as_ptr(), b.cast(), n) }) else {
+		return vec![];
+	};
+
+	names
+		.split(|&b| b == 0)
+		.filter(|s| !s.is_empty())
+		.filter_map(|name| {
+			let name = CString::new(name).ok()?;
+			let value = read(|b, n| unsafe { libc::lgetxattr(path.as_ptr(), name.as_ptr(), b, n) })?;
+			Some((name.to_string_lossy().into_owned(), value))
+		})
+		.collect()
+}
+
+// Text as it is, otherwise the leading bytes in hex
+#[cfg(target_os = "linux")]
+fn xattr_value(value: &[u8]) -> String {
+	let trimmed = value.strip_suffix(&[0]).unwrap_or(value);
+	match std::str::from_utf8(trimmed) {
+		Ok(s) if !s.chars().any(char::is_control) => s.to_owned(),
+		_ => {
+			let hex: String = value.iter().take(32).map(|b| format!("{b:02x}")).collect();
+			format!("0x{hex}{}", if value.len() > 32 { "…" } else { "" })
+		}
+	}
+}
+
+// Decodes `security.capability` the way getcap(8) prints it, e.g. "cap_net_raw=ep"
+#[cfg(target_os = "linux")]
+fn capabilities(value: &[u8]) -> Option<String> {
+	const NAMES: [&str; 41] = [
+		"cap_chown",
+		"cap_dac_override",
+		"cap_dac_read_search",
+		"cap_fowner",
+		"cap_fsetid",
+		"cap_kill",
+		"cap_setgid",
+		"cap_setuid",
+		"cap_setpcap",
+		"cap_linux_immutable",
+		"cap_net_bind_service",
+		"cap_net_broadcast",
+		"cap_net_admin",
+		"cap_net_raw",
+		"cap_ipc_lock",
+		"cap_ipc_owner",
+		"cap_sys_module",
+		"cap_sys_rawio",
+		"cap_sys_chroot",
+		"cap_sys_ptrace",
+		"cap_sys_pacct",
+		"cap_sys_admin",
+		"cap_sys_boot",
+		"cap_sys_nice",
+		"cap_sys_resource",
+		"cap_sys_time",
+		"cap_sys_tty_config",
+		"cap_mknod",
+		"cap_lease",
+		"cap_audit_write",
+		"cap_audit_control",
+		"cap_setfcap",
+		"cap_mac_override",
+		"cap_mac_admin",
+		"cap_syslog",
+		"cap_wake_alarm",
+		"cap_block_suspend",
+		"cap_audit_read",
+		"cap_perfmon",
+		"cap_bpf",
+		"cap_checkpoint_restore",
+	];
+
+	// Revision 1 has 32 bits, revisions 2 and 3 have 64 bits
+	let u32_at = |i: usize| Some(u32::from_le_bytes(value.get(i..i + 4)?.try_into().ok()?) as u64);
+	let effective = u32_at(0)? & 1 != 0;
+	let permitted = u32_at(4)? | u32_at(12).unwrap_or(0) << 32;
+	let inheritable = u32_at(8)? | u32_at(16).unwrap_or(0) << 32;
+
+	let mut groups: Vec<(String, Vec<&str>)> = vec![];
+	for (i, name) in NAMES.iter().enumerate() {
+		let (p, inh) = (permitted >> i & 1 != 0, inheritable >> i & 1 != 0);
+		if !p && !inh {
+			continue;
+		}
+
+		let flags = [(effective && p, 'e'), (inh, 'i'), (p, 'p')];
+		let flags: String = flags.iter().filter(|f| f.0).map(|f| f.1).collect();
+		match groups.iter_mut().find(|(f, _)| *f == flags) {
+			Some((_, caps)) => caps.push(name),
+			None => groups.push((flags, vec![name])),
+		}
+	}
+
+	let groups: Vec<_> =
+		groups.into_iter().map(|(f, caps)| format!("{}={f}", caps.join(","))).collect();
+	Some(groups.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+	#[cfg(target_os = "linux")]
+	#[test]
+	fn test_capabilities() {
+		use super::*;
+
+		let cap = |magic: u32, p: u32, i: u32| {
+			[magic, p, i, 0, 0].iter().flat_map(|n| n.to_le_bytes()).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			capabilities(&cap(0x02000001, 1 << 10 | 1 << 13, 0)).unwrap(),
+			"cap_net_bind_service,cap_net_raw=ep"
+		);
+		assert_eq!(capabilities(&cap(0x02000000, 1, 1 << 5)).unwrap(), "cap_chown=p cap_kill=i");
+		assert_eq!(capabilities(&[1, 2]), None);
+
+		let selinux = "unconfined_u:object_r:user_home_t:s0";
+		assert_eq!(xattr_value(format!("{selinux}\0").as_bytes()), selinux);
+		assert_eq!(xattr_value(&[0xff, 0x01]), "0xff01");
+	}
+}
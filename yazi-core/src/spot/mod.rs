@@ -0,0 +1,5 @@
+mod commands;
+mod metadata;
+mod spot;
+
+pub use spot::*;
@@ -0,0 +1,109 @@
+use tokio_util::sync::CancellationToken;
+use yazi_config::{popup::{Offset, Position, SelectPopup}, SELECT};
+use yazi_shared::fs::Url;
+
+#[derive(Default)]
+pub struct Spot {
+	pub(super) url:      Option<Url>,
+	pub(super) label:    Option<String>,
+	pub(super) rows:     Vec<(String, String)>,
+	pub(super) verdicts: Vec<Option<bool>>,
+	pub position:        Position,
+
+	// Whether it's showing the checksums of a `checksum`, not what's spotted of a file
+	pub(super) checksum: bool,
+
+	// The tabs of the spotters after the first one, and the one shown, 0 for the first
+	pub(super) tabs: Vec<SpotTab>,
+	pub(super) tab:  usize,
+	pub(super) ct:   Option<CancellationToken>,
+
+	pub(super) offset: usize,
+	pub(super) cursor: usize,
+
+	pub visible: bool,
+}
+
+pub struct SpotTab {
+	pub(super) title: String,
+	// `None` while the spotter is still at it
+	pub(super) rows:  Option<Vec<(String, String)>>,
+}
+
+impl Spot {
+	#[inline]
+	pub fn window(&self) -> &[(String, String)] {
+		let rows = self.rows();
+		let end = (self.offset + self.limit()).min(rows.len());
+		&rows[self.offset..end]
+	}
+
+	/// Whether the row at `i` of the window passed or failed a check, if it's one.
+	#[inline]
+	pub fn verdict(&self, i: usize) -> Option<bool> {
+		if self.tab != 0 {
+			return None;
+		}
+		self.verdicts.get(self.offset + i).copied().flatten()
+	}
+
+	#[inline]
+	pub(super) fn rows(&self) -> &[(String, String)] {
+		match self.tab {
+			0 => &self.rows,
+			n => self.tabs[n - 1].rows.as_deref().unwrap_or_default(),
+		}
+	}
+
+	#[inline]
+	pub(super) fn limit(&self) -> usize {
+		self.position.offset.height.saturating_sub(SELECT.border()) as usize
+	}
+
+	/// Fits the height of the popup to the rows of the tabs, the tallest one of them.
+	pub(super) fn resize(&mut self) {
+		let rows = self.tabs.iter().filter_map(|t| t.rows.as_ref()).map(|r| r.len());
+		let rows = rows.chain([self.rows.len(), !self.tabs.is_empty() as usize]).max().unwrap_or(0);
+
+		let popup = SELECT.popups.get(SelectPopup::Spot);
+		let height = popup.offset.height.min(SELECT.border() + rows as u16);
+		self.position = Position::new(popup.origin, Offset { height, ..popup.offset });
+	}
+}
+
+impl Spot {
+	#[inline]
+	pub fn title(&self) -> String {
+		if let Some(label) = &self.label {
+			return label.clone();
+		}
+
+		let name = self.url.as_ref().and_then(|u| u.file_name());
+		name.map(|s| s.to_string_lossy().into_owned()).unwrap_or_default()
+	}
+
+	/// The titles of the tabs after the first one, and the one shown, if there are any.
+	#[inline]
+	pub fn tabs(&self) -> Option<(Vec<&str>, usize)> {
+		if self.tabs.is_empty() {
+			return None;
+		}
+		Some((self.tabs.iter().map(|t| t.title.as_str()).collect(), self.tab))
+	}
+
+	#[inline]
+	pub fn checksum(&self) -> bool { self.checksum }
+
+	/// Whether the tab shown is still being collected, as the metadata of the first one is,
+	/// or filled by its spotter.
+	#[inline]
+	pub fn loading(&self) -> bool {
+		match self.tab {
+			0 => !self.checksum && self.rows.is_empty(),
+			n => self.tabs[n - 1].rows.is_none(),
+		}
+	}
+
+	#[inline]
+	pub fn rel_cursor(&self) -> usize { self.cursor - self.offset }
+}
@@ -1,3 +1,4 @@
+#[derive(Clone)]
 pub struct Backstack<T: Eq> {
 	cursor: usize,
 	stack:  Vec<T>,
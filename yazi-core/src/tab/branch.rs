@@ -0,0 +1,43 @@
+use std::fs;
+
+use yazi_shared::fs::Url;
+
+use super::Tab;
+use crate::manager::Manager;
+
+/// The git branch the cwd is on, read in the background, as the status bar can't wait for it.
+#[derive(Default)]
+pub struct Branch {
+	pub(crate) of: Option<Url>,
+	pub name:      Option<String>,
+}
+
+impl Tab {
+	/// Reads the branch afresh if the cwd has changed since it was last read.
+	pub fn read_branch(&mut self) {
+		let cwd = &self.current.cwd;
+		if self.branch.of.as_ref() == Some(cwd) {
+			return;
+		}
+
+		self.branch = Branch { of: Some(cwd.clone()), name: None };
+		if !cwd.is_regular() {
+			return;
+		}
+
+		let cwd = cwd.clone();
+		tokio::task::spawn_blocking(move || {
+			let head = cwd.ancestors().find_map(|p| fs::read_to_string(p.join(".git/HEAD")).ok());
+			let Some(head) = head.as_deref().map(str::trim).filter(|s| !s.is_empty()) else {
+				return;
+			};
+
+			// A detached HEAD is told by its commit
+			let name = match head.strip_prefix("ref: refs/heads/") {
+				Some(b) => b.to_owned(),
+				None => head.chars().take(7).collect(),
+			};
+			Manager::_update_branch(cwd, name);
+		});
+	}
+}
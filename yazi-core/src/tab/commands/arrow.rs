@@ -1,14 +1,42 @@
-use yazi_shared::{event::Cmd, render};
+use std::ffi::OsStr;
+
+use yazi_config::MANAGER;
+use yazi_shared::{event::Cmd, fs::File, render};
 
 use crate::{manager::Manager, tab::Tab, Step};
 
+/// What the step of an arrow counts.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+enum By {
+	#[default]
+	Item,
+	// The runs of the directories, or of the files with the same extension
+	Group,
+	// The directories
+	Dir,
+	// The files with an extension other than the one they're counted from
+	Ext,
+	// The selected files
+	Selected,
+}
+
 pub struct Opt {
 	step: Step,
+	by:   By,
 }
 
 impl From<Cmd> for Opt {
 	fn from(mut c: Cmd) -> Self {
-		Self { step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or_default() }
+		Self {
+			step: c.take_first().and_then(|s| s.parse().ok()).unwrap_or_default(),
+			by:   match c.named.get("by").map(|s| s.as_str()) {
+				Some("group") => By::Group,
+				Some("dir") => By::Dir,
+				Some("ext") => By::Ext,
+				Some("selected") => By::Selected,
+				_ => By::Item,
+			},
+		}
 	}
 }
 
@@ -16,16 +44,37 @@ impl<T> From<T> for Opt
 where
 	T: Into<Step>,
 {
-	fn from(t: T) -> Self { Self { step: t.into() } }
+	fn from(t: T) -> Self { Self { step: t.into(), by: By::Item } }
 }
 
 impl Tab {
 	pub fn arrow(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
-		if !self.current.arrow(opt.step) {
+		let step = match (opt.by, opt.step) {
+			(By::Item, step) | (_, step @ Step::Percent(_)) => step,
+			(By::Group, Step::Fixed(n)) => Step::Fixed(Self::group_step(self.files(), n)),
+			(By::Dir, Step::Fixed(n)) => Step::Fixed(Self::seek_step(self.files(), n, |_, f| f.is_dir())),
+			(By::Ext, Step::Fixed(n)) => Step::Fixed(Self::seek_step(self.files(), n, |from, f| {
+				!f.is_dir() && (from.is_dir() || f.url.extension() != from.url.extension())
+			})),
+			(By::Selected, Step::Fixed(n)) => {
+				Step::Fixed(Self::seek_step(self.files(), n, |_, f| self.selected.contains(&f.url)))
+			}
+		};
+
+		let offset = self.current.offset;
+		if !self.current.arrow(step) {
 			return;
 		}
 
+		// Glide from where the list was, if it's scrolled by more than a line
+		if MANAGER.smooth_scroll && offset.abs_diff(self.current.offset) > 1 {
+			if self.current.glide.is_none() {
+				Manager::_glide();
+			}
+			self.current.glide.get_or_insert(offset);
+		}
+
 		// Visual selection
 		if let Some((start, items)) = self.mode.visual_mut() {
 			let after = self.current.cursor;
@@ -39,4 +88,122 @@ impl Tab {
 		Manager::_hover(None);
 		render!();
 	}
+
+	// How far the start of the `n`-th next group is, or that of the current one, and of the previous
+	// ones, going back, like the paragraph motions of the editors.
+	fn group_step((files, cursor): (&[File], usize), n: isize) -> isize {
+		fn key(f: &File) -> Option<Option<&OsStr>> {
+			if f.is_dir() { None } else { Some(f.url.extension()) }
+		}
+
+		if cursor >= files.len() {
+			return 0;
+		}
+
+		let mut i = cursor;
+		for _ in 0..n.unsigned_abs() {
+			if n > 0 {
+				let k = key(&files[i]);
+				match files[i..].iter().position(|f| key(f) != k) {
+					Some(d) => i += d,
+					None => break,
+				}
+			} else {
+				i = i.saturating_sub(1);
+				let k = key(&files[i]);
+				i = files[..i].iter().rposition(|f| key(f) != k).map_or(0, |d| d + 1);
+			}
+		}
+		i as isize - cursor as isize
+	}
+
+	// How far the `n`-th next file that `hit` is, or the `n`-th previous one, where each is
+	// looked for from the one before, e.g. for the next extension after that.
+	fn seek_step(
+		(files, cursor): (&[File], usize),
+		n: isize,
+		hit: impl Fn(&File, &File) -> bool,
+	) -> isize {
+		if cursor >= files.len() {
+			return 0;
+		}
+
+		let mut i = cursor;
+		for _ in 0..n.unsigned_abs() {
+			let found = if n > 0 {
+				files[i + 1..].iter().position(|f| hit(&files[i], f)).map(|d| i + 1 + d)
+			} else {
+				files[..i].iter().rposition(|f| hit(&files[i], f))
+			};
+			match found {
+				Some(j) => i = j,
+				None => break,
+			}
+		}
+		i as isize - cursor as isize
+	}
+
+	#[inline]
+	fn files(&self) -> (&[File], usize) { (&self.current.files, self.current.cursor) }
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_shared::fs::{Cha, ChaKind, Url};
+
+	use super::*;
+
+	fn files(names: &[&str]) -> Vec<File> {
+		let file = |name: &str| {
+			let (name, kind) = match name.strip_suffix('/') {
+				Some(name) => (name, ChaKind::DIR),
+				None => (name, ChaKind::empty()),
+			};
+			let cha = Cha { kind, ..Default::default() };
+			File { url: Url::from(format!("/tmp/{name}")), cha, ..Default::default() }
+		};
+		names.iter().map(|&n| file(n)).collect()
+	}
+
+	#[test]
+	fn test_group_step() {
+		let files = files(&["a/", "b/", "c.rs", "d.rs", "e.md", "f"]);
+		let step = |cursor, n| Tab::group_step((&files, cursor), n);
+
+		assert_eq!(step(0, 1), 2);
+		assert_eq!(step(0, 2), 4);
+		assert_eq!(step(3, 1), 1);
+		assert_eq!(step(5, 1), 0);
+		assert_eq!(step(0, 9), 5);
+
+		// To the start of the current group first, then of the previous ones
+		assert_eq!(step(3, -1), -1);
+		assert_eq!(step(2, -1), -2);
+		assert_eq!(step(4, -2), -4);
+		assert_eq!(step(0, -1), 0);
+		assert_eq!(step(9, 1), 0);
+	}
+
+	#[test]
+	fn test_seek_step() {
+		let files = files(&["a/", "b.rs", "c.rs", "d/", "e.md", "f.rs"]);
+		let dir = |cursor, n| Tab::seek_step((&files, cursor), n, |_, f| f.is_dir());
+		let ext = |cursor, n| {
+			Tab::seek_step((&files, cursor), n, |from, f| {
+				!f.is_dir() && (from.is_dir() || f.url.extension() != from.url.extension())
+			})
+		};
+
+		assert_eq!(dir(0, 1), 3);
+		assert_eq!(dir(0, 2), 3);
+		assert_eq!(dir(5, -1), -2);
+		assert_eq!(dir(5, -2), -5);
+
+		// The next extension is looked for from the one found before
+		assert_eq!(ext(1, 1), 3);
+		assert_eq!(ext(1, 2), 4);
+		assert_eq!(ext(0, 1), 1);
+		assert_eq!(ext(5, -1), -1);
+		assert_eq!(ext(5, -2), -3);
+	}
 }
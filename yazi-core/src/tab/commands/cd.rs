@@ -5,7 +5,7 @@ use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use yazi_config::popup::InputCfg;
 use yazi_shared::{emit, event::Cmd, fs::{expand_path, Url}, render, Debounce, InputError, Layer};
 
-use crate::{completion::Completion, input::Input, manager::Manager, tab::Tab};
+use crate::{completion::Completion, input::Input, manager::{Manager, Recent}, sidebar::Sidebar, tab::Tab};
 
 pub struct Opt {
 	target:      Url,
@@ -60,10 +60,15 @@ impl Tab {
 		}
 
 		// Backstack
-		if opt.target.is_regular() {
+		if opt.target.is_regular() || opt.target.is_recent() {
 			self.backstack.push(opt.target.clone());
 		}
 
+		if opt.target.is_recent() {
+			Recent::_load(opt.target);
+		}
+
+		Sidebar::_visit(&self.current.cwd);
 		Manager::_refresh();
 		render!();
 	}
@@ -77,6 +82,7 @@ impl Tab {
 
 			while let Some(result) = rx.next().await {
 				match result {
+					Ok(s) if Url::from(s.as_str()).is_recent() => return Tab::_cd(&Url::from(s)),
 					Ok(s) => {
 						let u = Url::from(expand_path(s));
 						let Ok(meta) = fs::metadata(&u).await else {
@@ -5,11 +5,12 @@ use crate::{manager::Manager, tab::{Mode, Tab}};
 
 bitflags! {
 	pub struct Opt: u8 {
-		const FIND   = 0b00001;
-		const VISUAL = 0b00010;
-		const SELECT = 0b00100;
-		const FILTER = 0b01000;
-		const SEARCH = 0b10000;
+		const FIND    = 0b000001;
+		const VISUAL  = 0b000010;
+		const SELECT  = 0b000100;
+		const FILTER  = 0b001000;
+		const SEARCH  = 0b010000;
+		const PREVIEW = 0b100000;
 	}
 }
 
@@ -22,6 +23,7 @@ impl From<Cmd> for Opt {
 			"select" => acc | Self::SELECT,
 			"filter" => acc | Self::FILTER,
 			"search" => acc | Self::SEARCH,
+			"preview" => acc | Self::PREVIEW,
 			_ => acc,
 		})
 	}
@@ -47,6 +49,7 @@ impl Tab {
 		}
 
 		self.mode = Mode::Normal;
+		self.size_selected();
 		render!();
 		true
 	}
@@ -58,6 +61,7 @@ impl Tab {
 		}
 
 		self.selected.clear();
+		self.size_selected();
 		if self.current.hovered().is_some_and(|h| h.is_dir()) {
 			Manager::_peek(true);
 		}
@@ -78,11 +82,22 @@ impl Tab {
 		b
 	}
 
+	// The output of a `shell --preview` command, which gives way to the preview of the hovered file
+	#[inline]
+	pub fn escape_preview(&mut self) -> bool {
+		if self.preview.stream.take().is_none() {
+			return false;
+		}
+		Manager::_peek(true);
+		true
+	}
+
 	pub fn escape(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
 		if opt.is_empty() {
 			return render!(
-				self.escape_find()
+				self.escape_preview()
+					|| self.escape_find()
 					|| self.escape_visual()
 					|| self.escape_select()
 					|| self.escape_filter()
@@ -105,5 +120,8 @@ impl Tab {
 		if opt.contains(Opt::SEARCH) {
 			render!(self.escape_search());
 		}
+		if opt.contains(Opt::PREVIEW) {
+			render!(self.escape_preview());
+		}
 	}
 }
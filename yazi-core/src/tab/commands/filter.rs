@@ -42,6 +42,7 @@ impl Tab {
 				emit!(Call(
 					Cmd::args("filter_do", vec![s])
 						.with_bool("smart", opt.case == FilterCase::Smart)
+						.with_bool("sensitive", opt.case == FilterCase::Sensitive)
 						.with_bool("insensitive", opt.case == FilterCase::Insensitive)
 						.with_bool("done", done),
 					Layer::Manager
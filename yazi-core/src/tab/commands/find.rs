@@ -1,16 +1,22 @@
-use std::time::Duration;
+use std::{fs, path::{Path, MAIN_SEPARATOR}, time::Duration};
 
 use tokio::pin;
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use yazi_config::popup::InputCfg;
 use yazi_shared::{emit, event::Cmd, render, Debounce, InputError, Layer};
 
-use crate::{folder::FilterCase, input::Input, tab::{Finder, Tab}};
+use crate::{folder::{FilterCase, Fuzzy}, input::Input, tab::{Finder, Tab}};
+
+// The most entries collected by a recursive fuzzy find, and shown in the popup
+const FUZZY_ENTRIES: usize = 10000;
+const FUZZY_LIMIT: usize = 30;
 
 pub struct Opt {
 	query: Option<String>,
 	prev:  bool,
 	case:  FilterCase,
+	fuzzy: bool,
+	depth: usize,
 }
 
 impl From<Cmd> for Opt {
@@ -19,6 +25,8 @@ impl From<Cmd> for Opt {
 			query: c.take_first(),
 			prev:  c.named.contains_key("previous"),
 			case:  FilterCase::from(&c),
+			fuzzy: c.named.contains_key("fuzzy"),
+			depth: c.take_name("depth").and_then(|s| s.parse().ok()).unwrap_or(0),
 		}
 	}
 }
@@ -34,6 +42,10 @@ impl From<Cmd> for ArrowOpt {
 impl Tab {
 	pub fn find(&mut self, opt: impl Into<Opt>) {
 		let opt = opt.into() as Opt;
+		if opt.fuzzy {
+			return self.find_fuzzy(opt);
+		}
+
 		tokio::spawn(async move {
 			let rx = Input::_show(InputCfg::find(opt.prev));
 
@@ -45,6 +57,7 @@ impl Tab {
 					Cmd::args("find_do", vec![s])
 						.with_bool("previous", opt.prev)
 						.with_bool("smart", opt.case == FilterCase::Smart)
+						.with_bool("sensitive", opt.case == FilterCase::Sensitive)
 						.with_bool("insensitive", opt.case == FilterCase::Insensitive),
 					Layer::Manager
 				));
@@ -94,4 +107,97 @@ impl Tab {
 			finder.next(&self.current.files, self.current.cursor, false).map(|s| self.arrow(s));
 		}
 	}
+
+	// Ranks the files of the current folder, or those under it down to `depth`,
+	// in a popup that keeps narrowing down as we type, and reveals the chosen one.
+	fn find_fuzzy(&self, opt: Opt) {
+		let cwd = self.current.cwd.clone();
+		let names: Option<Vec<_>> = (opt.depth == 0).then(|| {
+			self.current.files.iter().filter_map(|f| Some(Self::fuzzy_entry(f.name()?.to_str()?, f.is_dir()))).collect()
+		});
+
+		let (root, hidden) = (cwd.to_path_buf(), self.conf.show_hidden);
+		let mut walk = Some(tokio::task::spawn_blocking(move || {
+			names.unwrap_or_else(|| {
+				let mut entries = vec![];
+				Self::fuzzy_walk(&root, "", opt.depth, hidden, &mut entries);
+				entries
+			})
+		}));
+
+		tokio::spawn(async move {
+			let mut rx = Input::_show(InputCfg::find_fuzzy());
+			emit!(Call(Cmd::new("complete").with_bool("show", true), Layer::Input));
+
+			let mut entries = vec![];
+			let target = loop {
+				let value = rx.recv().await;
+				if let Some(walk) = walk.take() {
+					entries = walk.await.unwrap_or_default();
+				}
+
+				match value {
+					Some(Ok(s)) if entries.contains(&s) => break s,
+					Some(Ok(s)) => match Self::fuzzy_rank(&entries, &s, opt.case).into_iter().next() {
+						Some(best) => break best,
+						None => return,
+					},
+					Some(Err(InputError::Completed(before, ticket))) => {
+						let ranked = Self::fuzzy_rank(&entries, &before, opt.case);
+						emit!(Call(
+							Cmd::args("show", ranked)
+								.with("cache-name", "fuzzy")
+								.with("base", "")
+								.with("word", before)
+								.with_bool("ranked", true)
+								.with_bool("smart", opt.case == FilterCase::Smart)
+								.with_bool("sensitive", opt.case == FilterCase::Sensitive)
+								.with_bool("insensitive", opt.case == FilterCase::Insensitive)
+								.with("ticket", ticket),
+							Layer::Completion
+						));
+					}
+					_ => return,
+				}
+			};
+
+			Tab::_reveal(&cwd.join(target.trim_end_matches(MAIN_SEPARATOR)));
+		});
+	}
+
+	fn fuzzy_rank(entries: &[String], word: &str, case: FilterCase) -> Vec<String> {
+		let fuzzy = Fuzzy::new(word, case);
+		let mut ranked: Vec<_> =
+			entries.iter().filter_map(|s| Some((fuzzy.matches(s)?.0, s))).collect();
+
+		// Higher scores first, then the shorter ones
+		ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.len().cmp(&b.1.len())));
+		ranked.into_iter().take(FUZZY_LIMIT).map(|(_, s)| s.to_owned()).collect()
+	}
+
+	fn fuzzy_walk(dir: &Path, prefix: &str, depth: usize, hidden: bool, entries: &mut Vec<String>) {
+		let Ok(it) = fs::read_dir(dir) else { return };
+		for entry in it.flatten() {
+			if entries.len() >= FUZZY_ENTRIES {
+				return;
+			}
+
+			let Ok(name) = entry.file_name().into_string() else { continue };
+			if !hidden && name.starts_with('.') {
+				continue;
+			}
+
+			let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+			let path = format!("{prefix}{name}");
+			entries.push(Self::fuzzy_entry(&path, is_dir));
+			if is_dir && depth > 0 {
+				Self::fuzzy_walk(&entry.path(), &format!("{path}{MAIN_SEPARATOR}"), depth - 1, hidden, entries);
+			}
+		}
+	}
+
+	#[inline]
+	fn fuzzy_entry(path: &str, is_dir: bool) -> String {
+		if is_dir { format!("{path}{MAIN_SEPARATOR}") } else { path.to_owned() }
+	}
 }
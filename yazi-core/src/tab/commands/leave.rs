@@ -2,7 +2,7 @@ use std::mem;
 
 use yazi_shared::{event::Cmd, render};
 
-use crate::{manager::Manager, tab::Tab};
+use crate::{manager::Manager, sidebar::Sidebar, tab::Tab};
 
 pub struct Opt;
 impl From<()> for Opt {
@@ -43,6 +43,7 @@ impl Tab {
 		// Backstack
 		self.backstack.push(current);
 
+		Sidebar::_visit(&self.current.cwd);
 		Manager::_refresh();
 		render!();
 	}
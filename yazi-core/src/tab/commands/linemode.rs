@@ -1,3 +1,4 @@
+use yazi_config::manager::linemode_max_len;
 use yazi_shared::{event::Cmd, render};
 
 use crate::tab::Tab;
@@ -8,7 +9,7 @@ impl Tab {
 			let Some(mode) = c.take_first() else {
 				return;
 			};
-			if !mode.is_empty() && mode.len() <= 20 {
+			if !mode.is_empty() && mode.len() <= linemode_max_len(&mode) {
 				new.linemode = mode;
 			}
 		}));
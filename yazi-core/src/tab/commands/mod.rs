@@ -7,6 +7,7 @@ mod escape;
 mod filter;
 mod find;
 mod hidden;
+mod ignored;
 mod jump;
 mod leave;
 mod linemode;
@@ -17,4 +18,6 @@ mod select;
 mod select_all;
 mod shell;
 mod sort;
+mod view;
 mod visual_mode;
+mod visual_range;
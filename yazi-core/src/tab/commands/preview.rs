@@ -1,35 +1,73 @@
+use yazi_config::tr;
 use yazi_plugin::utils::PreviewLock;
-use yazi_shared::{event::Cmd, render};
+use yazi_shared::{emit, event::Cmd, render, Layer};
 
-use crate::tab::Tab;
+use crate::tab::{PreviewStream, Tab, SKIP_END};
 
-pub struct Opt {
-	lock: PreviewLock,
+pub enum Opt {
+	Lock(PreviewLock),
+	Stream(PreviewStream),
+	Follow,
 }
 
 impl TryFrom<Cmd> for Opt {
 	type Error = ();
 
 	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> {
-		Ok(Self { lock: c.take_data().ok_or(())? })
+		if c.named.contains_key("follow") {
+			return Ok(Self::Follow);
+		}
+		if c.named.contains_key("stream") {
+			return Ok(Self::Stream(c.take_data().ok_or(())?));
+		}
+		Ok(Self::Lock(c.take_data().ok_or(())?))
 	}
 }
 
 impl Tab {
 	pub fn preview(&mut self, opt: impl TryInto<Opt>) {
-		let Some(hovered) = self.current.hovered().map(|h| &h.url) else {
-			return render!(self.preview.reset());
+		let lock = match opt.try_into() {
+			Ok(Opt::Lock(lock)) => lock,
+			Ok(Opt::Stream(stream)) => return self.preview_stream(stream),
+			Ok(Opt::Follow) => return self.preview_follow(),
+			Err(_) => return,
 		};
 
-		let Ok(opt) = opt.try_into() else {
-			return;
+		let Some(hovered) = self.current.hovered().map(|h| &h.url) else {
+			return render!(self.preview.reset());
 		};
 
-		if opt.lock.url != *hovered {
+		if lock.url != *hovered {
 			return;
 		}
 
-		self.preview.lock = Some(opt.lock);
+		self.preview.lock = Some(lock);
+		render!();
+	}
+
+	// Shown whatever is hovered, in an empty directory too, and replacing the one before if any
+	fn preview_stream(&mut self, stream: PreviewStream) {
+		self.preview.reset_image();
+		self.preview.stream = Some(stream);
 		render!();
 	}
+
+	fn preview_follow(&mut self) {
+		self.preview.follow = !self.preview.follow;
+		self.preview.paused = false;
+
+		let content = if self.preview.follow {
+			tr!("Following the end of the previewed file")
+		} else {
+			tr!("Stopped following the previewed file")
+		};
+		emit!(Notify(tr!("Preview"), content, "info", 2));
+
+		if let Some(h) = self.current.hovered().filter(|h| self.preview.follow && !h.is_dir()) {
+			emit!(Call(
+				Cmd::args("peek", vec![SKIP_END.to_string()]).with("only-if", h.url.to_string()),
+				Layer::Manager
+			));
+		}
+	}
 }
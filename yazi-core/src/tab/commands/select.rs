@@ -30,10 +30,14 @@ impl<'a> Tab {
 			return;
 		};
 
-		render!(match opt.state {
+		let b = match opt.state {
 			Some(true) => self.selected.insert(url.into_owned()),
 			Some(false) => self.selected.remove(&url),
 			None => self.selected.remove(&url) || self.selected.insert(url.into_owned()),
-		});
+		};
+		if b {
+			self.size_selected();
+			render!();
+		}
 	}
 }
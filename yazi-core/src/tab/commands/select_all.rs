@@ -41,6 +41,9 @@ impl Tab {
 				}
 			}
 		}
-		render!(b);
+		if b {
+			self.size_selected();
+			render!();
+		}
 	}
 }
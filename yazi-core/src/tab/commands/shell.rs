@@ -1,12 +1,25 @@
-use yazi_config::{open::Opener, popup::InputCfg};
-use yazi_shared::event::Cmd;
+use std::{collections::BTreeSet, env, mem, path::MAIN_SEPARATOR, sync::Arc, time::Duration};
 
-use crate::{input::Input, tab::Tab, tasks::Tasks};
+use ansi_to_tui::IntoText;
+use anyhow::Result;
+use parking_lot::Mutex;
+use ratatui::{style::Modifier, text::{Line, Text}};
+use tokio::{fs, io::{AsyncBufReadExt, BufReader}, select, time::{self, Instant}};
+use tokio_util::sync::CancellationToken;
+use yazi_config::{open::Opener, popup::InputCfg, KEYMAP};
+use yazi_plugin::external::{self, ShellOpt};
+use yazi_shared::{emit, event::Cmd, fs::Url, InputError, Layer};
+
+use crate::{completion::Completion, input::Input, tab::{PreviewStream, Tab, STREAM_LINES}, tasks::Tasks};
+
+// At most how often the output of a `--preview` command is shown again while it's streaming
+const STREAM_INTERVAL: Duration = Duration::from_millis(100);
 
 pub struct Opt {
 	exec:    String,
 	block:   bool,
 	confirm: bool,
+	preview: bool,
 }
 
 impl From<Cmd> for Opt {
@@ -15,6 +28,7 @@ impl From<Cmd> for Opt {
 			exec:    c.take_first().unwrap_or_default(),
 			block:   c.named.contains_key("block"),
 			confirm: c.named.contains_key("confirm"),
+			preview: c.named.contains_key("preview"),
 		}
 	}
 }
@@ -27,20 +41,153 @@ impl Tab {
 		tokio::spawn(async move {
 			if !opt.confirm || opt.exec.is_empty() {
 				let mut result = Input::_show(InputCfg::shell(opt.block).with_value(opt.exec));
-				match result.recv().await {
-					Some(Ok(e)) => opt.exec = e,
-					_ => return,
+				let mut commands = None;
+				loop {
+					match result.recv().await {
+						Some(Ok(e)) => break opt.exec = e,
+						Some(Err(InputError::Completed(before, ticket))) => {
+							if before.contains([' ', MAIN_SEPARATOR]) {
+								// Arguments are completed as paths
+								Completion::_trigger(before.rsplit(' ').next().unwrap(), ticket);
+								continue;
+							}
+
+							if commands.is_none() {
+								commands = Some(Self::shell_commands().await);
+							}
+							emit!(Call(
+								Cmd::args("show", commands.clone().unwrap())
+									.with("cache-name", "shell")
+									.with("word", before)
+									.with("ticket", ticket),
+								Layer::Completion
+							));
+						}
+						_ => return,
+					}
 				}
 			}
 
+			if opt.preview {
+				Self::shell_preview(opt.exec, selected).await.ok();
+				return;
+			}
+
 			Tasks::_open(selected, Opener {
-				exec:   opt.exec,
-				block:  opt.block,
-				orphan: false,
-				desc:   Default::default(),
-				for_:   None,
-				spread: true,
+				exec:    opt.exec,
+				block:   opt.block,
+				orphan:  false,
+				capture: false,
+				desc:    Default::default(),
+				for_:    None,
+				spread:  true,
 			});
 		});
 	}
+
+	/// Runs the command with its output streamed into the preview pane, ANSI colors included,
+	/// up to `STREAM_LINES` lines for it to be scrolled, and kept there until it's escaped.
+	async fn shell_preview(exec: String, selected: Vec<Url>) -> Result<()> {
+		let mut child = external::shell(ShellOpt {
+			cmd:     exec.into(),
+			args:    selected.into_iter().map(|u| u.into_os_string()).collect(),
+			piped:   true,
+			orphan:  false,
+			capture: false,
+		})?;
+		drop(child.stdin.take());
+
+		let (lines, ct) = (Arc::new(Mutex::new(vec![])), CancellationToken::new());
+		let stream = PreviewStream::new(lines.clone(), ct.clone());
+		emit!(Call(Cmd::new("preview").with_bool("stream", true).with_data(stream), Layer::Manager));
+
+		let mut stdout = BufReader::new(child.stdout.take().unwrap()).split(b'\n');
+		let mut stderr = BufReader::new(child.stderr.take().unwrap()).split(b'\n');
+
+		let (mut pending, mut n, mut shown) = (vec![], 0, Instant::now());
+		let capped = loop {
+			select! {
+				_ = ct.cancelled() => return Ok(child.start_kill()?),
+				Ok(Some(line)) = stdout.next_segment() => pending.push(line),
+				Ok(Some(line)) = stderr.next_segment() => pending.push(line),
+				_ = time::sleep(STREAM_INTERVAL), if !pending.is_empty() => {}
+				else => break false,
+			}
+
+			if n + pending.len() >= STREAM_LINES {
+				pending.truncate(STREAM_LINES - n);
+				break true;
+			}
+			if shown.elapsed() >= STREAM_INTERVAL {
+				n += Self::shell_preview_append(&lines, mem::take(&mut pending), None);
+				shown = Instant::now();
+			}
+		};
+
+		let status = if capped {
+			child.start_kill().ok();
+			Some(format!("Stopped at {STREAM_LINES} lines"))
+		} else {
+			match child.wait().await {
+				Ok(s) if !s.success() => Some(format!("Exited with {s}")),
+				_ if n + pending.len() == 0 => Some("No output".to_owned()),
+				_ => None,
+			}
+		};
+		Self::shell_preview_append(&lines, pending, status);
+		Ok(())
+	}
+
+	fn shell_preview_append(
+		lines: &Mutex<Vec<Line<'static>>>,
+		pending: Vec<Vec<u8>>,
+		status: Option<String>,
+	) -> usize {
+		let n = pending.len();
+		let text = if n == 0 { Ok(Text::default()) } else { pending.join(&b'\n').into_text() };
+
+		let mut lines = lines.lock();
+		lines.extend(text.map(|t| t.lines).unwrap_or_default());
+		lines.extend(status.map(|s| Line::styled(s, Modifier::DIM)));
+		emit!(Render);
+		n
+	}
+
+	// Candidates for the command being typed: the recent command lines,
+	// the shell commands in the keymap, and the executables in $PATH.
+	async fn shell_commands() -> Vec<String> {
+		let mut seen = BTreeSet::new();
+		let mut commands: Vec<_> = Input::history_of("shell")
+			.await
+			.into_iter()
+			.chain(
+				KEYMAP.manager.iter().flat_map(|c| &c.exec).filter(|c| c.name == "shell").filter_map(|c| c.args.first().cloned()),
+			)
+			.filter(|s| seen.insert(s.clone()))
+			.collect();
+
+		let mut executables = BTreeSet::new();
+		let paths = env::var_os("PATH").unwrap_or_default();
+		for dir in env::split_paths(&paths) {
+			let Ok(mut it) = fs::read_dir(dir).await else { continue };
+			while let Ok(Some(f)) = it.next_entry().await {
+				let Ok(meta) = fs::metadata(f.path()).await else { continue };
+
+				#[cfg(unix)]
+				let executable = {
+					use std::os::unix::fs::PermissionsExt;
+					meta.is_file() && meta.permissions().mode() & 0o111 != 0
+				};
+				#[cfg(windows)]
+				let executable = meta.is_file();
+
+				if executable {
+					executables.insert(f.file_name().to_string_lossy().into_owned());
+				}
+			}
+		}
+
+		commands.extend(executables.into_iter().filter(|s| seen.insert(s.clone())));
+		commands
+	}
 }
@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+use yazi_config::{manager::View, MANAGER};
+use yazi_shared::{emit, event::Cmd};
+
+use crate::tab::Tab;
+
+impl Tab {
+	pub fn view(&mut self, c: Cmd) {
+		let Some(view) = c.args.first().and_then(|s| View::from_str(s).ok()) else {
+			return;
+		};
+
+		// Views are per directory, switching to the active one again goes back to the list
+		let old = self.cwd_view();
+		let new = if old == view { View::List } else { view };
+		if new == old {
+			return;
+		} else if new == MANAGER.view {
+			self.views.remove(&self.current.cwd);
+		} else {
+			self.views.insert(self.current.cwd.clone(), new);
+		}
+
+		// Each view shows a different number of files, so the page needs resyncing
+		emit!(Resize);
+	}
+}
@@ -0,0 +1,100 @@
+use yazi_config::popup::InputCfg;
+use yazi_shared::{emit, event::Cmd, render, InputError, Layer};
+
+use crate::{folder::{Filter, FilterCase}, input::Input, tab::{Mode, Tab}};
+
+pub struct Opt {
+	op:    Op,
+	query: Option<String>,
+	case:  FilterCase,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Op {
+	// Selects, or unsets in the unset mode, only those of the range that match the query
+	Filter,
+	// Flips the selection of each of the range
+	Invert,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(c: Cmd) -> Result<Self, Self::Error> {
+		let op = match c.args.first().map(|s| s.as_str()) {
+			Some("filter") => Op::Filter,
+			Some("invert") => Op::Invert,
+			_ => return Err(()),
+		};
+		Ok(Self { op, query: c.args.get(1).cloned(), case: FilterCase::from(&c) })
+	}
+}
+
+impl Tab {
+	/// Applies an operator to the range of the visual mode, instead of selecting it as a whole,
+	/// and leaves the visual mode.
+	pub fn visual_range(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+		let Some((start, _)) = self.mode.visual() else {
+			return;
+		};
+
+		let filter = match (opt.op, opt.query) {
+			(Op::Invert, _) => None,
+			(Op::Filter, Some(query)) => match Filter::new(&query, opt.case) {
+				Ok(f) => Some(f),
+				Err(_) => return,
+			},
+			(Op::Filter, None) => return Self::visual_range_ask(opt.case),
+		};
+
+		let state = self.mode.is_select();
+		let cursor = self.current.cursor;
+		let range = start.min(cursor)..=start.max(cursor);
+		for f in self.current.files.get(range).unwrap_or_default() {
+			let Some(filter) = &filter else {
+				if !self.selected.remove(&f.url) {
+					self.selected.insert(f.url());
+				}
+				continue;
+			};
+
+			if !f.name().is_some_and(|n| filter.matches(n)) {
+				continue;
+			}
+			if state {
+				self.selected.insert(f.url());
+			} else {
+				self.selected.remove(&f.url);
+			}
+		}
+
+		self.mode = Mode::Normal;
+		self.size_selected();
+		render!();
+	}
+
+	fn visual_range_ask(case: FilterCase) {
+		tokio::spawn(async move {
+			// The filter input is a realtime one, whose keystrokes are skipped here
+			let mut result = Input::_show(InputCfg::filter());
+			let query = loop {
+				match result.recv().await {
+					Some(Ok(s)) => break s,
+					Some(Err(InputError::Typed(_))) => continue,
+					_ => return,
+				}
+			};
+
+			emit!(Call(
+				Cmd::args("visual_range", vec!["filter".to_owned(), query])
+					.with_bool("smart", case == FilterCase::Smart)
+					.with_bool("sensitive", case == FilterCase::Sensitive)
+					.with_bool("insensitive", case == FilterCase::Insensitive),
+				Layer::Manager
+			));
+		});
+	}
+}
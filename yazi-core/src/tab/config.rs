@@ -11,8 +11,9 @@ pub struct Config {
 	pub sort_dir_first: bool,
 
 	// Display
-	pub linemode:    String,
-	pub show_hidden: bool,
+	pub linemode:     String,
+	pub show_hidden:  bool,
+	pub show_ignored: bool,
 }
 
 impl Default for Config {
@@ -25,8 +26,9 @@ impl Default for Config {
 			sort_dir_first: MANAGER.sort_dir_first,
 
 			// Display
-			linemode:    MANAGER.linemode.to_owned(),
-			show_hidden: MANAGER.show_hidden,
+			linemode:     MANAGER.linemode.to_owned(),
+			show_hidden:  MANAGER.show_hidden,
+			show_ignored: MANAGER.show_ignored,
 		}
 	}
 }
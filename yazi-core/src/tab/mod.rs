@@ -1,14 +1,23 @@
 mod backstack;
+mod branch;
 mod commands;
 mod config;
 mod finder;
 mod mode;
+mod overrides;
+mod preload;
 mod preview;
+mod sizing;
+mod stream;
 mod tab;
 
 pub use backstack::*;
+pub use branch::*;
 pub use config::*;
 pub use finder::*;
 pub use mode::*;
+pub use overrides::*;
 pub use preview::*;
+pub use sizing::*;
+pub use stream::*;
 pub use tab::*;
@@ -0,0 +1,90 @@
+use std::rc::Rc;
+
+use yazi_config::local::Local;
+use yazi_shared::fs::Url;
+
+use super::{Config, Tab};
+use crate::manager::Manager;
+
+/// The `.yazi.toml` in effect for the tab, and the settings from before it took effect.
+#[derive(Clone)]
+pub struct Overrides {
+	pub root: Url,
+	id:       String,
+	local:    Rc<Local>,
+	saved:    Option<Config>,
+}
+
+impl Overrides {
+	#[inline]
+	pub(crate) fn new(root: Url, id: String, local: Local) -> Self {
+		Self { root, id, local: Rc::new(local), saved: None }
+	}
+}
+
+impl PartialEq for Overrides {
+	fn eq(&self, other: &Self) -> bool { self.root == other.root && self.id == other.id }
+}
+
+impl Tab {
+	/// Switches to the settings of another `.yazi.toml`, or to none, restoring those it set from
+	/// before the previous one first, so that leaving its subtree is back to how it was before
+	/// entering, except for what's been changed in there that it didn't set.
+	pub fn set_overrides(&mut self, new: Option<Overrides>) {
+		if self.overrides == new {
+			return;
+		}
+
+		if let Some(Overrides { local, saved: Some(saved), .. }) = self.overrides.take() {
+			let (m, conf) = (&local.manager, &mut self.conf);
+			if m.sort_by.is_some() {
+				conf.sort_by = saved.sort_by;
+			}
+			if m.sort_sensitive.is_some() {
+				conf.sort_sensitive = saved.sort_sensitive;
+			}
+			if m.sort_reverse.is_some() {
+				conf.sort_reverse = saved.sort_reverse;
+			}
+			if m.sort_dir_first.is_some() {
+				conf.sort_dir_first = saved.sort_dir_first;
+			}
+			if m.linemode.is_some() {
+				conf.linemode = saved.linemode;
+			}
+			if m.show_hidden.is_some() {
+				conf.show_hidden = saved.show_hidden;
+			}
+			if m.show_ignored.is_some() {
+				conf.show_ignored = saved.show_ignored;
+			}
+		}
+
+		self.preview.local = new.as_ref().map(|o| o.local.clone());
+		if let Some(mut new) = new {
+			let (m, conf) = (&new.local.manager, &mut self.conf);
+			new.saved = Some(conf.clone());
+
+			conf.sort_by = m.sort_by.unwrap_or(conf.sort_by);
+			conf.sort_sensitive = m.sort_sensitive.unwrap_or(conf.sort_sensitive);
+			conf.sort_reverse = m.sort_reverse.unwrap_or(conf.sort_reverse);
+			conf.sort_dir_first = m.sort_dir_first.unwrap_or(conf.sort_dir_first);
+
+			if let Some(linemode) = &m.linemode {
+				conf.linemode = linemode.clone();
+			}
+			conf.show_hidden = m.show_hidden.unwrap_or(conf.show_hidden);
+			conf.show_ignored = m.show_ignored.unwrap_or(conf.show_ignored);
+			self.overrides = Some(new);
+		}
+
+		let hovered = self.current.hovered().map(|f| f.url());
+		self.apply_files_attrs();
+
+		if hovered.as_ref() != self.current.hovered().map(|f| &f.url) {
+			Manager::_hover(hovered);
+		}
+		Manager::_peek(true);
+		Manager::_update_paged();
+	}
+}
@@ -0,0 +1,91 @@
+use std::{collections::BTreeMap, sync::{atomic::{AtomicUsize, Ordering}, Arc}};
+
+use tokio::{fs, sync::Semaphore};
+use yazi_config::{manager::DirPreload, LAYOUT, MANAGER};
+use yazi_shared::fs::{File, FilesOp, Url};
+
+use super::Tab;
+use crate::folder::{Files, Folder, FolderStage};
+
+// Bounds the number of directories being preloaded at the same time
+static PRELOAD: Semaphore = Semaphore::const_new(4);
+
+impl Tab {
+	/// Lists the directories the user is likely to navigate into next in the
+	/// background, so that entering them is instant.
+	pub fn preload_dirs(&mut self) {
+		if MANAGER.dir_preload == DirPreload::None {
+			return;
+		}
+
+		let limit = LAYOUT.load().current_limit.max(1);
+		let mut todo: Vec<_> =
+			self.hovered_folder().map(|f| Self::nearby(f, limit)).unwrap_or_default();
+		if MANAGER.dir_preload == DirPreload::Siblings {
+			todo.extend(self.parent.as_ref().map(|f| Self::nearby(f, limit)).unwrap_or_default());
+		}
+
+		let hovered = self.current.hovered().map(File::url);
+		Self::evict_placeholders(&mut self.history, &todo, hovered.as_ref());
+
+		let used: usize = self.history.values().map(|f| f.files.len()).sum();
+		let Some(budget) = MANAGER.dir_preload_budget.checked_sub(used).filter(|&n| n > 0) else {
+			return;
+		};
+
+		// Shared by the whole pass, as the directories are listed at the same time
+		let budget = Arc::new(AtomicUsize::new(budget));
+		for url in todo {
+			if self.history.contains_key(&url) || self.current.cwd == url {
+				continue;
+			}
+
+			self.history.insert(url.clone(), Folder::from(&url));
+			tokio::spawn(Self::preload_dir(url, budget.clone()));
+		}
+	}
+
+	// Drops the placeholders of the directories no longer nearby, which are still empty as they're
+	// being preloaded or were given up on, for them not to pile up as the user moves around
+	fn evict_placeholders(history: &mut BTreeMap<Url, Folder>, todo: &[Url], hovered: Option<&Url>) {
+		history.retain(|url, f| {
+			f.stage == FolderStage::Loaded
+				|| !f.files.is_empty()
+				|| todo.contains(url)
+				|| hovered == Some(url)
+		});
+	}
+
+	fn nearby(folder: &Folder, limit: usize) -> Vec<Url> {
+		if !folder.cwd.is_regular() {
+			return vec![];
+		}
+
+		let start = folder.cursor.saturating_sub(limit / 2);
+		let end = folder.files.len().min(start + limit);
+		folder.files[start..end].iter().filter(|f| f.is_dir()).map(File::url).collect()
+	}
+
+	async fn preload_dir(url: Url, budget: Arc<AtomicUsize>) {
+		let Ok(_permit) = PRELOAD.acquire().await else {
+			return;
+		};
+
+		let (Ok(meta), Ok(mut rx)) = (fs::metadata(&url).await, Files::from_dir(&url).await) else {
+			return;
+		};
+
+		// Give up on the directories that don't fit in what's left of the budget, which gets back
+		// what they took for the others, they will be loaded as usual once the user gets there.
+		let mut files = vec![];
+		while let Some(file) = rx.recv().await {
+			if budget.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1)).is_err() {
+				budget.fetch_add(files.len(), Ordering::Relaxed);
+				return;
+			}
+			files.push(file);
+		}
+
+		FilesOp::Full(url, files, meta.modified().ok()).emit();
+	}
+}
@@ -1,31 +1,90 @@
-use std::time::{Duration, SystemTime};
+use std::{num::NonZeroUsize, rc::Rc, time::{Duration, SystemTime}};
 
-use tokio::{fs, pin, task::JoinHandle};
+use lru::LruCache;
+use tokio::{fs, pin, task::JoinHandle, time::{self, Instant}};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamExt};
 use tokio_util::sync::CancellationToken;
 use yazi_adaptor::ADAPTOR;
-use yazi_config::PLUGIN;
+use yazi_config::{local::Local, PLUGIN, PREVIEW};
 use yazi_plugin::{external::Highlighter, isolate, utils::PreviewLock};
-use yazi_shared::{fs::{Cha, File, FilesOp, Url}, MIME_DIR};
+use yazi_shared::{emit, event::Cmd, fs::{Cha, File, FilesOp, Url}, Layer, MIME_DIR};
 
+use super::PreviewStream;
 use crate::folder::Files;
 
-#[derive(Default)]
+// At most how often the preview is reloaded, while the previewed file keeps changing on disk
+const RELOAD_INTERVAL: Duration = Duration::from_millis(500);
+
+// Past the end of any file, so that the previewer settles on the last page
+pub(crate) const SKIP_END: usize = i64::MAX as usize;
+
+// Number of the files whose preview positions are remembered
+const POSITIONS: usize = 1000;
+
 pub struct Preview {
 	pub lock: Option<PreviewLock>,
 	pub skip: usize,
 
+	// The output of a `shell --preview` command, shown in place of the previews till it's escaped
+	pub stream: Option<PreviewStream>,
+
+	// The previewers of the `.yazi.toml` in effect, tried before the others
+	pub local: Option<Rc<Local>>,
+
+	// Keeps a changing file at its end, unless paused by scrolling up
+	pub follow: bool,
+	pub paused: bool,
+
 	previewer_ct:  Option<CancellationToken>,
 	folder_loader: Option<(Url, JoinHandle<()>)>,
+
+	// When the preview was last reloaded for the file changing, and the state it was reloaded for
+	reloaded: Option<(Instant, Cha)>,
+	reloader: Option<JoinHandle<()>>,
+
+	// How far the previews of the files are scrolled, restored when they're hovered again
+	positions: LruCache<Url, usize>,
+}
+
+impl Default for Preview {
+	fn default() -> Self {
+		Self {
+			lock: None,
+			skip: 0,
+
+			stream: None,
+
+			local: None,
+
+			follow: PREVIEW.follow,
+			paused: false,
+
+			previewer_ct:  None,
+			folder_loader: None,
+
+			reloaded: None,
+			reloader: None,
+
+			positions: LruCache::new(NonZeroUsize::new(POSITIONS).unwrap()),
+		}
+	}
 }
 
 impl Preview {
 	pub fn go(&mut self, file: File, mime: &str, force: bool) {
+		if self.stream.is_some() {
+			return;
+		}
 		if !force && self.content_unchanged(&file.url, &file.cha) {
 			return;
 		}
+		if !force && self.content_changed(&file.url, &file.cha) && !self.reload_now(&file) {
+			return;
+		}
 
-		let Some(previewer) = PLUGIN.previewer(&file.url, mime) else {
+		let local = self.local.clone();
+		let local = local.as_ref().and_then(|l| l.previewer(&file.url, mime));
+		let Some(previewer) = local.or_else(|| PLUGIN.previewer(&file.url, mime)) else {
 			self.reset();
 			return;
 		};
@@ -85,6 +144,18 @@ impl Preview {
 		));
 	}
 
+	/// Remembers how far the preview is scrolled, and moves to where that of `url` was left.
+	pub fn switch_to(&mut self, url: &Url) {
+		if let Some(lock) = &self.lock {
+			if self.skip > 0 {
+				self.positions.put(lock.url.clone(), self.skip);
+			} else {
+				self.positions.pop(&lock.url);
+			}
+		}
+		self.skip = self.positions.get(url).copied().unwrap_or(0);
+	}
+
 	#[inline]
 	pub fn abort(&mut self) {
 		self.previewer_ct.take().map(|ct| ct.cancel());
@@ -94,6 +165,8 @@ impl Preview {
 	#[inline]
 	pub fn reset(&mut self) -> bool {
 		self.abort();
+		self.reloaded = None;
+		self.reloader.take().map(|h| h.abort());
 		ADAPTOR.image_hide().ok();
 		self.lock.take().is_some()
 	}
@@ -109,6 +182,42 @@ impl Preview {
 		matches!(self.lock, Some(ref lock) if lock.url == *url)
 	}
 
+	/// Whether the file has been changed on disk since it was previewed.
+	fn content_changed(&self, url: &Url, cha: &Cha) -> bool {
+		matches!(&self.lock, Some(lock) if lock.url == *url && !Self::same_content(&lock.cha, cha))
+	}
+
+	/// Throttles the reloads of a changing file, returning whether to reload it now,
+	/// otherwise one is scheduled after the interval.
+	fn reload_now(&mut self, file: &File) -> bool {
+		let wait = match self.reloaded {
+			// Already reloaded for this state, e.g. the previewer is settling on the last page
+			Some((_, ref cha)) if Self::same_content(cha, &file.cha) => return true,
+			Some((t, _)) => RELOAD_INTERVAL.saturating_sub(t.elapsed()),
+			None => Duration::ZERO,
+		};
+
+		if !wait.is_zero() {
+			if self.reloader.as_ref().is_none_or(|h| h.is_finished()) {
+				let url = file.url();
+				self.reloader = Some(tokio::spawn(async move {
+					time::sleep(wait).await;
+					emit!(Call(Cmd::new("peek").with("only-if", url.to_string()), Layer::Manager));
+				}));
+			}
+			return false;
+		}
+
+		self.reloaded = Some((Instant::now(), file.cha));
+		if self.follow && !self.paused && !file.is_dir() {
+			self.skip = SKIP_END;
+		}
+		true
+	}
+
+	#[inline]
+	fn same_content(a: &Cha, b: &Cha) -> bool { a.len == b.len && a.modified == b.modified }
+
 	fn content_unchanged(&self, url: &Url, cha: &Cha) -> bool {
 		let Some(lock) = &self.lock else {
 			return false;
@@ -0,0 +1,77 @@
+use std::{sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+
+use tokio::{task::JoinHandle, time::Instant};
+use yazi_shared::{emit, event::Cmd, fs::calculate_size_with_progress, Layer};
+
+use super::Tab;
+
+// At most how often the total is told while the directories are still being sized
+const REPORT_INTERVAL: Duration = Duration::from_millis(200);
+
+static TICKETS: AtomicUsize = AtomicUsize::new(0);
+
+/// The total size of the selected files, summed up in the background, the directories
+/// with all their contents, and told as it goes.
+#[derive(Default)]
+pub struct Sizing {
+	pub total:  Option<u64>,
+	pub done:   bool,
+	pub ticket: usize,
+	handle:     Option<JoinHandle<()>>,
+}
+
+impl Sizing {
+	#[inline]
+	fn abort(&mut self) {
+		if let Some(h) = self.handle.take() {
+			h.abort();
+		}
+	}
+}
+
+impl Drop for Sizing {
+	fn drop(&mut self) { self.abort(); }
+}
+
+impl Tab {
+	/// Sizes the selection afresh, as it's changed, told as each directory is walked too.
+	pub(super) fn size_selected(&mut self) {
+		let sizing = &mut self.sizing;
+		sizing.abort();
+		(sizing.total, sizing.done) = (None, self.selected.is_empty());
+		if sizing.done {
+			return;
+		}
+
+		let ticket = TICKETS.fetch_add(1, Ordering::Relaxed) + 1;
+		let urls: Vec<_> = self.selected.iter().cloned().collect();
+		sizing.ticket = ticket;
+		sizing.handle = Some(tokio::spawn(async move {
+			let (mut total, mut told) = (0, Instant::now());
+			for url in urls {
+				// The running total of a directory, with those before it
+				let mut rx = calculate_size_with_progress(&url);
+				let mut n = 0;
+				while let Some(m) = rx.recv().await {
+					n = m;
+					if told.elapsed() >= REPORT_INTERVAL {
+						told = Instant::now();
+						Self::size_selected_tell(ticket, total + n, false);
+					}
+				}
+				total += n;
+			}
+			Self::size_selected_tell(ticket, total, true);
+		}));
+	}
+
+	#[inline]
+	fn size_selected_tell(ticket: usize, total: u64, done: bool) {
+		emit!(Call(
+			Cmd::args("update_sizing", vec![total.to_string()])
+				.with("ticket", ticket)
+				.with_bool("done", done),
+			Layer::Manager
+		));
+	}
+}
@@ -0,0 +1,57 @@
+use std::{mem, sync::Arc};
+
+use parking_lot::Mutex;
+use ratatui::text::Line;
+use tokio_util::sync::CancellationToken;
+
+// At most how many lines of the output are kept, beyond which the command is stopped
+pub(crate) const STREAM_LINES: usize = 10000;
+
+/// The output of a `shell --preview` command, buffered for it to be scrolled, and shown in
+/// place of the previews of the files until it's escaped, which stops the command if it's running.
+pub struct PreviewStream {
+	pub lines: Arc<Mutex<Vec<Line<'static>>>>,
+	pub skip:  usize,
+
+	ct: CancellationToken,
+}
+
+impl PreviewStream {
+	pub(crate) fn new(lines: Arc<Mutex<Vec<Line<'static>>>>, ct: CancellationToken) -> Self {
+		Self { lines, skip: 0, ct }
+	}
+
+	/// Scrolls by the lines of `units`, settling on the last page of the `height` at most,
+	/// returning whether it's moved.
+	pub fn seek(&mut self, units: i16, height: usize) -> bool {
+		let max = self.lines.lock().len().saturating_sub(height);
+		let skip = (self.skip as isize + units as isize).clamp(0, max as isize) as usize;
+		mem::replace(&mut self.skip, skip) != skip
+	}
+}
+
+impl Drop for PreviewStream {
+	fn drop(&mut self) { self.ct.cancel(); }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_seek() {
+		let lines = Arc::new(Mutex::new(vec![Line::raw(""); 10]));
+		let mut stream = PreviewStream::new(lines.clone(), CancellationToken::new());
+
+		assert!(!stream.seek(-5, 4));
+		assert!(stream.seek(5, 4));
+		assert_eq!(stream.skip, 5);
+		assert!(stream.seek(i16::MAX, 4));
+		assert_eq!(stream.skip, 6);
+
+		// Further as more comes in
+		lines.lock().extend(vec![Line::raw(""); 10]);
+		assert!(stream.seek(i16::MAX, 4));
+		assert_eq!(stream.skip, 16);
+	}
+}
@@ -1,23 +1,30 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use anyhow::Result;
 use tokio::task::JoinHandle;
+use yazi_config::{manager::View, MANAGER};
 use yazi_shared::{fs::Url, render};
 
-use super::{Backstack, Config, Finder, Mode, Preview};
+use super::{Backstack, Branch, Config, Finder, Mode, Overrides, Preview, Sizing};
 use crate::folder::{Folder, FolderStage};
 
 pub struct Tab {
-	pub mode:    Mode,
-	pub conf:    Config,
-	pub current: Folder,
-	pub parent:  Option<Folder>,
+	pub mode:      Mode,
+	pub conf:      Config,
+	pub overrides: Option<Overrides>,
+	pub label:     Option<String>,
+	pub pinned:    bool,
+	pub current:   Folder,
+	pub parent:    Option<Folder>,
 
 	pub backstack: Backstack<Url>,
 	pub history:   BTreeMap<Url, Folder>,
 	pub selected:  BTreeSet<Url>,
+	pub views:     HashMap<Url, View>,
 
 	pub preview:       Preview,
+	pub sizing:        Sizing,
+	pub branch:        Branch,
 	pub finder:        Option<Finder>,
 	pub(super) search: Option<JoinHandle<Result<()>>>,
 }
@@ -28,18 +35,24 @@ impl From<Url> for Tab {
 
 		Self {
 			mode: Default::default(),
+			label: None,
+			pinned: false,
 			current: Folder::from(url.clone()),
 			parent,
 
 			backstack: Backstack::new(url),
 			history: Default::default(),
 			selected: Default::default(),
+			views: Default::default(),
 
 			preview: Default::default(),
+			sizing: Default::default(),
+			branch: Default::default(),
 			finder: None,
 			search: None,
 
 			conf: Default::default(),
+			overrides: None,
 		}
 	}
 }
@@ -48,8 +61,42 @@ impl From<&Url> for Tab {
 	fn from(url: &Url) -> Self { Self::from(url.clone()) }
 }
 
+impl Tab {
+	/// Creates a copy of the tab, where we are, what's selected and what has been
+	/// visited, but not the running search or find.
+	pub fn duplicate(&self) -> Self {
+		let mut tab = Self {
+			mode:      Default::default(),
+			conf:      self.conf.clone(),
+			overrides: self.overrides.clone(),
+			label:     self.label.clone(),
+			pinned:    false,
+			current:   self.current.clone(),
+			parent:    self.parent.clone(),
+
+			backstack: self.backstack.clone(),
+			history:   self.history.clone(),
+			selected:  self.selected.clone(),
+			views:     self.views.clone(),
+
+			preview: Default::default(),
+			sizing:  Default::default(),
+			branch:  Default::default(),
+			finder:  None,
+			search:  None,
+		};
+		tab.size_selected();
+		tab
+	}
+}
+
 impl Tab {
 	// --- Current
+	#[inline]
+	pub fn cwd_view(&self) -> View {
+		self.views.get(&self.current.cwd).copied().unwrap_or(MANAGER.view)
+	}
+
 	pub fn selected_or_hovered(&self) -> Vec<&Url> {
 		if self.selected.is_empty() {
 			self.current.hovered().map(|h| vec![&h.url]).unwrap_or_default()
@@ -76,8 +123,11 @@ impl Tab {
 			}
 
 			let hovered = f.hovered().filter(|_| f.tracing).map(|h| h.url());
-			f.files.set_show_hidden(self.conf.show_hidden);
-			f.files.set_sorter(self.conf.sorter());
+			// The recent files are all shown, in the order they were opened
+			let recent = f.cwd.is_recent();
+			f.files.set_show_hidden(self.conf.show_hidden || recent);
+			f.files.set_show_ignored(self.conf.show_ignored);
+			f.files.set_sorter(if recent { Default::default() } else { self.conf.sorter() });
 
 			render!(f.files.catchup_revision());
 			render!(f.repos(hovered));
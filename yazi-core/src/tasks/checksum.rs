@@ -0,0 +1,58 @@
+use tokio::fs;
+use yazi_config::{popup::InputCfg, tr};
+use yazi_plugin::external::Checksum;
+use yazi_shared::{emit, fs::Url};
+
+use crate::{input::Input, tasks::Tasks};
+
+impl Tasks {
+	/// Calculates the checksums of the files, asking first if the sums file to write is there.
+	pub fn file_checksum(
+		&self,
+		algo: Checksum,
+		targets: Vec<(Url, String, Option<String>)>,
+		write: Option<Url>,
+		copy: bool,
+	) {
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			if let Some(path) = &write {
+				if fs::symlink_metadata(path).await.is_ok() {
+					match Input::_show(InputCfg::overwrite()).recv().await {
+						Some(Ok(c)) if c == "y" || c == "Y" => (),
+						_ => return,
+					}
+				}
+			}
+			scheduler.file_checksum(algo, targets, write, copy);
+		});
+	}
+
+	/// Verifies the files listed in the sums file, relative to the directory it's in.
+	pub fn file_checksum_verify(&self, algo: Option<Checksum>, sums: Url) {
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			let listed = match fs::read_to_string(&sums).await {
+				Ok(s) => Checksum::parse_sums(&s),
+				Err(e) => {
+					let content = tr!("Failed to read {path}: {error}", path = sums.display(), error = e);
+					return emit!(Notify(tr!("Checksum"), content, "warn", 5));
+				}
+			};
+			let Some((_, first)) = listed.first() else {
+				let content = tr!("No checksums found in {path}", path = sums.display());
+				return emit!(Notify(tr!("Checksum"), content, "warn", 5));
+			};
+
+			let name = sums.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+			let algo = algo.unwrap_or_else(|| Checksum::guess(&name, first));
+
+			let base = sums.parent().map(|p| p.to_owned()).unwrap_or_default();
+			let targets = listed
+				.into_iter()
+				.map(|(name, hash)| (Url::from(base.join(&name)), name, Some(hash)))
+				.collect();
+			scheduler.file_checksum(algo, targets, None, false);
+		});
+	}
+}
@@ -0,0 +1,27 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::tasks::Tasks;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+
+impl Tasks {
+	/// Boosts the hovered task, or all of a group, to the high priority, or back if it is already.
+	pub fn boost(&mut self, _: impl Into<Opt>) {
+		let Some(summary) = self.summaries.get(self.cursor) else {
+			return;
+		};
+
+		let boosted = !summary.boosted;
+		let ids = summary.ids.clone();
+		if ids.into_iter().filter(|&id| self.scheduler.boost(id, boosted)).count() == 0 {
+			return;
+		}
+
+		self.summaries = self.paginate();
+		render!();
+	}
+}
@@ -2,10 +2,29 @@ use yazi_shared::{event::Cmd, render};
 
 use crate::tasks::Tasks;
 
+pub struct Opt {
+	all: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { all: c.named.contains_key("all") } }
+}
+
 impl Tasks {
-	pub fn cancel(&mut self, _: Cmd) {
-		let id = self.scheduler.running.lock().get_id(self.cursor);
-		if id.map(|id| self.scheduler.cancel(id)) != Some(true) {
+	/// Cancels the hovered task, or all of a group, or all of those listed if `--all`.
+	pub fn cancel(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let ids: Vec<_> = if opt.all {
+			let running = self.scheduler.running.lock();
+			running.values().filter(|&t| self.filter.matches(t)).map(|t| t.id).collect()
+		} else if let Some(summary) = self.summaries.get(self.cursor) {
+			summary.ids.clone()
+		} else {
+			return;
+		};
+
+		let canceled = ids.into_iter().filter(|&id| self.scheduler.cancel(id)).count();
+		if canceled == 0 {
 			return;
 		}
 
@@ -0,0 +1,35 @@
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	targets:   Vec<Url>,
+	mode:      u32,
+	mask:      u32,
+	recursive: bool,
+}
+
+impl TryFrom<Cmd> for Opt {
+	type Error = ();
+
+	fn try_from(mut c: Cmd) -> Result<Self, Self::Error> { c.take_data().ok_or(()) }
+}
+
+impl Tasks {
+	/// Sets the bits in `mask` to those of `mode`, leaving the others as they are.
+	#[inline]
+	pub fn _chmod(targets: Vec<Url>, mode: u32, mask: u32, recursive: bool) {
+		let opt = Opt { targets, mode, mask, recursive };
+		emit!(Call(Cmd::new("chmod").with_data(opt), Layer::Tasks));
+	}
+
+	pub fn chmod(&mut self, opt: impl TryInto<Opt>) {
+		let Ok(opt) = opt.try_into() else {
+			return;
+		};
+
+		for target in opt.targets {
+			self.scheduler.file_chmod(target, opt.mode, opt.mask, opt.recursive);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+use yazi_config::popup::InputCfg;
+use yazi_shared::event::Cmd;
+
+use crate::{input::Input, tasks::Tasks};
+
+pub struct Opt {
+	id: usize,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self {
+		Self { id: c.named.get("id").and_then(|s| s.parse().ok()).unwrap_or_default() }
+	}
+}
+
+impl Tasks {
+	// Offers to retry the operations of the task denied with a permission error as root
+	pub fn escalate(&mut self, opt: impl Into<Opt>) {
+		let id = opt.into().id;
+		let n = self.scheduler.running.lock().get(id).map_or(0, |t| t.denied.len());
+		if n == 0 {
+			return;
+		}
+
+		let scheduler = self.scheduler.clone();
+		tokio::spawn(async move {
+			let mut result = Input::_show(InputCfg::escalate(n));
+			match result.recv().await {
+				Some(Ok(choice)) if choice == "y" || choice == "Y" => scheduler.file_escalate(id),
+				_ => scheduler.file_escalate_decline(id),
+			}
+		});
+	}
+}
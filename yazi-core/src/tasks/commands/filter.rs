@@ -0,0 +1,23 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::tasks::{Tasks, TasksFilter};
+
+pub struct Opt {
+	filter: Option<TasksFilter>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { filter: c.take_first().and_then(|s| s.parse().ok()) } }
+}
+
+impl Tasks {
+	/// Lists the tasks in the state, or in the next one of all, running and failed if not given.
+	pub fn filter(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		self.filter = opt.filter.unwrap_or(self.filter.next());
+
+		self.summaries = self.paginate();
+		self.cursor = 0;
+		render!();
+	}
+}
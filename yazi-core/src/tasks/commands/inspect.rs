@@ -9,7 +9,7 @@ use crate::tasks::Tasks;
 
 impl Tasks {
 	pub fn inspect(&self, _: Cmd) {
-		let Some(id) = self.scheduler.running.lock().get_id(self.cursor) else {
+		let Some(&id) = self.summaries.get(self.cursor).and_then(|s| s.ids.first()) else {
 			return;
 		};
 
@@ -1,5 +1,12 @@
 mod arrow;
+mod boost;
 mod cancel;
+mod chmod;
+mod escalate;
+mod filter;
 mod inspect;
 mod open;
+mod rename;
+mod retry;
 mod toggle;
+mod workers;
@@ -0,0 +1,57 @@
+use yazi_config::tr;
+use yazi_shared::{emit, event::Cmd, fs::Url, Layer};
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	pairs: Vec<(Url, Url)>,
+	undo:  bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { pairs: c.take_data().unwrap_or_default(), undo: c.named.contains_key("undo") }
+	}
+}
+
+impl Tasks {
+	#[inline]
+	pub fn _rename(pairs: Vec<(Url, Url)>) {
+		emit!(Call(Cmd::new("rename").with_data(pairs), Layer::Tasks));
+	}
+
+	#[inline]
+	pub fn _rename_undo() {
+		emit!(Call(Cmd::new("rename").with_bool("undo", true), Layer::Tasks));
+	}
+
+	pub fn rename(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+
+		// Undoing renames the last batch back, and can itself be undone
+		let pairs: Vec<_> = if opt.undo {
+			self.renamed.lock().iter().map(|(from, to)| (to.clone(), from.clone())).collect()
+		} else {
+			opt.pairs
+		};
+		if pairs.is_empty() {
+			return;
+		}
+
+		// Both the old names and the new ones, which may be of files moved away in a swap
+		let paths = pairs.iter().flat_map(|(from, to)| [from.to_path_buf(), to.to_path_buf()]);
+		let checks = vec![(tr!("rename"), paths.collect())];
+
+		// All in one task, for the swaps and chains in it to be ordered, and rolled back on failure,
+		// which leaves the last batch that's done to be undone
+		let renamed = self.renamed.clone();
+		Self::guarded(&self.scheduler, checks, move |scheduler| {
+			let done = scheduler.file_rename(pairs.clone());
+			tokio::spawn(async move {
+				if done.await == Ok(true) {
+					*renamed.lock() = pairs;
+				}
+			});
+		});
+	}
+}
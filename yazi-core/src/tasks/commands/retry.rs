@@ -0,0 +1,36 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	all: bool,
+}
+
+impl From<Cmd> for Opt {
+	fn from(c: Cmd) -> Self { Self { all: c.named.contains_key("all") } }
+}
+
+impl Tasks {
+	/// Retries the failed operations of the hovered task, or of all of a group,
+	/// or of all of those listed if `--all`.
+	pub fn retry(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let ids: Vec<_> = if opt.all {
+			let running = self.scheduler.running.lock();
+			running.values().filter(|&t| self.filter.matches(t)).map(|t| t.id).collect()
+		} else if let Some(summary) = self.summaries.get(self.cursor) {
+			summary.ids.clone()
+		} else {
+			return;
+		};
+
+		let retried = ids.into_iter().filter(|&id| self.scheduler.file_retry(id)).count();
+		if retried == 0 {
+			return;
+		}
+
+		self.summaries = self.paginate();
+		self.arrow(0);
+		render!();
+	}
+}
@@ -0,0 +1,41 @@
+use yazi_scheduler::Lane;
+use yazi_shared::{event::Cmd, render};
+
+use crate::tasks::Tasks;
+
+pub struct Opt {
+	counts: Vec<(Lane, String)>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		let counts = Lane::ALL
+			.into_iter()
+			.filter_map(|lane| Some((lane, c.take_name(&lane.to_string())?)))
+			.collect();
+		Self { counts }
+	}
+}
+
+impl Tasks {
+	/// Changes the numbers of the workers of the lanes, to the one given, or by it if it's signed,
+	/// such as `workers --macro=4` or `workers --prework=-2`.
+	pub fn workers(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		if opt.counts.is_empty() {
+			return;
+		}
+
+		let lanes = self.scheduler.lanes();
+		for (lane, s) in opt.counts {
+			let now = lanes.iter().find(|l| l.lane == lane).map_or(0, |l| l.workers);
+			let Ok(n) = s.parse::<isize>() else {
+				continue;
+			};
+
+			let n = if s.starts_with(['+', '-']) { now.saturating_add_signed(n) } else { n.max(0) as _ };
+			self.scheduler.resize(lane, n);
+		}
+		render!();
+	}
+}
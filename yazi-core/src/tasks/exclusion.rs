@@ -0,0 +1,70 @@
+use std::{collections::HashMap, path::{Path, PathBuf}, sync::Arc};
+
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+use yazi_scheduler::{Exclude, ExcludeRules};
+
+use crate::folder::Ignore;
+
+/// What's left out of a paste, by the patterns relative to each of the pasted directories,
+/// and the `.gitignore` rules in their trees if they're honored.
+#[derive(Debug, Default)]
+pub struct Exclusion {
+	patterns:  Vec<String>,
+	gitignore: bool,
+}
+
+impl Exclusion {
+	pub fn new(patterns: Vec<String>, gitignore: bool) -> Self { Self { patterns, gitignore } }
+
+	#[inline]
+	pub(super) fn from_rules(rules: ExcludeRules) -> Self {
+		Self::new(rules.patterns, rules.gitignore)
+	}
+
+	/// The rules for pasting the `source`, or `None` if nothing is excluded.
+	pub(super) fn at(&self, source: &Path) -> Option<Arc<dyn Exclude>> {
+		if self.patterns.is_empty() && !self.gitignore {
+			return None;
+		}
+
+		let rules = ExcludeRules { patterns: self.patterns.clone(), gitignore: self.gitignore };
+		let patterns = Ignore::from_patterns(&self.patterns, source);
+		Some(Arc::new(Excluder { rules, patterns, loaded: Default::default() }))
+	}
+}
+
+#[derive(Debug)]
+struct Excluder {
+	rules:    ExcludeRules,
+	patterns: Option<Ignore>,
+	// The `.gitignore` rules of each directory walked, read once for all its entries
+	loaded:   Mutex<HashMap<PathBuf, Option<Arc<Ignore>>>>,
+}
+
+impl Exclude for Excluder {
+	fn excluded<'a>(&'a self, path: &'a Path, is_dir: bool) -> BoxFuture<'a, bool> {
+		Box::pin(async move {
+			if self.patterns.as_ref().is_some_and(|i| i.matches(path, Some(is_dir))) {
+				return true;
+			}
+
+			let Some(parent) = path.parent().filter(|_| self.rules.gitignore) else {
+				return false;
+			};
+
+			let loaded = self.loaded.lock().get(parent).cloned();
+			let ignore = match loaded {
+				Some(ignore) => ignore,
+				None => {
+					let ignore = Ignore::read(parent).await;
+					self.loaded.lock().insert(parent.to_owned(), ignore.clone());
+					ignore
+				}
+			};
+			ignore.is_some_and(|i| i.matches(path, Some(is_dir)))
+		})
+	}
+
+	fn rules(&self) -> ExcludeRules { self.rules.clone() }
+}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use yazi_scheduler::Task;
+
+/// Which of the tasks the task manager lists, by the state they're in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TasksFilter {
+	#[default]
+	All,
+	Running,
+	Failed,
+}
+
+impl FromStr for TasksFilter {
+	type Err = ();
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"all" => Self::All,
+			"running" => Self::Running,
+			"failed" => Self::Failed,
+			_ => return Err(()),
+		})
+	}
+}
+
+impl TasksFilter {
+	#[inline]
+	pub fn matches(self, task: &Task) -> bool {
+		match self {
+			Self::All => true,
+			Self::Running => task.fail == 0,
+			Self::Failed => task.fail > 0,
+		}
+	}
+
+	#[inline]
+	pub(super) fn next(self) -> Self {
+		match self {
+			Self::All => Self::Running,
+			Self::Running => Self::Failed,
+			Self::Failed => Self::All,
+		}
+	}
+}
@@ -1,10 +1,16 @@
+mod checksum;
 mod commands;
+mod exclusion;
+mod filter;
 mod progress;
+mod protect;
+mod resume;
 mod tasks;
 
+pub use exclusion::*;
+pub use filter::*;
 pub use progress::*;
 pub use tasks::*;
 
 pub const TASKS_BORDER: u16 = 2;
-pub const TASKS_PADDING: u16 = 2;
 pub const TASKS_PERCENT: u16 = 80;
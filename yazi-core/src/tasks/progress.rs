@@ -9,6 +9,7 @@ pub struct TasksProgress {
 
 	pub found:     u64,
 	pub processed: u64,
+	pub eta:       u64,
 }
 
 impl From<&Running> for TasksProgress {
@@ -23,8 +24,9 @@ impl From<&Running> for TasksProgress {
 			progress.succ += task.succ;
 			progress.fail += task.fail;
 
-			progress.found += task.found;
+			progress.found += task.size();
 			progress.processed += task.processed;
+			progress.eta = progress.eta.max(task.eta().unwrap_or(0));
 		}
 		progress
 	}
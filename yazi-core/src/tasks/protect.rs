@@ -0,0 +1,96 @@
+use std::{collections::BTreeSet, path::{Path, PathBuf}, sync::Arc};
+
+use yazi_config::{popup::InputCfg, protect::Protection, tr, PROTECT};
+use yazi_scheduler::Scheduler;
+use yazi_shared::emit;
+
+use super::Tasks;
+use crate::input::Input;
+
+impl Tasks {
+	/// Whether any of the files is in a protected path, and has to be guarded first.
+	pub(crate) fn protected(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> bool {
+		!PROTECT.is_empty() && paths.into_iter().any(|p| PROTECT.of(p.as_ref()).is_some())
+	}
+
+	/// Whether the files may be removed, moved away or renamed, as told by the `action`,
+	/// i.e. none of them is read-only, and the name of each protected path they're in is typed.
+	pub(crate) async fn guard(
+		action: &str,
+		paths: impl IntoIterator<Item = impl AsRef<Path>>,
+	) -> bool {
+		let mut zones = BTreeSet::new();
+		for p in paths {
+			match PROTECT.of(p.as_ref()) {
+				Some((Protection::ReadOnly, zone)) => {
+					Self::read_only_notify(action, zone);
+					return false;
+				}
+				Some((Protection::Confirm, zone)) => _ = zones.insert(zone.to_owned()),
+				None => {}
+			}
+		}
+
+		for zone in zones {
+			let name = zone.file_name().unwrap_or(zone.as_os_str()).to_string_lossy().into_owned();
+			match Input::_show(InputCfg::protect(&name, action)).recv().await {
+				Some(Ok(s)) if s == name => {}
+				Some(Ok(_)) => {
+					let content = tr!("Not the name of {name}, nothing is done", name = name);
+					emit!(Notify(tr!("Protected"), content, "warn", 5));
+					return false;
+				}
+				_ => return false,
+			}
+		}
+		true
+	}
+
+	/// Whether any of the files is read-only, refused to be touched by the `action` before anything
+	/// is asked for it, leaving the rest to be guarded as it's done.
+	pub(crate) fn read_only(
+		action: &str,
+		paths: impl IntoIterator<Item = impl AsRef<Path>>,
+	) -> bool {
+		let zone = paths.into_iter().find_map(|p| match PROTECT.of(p.as_ref()) {
+			Some((Protection::ReadOnly, zone)) => Some(zone.to_owned()),
+			_ => None,
+		});
+		zone.inspect(|z| Self::read_only_notify(action, z)).is_some()
+	}
+
+	/// Runs `f` once the files it touches may be, by the `action` on each of them, e.g. the sources
+	/// to be moved away and the destinations to be overwritten, as the one way the file tasks are
+	/// started. It's run right away if none of them is protected, and returns whether it's done so.
+	pub(crate) fn guarded(
+		scheduler: &Arc<Scheduler>,
+		checks: Vec<(&'static str, Vec<PathBuf>)>,
+		f: impl FnOnce(&Scheduler) + Send + 'static,
+	) -> bool {
+		if !checks.iter().any(|(_, paths)| Self::protected(paths)) {
+			f(scheduler);
+			return true;
+		}
+
+		let scheduler = scheduler.clone();
+		tokio::spawn(async move {
+			for (action, paths) in checks {
+				if !Self::guard(action, paths).await {
+					return;
+				}
+			}
+			f(&scheduler);
+		});
+		false
+	}
+
+	#[inline]
+	fn read_only_notify(action: &str, zone: &Path) {
+		let content = tr!(
+			"{path} is read-only, refused to {action} the files in it",
+			path = zone.display(),
+			action = action
+		);
+		emit!(Notify(tr!("Protected"), content, "warn", 5));
+	}
+}
@@ -0,0 +1,178 @@
+use std::{fs, path::PathBuf, sync::Arc};
+
+use yazi_config::{popup::SelectCfg, tr, BOOT};
+use yazi_scheduler::{Resumable, Scheduler};
+use yazi_shared::pid_alive;
+
+use super::Exclusion;
+use crate::{select::Select, tasks::Tasks};
+
+impl Tasks {
+	/// Saves the tasks still waiting for a worker, for them to be offered on the next launch,
+	/// leaving out those already started, as what's done of them can't be told apart.
+	pub fn save_queued(&self) {
+		let path = Self::queued_path(std::process::id());
+		let queued = self.scheduler.file_queued();
+		if queued.is_empty() {
+			fs::remove_file(path).ok();
+		} else if let Ok(s) = serde_json::to_string(&queued) {
+			fs::write(path, s).ok();
+		}
+	}
+
+	// Offers the tasks left waiting by the instances no longer running, all of them, or one of
+	// them, to be resumed, which are forgotten once answered, and offered again if it's dismissed.
+	pub(super) fn resume_queued(scheduler: Arc<Scheduler>) {
+		let (claimed, mut queued) = Self::claim_queued();
+		if queued.is_empty() {
+			claimed.into_iter().for_each(|p| _ = fs::remove_file(p));
+			return;
+		}
+
+		tokio::spawn(async move {
+			let items = [tr!("Resume all {n} task{s}", n = queued.len())]
+				.into_iter()
+				.chain(queued.iter().map(|r| r.name()))
+				.chain([tr!("Discard them").to_owned()])
+				.collect();
+
+			let n = queued.len();
+			let queued = match Select::_show(SelectCfg::resume(items)).await {
+				Ok(0) => queued,
+				Ok(i) if i <= n => vec![queued.swap_remove(i - 1)],
+				Ok(_) => vec![],
+				Err(_) => return,
+			};
+			claimed.into_iter().for_each(|p| _ = fs::remove_file(p));
+			if queued.is_empty() {
+				return;
+			}
+
+			// Guarded as when they were asked for, as the protected paths may have changed since
+			let checks = Self::resume_checks(&queued);
+			Self::guarded(&scheduler, checks, move |scheduler| {
+				let n = queued.len();
+				let ids: Vec<_> = queued.into_iter().map(|r| Self::resume(scheduler, r)).collect();
+				scheduler.group(tr!("Resume {n} task{s}", n = n), &ids);
+			});
+		});
+	}
+
+	// Takes the tasks saved by the instances no longer running, by renaming their files to ones
+	// of our own, which only one of the instances started at once can do, and which are kept
+	// until the user answers, to be offered again by the next one if this one is gone before.
+	fn claim_queued() -> (Vec<PathBuf>, Vec<Resumable>) {
+		let Ok(it) = fs::read_dir(&BOOT.state_dir) else {
+			return Default::default();
+		};
+
+		let owners = it.flatten().filter_map(|e| {
+			let name = e.file_name();
+			let pid = name.to_str()?.strip_prefix("tasks-")?.split('.').next()?.parse::<u32>().ok()?;
+			(pid != std::process::id() && !pid_alive(pid)).then(|| e.path())
+		});
+
+		let (mut claimed, mut queued) = (vec![], vec![]);
+		for (i, path) in owners.enumerate() {
+			let to = Self::queued_path(std::process::id()).with_extension(i.to_string());
+			if fs::rename(path, &to).is_err() {
+				continue;
+			}
+			if let Ok(v) = fs::read(&to).map(|b| serde_json::from_slice::<Vec<Resumable>>(&b)) {
+				queued.extend(v.unwrap_or_default());
+			}
+			claimed.push(to);
+		}
+		(claimed, queued)
+	}
+
+	fn resume_checks(queued: &[Resumable]) -> Vec<(&'static str, Vec<PathBuf>)> {
+		let (mut moved, mut overwritten, mut removed) = (vec![], vec![], vec![]);
+		for r in queued {
+			match r {
+				Resumable::Cut { from, to, force, .. } => {
+					moved.push(from.clone());
+					if *force {
+						overwritten.push(to.clone());
+					}
+				}
+				Resumable::Copy { to, force: true, .. } | Resumable::Link { to, force: true, .. } => {
+					overwritten.push(to.clone());
+				}
+				Resumable::Copy { .. } | Resumable::Link { .. } => {}
+				Resumable::Delete { target } | Resumable::Trash { target } => removed.push(target.clone()),
+			}
+		}
+		vec![(tr!("move"), moved), (tr!("overwrite"), overwritten), (tr!("remove"), removed)]
+	}
+
+	fn resume(scheduler: &Scheduler, resumable: Resumable) -> usize {
+		match resumable {
+			Resumable::Cut { from, to, force, exclude } => {
+				let exclude = exclude.and_then(|e| Exclusion::from_rules(e).at(&from));
+				scheduler.file_cut(from.into(), to.into(), force, exclude)
+			}
+			Resumable::Copy { from, to, force, follow, exclude } => {
+				let exclude = exclude.and_then(|e| Exclusion::from_rules(e).at(&from));
+				scheduler.file_copy(from.into(), to.into(), force, follow, exclude)
+			}
+			Resumable::Link { from, to, relative, resolve, force } => {
+				scheduler.file_link(from.into(), to.into(), relative, resolve, force)
+			}
+			Resumable::Delete { target } => scheduler.file_delete(target.into()),
+			Resumable::Trash { target } => scheduler.file_trash(target.into()),
+		}
+	}
+
+	#[inline]
+	fn queued_path(pid: u32) -> PathBuf { BOOT.state_dir.join(format!("tasks-{pid}")) }
+}
+
+#[cfg(test)]
+mod tests {
+	use yazi_scheduler::ExcludeRules;
+
+	use super::*;
+
+	// As it's saved by `save_queued()` and read back by `claim_queued()`
+	fn roundtrip(r: Resumable) {
+		let s = serde_json::to_string(&[&r]).unwrap();
+		assert_eq!(serde_json::from_str::<Vec<Resumable>>(&s).unwrap(), [r]);
+	}
+
+	#[test]
+	fn test_roundtrip() {
+		let exclude = ExcludeRules { patterns: vec!["node_modules".to_owned()], gitignore: true };
+		roundtrip(Resumable::Cut {
+			from:    "/a/b".into(),
+			to:      "/c/b".into(),
+			force:   false,
+			exclude: Some(exclude),
+		});
+		roundtrip(Resumable::Copy {
+			from:    "/a/b".into(),
+			to:      "/c/b".into(),
+			force:   true,
+			follow:  false,
+			exclude: None,
+		});
+		roundtrip(Resumable::Link {
+			from:     "/a".into(),
+			to:       "/b".into(),
+			relative: true,
+			resolve:  false,
+			force:    false,
+		});
+		roundtrip(Resumable::Delete { target: "/a/ü b".into() });
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_roundtrip_non_utf8() {
+		use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+		let target = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff\xfe"));
+		assert!(target.to_str().is_none());
+		roundtrip(Resumable::Trash { target });
+	}
+}
@@ -1,14 +1,15 @@
-use std::{collections::{BTreeMap, HashMap, HashSet}, ffi::OsStr, mem, path::Path, sync::Arc, time::Duration};
+use std::{collections::{BTreeMap, HashMap, HashSet}, ffi::OsStr, mem, path::{Path, PathBuf}, sync::Arc, time::Duration};
 
+use parking_lot::Mutex;
 use tokio::time::sleep;
 use tracing::debug;
-use yazi_config::{manager::SortBy, open::Opener, plugin::{PluginRule, MAX_PRELOADERS}, popup::InputCfg, OPEN, PLUGIN};
+use yazi_config::{manager::SortBy, open::Opener, plugin::{PluginRule, MAX_PRELOADERS}, popup::InputCfg, tr, OPEN, PLUGIN, TASKS, THEME};
 use yazi_plugin::ValueSendable;
-use yazi_scheduler::{Scheduler, TaskSummary};
+use yazi_scheduler::{LaneStat, Scheduler, TaskSummary};
 use yazi_shared::{emit, event::Cmd, fs::{File, Url}, term::Term, Layer, MIME_DIR};
 
-use super::{TasksProgress, TASKS_BORDER, TASKS_PADDING, TASKS_PERCENT};
-use crate::{folder::Files, input::Input};
+use super::{Exclusion, TasksFilter, TasksProgress, TASKS_BORDER, TASKS_PERCENT};
+use crate::{folder::Files, input::Input, manager::Learned, mounts::Mounts};
 
 pub struct Tasks {
 	pub(super) scheduler: Arc<Scheduler>,
@@ -17,6 +18,10 @@ pub struct Tasks {
 	pub cursor:    usize,
 	pub progress:  TasksProgress,
 	pub summaries: Vec<TaskSummary>,
+	pub filter:    TasksFilter,
+
+	// The last batch of renames, from the old to the new names, for undoing it
+	pub(super) renamed: Arc<Mutex<Vec<(Url, Url)>>>,
 }
 
 impl Tasks {
@@ -27,8 +32,13 @@ impl Tasks {
 			cursor:    0,
 			progress:  Default::default(),
 			summaries: Default::default(),
+			filter:    Default::default(),
+			renamed:   Default::default(),
 		};
 
+		Self::resume_queued(tasks.scheduler.clone());
+		Learned::preload();
+
 		let running = tasks.scheduler.running.clone();
 		tokio::spawn(async move {
 			let mut last = TasksProgress::default();
@@ -48,18 +58,27 @@ impl Tasks {
 
 	#[inline]
 	pub fn limit() -> usize {
-		(Term::size().rows * TASKS_PERCENT / 100).saturating_sub(TASKS_BORDER + TASKS_PADDING) as usize
+		let padding = THEME.load().ui.density.padding();
+		let frame = TASKS_BORDER + padding.top + padding.bottom;
+		(Term::size().rows * TASKS_PERCENT / 100).saturating_sub(frame) as usize
 	}
 
 	pub fn paginate(&self) -> Vec<TaskSummary> {
 		let running = self.scheduler.running.lock();
-		running.values().take(Self::limit()).map(Into::into).collect()
+		let mut summaries = running.summaries(|t| self.filter.matches(t));
+		summaries.truncate(Self::limit());
+		summaries
 	}
 
 	pub fn file_open(&self, targets: &[(impl AsRef<Path>, impl AsRef<str>)]) -> bool {
 		let mut openers = BTreeMap::new();
 		for (path, mime) in targets {
-			if let Some(opener) = OPEN.openers(path, mime).and_then(|o| o.first().copied()) {
+			// An opener learned for the extension comes before the rules
+			let opener = Some(path.as_ref())
+				.filter(|_| mime.as_ref() != MIME_DIR)
+				.and_then(Learned::get)
+				.or_else(|| OPEN.openers(path, mime).and_then(|o| o.first().copied()));
+			if let Some(opener) = opener {
 				openers.entry(opener).or_insert_with(Vec::new).push(path.as_ref().as_os_str());
 			}
 		}
@@ -80,74 +99,186 @@ impl Tasks {
 		false
 	}
 
-	pub fn file_cut(&self, src: &HashSet<Url>, dest: &Url, force: bool) {
+	/// Moves the files, and returns whether it's started right away, not waiting to be confirmed
+	/// for the protected ones.
+	pub fn file_cut(
+		&self,
+		src: &HashSet<Url>,
+		dest: &Url,
+		force: bool,
+		exclusion: &Exclusion,
+	) -> bool {
+		let mut todo = Vec::with_capacity(src.len());
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
 			if force && u == &to {
 				debug!("file_cut: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_cut(u.clone(), to, force);
+				todo.push((u.clone(), to, exclusion.at(u)));
 			}
 		}
+
+		let name = tr!("Cut {n} file{s} to {to}", n = todo.len(), to = dest.display());
+		let checks = Self::paste_checks(todo.iter().map(|(from, to, _)| (from, to)), force, true);
+		Self::guarded(&self.scheduler, checks, move |scheduler| {
+			let ids: Vec<_> = todo
+				.into_iter()
+				.map(|(from, to, exclude)| scheduler.file_cut(from, to, force, exclude))
+				.collect();
+			scheduler.group(name, &ids);
+		})
 	}
 
-	pub fn file_copy(&self, src: &HashSet<Url>, dest: &Url, force: bool, follow: bool) {
+	pub fn file_copy(
+		&self,
+		src: &HashSet<Url>,
+		dest: &Url,
+		force: bool,
+		follow: bool,
+		exclusion: &Exclusion,
+	) {
+		let mut todo = Vec::with_capacity(src.len());
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
 			if force && u == &to {
 				debug!("file_copy: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_copy(u.clone(), to, force, follow);
+				todo.push((u.clone(), to, exclusion.at(u)));
 			}
 		}
+
+		let name = tr!("Copy {n} file{s} to {to}", n = todo.len(), to = dest.display());
+		let checks = Self::paste_checks(todo.iter().map(|(from, to, _)| (from, to)), force, false);
+		Self::guarded(&self.scheduler, checks, move |scheduler| {
+			let ids: Vec<_> = todo
+				.into_iter()
+				.map(|(from, to, exclude)| scheduler.file_copy(from, to, force, follow, exclude))
+				.collect();
+			scheduler.group(name, &ids);
+		});
 	}
 
-	pub fn file_link(&self, src: &HashSet<Url>, dest: &Url, relative: bool, force: bool) {
+	pub fn file_link(
+		&self,
+		src: &HashSet<Url>,
+		dest: &Url,
+		relative: bool,
+		resolve: bool,
+		force: bool,
+	) {
+		let mut todo = Vec::with_capacity(src.len());
 		for u in src {
 			let to = dest.join(u.file_name().unwrap());
 			if force && *u == to {
 				debug!("file_link: same file, skipping {:?}", to);
 			} else {
-				self.scheduler.file_link(u.clone(), to, relative, force);
+				todo.push((u.clone(), to));
 			}
 		}
+
+		let name = tr!("Link {n} file{s} to {to}", n = todo.len(), to = dest.display());
+		let checks = Self::paste_checks(todo.iter().map(|(from, to)| (from, to)), force, false);
+		Self::guarded(&self.scheduler, checks, move |scheduler| {
+			let ids: Vec<_> = todo
+				.into_iter()
+				.map(|(from, to)| scheduler.file_link(from, to, relative, resolve, force))
+				.collect();
+			scheduler.group(name, &ids);
+		});
 	}
 
-	pub fn file_remove(&self, targets: Vec<Url>, force: bool, permanently: bool) {
-		if force {
-			for u in targets {
-				if permanently {
-					self.scheduler.file_delete(u);
-				} else {
-					self.scheduler.file_trash(u);
-				}
-			}
-			return;
+	// The sources are moved away by a cut, and the destinations overwritten if it's forced,
+	// otherwise they're given a name not taken
+	fn paste_checks<'a>(
+		pairs: impl Iterator<Item = (&'a Url, &'a Url)>,
+		force: bool,
+		cut: bool,
+	) -> Vec<(&'static str, Vec<PathBuf>)> {
+		let (sources, dests): (Vec<_>, Vec<_>) =
+			pairs.map(|(from, to)| (from.to_path_buf(), to.to_path_buf())).unzip();
+		[(cut, tr!("move"), sources), (force, tr!("overwrite"), dests)]
+			.into_iter()
+			.filter_map(|(b, action, paths)| b.then_some((action, paths)))
+			.collect()
+	}
+
+	pub fn file_remove(&self, targets: Vec<Url>, force: bool, permanently: bool, mounts: &Mounts) {
+		// Those without a recycle bin can only be deleted, and those on a filesystem
+		// the `remove_rules` say to delete on are, both of which are always asked for
+		let (targets, deleting, untrashable) = if permanently {
+			(targets, vec![], vec![])
+		} else {
+			let (targets, deleting) = Self::file_remove_split(targets, mounts);
+			let (targets, untrashable) = targets.into_iter().partition(|u| Scheduler::can_trash(u));
+			(targets, deleting, untrashable)
+		};
+
+		let guarded = Self::protected(targets.iter().chain(&deleting).chain(&untrashable));
+		if force && !guarded && deleting.is_empty() && untrashable.is_empty() {
+			return Self::file_remove_do(&self.scheduler, targets, permanently);
 		}
 
 		let scheduler = self.scheduler.clone();
 		tokio::spawn(async move {
-			let mut result = Input::_show(if permanently {
-				InputCfg::delete(targets.len())
-			} else {
-				InputCfg::trash(targets.len())
-			});
+			let confirm = |cfg| async move {
+				let mut result = Input::_show(cfg);
+				matches!(result.recv().await, Some(Ok(choice)) if choice == "y" || choice == "Y")
+			};
 
-			if let Some(Ok(choice)) = result.recv().await {
-				if choice != "y" && choice != "Y" {
+			let all = targets.iter().chain(&deleting).chain(&untrashable);
+			if guarded && !Self::guard(tr!("remove"), all).await {
+				return;
+			}
+
+			if !targets.is_empty() && !force {
+				let cfg = if permanently {
+					InputCfg::delete(targets.len())
+				} else {
+					InputCfg::trash(targets.len())
+				};
+				if !confirm(cfg).await {
 					return;
 				}
-				for u in targets {
-					if permanently {
-						scheduler.file_delete(u);
-					} else {
-						scheduler.file_trash(u);
-					}
-				}
+			}
+			Self::file_remove_do(&scheduler, targets, permanently);
+
+			if !deleting.is_empty() && confirm(InputCfg::delete(deleting.len())).await {
+				Self::file_remove_do(&scheduler, deleting, true);
+			}
+			if !untrashable.is_empty() && confirm(InputCfg::no_trash(untrashable.len())).await {
+				Self::file_remove_do(&scheduler, untrashable, true);
 			}
 		});
 	}
 
+	// Into those to trash, and those to delete permanently by the filesystem they're on,
+	// in the table of the mounts as they were last refreshed
+	fn file_remove_split(targets: Vec<Url>, mounts: &Mounts) -> (Vec<Url>, Vec<Url>) {
+		if TASKS.remove_rules.is_empty() {
+			return (targets, vec![]);
+		}
+
+		targets.into_iter().partition(|u| {
+			!Mounts::filesystem_of(&mounts.table, u)
+				.is_some_and(|(mount, fstype)| TASKS.remove_permanently(mount, fstype))
+		})
+	}
+
+	fn file_remove_do(scheduler: &Scheduler, targets: Vec<Url>, permanently: bool) {
+		let n = targets.len();
+		let ids: Vec<_> = targets
+			.into_iter()
+			.map(|u| if permanently { scheduler.file_delete(u) } else { scheduler.file_trash(u) })
+			.collect();
+
+		let name = if permanently {
+			tr!("Delete {n} file{s}", n = n)
+		} else {
+			tr!("Trash {n} file{s}", n = n)
+		};
+		scheduler.group(name, &ids);
+	}
+
 	#[inline]
 	pub fn plugin_micro(&self, name: String, args: Vec<ValueSendable>) {
 		self.scheduler.plugin_micro(name, args);
@@ -247,4 +378,7 @@ impl Tasks {
 impl Tasks {
 	#[inline]
 	pub fn len(&self) -> usize { self.scheduler.running.lock().len() }
+
+	#[inline]
+	pub fn lanes(&self) -> Vec<LaneStat> { self.scheduler.lanes() }
 }
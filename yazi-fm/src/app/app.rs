@@ -1,39 +1,109 @@
-use std::{collections::VecDeque, sync::atomic::Ordering};
+use std::{collections::VecDeque, sync::atomic::Ordering, time::{Duration, Instant}};
 
 use anyhow::Result;
 use crossterm::event::KeyEvent;
-use yazi_config::keymap::Key;
-use yazi_core::input::InputMode;
-use yazi_shared::{emit, event::{Cmd, Event, NEED_RENDER}, term::Term, Layer};
+use tokio::{select, task::JoinHandle, time};
+use yazi_config::{check::Severity, keymap::Key, tr, ARGS, CHECK, LOCALE, MANAGER, THEME};
+use yazi_core::{input::InputMode, manager::{Journal, Setup}};
+use yazi_plugin::external::Highlighter;
+use yazi_shared::{emit, event::{Cmd, Event, NEED_RENDER}, term::{Colors, Term}, Layer};
 
-use crate::{lives::Lives, Ctx, Executor, Logs, Panic, Router, Signals};
+use super::{Damage, FRAME_BUDGET};
+use crate::{debug::{Debug, Slow}, lives::Lives, Ctx, Executor, Feed, Logs, Panic, Profile, Record, Router, Signals};
 
 pub(crate) struct App {
 	pub(crate) cx:      Ctx,
 	pub(crate) term:    Option<Term>,
 	pub(crate) signals: Signals,
+
+	pub(crate) mouse_grab:  Option<&'static str>,
+	pub(crate) damage:      Damage,
+	pub(crate) rendered_at: Instant,
+	pub(crate) debug:       Debug,
+	pub(crate) journaled:   (Instant, Option<Journal>),
+	pub(crate) feed:        Feed,
+	pub(crate) clock:       Option<JoinHandle<()>>,
 }
 
 impl App {
-	pub(crate) async fn run() -> Result<()> {
+	pub(crate) async fn run(mut profile: Profile) -> Result<()> {
 		Panic::install();
 		let _log = Logs::init()?;
-		let term = Term::start()?;
+		Record::start()?;
+		let term = Term::start(!MANAGER.mouse_events.is_empty())?;
 		let signals = Signals::start()?;
+		Colors::init(MANAGER.colors.colors());
+		profile.mark("terminal");
 
 		Lives::register()?;
-		let mut app = Self { cx: Ctx::make(), term: Some(term), signals };
+		crate::server::Server::start();
+		let mut app = Self {
+			cx:          Ctx::make(),
+			term:        Some(term),
+			signals,
+			mouse_grab:  None,
+			damage:      Default::default(),
+			rendered_at: Instant::now(),
+			debug:       Default::default(),
+			journaled:   (Instant::now(), None),
+			feed:        Feed::start(),
+			clock:       None,
+		};
+		profile.mark("context");
+
 		app.render();
+		profile.mark("first frame");
+		if ARGS.profile_startup {
+			Term::goodbye(|| {
+				print!("{}", profile.report());
+				false
+			});
+		}
+
+		// The icon index and the syntaxes of the highlighter aren't needed for the first frame,
+		// so they're prepared afterwards, for the first preview and shell input not to wait on them
+		std::thread::spawn(|| {
+			THEME.load().icons.prepare();
+			Highlighter::init();
+		});
+		Setup::offer();
+		Journal::offer();
+
+		let warnings = CHECK.count(Severity::Warning);
+		if warnings > 0 {
+			let content = tr!(
+				"{n} problem{s} found in the config,\nrun `yazi --check-config` to see them",
+				n = warnings
+			);
+			emit!(Notify(tr!("Config"), content, "warn", 10));
+		}
+
+		if let Some(error) = &LOCALE.error {
+			emit!(Notify(tr!("Locale"), error.as_str(), "warn", 10));
+		}
 
 		let mut times = 0;
 		let mut events = Vec::with_capacity(200);
-		while app.signals.rx.recv_many(&mut events, 50).await > 0 {
+		loop {
+			let wait = app.render_wait();
+			select! {
+				n = app.signals.rx.recv_many(&mut events, 50) => if n == 0 { break },
+				_ = time::sleep(wait.unwrap_or_default()), if wait.is_some() => {}
+			}
+
+			app.debug.events = events.len();
 			for event in events.drain(..) {
 				times += 1;
 				app.dispatch(event)?;
 			}
+			app.journal();
+			app.clock();
+			app.feed.observe(&app.cx.manager);
 
-			if !NEED_RENDER.swap(false, Ordering::Relaxed) {
+			if app.render_wait() != Some(Duration::ZERO) {
+				continue;
+			} else if !NEED_RENDER.swap(false, Ordering::Relaxed) {
+				app.render_partially();
 				continue;
 			}
 
@@ -51,6 +121,53 @@ impl App {
 		Ok(())
 	}
 
+	// Journals the tabs if they've changed, at most once a second
+	fn journal(&mut self) {
+		if self.journaled.0.elapsed() < Duration::from_secs(1) {
+			return;
+		}
+
+		self.journaled.0 = Instant::now();
+		let journal = self.cx.manager.journal();
+		if self.journaled.1.as_ref() == Some(&journal) {
+			return;
+		}
+
+		// Written off the event loop, as the disk may be slow, e.g. a network home
+		let j = journal.clone();
+		tokio::task::spawn_blocking(move || j.write().ok());
+		self.journaled.1 = Some(journal);
+	}
+
+	// Redraws every minute while any times are shown relative, for them not to go stale
+	fn clock(&mut self) {
+		let relative = MANAGER.time_format == "relative"
+			|| self.cx.manager.tabs.iter().any(|t| t.conf.linemode.contains("relative"));
+		if relative == self.clock.is_some() {
+			return;
+		}
+
+		if let Some(h) = self.clock.take() {
+			h.abort();
+		}
+		if relative {
+			self.clock = Some(tokio::spawn(async {
+				loop {
+					time::sleep(Duration::from_secs(60)).await;
+					emit!(Render);
+				}
+			}));
+		}
+	}
+
+	// How long to wait before the next render if there is anything to render
+	fn render_wait(&self) -> Option<Duration> {
+		if !NEED_RENDER.load(Ordering::Relaxed) && self.damage.is_empty() {
+			return None;
+		}
+		Some(FRAME_BUDGET.saturating_sub(self.rendered_at.elapsed()))
+	}
+
 	#[inline]
 	fn dispatch(&mut self, event: Event) -> Result<()> {
 		match event {
@@ -58,6 +175,7 @@ impl App {
 			Event::Seq(cmds, layer) => self.dispatch_seq(cmds, layer),
 			Event::Render => self.dispatch_render(),
 			Event::Key(key) => self.dispatch_key(key),
+			Event::Mouse(mouse) => self.mouse(mouse),
 			Event::Resize => self.resize(()),
 			Event::Paste(str) => self.dispatch_paste(str),
 			Event::Quit(opt) => self.quit(opt),
@@ -66,11 +184,29 @@ impl App {
 	}
 
 	#[inline]
-	fn dispatch_call(&mut self, cmd: Cmd, layer: Layer) { Executor::new(self).execute(cmd, layer); }
+	fn dispatch_call(&mut self, cmd: Cmd, layer: Layer) {
+		Panic::record_cmd(layer, &cmd);
+		if !Record::guard(layer, &cmd) {
+			return;
+		}
+		// Only timed with the debug overlay shown, for the rest not to pay for it on each command
+		if !self.debug.visible {
+			return Executor::new(self).execute(cmd, layer);
+		}
+
+		let (name, start) = (cmd.name.clone(), Instant::now());
+		Executor::new(self).execute(cmd, layer);
+		Slow::report(format_args!("Run `{name}` in the {layer} layer"), start);
+	}
 
 	#[inline]
 	fn dispatch_seq(&mut self, mut cmds: VecDeque<Cmd>, layer: Layer) {
 		if let Some(cmd) = cmds.pop_front() {
+			Panic::record_cmd(layer, &cmd);
+			Record::cmd(layer, &cmd);
+			if !Record::guard(layer, &cmd) {
+				return;
+			}
 			Executor::new(self).execute(cmd, layer);
 		}
 		if !cmds.is_empty() {
@@ -82,14 +218,20 @@ impl App {
 	fn dispatch_render(&mut self) { NEED_RENDER.store(true, Ordering::Relaxed); }
 
 	#[inline]
-	fn dispatch_key(&mut self, key: KeyEvent) { Router::new(self).route(Key::from(key)); }
+	fn dispatch_key(&mut self, key: KeyEvent) {
+		let key = Key::from(key);
+		Panic::record(format_args!("key: {key}"));
+		Record::key(key);
+		Router::new(self).route(key);
+	}
 
 	#[inline]
 	fn dispatch_paste(&mut self, str: String) {
+		Record::paste(&str);
 		if self.cx.input.visible {
 			let input = &mut self.cx.input;
 			if input.mode() == InputMode::Insert {
-				input.type_str(&str);
+				input.type_paste(&str);
 			}
 		}
 	}
@@ -0,0 +1,18 @@
+use yazi_shared::{event::Cmd, render};
+
+use crate::app::App;
+
+pub struct Opt;
+
+impl From<Cmd> for Opt {
+	fn from(_: Cmd) -> Self { Self }
+}
+
+impl App {
+	/// Shows or hides the debug overlay, of how long the frames take, how busy the app
+	/// and the workers are, and the operations slow lately.
+	pub(crate) fn debug(&mut self, _: impl Into<Opt>) {
+		self.debug.toggle();
+		render!();
+	}
+}
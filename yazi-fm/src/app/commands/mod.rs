@@ -1,9 +1,13 @@
+mod debug;
+mod mouse;
 mod notify;
 mod plugin;
+mod query;
 mod quit;
 mod render;
 mod resize;
 mod resume;
 mod stop;
+mod theme;
 mod update_notify;
 mod update_progress;
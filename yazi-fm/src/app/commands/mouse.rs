@@ -0,0 +1,65 @@
+use crossterm::event::{MouseEvent, MouseEventKind};
+use mlua::{Table, TableExt};
+use tracing::error;
+use yazi_config::MANAGER;
+use yazi_plugin::{bindings::Cast, LUA};
+use yazi_shared::Layer;
+
+use crate::{app::App, lives::Lives};
+
+impl App {
+	pub(crate) fn mouse(&mut self, event: MouseEvent) {
+		let Some(size) = self.term.as_ref().and_then(|t| t.size().ok()) else {
+			return;
+		};
+		if self.cx.layer() != Layer::Manager {
+			return;
+		}
+
+		// Keep in sync with the layout of `Root`
+		let hit = if event.row == 0 {
+			"Header"
+		} else if event.row + 1 == size.height {
+			"Status"
+		} else {
+			"Manager"
+		};
+
+		// Drags and releases go to the component that received the press
+		let component = match event.kind {
+			MouseEventKind::Down(_) => *self.mouse_grab.insert(hit),
+			MouseEventKind::Up(_) => self.mouse_grab.take().unwrap_or(hit),
+			MouseEventKind::Drag(_) => self.mouse_grab.unwrap_or(hit),
+			_ => hit,
+		};
+
+		let result = Lives::scope(&self.cx, |_| {
+			let evt = yazi_plugin::bindings::MouseEvent::cast(&LUA, event)?;
+			let comp: Table = LUA.globals().get(component)?;
+
+			match event.kind {
+				MouseEventKind::Down(_) if MANAGER.mouse_events.click => {
+					comp.call_method::<_, ()>("click", (evt, false))?;
+				}
+				MouseEventKind::Up(_) if MANAGER.mouse_events.click => {
+					comp.call_method::<_, ()>("click", (evt, true))?;
+				}
+				MouseEventKind::ScrollDown if MANAGER.mouse_events.scroll => {
+					comp.call_method::<_, ()>("scroll", (evt, 1))?;
+				}
+				MouseEventKind::ScrollUp if MANAGER.mouse_events.scroll => {
+					comp.call_method::<_, ()>("scroll", (evt, -1))?;
+				}
+				MouseEventKind::Drag(_) if MANAGER.mouse_events.drag => {
+					comp.call_method::<_, ()>("drag", evt)?;
+				}
+				_ => {}
+			}
+			Ok(())
+		});
+
+		if let Err(e) = result {
+			error!("{:?}", e);
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+use yazi_shared::{event::Cmd, fs::lossless::Lossless};
+
+use crate::app::App;
+
+pub struct Opt {
+	what:  String,
+	reply: Option<oneshot::Sender<Result<Value, String>>>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self {
+		Self { what: c.take_first().unwrap_or_default(), reply: c.take_data() }
+	}
+}
+
+impl App {
+	/// Answers a query of the state from `ya get` and `export_state`, of the active tab unless
+	/// it's `tabs`.
+	pub(crate) fn query(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let Some(reply) = opt.reply else {
+			return;
+		};
+
+		let state = self.cx.manager.state();
+		let result = match opt.what.as_str() {
+			"cwd" => Ok(json!(Lossless(&state.cwd))),
+			"hovered" => Ok(json!(state.hovered.as_deref().map(Lossless))),
+			"selection" => Ok(json!(state.selected.iter().map(|p| Lossless(p)).collect::<Vec<_>>())),
+			"state" => Ok(json!(state)),
+			"tabs" => {
+				let journal = self.cx.manager.journal();
+				Ok(Value::Array(
+					journal
+						.tabs
+						.into_iter()
+						.enumerate()
+						.map(|(i, t)| {
+							json!({
+								"cwd": Lossless(&t.cwd),
+								"hovered": t.hovered.as_deref().map(Lossless),
+								"label": t.label,
+								"active": i == journal.idx,
+							})
+						})
+						.collect(),
+				))
+			}
+			w => Err(format!("Unknown `{w}`, it's one of cwd, hovered, selection, state and tabs")),
+		};
+		reply.send(result).ok();
+	}
+}
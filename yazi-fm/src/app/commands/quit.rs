@@ -1,6 +1,7 @@
 use std::ffi::OsString;
 
 use yazi_config::ARGS;
+use yazi_core::manager::{Journal, State};
 use yazi_shared::{event::EventQuit, term::Term};
 
 use crate::app::App;
@@ -10,10 +11,17 @@ impl App {
 		if !opt.no_cwd_file {
 			self.cwd_to_file();
 		}
+		// Not the cwd for a shell to change to, but what's there, that's told all the same
+		self.state_to_file();
 		if let Some(selected) = opt.selected {
 			self.selected_to_file(selected);
 		}
 
+		self.cx.manager.mime_cache.save();
+		self.cx.tasks.save_queued();
+		Journal::clear();
+		crate::server::Server::stop();
+
 		Term::goodbye(|| false);
 	}
 
@@ -24,6 +32,14 @@ impl App {
 		}
 	}
 
+	fn state_to_file(&self) {
+		if let Some(p) = &ARGS.state_file {
+			if let Ok(json) = serde_json::to_string_pretty(&self.cx.manager.state()) {
+				State::write(p, &json).ok();
+			}
+		}
+	}
+
 	fn selected_to_file(&self, selected: OsString) {
 		if let Some(p) = &ARGS.chooser_file {
 			std::fs::write(p, selected.as_encoded_bytes()).ok();
@@ -1,8 +1,10 @@
-use std::sync::atomic::Ordering;
+use std::{mem, sync::atomic::Ordering, time::Instant};
 
 use ratatui::{backend::{Backend, CrosstermBackend}, CompletedFrame};
+use yazi_adaptor::Thumbnail;
+use yazi_shared::term::Colors;
 
-use crate::{app::App, lives::Lives, notify::Notify, root::{Root, COLLISION}};
+use crate::{app::{App, Damage}, components::Progress, debug::{Overlay, Slow}, lives::Lives, notify::Notify, root::{Root, COLLISION}};
 
 impl App {
 	pub(crate) fn render(&mut self) {
@@ -10,20 +12,36 @@ impl App {
 			return;
 		};
 
+		self.damage = Damage::empty();
+		self.rendered_at = Instant::now();
+		if self.debug.visible {
+			self.debug.sample();
+		}
+
 		let collision = COLLISION.swap(false, Ordering::Relaxed);
 		let frame = term
 			.draw(|f| {
 				_ = Lives::scope(&self.cx, |_| Ok(f.render_widget(Root::new(&self.cx), f.size())));
 
+				if self.debug.visible {
+					f.render_widget(Overlay::new(&self.cx, &self.debug), f.size());
+				}
 				if let Some((x, y)) = self.cx.cursor() {
 					f.set_cursor(x, y);
 				}
 			})
 			.unwrap();
 
+		let buffer = frame.buffer;
 		if COLLISION.load(Ordering::Relaxed) {
 			Self::patch(frame, self.cx.cursor());
 		}
+		Thumbnail::flush(buffer, true);
+		self.debug.frame(self.rendered_at.elapsed());
+		if self.debug.visible {
+			Slow::report("Render the frame", self.rendered_at);
+		}
+
 		if !self.cx.notify.messages.is_empty() {
 			self.render_notify();
 		}
@@ -34,6 +52,16 @@ impl App {
 		}
 	}
 
+	pub(crate) fn render_partially(&mut self) {
+		let damage = mem::take(&mut self.damage);
+		if damage.contains(Damage::NOTIFY) {
+			self.render_notify();
+		}
+		if damage.contains(Damage::PROGRESS) {
+			self.render_progress();
+		}
+	}
+
 	pub(crate) fn render_notify(&mut self) {
 		let Some(term) = &mut self.term else {
 			return;
@@ -43,6 +71,8 @@ impl App {
 			return self.render();
 		}
 
+		self.rendered_at = Instant::now();
+
 		let frame = term
 			.draw_partial(|f| {
 				f.render_widget(Notify::new(&self.cx), f.size());
@@ -53,9 +83,31 @@ impl App {
 			})
 			.unwrap();
 
+		let buffer = frame.buffer;
 		if COLLISION.load(Ordering::Relaxed) {
 			Self::patch(frame, self.cx.cursor());
 		}
+		Thumbnail::flush(buffer, false);
+	}
+
+	fn render_progress(&mut self) {
+		let Some(term) = &mut self.term else {
+			return;
+		};
+
+		self.rendered_at = Instant::now();
+		_ = Lives::scope(&self.cx, |_| {
+			for mut patch in Progress::partial_render(term.current_buffer_mut()) {
+				patch.iter_mut().for_each(|(_, _, cell)| Colors::get().degrade_cell(cell));
+				term.backend_mut().draw(patch.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+				if let Some((x, y)) = self.cx.cursor() {
+					term.show_cursor()?;
+					term.set_cursor(x, y)?;
+				}
+				term.backend_mut().flush()?;
+			}
+			Ok(())
+		});
 	}
 
 	#[inline]
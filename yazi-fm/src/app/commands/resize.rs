@@ -1,3 +1,4 @@
+use yazi_adaptor::Thumbnail;
 use yazi_shared::event::Cmd;
 
 use crate::app::App;
@@ -15,6 +16,7 @@ impl From<()> for Opt {
 impl App {
 	pub(crate) fn resize(&mut self, _: impl Into<Opt>) {
 		self.cx.manager.active_mut().preview.reset();
+		Thumbnail::reset();
 		self.render();
 
 		self.cx.manager.current_mut().sync_page(true);
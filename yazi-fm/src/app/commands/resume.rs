@@ -1,3 +1,4 @@
+use yazi_config::MANAGER;
 use yazi_shared::{event::Cmd, term::Term};
 
 use crate::app::App;
@@ -5,7 +6,7 @@ use crate::app::App;
 impl App {
 	pub(crate) fn resume(&mut self, _: Cmd) {
 		self.cx.manager.active_mut().preview.reset_image();
-		self.term = Some(Term::start().unwrap());
+		self.term = Some(Term::start(!MANAGER.mouse_events.is_empty()).unwrap());
 
 		// While the app resumes, it's possible that the terminal size has changed.
 		// We need to trigger a resize, and render the UI based on the resized area.
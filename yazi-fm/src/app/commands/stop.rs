@@ -1,4 +1,5 @@
 use tokio::sync::oneshot;
+use yazi_adaptor::Thumbnail;
 use yazi_shared::event::Cmd;
 
 use crate::app::App;
@@ -14,6 +15,7 @@ impl From<Cmd> for Opt {
 impl App {
 	pub(crate) fn stop(&mut self, opt: impl Into<Opt>) {
 		self.cx.manager.active_mut().preview.reset_image();
+		Thumbnail::hide_all();
 
 		self.signals.stop();
 		self.term = None;
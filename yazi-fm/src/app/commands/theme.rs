@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use tracing::warn;
+use yazi_config::{theme::Theme, tr, THEME};
+use yazi_plugin::LUA;
+use yazi_shared::{emit, event::Cmd};
+
+use crate::app::App;
+
+pub struct Opt {
+	name: Option<String>,
+}
+
+impl From<Cmd> for Opt {
+	fn from(mut c: Cmd) -> Self { Self { name: c.take_first() } }
+}
+
+impl App {
+	pub(crate) fn theme(&mut self, opt: impl Into<Opt>) {
+		let opt = opt.into() as Opt;
+		let theme = match Theme::load(opt.name.as_deref()) {
+			Ok(theme) => theme,
+			Err(e) => {
+				let content = tr!("Failed to load the theme: {error}", error = e);
+				return emit!(Notify(tr!("Theme"), content, "warn", 5));
+			}
+		};
+
+		THEME.store(Arc::new(theme));
+		std::thread::spawn(|| THEME.load().icons.prepare());
+
+		if let Err(e) = yazi_plugin::Config::new(&LUA).install_theme() {
+			warn!("Failed to install the theme into Lua: {e}");
+		}
+		self.render();
+	}
+}
@@ -1,15 +1,15 @@
-use yazi_shared::event::Cmd;
+use yazi_shared::{event::Cmd, render};
 
-use crate::app::App;
+use crate::app::{App, Damage};
 
 impl App {
 	pub(crate) fn update_notify(&mut self, cmd: Cmd) {
 		self.cx.notify.tick(cmd);
 
 		if self.cx.notify.messages.is_empty() {
-			self.render();
+			render!();
 		} else {
-			self.render_notify();
+			self.damage |= Damage::NOTIFY;
 		}
 	}
 }
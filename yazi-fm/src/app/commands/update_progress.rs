@@ -1,8 +1,8 @@
-use ratatui::backend::Backend;
+use yazi_config::TASKS;
 use yazi_core::tasks::TasksProgress;
-use yazi_shared::{event::Cmd, render};
+use yazi_shared::{event::Cmd, render, term::Term};
 
-use crate::{app::App, components::Progress, lives::Lives};
+use crate::app::{App, Damage};
 
 pub struct Opt {
 	progress: TasksProgress,
@@ -25,12 +25,18 @@ impl App {
 		// Update the progress of all tasks.
 		let tasks = &mut self.cx.tasks;
 		tasks.progress = opt.progress;
+		self.feed.progress(opt.progress);
+		if TASKS.report_progress {
+			Term::report_progress(Self::percent(&opt.progress));
+		}
 
 		// If the task manager is visible, update the summaries with a complete render.
 		if tasks.visible {
 			let new = tasks.paginate();
 			if new.len() != tasks.summaries.len()
-				|| new.iter().zip(&tasks.summaries).any(|(a, b)| a.name != b.name)
+				|| new.iter().zip(&tasks.summaries).any(|(a, b)| {
+					a.name != b.name || a.ids != b.ids || a.fail != b.fail || a.percent() != b.percent()
+				})
 			{
 				tasks.summaries = new;
 				tasks.arrow(0);
@@ -38,21 +44,20 @@ impl App {
 			}
 		}
 
-		// Otherwise, only partially update the progress.
-		let Some(term) = &mut self.term else {
-			return;
-		};
+		// Otherwise, only partially update the progress in the next frame.
+		self.damage |= Damage::PROGRESS;
+	}
 
-		_ = Lives::scope(&self.cx, |_| {
-			for patch in Progress::partial_render(term.current_buffer_mut()) {
-				term.backend_mut().draw(patch.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
-				if let Some((x, y)) = self.cx.cursor() {
-					term.show_cursor()?;
-					term.set_cursor(x, y)?;
-				}
-				term.backend_mut().flush()?;
-			}
-			Ok(())
-		});
+	// The same percentage as the progress bar, capped at 99 until all tasks are done
+	fn percent(progress: &TasksProgress) -> Option<(u8, bool)> {
+		if progress.total == 0 {
+			return None;
+		}
+
+		let percent = match progress.found {
+			0 => 99,
+			n => (progress.processed as f64 * 100.0 / n as f64).round().min(99.0) as u8,
+		};
+		Some((percent, progress.fail > 0))
 	}
 }
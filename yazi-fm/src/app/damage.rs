@@ -0,0 +1,16 @@
+use std::time::Duration;
+
+use bitflags::bitflags;
+
+// Renders are coalesced to stay within this budget, to keep up over slow links
+pub(crate) const FRAME_BUDGET: Duration = Duration::from_millis(16);
+
+bitflags! {
+	/// The regions that have changed since the last render, and can be redrawn
+	/// on their own without a full render.
+	#[derive(Clone, Copy, Default)]
+	pub(crate) struct Damage: u8 {
+		const NOTIFY   = 0b01;
+		const PROGRESS = 0b10;
+	}
+}
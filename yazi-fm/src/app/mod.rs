@@ -1,4 +1,6 @@
 mod app;
 mod commands;
+mod damage;
 
 pub(crate) use app::*;
+pub(crate) use damage::*;
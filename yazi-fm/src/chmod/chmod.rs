@@ -0,0 +1,55 @@
+use ratatui::{buffer::Buffer, layout::Rect, text::{Line, Span}, widgets::{Padding, Paragraph, Widget}};
+use yazi_config::{tr, THEME};
+use yazi_core::chmod::{ChmodBit, CHMOD_COLS, CHMOD_ROWS};
+
+use crate::{widgets, Ctx};
+
+pub(crate) struct Chmod<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Chmod<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl<'a> Widget for Chmod<'a> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let chmod = &self.cx.chmod;
+		let area = self.cx.area(&chmod.position);
+
+		let mut lines = vec![Line::default()];
+		for (row, label) in CHMOD_ROWS.iter().enumerate() {
+			let mut spans = vec![Span::raw(format!("  {label:<7}"))];
+			for (col, &c) in CHMOD_COLS.iter().enumerate() {
+				let (c, style) = match chmod.bit(row, col) {
+					ChmodBit::On => (c, [theme.chmod.read, theme.chmod.write, theme.chmod.exec][col]),
+					ChmodBit::Off => ('-', theme.chmod.unset),
+					ChmodBit::Mixed => ('~', theme.chmod.unset),
+				};
+				let style = if chmod.cursor == row * 3 + col { theme.chmod.active } else { style };
+				spans.push(Span::styled(format!(" {c} "), style));
+			}
+			lines.push(Line::from(spans));
+		}
+
+		lines.push(Line::default());
+		lines.push(Line::from(vec![
+			Span::raw(format!("  {}  ", tr!("Octal"))),
+			Span::styled(chmod.octal(), theme.chmod.value),
+			Span::raw(format!("   {}: ", tr!("Recursive"))),
+			Span::styled(tr!(if chmod.recursive { "on" } else { "off" }), theme.chmod.value),
+		]));
+
+		let border = theme.ui.border_popup;
+		widgets::Clear.render(area, buf);
+		Paragraph::new(lines)
+			.block(
+				border
+					.block(Padding::zero())
+					.title(border.title(chmod.title(), theme.chmod.border))
+					.border_style(theme.chmod.border),
+			)
+			.render(area, buf);
+	}
+}
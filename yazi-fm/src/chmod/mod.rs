@@ -0,0 +1,3 @@
+mod chmod;
+
+pub(super) use chmod::*;
@@ -1,6 +1,6 @@
 use std::path::MAIN_SEPARATOR;
 
-use ratatui::{buffer::Buffer, layout::Rect, widgets::{Block, BorderType, List, ListItem, Widget}};
+use ratatui::{buffer::Buffer, layout::Rect, text::{Line, Span}, widgets::{List, ListItem, Padding, Widget}};
 use yazi_config::{popup::{Offset, Position}, THEME};
 
 use crate::{widgets, Ctx};
@@ -15,6 +15,7 @@ impl<'a> Completion<'a> {
 
 impl<'a> Widget for Completion<'a> {
 	fn render(self, rect: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let items: Vec<_> = self
 			.cx
 			.completion
@@ -23,16 +24,25 @@ impl<'a> Widget for Completion<'a> {
 			.enumerate()
 			.map(|(i, x)| {
 				let icon = if x.ends_with(MAIN_SEPARATOR) {
-					&THEME.completion.icon_folder
+					&theme.completion.icon_folder
 				} else {
-					&THEME.completion.icon_file
+					&theme.completion.icon_file
 				};
 
-				let mut item = ListItem::new(format!(" {} {}", icon, x));
+				let mut spans = vec![Span::raw(format!(" {icon} "))];
+				let mut last = 0;
+				for r in self.cx.completion.highlighted(x) {
+					spans.push(Span::raw(&x[last..r.start]));
+					spans.push(Span::styled(&x[r.start..r.end], theme.completion.highlight));
+					last = r.end;
+				}
+				spans.push(Span::raw(&x[last..]));
+
+				let mut item = ListItem::new(Line::from(spans));
 				if i == self.cx.completion.rel_cursor() {
-					item = item.style(THEME.completion.active);
+					item = item.style(theme.completion.active);
 				} else {
-					item = item.style(THEME.completion.inactive);
+					item = item.style(theme.completion.inactive);
 				}
 
 				item
@@ -57,7 +67,7 @@ impl<'a> Widget for Completion<'a> {
 		widgets::Clear.render(area, buf);
 		List::new(items)
 			.block(
-				Block::bordered().border_type(BorderType::Rounded).border_style(THEME.completion.border),
+				theme.ui.border_popup.block(Padding::zero()).border_style(theme.completion.border),
 			)
 			.render(area, buf);
 	}
@@ -1,4 +1,5 @@
-use ratatui::{buffer::Buffer, widgets::Widget};
+use ratatui::{buffer::Buffer, widgets::{Paragraph, Widget}};
+use yazi_config::LAYOUT;
 
 use crate::Ctx;
 
@@ -14,6 +15,13 @@ impl<'a> Preview<'a> {
 impl Widget for Preview<'_> {
 	fn render(self, area: ratatui::layout::Rect, buf: &mut Buffer) {
 		let preview = &self.cx.manager.active().preview;
+		if let Some(stream) = &preview.stream {
+			let area = LAYOUT.load().preview;
+			let lines = stream.lines.lock();
+			let lines = lines.iter().skip(stream.skip).take(area.height as usize).cloned();
+			return Paragraph::new(lines.collect::<Vec<_>>()).render(area, buf);
+		}
+
 		let Some(lock) = &preview.lock else {
 			return;
 		};
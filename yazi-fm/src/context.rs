@@ -1,11 +1,16 @@
 use ratatui::layout::Rect;
 use yazi_config::popup::{Origin, Position};
-use yazi_core::{completion::Completion, help::Help, input::Input, manager::Manager, notify::Notify, select::Select, tasks::Tasks, which::Which};
+use yazi_core::{chmod::Chmod, completion::Completion, help::Help, input::Input, manager::Manager, mounts::Mounts, notify::Notify, select::Select, sidebar::Sidebar, spot::Spot, tasks::Tasks, which::Which};
+use yazi_shared::Layer;
 
 pub struct Ctx {
 	pub manager:    Manager,
 	pub tasks:      Tasks,
+	pub mounts:     Mounts,
+	pub sidebar:    Sidebar,
 	pub select:     Select,
+	pub chmod:      Chmod,
+	pub spot:       Spot,
 	pub input:      Input,
 	pub help:       Help,
 	pub completion: Completion,
@@ -18,7 +23,11 @@ impl Ctx {
 		Self {
 			manager:    Manager::make(),
 			tasks:      Tasks::start(),
+			mounts:     Mounts::start(),
+			sidebar:    Sidebar::start(),
 			select:     Default::default(),
+			chmod:      Default::default(),
+			spot:       Default::default(),
 			input:      Default::default(),
 			help:       Default::default(),
 			completion: Default::default(),
@@ -52,4 +61,33 @@ impl Ctx {
 		}
 		None
 	}
+
+	#[inline]
+	pub fn layer(&self) -> Layer {
+		if self.which.visible {
+			Layer::Which
+		} else if self.completion.visible {
+			Layer::Completion
+		} else if self.help.visible {
+			Layer::Help
+		} else if self.input.visible {
+			Layer::Input
+		} else if self.select.visible {
+			Layer::Select
+		} else if self.chmod.visible {
+			Layer::Chmod
+		} else if self.spot.visible {
+			Layer::Spot
+		} else if self.tasks.visible {
+			Layer::Tasks
+		} else if self.mounts.visible {
+			Layer::Mounts
+		} else if self.notify.visible {
+			Layer::Notify
+		} else if self.sidebar.focused {
+			Layer::Sidebar
+		} else {
+			Layer::Manager
+		}
+	}
 }
@@ -0,0 +1,71 @@
+use std::{collections::VecDeque, sync::atomic::Ordering, time::{Duration, Instant}};
+
+use tokio::{task::JoinHandle, time::sleep};
+use yazi_core::manager::WATCHER_EVENTS;
+use yazi_shared::emit;
+
+// The frames the frame time is taken over
+const FRAMES: usize = 60;
+
+/// What the debug overlay shows, measured as the app runs.
+#[derive(Default)]
+pub(crate) struct Debug {
+	pub(crate) visible: bool,
+	ticker:             Option<JoinHandle<()>>,
+
+	frames:            VecDeque<Duration>,
+	// The events handled at once in the last turn of the loop, queued while the app was busy
+	pub(crate) events: usize,
+
+	watched:               (Option<Instant>, usize),
+	pub(crate) watch_rate: f64,
+}
+
+impl Debug {
+	pub(crate) fn toggle(&mut self) {
+		self.visible = !self.visible;
+		if let Some(ticker) = self.ticker.take() {
+			ticker.abort();
+		}
+
+		// Redrawn every second, for the rates to be seen even if nothing else is going on
+		if self.visible {
+			self.ticker = Some(tokio::spawn(async {
+				loop {
+					sleep(Duration::from_secs(1)).await;
+					emit!(Render);
+				}
+			}));
+		}
+	}
+
+	pub(crate) fn frame(&mut self, elapsed: Duration) {
+		if self.frames.len() >= FRAMES {
+			self.frames.pop_front();
+		}
+		self.frames.push_back(elapsed);
+	}
+
+	pub(crate) fn frame_avg(&self) -> Duration {
+		let sum: Duration = self.frames.iter().sum();
+		sum.checked_div(self.frames.len() as u32).unwrap_or_default()
+	}
+
+	#[inline]
+	pub(crate) fn frame_max(&self) -> Duration {
+		self.frames.iter().max().copied().unwrap_or_default()
+	}
+
+	/// Samples the changes reported by the watcher, at most once a second, for their rate.
+	pub(crate) fn sample(&mut self) {
+		let (now, total) = (Instant::now(), WATCHER_EVENTS.load(Ordering::Relaxed));
+		if let Some(at) = self.watched.0 {
+			let elapsed = now - at;
+			if elapsed < Duration::from_secs(1) {
+				return;
+			}
+			self.watch_rate = (total - self.watched.1) as f64 / elapsed.as_secs_f64();
+		}
+		self.watched = (Some(now), total);
+	}
+}
@@ -0,0 +1,7 @@
+mod debug;
+mod overlay;
+mod slow;
+
+pub(super) use debug::*;
+pub(super) use overlay::*;
+pub(super) use slow::*;
@@ -0,0 +1,64 @@
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Paragraph, Widget}};
+use yazi_config::THEME;
+
+use super::{Debug, Slow};
+use crate::{widgets, Ctx};
+
+pub(crate) struct Overlay<'a> {
+	cx:    &'a Ctx,
+	debug: &'a Debug,
+}
+
+impl<'a> Overlay<'a> {
+	pub(crate) fn new(cx: &'a Ctx, debug: &'a Debug) -> Self { Self { cx, debug } }
+
+	fn lines(&self) -> Vec<Line<'static>> {
+		let ms = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+		let mut lines = vec![
+			Line::from(format!(
+				"Frame    {:.1}ms avg, {:.1}ms max",
+				ms(self.debug.frame_avg()),
+				ms(self.debug.frame_max())
+			)),
+			Line::from(format!("Events   {} at the last turn", self.debug.events)),
+			Line::from(format!("Watcher  {:.1} changes/s", self.debug.watch_rate)),
+		];
+
+		for l in self.cx.tasks.lanes() {
+			let lane = l.lane.to_string();
+			lines.push(Line::from(format!(
+				"{lane:<8} {}/{} busy, {} queued",
+				l.busy, l.workers, l.queued
+			)));
+		}
+
+		let slow = Slow::recent();
+		lines.push(Line::from(if slow.is_empty() { "Slow     none" } else { "Slow" }));
+		for s in slow.into_iter().rev() {
+			let ago = s.at.elapsed().as_secs();
+			lines.push(Line::from(format!("  {:>6}ms {ago:>4}s ago  {}", s.elapsed.as_millis(), s.what)));
+		}
+		lines
+	}
+}
+
+impl<'a> Widget for Overlay<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let lines = self.lines();
+		let padding = theme.ui.density.padding();
+
+		// At the top right, over everything else
+		let width = (50 + padding.left + padding.right).min(area.width);
+		let height = (lines.len() as u16 + 2 + padding.top + padding.bottom).min(area.height);
+		let area = Rect { x: area.right() - width, y: area.y, width, height };
+
+		widgets::Clear.render(area, buf);
+		let border = theme.ui.border_panel;
+		let block = border
+			.block(padding)
+			.title(border.title(Line::styled("Debug", theme.tasks.title), theme.tasks.border))
+			.border_style(theme.tasks.border);
+		Paragraph::new(lines).block(block).render(area, buf);
+	}
+}
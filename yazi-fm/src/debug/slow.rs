@@ -0,0 +1,76 @@
+use std::{collections::VecDeque, fmt::{self, Display}, sync::Mutex, time::{Duration, Instant}};
+
+use tracing::{field::{Field, Visit}, warn, Event, Subscriber};
+use tracing_subscriber::{layer::Context, Layer};
+
+// What takes longer than this is slow, about three frames
+const SLOW: Duration = Duration::from_millis(50);
+
+// The slow operations kept for the debug overlay
+const KEPT: usize = 10;
+
+static RECENT: Mutex<VecDeque<Slow>> = Mutex::new(VecDeque::new());
+
+#[derive(Clone)]
+pub(crate) struct Slow {
+	pub(crate) at:      Instant,
+	pub(crate) what:    String,
+	pub(crate) elapsed: Duration,
+}
+
+impl Slow {
+	/// Logs the operation started at the time as a slow one, if it took longer than 50ms.
+	pub(crate) fn report(what: impl Display, start: Instant) {
+		let elapsed = start.elapsed();
+		if elapsed >= SLOW {
+			warn!(target: "yazi::slow", ms = elapsed.as_millis() as u64, "{what}");
+		}
+	}
+
+	#[inline]
+	pub(crate) fn recent() -> Vec<Self> { RECENT.lock().unwrap().iter().cloned().collect() }
+}
+
+/// Keeps the recent slow operations, those logged with the `yazi::slow` target.
+pub(crate) struct SlowLayer;
+
+impl<S: Subscriber> Layer<S> for SlowLayer {
+	fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+		if event.metadata().target() != "yazi::slow" {
+			return;
+		}
+
+		let mut visitor = SlowVisitor::default();
+		event.record(&mut visitor);
+
+		let mut recent = RECENT.lock().unwrap();
+		if recent.len() >= KEPT {
+			recent.pop_front();
+		}
+		recent.push_back(Slow {
+			at:      Instant::now(),
+			what:    visitor.what,
+			elapsed: Duration::from_millis(visitor.ms),
+		});
+	}
+}
+
+#[derive(Default)]
+struct SlowVisitor {
+	what: String,
+	ms:   u64,
+}
+
+impl Visit for SlowVisitor {
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		if field.name() == "ms" {
+			self.ms = value;
+		}
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		if field.name() == "message" {
+			self.what = format!("{value:?}");
+		}
+	}
+}
@@ -17,7 +17,12 @@ impl<'a> Executor<'a> {
 			Layer::App => self.app(cmd),
 			Layer::Manager => self.manager(cmd),
 			Layer::Tasks => self.tasks(cmd),
+			Layer::Mounts => self.mounts(cmd),
+			Layer::Sidebar => self.sidebar(cmd),
+			Layer::Notify => self.notify(cmd),
 			Layer::Select => self.select(cmd),
+			Layer::Chmod => self.chmod(cmd),
+			Layer::Spot => self.spot(cmd),
 			Layer::Input => self.input(cmd),
 			Layer::Help => self.help(cmd),
 			Layer::Completion => self.completion(cmd),
@@ -42,6 +47,9 @@ impl<'a> Executor<'a> {
 		on!(resize);
 		on!(stop);
 		on!(resume);
+		on!(theme);
+		on!(debug);
+		on!(query);
 	}
 
 	fn manager(&mut self, cmd: Cmd) {
@@ -61,18 +69,30 @@ impl<'a> Executor<'a> {
 					return self.app.cx.manager.tabs.$name(cmd);
 				}
 			};
+			(TABS, $name:ident, $alias:literal) => {
+				if cmd.name == concat!("tab_", $alias) {
+					return self.app.cx.manager.tabs.$name(cmd);
+				}
+			};
 		}
 
+		on!(MANAGER, update_branch);
+		on!(MANAGER, update_fields);
 		on!(MANAGER, update_files, &self.app.cx.tasks);
 		on!(MANAGER, update_mimetype, &self.app.cx.tasks);
 		on!(MANAGER, update_paged, &self.app.cx.tasks);
+		on!(MANAGER, update_yanked);
+		on!(MANAGER, update_sizing);
 		on!(MANAGER, hover);
 		on!(MANAGER, peek);
 		on!(MANAGER, seek);
+		on!(MANAGER, glide);
 		on!(MANAGER, refresh, &self.app.cx.tasks);
 		on!(MANAGER, quit, &self.app.cx.tasks);
 		on!(MANAGER, close, &self.app.cx.tasks);
 		on!(MANAGER, suspend);
+		on!(MANAGER, ratio);
+		on!(MANAGER, size_format);
 		on!(ACTIVE, escape);
 		on!(ACTIVE, preview);
 
@@ -89,21 +109,34 @@ impl<'a> Executor<'a> {
 		on!(ACTIVE, select);
 		on!(ACTIVE, select_all);
 		on!(ACTIVE, visual_mode);
+		on!(ACTIVE, visual_range);
 
 		// Operation
 		on!(MANAGER, open, &self.app.cx.tasks);
 		on!(MANAGER, open_do, &self.app.cx.tasks);
+		on!(MANAGER, open_terminal, &self.app.cx.tasks);
+		on!(MANAGER, open_editor, &self.app.cx.tasks);
 		on!(MANAGER, yank);
 		on!(MANAGER, unyank);
+		on!(MANAGER, registers);
 		on!(MANAGER, paste, &self.app.cx.tasks);
 		on!(MANAGER, link, &self.app.cx.tasks);
-		on!(MANAGER, remove, &self.app.cx.tasks);
+		on!(MANAGER, edit_link);
+		on!(MANAGER, remove, &self.app.cx.tasks, &self.app.cx.mounts);
 		on!(MANAGER, create);
 		on!(MANAGER, rename);
+		on!(MANAGER, chmod);
+		on!(MANAGER, spot);
+		on!(MANAGER, qr);
+		on!(MANAGER, checksum, &self.app.cx.tasks);
+		on!(MANAGER, compare);
+		on!(MANAGER, local);
 		on!(ACTIVE, copy);
 		on!(ACTIVE, shell);
 		on!(ACTIVE, hidden);
+		on!(ACTIVE, ignored);
 		on!(ACTIVE, linemode);
+		on!(ACTIVE, view);
 		on!(ACTIVE, search);
 		on!(ACTIVE, jump);
 
@@ -124,14 +157,28 @@ impl<'a> Executor<'a> {
 		on!(TABS, close);
 		on!(TABS, switch);
 		on!(TABS, swap);
+		on!(TABS, move_, "move");
+		on!(TABS, pin);
+		on!(TABS, rename);
+		on!(TABS, sync);
 
 		match cmd.name.as_bytes() {
 			// Tasks
 			b"tasks_show" => self.app.cx.tasks.toggle(()),
+			// Mounts
+			b"mounts_show" => self.app.cx.mounts.toggle(()),
+			// Sidebar
+			b"sidebar" => self.app.cx.sidebar.toggle(cmd),
+			// Notify
+			b"notify_show" => self.app.cx.notify.toggle(()),
 			// Help
 			b"help" => self.app.cx.help.toggle(Layer::Manager),
 			// Plugin
 			b"plugin" => self.app.plugin(cmd),
+			// Theme
+			b"theme" => self.app.theme(cmd),
+			// Debug
+			b"debug" => self.app.debug(cmd),
 			_ => {}
 		}
 	}
@@ -154,7 +201,14 @@ impl<'a> Executor<'a> {
 		on!(toggle, "close");
 		on!(arrow);
 		on!(inspect);
+		on!(filter);
 		on!(cancel);
+		on!(retry);
+		on!(boost);
+		on!(workers);
+		on!(rename);
+		on!(chmod);
+		on!(escalate);
 
 		#[allow(clippy::single_match)]
 		match cmd.name.as_str() {
@@ -166,6 +220,101 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	fn sidebar(&mut self, cmd: Cmd) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.sidebar.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.sidebar.$name(cmd);
+				}
+			};
+		}
+
+		on!(close);
+		on!(arrow);
+		on!(enter);
+		on!(loaded);
+		on!(move_, "move");
+		on!(remove);
+		on!(update);
+		on!(visit);
+
+		match cmd.name.as_str() {
+			"pin" => self.app.cx.sidebar.pin(self.app.cx.manager.cwd()),
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Sidebar),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn mounts(&mut self, cmd: Cmd) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.mounts.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.mounts.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(arrow);
+		on!(enter);
+		on!(update);
+
+		match cmd.name.as_str() {
+			"refresh" => {
+				let cx = &mut self.app.cx;
+				cx.mounts.refresh(cmd, cx.manager.cwd(), cx.sidebar.visible)
+			}
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Mounts),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn notify(&mut self, cmd: Cmd) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.notify.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.notify.$name(cmd);
+				}
+			};
+		}
+
+		on!(toggle, "close");
+		on!(arrow);
+		on!(act);
+		on!(expand);
+		on!(clear);
+
+		#[allow(clippy::single_match)]
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Notify),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
 	fn select(&mut self, cmd: Cmd) {
 		macro_rules! on {
 			($name:ident) => {
@@ -178,6 +327,7 @@ impl<'a> Executor<'a> {
 		on!(show);
 		on!(close);
 		on!(arrow);
+		on!(toggle);
 
 		#[allow(clippy::single_match)]
 		match cmd.name.as_str() {
@@ -189,6 +339,64 @@ impl<'a> Executor<'a> {
 		}
 	}
 
+	fn chmod(&mut self, cmd: Cmd) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.chmod.$name(cmd);
+				}
+			};
+			($name:ident, $alias:literal) => {
+				if cmd.name == $alias {
+					return self.app.cx.chmod.$name(cmd);
+				}
+			};
+		}
+
+		on!(show);
+		on!(close);
+		on!(arrow);
+		on!(move_, "move");
+		on!(toggle);
+		on!(recursive);
+
+		#[allow(clippy::single_match)]
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Chmod),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
+	fn spot(&mut self, cmd: Cmd) {
+		macro_rules! on {
+			($name:ident) => {
+				if cmd.name == stringify!($name) {
+					return self.app.cx.spot.$name(cmd);
+				}
+			};
+		}
+
+		on!(show);
+		on!(fill);
+		on!(checksums);
+		on!(close);
+		on!(arrow);
+		on!(tab);
+		on!(copy);
+
+		#[allow(clippy::single_match)]
+		match cmd.name.as_str() {
+			// Help
+			"help" => self.app.cx.help.toggle(Layer::Spot),
+			// Plugin
+			"plugin" => self.app.plugin(cmd),
+			_ => {}
+		}
+	}
+
 	fn input(&mut self, cmd: Cmd) {
 		macro_rules! on {
 			($name:ident) => {
@@ -209,6 +417,7 @@ impl<'a> Executor<'a> {
 		on!(move_, "move");
 		on!(backward);
 		on!(forward);
+		on!(history);
 
 		if cmd.name.as_str() == "complete" {
 			return if cmd.named.contains_key("trigger") {
@@ -242,6 +451,9 @@ impl<'a> Executor<'a> {
 			InputMode::Insert => {
 				on!(backspace);
 				on!(kill);
+				on!(paste);
+
+				on!(undo);
 			}
 		}
 	}
@@ -258,6 +470,7 @@ impl<'a> Executor<'a> {
 		on!(escape);
 		on!(arrow);
 		on!(filter);
+		on!(switch);
 
 		#[allow(clippy::single_match)]
 		match cmd.name.as_str() {
@@ -277,10 +490,16 @@ impl<'a> Executor<'a> {
 			};
 		}
 
+		// The candidates may arrive after the input has been closed
+		if matches!(cmd.name.as_str(), "trigger" | "show") && !self.app.cx.input.visible {
+			return;
+		}
+
 		on!(trigger);
 		on!(show);
 		on!(close);
 		on!(arrow);
+		on!(hidden);
 
 		#[allow(clippy::single_match)]
 		match cmd.name.as_str() {
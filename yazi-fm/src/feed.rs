@@ -0,0 +1,107 @@
+use std::{fs::OpenOptions, io::{BufWriter, Write}, sync::mpsc, thread};
+
+use serde::Serialize;
+use yazi_config::ARGS;
+use yazi_core::{manager::{Manager, State}, tasks::TasksProgress};
+use yazi_shared::fs::lossless::Lossless;
+
+/// The changes of the state, written for external tools to follow as they happen,
+/// one JSON object a line, to the file given by `--events-json`, such as a FIFO,
+/// and to the connections of the server subscribed to them.
+///
+/// Written from a thread of its own, for a reader that's slow or yet to open a FIFO
+/// not to hold up the app.
+#[derive(Default)]
+pub(super) struct Feed {
+	tx:          Option<mpsc::Sender<String>>,
+	state:       Option<State>,
+	progress:    TasksProgress,
+	subscribers: usize,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "event", rename_all = "kebab-case")]
+enum FeedEvent<'a> {
+	Cd { tab: usize, cwd: Lossless<'a> },
+	Hover { tab: usize, url: Option<Lossless<'a>> },
+	Select { tab: usize, urls: Vec<Lossless<'a>> },
+	Tab { tab: usize },
+	Tasks(&'a TasksProgress),
+}
+
+impl Feed {
+	pub(super) fn start() -> Self {
+		let Some(path) = ARGS.events_json.clone() else {
+			return Self::default();
+		};
+
+		let (tx, rx) = mpsc::channel::<String>();
+		thread::spawn(move || {
+			let Ok(file) = OpenOptions::new().append(true).create(true).open(path) else {
+				return;
+			};
+			let mut w = BufWriter::new(file);
+			while let Ok(line) = rx.recv() {
+				if writeln!(w, "{line}").and_then(|_| w.flush()).is_err() {
+					break;
+				}
+			}
+		});
+		Self { tx: Some(tx), ..Default::default() }
+	}
+
+	/// Tells the changes of the active tab since the last time, its cwd, hovered and selected files.
+	pub(super) fn observe(&mut self, manager: &Manager) {
+		// Told afresh to a new subscriber, or one that missed some, to be in step with the state
+		let subscribers = crate::server::Server::subscribers();
+		if crate::server::Server::lagged() || subscribers > self.subscribers {
+			self.state = None;
+		}
+		self.subscribers = subscribers;
+		if self.tx.is_none() && self.subscribers == 0 {
+			self.state = None;
+			return;
+		}
+
+		let new = manager.state();
+		let old = self.state.as_ref();
+		let tab = new.tab;
+		if old.map(|s| s.tab) != Some(tab) {
+			self.send(FeedEvent::Tab { tab });
+		}
+		if old.map(|s| &s.cwd) != Some(&new.cwd) {
+			self.send(FeedEvent::Cd { tab, cwd: Lossless(&new.cwd) });
+		}
+		if old.map(|s| &s.hovered) != Some(&new.hovered) {
+			self.send(FeedEvent::Hover { tab, url: new.hovered.as_deref().map(Lossless) });
+		}
+		if old.map(|s| &s.selected) != Some(&new.selected) {
+			let urls = new.selected.iter().map(|p| Lossless(p)).collect();
+			self.send(FeedEvent::Select { tab, urls });
+		}
+		self.state = Some(new);
+	}
+
+	/// Tells the progress of the tasks, when a task is added, done, or failed.
+	pub(super) fn progress(&mut self, progress: TasksProgress) {
+		let old = self.progress;
+		self.progress = progress;
+		if (old.total, old.succ, old.fail) != (progress.total, progress.succ, progress.fail) {
+			self.send(FeedEvent::Tasks(&progress));
+		}
+	}
+
+	fn send(&self, event: FeedEvent) {
+		if self.tx.is_none() && self.subscribers == 0 {
+			return;
+		}
+		let Ok(line) = serde_json::to_string(&event) else { return };
+
+		if self.subscribers > 0 {
+			crate::server::Server::publish(&line);
+		}
+		if let Some(tx) = &self.tx {
+			tx.send(line).ok();
+		}
+	}
+}
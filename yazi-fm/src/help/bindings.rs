@@ -1,5 +1,6 @@
-use ratatui::{buffer::Buffer, layout::{self, Constraint, Rect}, widgets::{List, ListItem, Widget}};
+use ratatui::{buffer::Buffer, layout::{self, Constraint, Rect}, text::Line, widgets::Widget};
 use yazi_config::THEME;
+use yazi_core::help::HelpRow;
 
 use crate::Ctx;
 
@@ -13,40 +14,38 @@ impl<'a> Bindings<'a> {
 
 impl Widget for Bindings<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
-		let bindings = &self.cx.help.window();
-		if bindings.is_empty() {
+		let theme = THEME.load();
+		let rows = self.cx.help.window();
+		if rows.is_empty() {
 			return;
 		}
 
-		// On
-		let col1: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.on()).style(THEME.help.on)).collect();
-
-		// Exec
-		let col2: Vec<_> =
-			bindings.iter().map(|c| ListItem::new(c.exec()).style(THEME.help.exec)).collect();
-
-		// Desc
-		let col3: Vec<_> = bindings
-			.iter()
-			.map(|c| ListItem::new(c.desc.as_deref().unwrap_or("-")).style(THEME.help.desc))
-			.collect();
-
-		let chunks = layout::Layout::horizontal([
-			Constraint::Ratio(2, 10),
-			Constraint::Ratio(3, 10),
-			Constraint::Ratio(5, 10),
-		])
-		.split(area);
-
 		let cursor = self.cx.help.rel_cursor() as u16;
 		buf.set_style(
 			Rect { x: area.x, y: area.y + cursor, width: area.width, height: 1 },
-			THEME.help.hovered,
+			theme.help.hovered,
 		);
 
-		List::new(col1).render(chunks[0], buf);
-		List::new(col2).render(chunks[1], buf);
-		List::new(col3).render(chunks[2], buf);
+		for (i, row) in rows.iter().take(area.height as usize).enumerate() {
+			let line = Rect { y: area.y + i as u16, height: 1, ..area };
+			let c = match row {
+				HelpRow::Group(title) => {
+					Line::styled(title.as_str(), theme.help.group).render(line, buf);
+					continue;
+				}
+				HelpRow::Control(c) => c,
+			};
+
+			let chunks = layout::Layout::horizontal([
+				Constraint::Ratio(2, 10),
+				Constraint::Ratio(3, 10),
+				Constraint::Ratio(5, 10),
+			])
+			.split(line);
+
+			Line::styled(format!("  {}", c.on()), theme.help.on).render(chunks[0], buf);
+			Line::styled(c.exec(), theme.help.exec).render(chunks[1], buf);
+			Line::styled(c.desc().unwrap_or("-"), theme.help.desc).render(chunks[2], buf);
+		}
 	}
 }
@@ -19,8 +19,8 @@ impl<'a> Widget for Layout<'a> {
 
 		let chunks = layout::Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).split(area);
 		Line::styled(
-			help.keyword().unwrap_or_else(|| format!("{}.help", help.layer.to_string())),
-			THEME.help.footer,
+			help.keyword().unwrap_or_else(|| format!("{}.help", help.scope())),
+			THEME.load().help.footer,
 		)
 		.render(chunks[1], buf);
 
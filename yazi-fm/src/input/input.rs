@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 use anyhow::{bail, Result};
-use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Block, BorderType, Paragraph, Widget}};
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{Padding, Paragraph, Widget}};
 use syntect::easy::HighlightLines;
 use yazi_config::THEME;
 use yazi_core::input::InputMode;
@@ -34,18 +34,20 @@ impl<'a> Input<'a> {
 
 impl<'a> Widget for Input<'a> {
 	fn render(self, win: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let input = &self.cx.input;
 		let area = self.cx.area(&input.position);
 
+		let (border, title) = (theme.ui.border_popup, Line::styled(&input.title, theme.input.title));
 		widgets::Clear.render(area, buf);
 		Paragraph::new(self.highlighted_value().unwrap_or_else(|_| Line::from(input.value())))
 			.block(
-				Block::bordered()
-					.border_type(BorderType::Rounded)
-					.border_style(THEME.input.border)
-					.title(Line::styled(&input.title, THEME.input.title)),
+				border
+					.block(Padding::zero())
+					.border_style(theme.input.border)
+					.title(border.title(title, theme.input.border)),
 			)
-			.style(THEME.input.value)
+			.style(theme.input.value)
 			.render(area, buf);
 
 		if let Some(Range { start, end }) = input.selected() {
@@ -54,7 +56,7 @@ impl<'a> Widget for Input<'a> {
 
 			buf.set_style(
 				Rect { x, y, width: (end - start).min(win.width - x), height: 1.min(win.height - y) },
-				THEME.input.selected,
+				theme.input.selected,
 			)
 		}
 
@@ -29,6 +29,7 @@ impl Config {
 
 			reg.add_field_method_get("linemode", |_, me| Ok(me.linemode.to_owned()));
 			reg.add_field_method_get("show_hidden", |_, me| Ok(me.show_hidden));
+			reg.add_field_method_get("show_ignored", |_, me| Ok(me.show_ignored));
 		})
 	}
 }
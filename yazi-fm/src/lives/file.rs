@@ -47,8 +47,17 @@ impl File {
 				let cx = lua.named_registry_value::<CtxRef>("cx")?;
 				Ok(cx.manager.mimetype.get(&me.url).cloned())
 			});
+			// `nil` if no preloader fetches the field, and "" if it's not fetched for the file yet
+			reg.add_method("field", |lua, me, name: mlua::String| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let Some(values) = name.to_str().ok().and_then(|n| cx.manager.fields.get(n)) else {
+					return Ok(None);
+				};
+				lua.create_string(values.get(&me.url).map_or("", |v| v)).map(Some)
+			});
 			reg.add_method("prefix", |lua, me, ()| {
-				if !me.folder().cwd.is_search() {
+				let cwd = &me.folder().cwd;
+				if !cwd.is_search() && !cwd.is_recent() {
 					return Ok(None);
 				}
 
@@ -57,19 +66,33 @@ impl File {
 				Some(lua.create_string(p.as_path().as_os_str().as_encoded_bytes())).transpose()
 			});
 			reg.add_method("icon", |lua, me, ()| {
-				THEME
-					.icons
-					.iter()
-					.find(|&x| x.name.match_path(&me.url, me.is_dir()))
-					.map(|x| Icon::cast(lua, x))
-					.transpose()
+				let theme = THEME.load();
+				let Some(icon) = theme.icons.matches(&me.url, me.is_dir()) else {
+					return Ok(None);
+				};
+
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let mime =
+					if me.is_dir() { Some(MIME_DIR) } else { cx.manager.mimetype.get(&me.url).map(|x| &**x) };
+
+				match theme.filetype(me, mime).and_then(|x| x.icon) {
+					Some(style) => Icon::cast_styled(lua, icon, style).map(Some),
+					None => Icon::cast(lua, icon).map(Some),
+				}
+			});
+			reg.add_method("mime", |lua, me, ()| {
+				let cx = lua.named_registry_value::<CtxRef>("cx")?;
+				let mime =
+					if me.is_dir() { Some(MIME_DIR) } else { cx.manager.mimetype.get(&me.url).map(|x| &**x) };
+
+				mime.map(|s| lua.create_string(s)).transpose()
 			});
 			reg.add_method("style", |lua, me, ()| {
 				let cx = lua.named_registry_value::<CtxRef>("cx")?;
 				let mime =
 					if me.is_dir() { Some(MIME_DIR) } else { cx.manager.mimetype.get(&me.url).map(|x| &**x) };
 
-				Ok(THEME.filetypes.iter().find(|&x| x.matches(me, mime)).map(|x| Style::from(x.style)))
+				Ok(THEME.load().filetype(me, mime).map(|x| Style::from(x.style)))
 			});
 			reg.add_method("is_hovered", |_, me, ()| {
 				Ok(matches!(me.folder().hovered(), Some(f) if f.url == me.url))
@@ -38,6 +38,10 @@ impl Files {
 					Some(File::make(idx - 1, me.folder(), me.tab())).transpose()
 				}
 			});
+
+			reg.add_method("filter", |lua, me, ()| {
+				me.filter().map(|f| lua.create_string(f.to_string())).transpose()
+			});
 		})?;
 
 		Ok(())
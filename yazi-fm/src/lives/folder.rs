@@ -29,7 +29,8 @@ impl Folder {
 			Some(w) => w,
 			None => {
 				let limit = LAYOUT.load().preview.height as usize;
-				inner.offset..inner.files.len().min(inner.offset + limit)
+				let offset = inner.shown_offset();
+				offset..inner.files.len().min(offset + limit)
 			}
 		};
 
@@ -43,8 +44,9 @@ impl Folder {
 			reg.add_field_method_get("stage", |lua, me| lua.create_any_userdata(me.stage));
 			reg.add_field_method_get("window", |_, me| Files::make(me.window.clone(), me, me.tab()));
 
-			reg.add_field_method_get("offset", |_, me| Ok(me.offset));
+			reg.add_field_method_get("offset", |_, me| Ok(me.shown_offset()));
 			reg.add_field_method_get("cursor", |_, me| Ok(me.cursor));
+			reg.add_field_method_get("ignored", |_, me| Ok(me.files.ignored()));
 			reg.add_field_method_get("hovered", |_, me| {
 				me.hovered().map(|_| File::make(me.cursor, me, me.tab())).transpose()
 			});
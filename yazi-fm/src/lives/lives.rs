@@ -1,6 +1,6 @@
 use std::{mem, sync::Arc};
 
-use mlua::{Scope, Table};
+use mlua::{LuaSerdeExt, Scope, Table};
 use tracing::error;
 use yazi_config::LAYOUT;
 use yazi_plugin::{elements::RectRef, LUA};
@@ -34,28 +34,36 @@ impl Lives {
 		f: impl FnOnce(&Scope<'a, 'a>) -> mlua::Result<T>,
 	) -> mlua::Result<T> {
 		let result = LUA.scope(|scope| {
-			SCOPE.init(unsafe { mem::transmute(scope) });
+			SCOPE.init(unsafe { mem::transmute::<&Scope<'_, '_>, &Scope<'static, 'static>>(scope) });
 			LUA.set_named_registry_value("cx", scope.create_any_userdata_ref(cx)?)?;
 
 			let global = LUA.globals();
-			global.set(
-				"cx",
-				LUA.create_table_from([
-					("active", super::Tab::make(cx.manager.active())?),
-					("tabs", super::Tabs::make(&cx.manager.tabs)?),
-					("tasks", super::Tasks::make(&cx.tasks)?),
-					("yanked", scope.create_any_userdata_ref(&cx.manager.yanked)?),
-				])?,
-			)?;
+			let table = LUA.create_table_from([
+				("active", super::Tab::make(cx.manager.active())?),
+				("tabs", super::Tabs::make(&cx.manager.tabs)?),
+				("tasks", super::Tasks::make(&cx.tasks)?),
+				("yanked", scope.create_any_userdata_ref(&cx.manager.yanked)?),
+			])?;
+			table.raw_set("ratio", LUA.to_value(&cx.manager.ratio)?)?;
+			if let Some(disk) = cx.mounts.disk {
+				table.raw_set("disk", LUA.to_value(&disk)?)?;
+			}
+			global.set("cx", table)?;
 
 			let ret = f(scope)?;
 
+			let current = global.raw_get::<_, Table>("Current")?;
+			let current_area = *current.raw_get::<_, RectRef>("area")?;
 			LAYOUT.store(Arc::new(yazi_config::Layout {
 				header:  *global.raw_get::<_, Table>("Header")?.raw_get::<_, RectRef>("area")?,
 				parent:  *global.raw_get::<_, Table>("Parent")?.raw_get::<_, RectRef>("area")?,
-				current: *global.raw_get::<_, Table>("Current")?.raw_get::<_, RectRef>("area")?,
+				current: current_area,
 				preview: *global.raw_get::<_, Table>("Preview")?.raw_get::<_, RectRef>("area")?,
 				status:  *global.raw_get::<_, Table>("Status")?.raw_get::<_, RectRef>("area")?,
+
+				current_limit: current
+					.raw_get::<_, Option<usize>>("limit")?
+					.unwrap_or(current_area.height as usize),
 			}));
 
 			SCOPE.drop();
@@ -23,6 +23,9 @@ impl Tab {
 	pub(super) fn register(lua: &Lua) -> mlua::Result<()> {
 		lua.register_userdata_type::<Self>(|reg| {
 			reg.add_method("name", |lua, me, ()| {
+				if let Some(label) = &me.label {
+					return Some(lua.create_string(label)).transpose();
+				}
 				Some(
 					lua.create_string(
 						me.current
@@ -35,13 +38,20 @@ impl Tab {
 			});
 
 			reg.add_field_method_get("mode", |_, me| Mode::make(&me.mode));
+			reg.add_field_method_get("label", |_, me| Ok(me.label.clone()));
+			reg.add_field_method_get("pinned", |_, me| Ok(me.pinned));
 			reg.add_field_method_get("conf", |_, me| Config::make(&me.conf));
+			reg.add_field_method_get("view", |_, me| Ok(me.cwd_view().to_string()));
 			reg.add_field_method_get("current", |_, me| Folder::make(None, &me.current, me));
 			reg.add_field_method_get("parent", |_, me| {
 				me.parent.as_ref().map(|f| Folder::make(None, f, me)).transpose()
 			});
 
 			reg.add_field_method_get("selected", |_, me| Selected::make(&me.selected));
+			reg.add_field_method_get("selected_size", |_, me| Ok(me.sizing.total));
+			reg.add_field_method_get("selected_sized", |_, me| Ok(me.sizing.done));
+
+			reg.add_field_method_get("branch", |_, me| Ok(me.branch.name.clone()));
 
 			reg.add_field_method_get("preview", |_, me| Preview::make(me));
 		})?;
@@ -3,6 +3,8 @@ use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, prelude::__tracing_subscriber_SubscriberExt, Registry};
 use yazi_config::BOOT;
 
+use crate::debug::SlowLayer;
+
 pub(super) struct Logs;
 
 impl Logs {
@@ -11,7 +13,8 @@ impl Logs {
 		let (handle, guard) = tracing_appender::non_blocking(appender);
 
 		// let filter = EnvFilter::from_default_env();
-		let subscriber = Registry::default().with(fmt::layer().pretty().with_writer(handle));
+		let subscriber =
+			Registry::default().with(fmt::layer().pretty().with_writer(handle)).with(SlowLayer);
 
 		tracing::subscriber::set_global_default(subscriber)
 			.context("setting default subscriber failed")?;
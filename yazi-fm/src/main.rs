@@ -6,47 +6,78 @@
 static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 mod app;
+mod chmod;
 mod completion;
 mod components;
 mod context;
+mod debug;
 mod executor;
+mod feed;
 mod help;
 mod input;
 mod lives;
 mod logs;
+mod mounts;
 mod notify;
 mod panic;
+mod profile;
+mod record;
 mod root;
 mod router;
 mod select;
+mod server;
+mod sidebar;
 mod signals;
+mod spot;
 mod tasks;
 mod which;
 mod widgets;
 
 use context::*;
 use executor::*;
+use feed::*;
 use logs::*;
 use panic::*;
+use profile::*;
+use record::*;
 #[allow(unused_imports)]
 use root::*;
 use router::*;
 use signals::*;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<()> {
+	// For the names of the months and days, and the formats of `%x` and `%X`, of the locale,
+	// set before the threads of the runtime are started, as it's not thread-safe
+	#[cfg(unix)]
+	unsafe {
+		libc::setlocale(libc::LC_TIME, c"".as_ptr());
+	}
+
+	tokio::runtime::Builder::new_multi_thread().enable_all().build()?.block_on(run())
+}
+
+async fn run() -> anyhow::Result<()> {
+	let mut profile = Profile::start();
 	_ = fdlimit::raise_fd_limit();
 	// console_subscriber::init();
 
 	yazi_config::init();
+	profile.mark("config");
+
+	if server::Server::forward()? {
+		return Ok(());
+	}
 
 	yazi_core::init();
 
 	yazi_scheduler::init();
+	profile.mark("core");
 
 	yazi_plugin::init();
+	profile.mark("lua");
 
 	yazi_adaptor::init();
+	profile.mark("adaptor");
 
-	app::App::run().await
+	app::App::run(profile).await
 }
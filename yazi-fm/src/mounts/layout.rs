@@ -0,0 +1,97 @@
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::{Line, Span}, widgets::{List, ListItem, Widget}};
+use yazi_config::{tr, THEME};
+use yazi_core::mounts::{Mount, MOUNTS_PERCENT};
+use yazi_shared::fs::readable_size;
+
+use crate::{widgets, Ctx};
+
+const GAUGE_WIDTH: usize = 20;
+
+pub(crate) struct Layout<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Layout<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	fn area(area: Rect) -> Rect {
+		let chunk = layout::Layout::vertical([
+			Constraint::Percentage((100 - MOUNTS_PERCENT) / 2),
+			Constraint::Percentage(MOUNTS_PERCENT),
+			Constraint::Percentage((100 - MOUNTS_PERCENT) / 2),
+		])
+		.split(area)[1];
+
+		layout::Layout::horizontal([
+			Constraint::Percentage((100 - MOUNTS_PERCENT) / 2),
+			Constraint::Percentage(MOUNTS_PERCENT),
+			Constraint::Percentage((100 - MOUNTS_PERCENT) / 2),
+		])
+		.split(chunk)[1]
+	}
+
+	fn gauge(mount: &Mount, path_width: usize) -> Line<'static> {
+		let theme = THEME.load();
+		let used = mount.total - mount.free;
+		let percent = (used as f64 / mount.total as f64 * 100.0).round() as usize;
+		let filled = (percent * GAUGE_WIDTH).div_ceil(100).min(GAUGE_WIDTH);
+
+		// Long mount points are cut from the left, the end being the telling part
+		let path = mount.path.to_string_lossy();
+		let len = path.chars().count();
+		let path = if len > path_width {
+			format!("…{}", path.chars().skip(len + 1 - path_width).collect::<String>())
+		} else {
+			path.into_owned()
+		};
+
+		let m = &theme.mounts;
+		let style = if percent >= m.full as usize { m.gauge_full } else { m.gauge };
+		let (free, total) = (readable_size(mount.free), readable_size(mount.total));
+		Line::from(vec![
+			Span::raw(format!("{path:<path_width$}  {:<8} ", mount.fstype)),
+			Span::styled("━".repeat(filled), style),
+			Span::styled("─".repeat(GAUGE_WIDTH - filled), theme.mounts.border),
+			Span::styled(format!(" {percent:>3}%"), style),
+			Span::raw(format!("  {}", tr!("{free} free of {total}", free = free, total = total))),
+		])
+	}
+}
+
+impl<'a> Widget for Layout<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let area = Self::area(area);
+
+		widgets::Clear.render(area, buf);
+		let border = theme.ui.border_panel;
+		let block = border
+			.block(theme.ui.density.padding())
+			.title(border.title(Line::styled(tr!("Mounts"), theme.mounts.title), theme.mounts.border))
+			.title_alignment(Alignment::Center)
+			.border_style(theme.mounts.border);
+		block.clone().render(area, buf);
+
+		let mounts = &self.cx.mounts;
+		let inner = block.inner(area);
+		let width = mounts.items.iter().map(|m| m.path.to_string_lossy().chars().count()).max().unwrap_or(0).min(30);
+
+		// Keep the hovered mount in sight
+		let limit = inner.height as usize;
+		let offset = (mounts.cursor + 1).saturating_sub(limit);
+
+		let items = mounts
+			.items
+			.iter()
+			.enumerate()
+			.skip(offset)
+			.take(limit)
+			.map(|(i, m)| {
+				let item = ListItem::new(Self::gauge(m, width));
+				if i == mounts.cursor { item.style(theme.mounts.hovered) } else { item }
+			})
+			.collect::<Vec<_>>();
+
+		List::new(items).render(inner, buf);
+	}
+}
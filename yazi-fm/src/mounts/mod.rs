@@ -0,0 +1,3 @@
+mod layout;
+
+pub(super) use layout::*;
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::{Line, Span}, widgets::{List, ListItem, Widget}};
+use yazi_config::{tr, THEME};
+use yazi_core::tasks::TASKS_PERCENT;
+
+use super::Notify;
+use crate::{widgets, Ctx};
+
+pub(crate) struct History<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> History<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	fn area(area: Rect) -> Rect {
+		let chunk = layout::Layout::vertical([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(area)[1];
+
+		layout::Layout::horizontal([
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+			Constraint::Percentage(TASKS_PERCENT),
+			Constraint::Percentage((100 - TASKS_PERCENT) / 2),
+		])
+		.split(chunk)[1]
+	}
+
+	fn ago(d: Duration) -> String {
+		match d.as_secs() {
+			s @ 0..=59 => format!("{s}s"),
+			s @ 60..=3599 => format!("{}m", s / 60),
+			s => format!("{}h", s / 3600),
+		}
+	}
+}
+
+impl<'a> Widget for History<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let area = Self::area(area);
+
+		widgets::Clear.render(area, buf);
+		let border = theme.ui.border_panel;
+		let block = border
+			.block(theme.ui.density.padding())
+			.title(border.title(tr!("Notifications"), theme.notify.border))
+			.title_alignment(Alignment::Center)
+			.border_style(theme.notify.border);
+		block.clone().render(area, buf);
+
+		let notify = &self.cx.notify;
+		let mut items = vec![];
+		let mut at = 0;
+		for (i, r) in notify.history.iter().enumerate() {
+			let (icon, style) = Notify::decoration(r.level);
+			let mut spans = vec![
+				Span::styled(format!("{icon} {}", r.title), style),
+				Span::raw(format!("  {}", r.content.lines().next().unwrap_or_default())),
+			];
+			if let Some(p) = r.progress {
+				spans.push(Span::styled(format!(" {p}%"), style));
+			}
+			if r.count > 1 {
+				spans.push(Span::styled(format!(" ({})", r.count), style));
+			}
+			let ago = tr!("{time} ago", time = Self::ago(r.instant.elapsed()));
+			spans.push(Span::raw(format!("  {ago}")));
+			if r.action.is_some() {
+				spans.push(Span::raw("  ⏎"));
+			}
+
+			let mut item = ListItem::new(Line::from(spans));
+			if i == notify.cursor {
+				at = items.len();
+				item = item.style(theme.notify.hovered);
+			}
+			items.push(item);
+
+			if r.expanded {
+				items.extend(r.details.iter().map(|d| {
+					ListItem::new(format!("    {}", d.lines().next().unwrap_or_default()))
+				}));
+			}
+		}
+
+		let height = block.inner(area).height as usize;
+		let offset = at.saturating_sub(height.saturating_sub(1));
+		let items: Vec<_> = items.into_iter().skip(offset).take(height).collect();
+
+		List::new(items).render(block.inner(area), buf);
+	}
+}
@@ -1,3 +1,5 @@
+mod history;
 mod notify;
 
+pub(super) use history::*;
 pub(super) use notify::*;
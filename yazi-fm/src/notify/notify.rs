@@ -1,6 +1,7 @@
 use std::rc::Rc;
 
-use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Offset, Rect}, style::{Style, Stylize}, widgets::{Block, BorderType, Paragraph, Widget}};
+use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Offset, Rect}, style::Style, widgets::{Padding, Paragraph, Widget}};
+use yazi_config::THEME;
 use yazi_core::notify::{Level, Message};
 
 use crate::{widgets::Clear, Ctx};
@@ -12,6 +13,15 @@ pub(crate) struct Notify<'a> {
 impl<'a> Notify<'a> {
 	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
 
+	pub(super) fn decoration(level: Level) -> (String, Style) {
+		let notify = &THEME.load().notify;
+		match level {
+			Level::Info => (notify.icon_info.clone(), notify.title_info.into()),
+			Level::Warn => (notify.icon_warn.clone(), notify.title_warn.into()),
+			Level::Error => (notify.icon_error.clone(), notify.title_error.into()),
+		}
+	}
+
 	fn chunks(area: Rect, messages: &[Message]) -> Rc<[Rect]> {
 		let chunks =
 			Layout::horizontal([Constraint::Fill(1), Constraint::Length(40), Constraint::Max(1)])
@@ -32,12 +42,10 @@ impl<'a> Widget for Notify<'a> {
 		let limit = notify.limit();
 		let chunks = Self::chunks(area, &notify.messages[..limit]);
 
+		let border = THEME.load().ui.border_popup;
 		for (i, m) in notify.messages.iter().enumerate().take(limit) {
-			let (icon, style) = match m.level {
-				Level::Info => ("", Style::default().green()),
-				Level::Warn => ("", Style::default().yellow()),
-				Level::Error => ("", Style::default().red()),
-			};
+			let (icon, style) = Self::decoration(m.level);
+			let title = format!("{icon} {}", m.title());
 
 			let mut rect = chunks[i]
 				.offset(Offset { x: (100 - m.percent) as i32 * chunks[i].width as i32 / 100, y: 0 });
@@ -46,9 +54,9 @@ impl<'a> Widget for Notify<'a> {
 			Clear.render(rect, buf);
 			Paragraph::new(m.content.as_str())
 				.block(
-					Block::bordered()
-						.border_type(BorderType::Rounded)
-						.title(format!("{} {}", icon, m.title))
+					border
+						.block(Padding::zero())
+						.title(border.title(title, style))
 						.title_style(style)
 						.border_style(style),
 				)
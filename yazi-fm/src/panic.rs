@@ -1,4 +1,15 @@
-use yazi_shared::term::Term;
+use std::{backtrace::Backtrace, collections::VecDeque, fmt::{Display, Write}, panic::PanicHookInfo, path::PathBuf, sync::Mutex};
+
+use yazi_config::BOOT;
+use yazi_shared::{event::Cmd, term::Term, Layer};
+
+// The events kept for the crash report
+const KEPT: usize = 50;
+
+// The commands run on a timer, not by the user, which would push out all the others
+const TICKS: [&str; 4] = ["glide", "update_notify", "update_progress", "update_sizing"];
+
+static EVENTS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
 
 pub(super) struct Panic;
 
@@ -10,8 +21,46 @@ impl Panic {
 		std::panic::set_hook(Box::new(move |info| {
 			Term::goodbye(|| {
 				hook(info);
+				if let Some(path) = Self::report(info) {
+					eprintln!("\nThe crash report is written to {}", path.display());
+				}
 				true
 			});
 		}));
 	}
+
+	/// Keeps the event for the crash report, which has the last 50 of them.
+	pub(super) fn record(event: impl Display) {
+		let Ok(mut events) = EVENTS.lock() else {
+			return;
+		};
+
+		// The oldest one's buffer is reused, for no allocation on each key once it's full
+		let mut s = if events.len() >= KEPT { events.pop_front().unwrap() } else { String::new() };
+		s.clear();
+		_ = write!(s, "{event}");
+		events.push_back(s);
+	}
+
+	#[inline]
+	pub(super) fn record_cmd(layer: Layer, cmd: &Cmd) {
+		if !TICKS.contains(&cmd.name.as_str()) {
+			Self::record(format_args!("{layer}: {cmd}"));
+		}
+	}
+
+	// Writes what panicked, where, and the events leading up to it, to the state directory
+	fn report(info: &PanicHookInfo) -> Option<PathBuf> {
+		let mut s = format!("{info}\n\nBacktrace:\n{}\n", Backtrace::force_capture());
+
+		// Not waited for, as the panic may be of the one holding it
+		s.push_str("Recent events, the last one at the bottom:\n");
+		if let Ok(events) = EVENTS.try_lock() {
+			events.iter().for_each(|e| _ = writeln!(s, "  {e}"));
+		}
+
+		let path = BOOT.state_dir.join("crash.log");
+		std::fs::write(&path, s).ok()?;
+		Some(path)
+	}
 }
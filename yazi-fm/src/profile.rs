@@ -0,0 +1,30 @@
+use std::{fmt::Write, time::{Duration, Instant}};
+
+/// Time spent in each phase of the startup, reported by `--profile-startup`.
+pub(crate) struct Profile {
+	start:  Instant,
+	last:   Instant,
+	phases: Vec<(&'static str, Duration)>,
+}
+
+impl Profile {
+	pub(crate) fn start() -> Self {
+		let now = Instant::now();
+		Self { start: now, last: now, phases: Vec::with_capacity(10) }
+	}
+
+	pub(crate) fn mark(&mut self, phase: &'static str) {
+		let now = Instant::now();
+		self.phases.push((phase, now - self.last));
+		self.last = now;
+	}
+
+	pub(crate) fn report(&self) -> String {
+		let mut s = String::new();
+		for (phase, elapsed) in &self.phases {
+			writeln!(s, "{phase:<12} {:>8.2}ms", elapsed.as_secs_f64() * 1000.0).ok();
+		}
+		writeln!(s, "{:<12} {:>8.2}ms", "total", (self.last - self.start).as_secs_f64() * 1000.0).ok();
+		s
+	}
+}
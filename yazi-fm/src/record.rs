@@ -0,0 +1,217 @@
+use std::{collections::VecDeque, fmt::Display, fs::File, io::{BufRead, BufReader, BufWriter, Write}, path::{Component, Path, PathBuf}, sync::Mutex, time::{Duration, Instant}};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::{task::AbortHandle, time::sleep_until};
+use yazi_config::{keymap::Key, tr, ARGS, BOOT};
+use yazi_shared::{emit, event::{Cmd, Event}, fs::{expand_path, Url}, Layer};
+
+static RECORDER: Mutex<Option<(Instant, BufWriter<File>)>> = Mutex::new(None);
+
+// The commands dispatched as they were recorded, to tell where a replay goes another way
+static EXPECTED: Mutex<Option<VecDeque<String>>> = Mutex::new(None);
+
+// The sandbox of a replay, kept to for the rest of the session, and the task playing it back
+static REPLAY: Mutex<Option<(PathBuf, AbortHandle)>> = Mutex::new(None);
+
+/// An event of the user, as recorded by `--record` and replayed by `--replay`,
+/// at the milliseconds since the start, e.g. `{"at":1200,"key":"<C-a>"}`.
+///
+/// A recording starts with the directory it's in, `{"at":0,"cwd":"/home/me"}`, and has
+/// the commands the keys ran, `{"at":1201,"cmd":"manager: arrow 1"}`, along with them.
+#[derive(Deserialize, Serialize)]
+struct Recorded {
+	at:    u64,
+	#[serde(flatten)]
+	event: RecordedEvent,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum RecordedEvent {
+	Cwd(PathBuf),
+	Key(String),
+	Paste(String),
+	Cmd(String),
+}
+
+pub(super) struct Record;
+
+impl Record {
+	pub(super) fn start() -> Result<()> {
+		if let Some(p) = &ARGS.record {
+			let file = File::create(p).with_context(|| format!("failed to create {p:?}"))?;
+			*RECORDER.lock().unwrap() = Some((Instant::now(), BufWriter::new(file)));
+			Self::write(|| RecordedEvent::Cwd(BOOT.cwds[0].clone()));
+		}
+		if let (Some(p), Some(sandbox)) = (&ARGS.replay, &BOOT.cwds.first()) {
+			Self::replay(p, sandbox)?;
+		}
+		Ok(())
+	}
+
+	#[inline]
+	pub(super) fn key(key: Key) { Self::write(|| RecordedEvent::Key(key.to_string())); }
+
+	#[inline]
+	pub(super) fn paste(s: &str) { Self::write(|| RecordedEvent::Paste(s.to_owned())); }
+
+	/// Records the command run by the keys, or checks it's the one recorded if replaying.
+	pub(super) fn cmd(layer: Layer, cmd: &Cmd) {
+		Self::write(|| RecordedEvent::Cmd(format!("{layer}: {cmd}")));
+		Self::expect(format_args!("{layer}: {cmd}"));
+	}
+
+	/// Whether the command stays in the sandbox of a replay. One going out of it, e.g. by `g h`,
+	/// a typed `cd`, or an absolute path of the recorded directory, isn't run, and stops the replay.
+	pub(super) fn guard(layer: Layer, cmd: &Cmd) -> bool {
+		let replay = REPLAY.lock().unwrap();
+		let Some((sandbox, task)) = replay.as_ref() else {
+			return true;
+		};
+		if layer != Layer::Manager || !matches!(cmd.name.as_str(), "cd" | "reveal" | "tab_create") {
+			return true;
+		}
+		let Some(target) = cmd.args.first() else {
+			return true;
+		};
+		if Self::resolve(&Url::from(target.as_str())).starts_with(sandbox) {
+			return true;
+		}
+
+		task.abort();
+		*EXPECTED.lock().unwrap() = None;
+		let content = tr!(
+			"Refused `{cmd}`, which leaves the sandbox {sandbox}, and stopped the replay",
+			cmd = cmd,
+			sandbox = sandbox.display()
+		);
+		emit!(Notify(tr!("Replay"), content, "error", 10));
+		false
+	}
+
+	// Flushed on each one, for the events up to a crash to be kept
+	fn write(f: impl FnOnce() -> RecordedEvent) {
+		let mut recorder = RECORDER.lock().unwrap();
+		let Some((start, w)) = recorder.as_mut() else {
+			return;
+		};
+
+		let recorded = Recorded { at: start.elapsed().as_millis() as u64, event: f() };
+		if serde_json::to_writer(&mut *w, &recorded).is_ok() {
+			writeln!(w).and_then(|_| w.flush()).ok();
+		}
+	}
+
+	// Warns of the first command that's not the one recorded, and stops checking after it
+	fn expect(actual: impl Display) {
+		let mut expected = EXPECTED.lock().unwrap();
+		let Some(queue) = expected.as_mut() else {
+			return;
+		};
+
+		let actual = actual.to_string();
+		let content = match queue.pop_front() {
+			Some(e) if e == actual => return,
+			Some(e) => tr!(
+				"Ran `{actual}` instead of `{expected}`, the replay isn't faithful",
+				actual = actual,
+				expected = e
+			),
+			None => tr!("Ran `{actual}`, not in the recording", actual = actual),
+		};
+		emit!(Notify(tr!("Replay"), content, "warn", 5));
+		*expected = None;
+	}
+
+	// Plays the events back in the sandbox, which has to be a copy of the directory recorded in,
+	// not that one itself, for its files not to be touched by e.g. a recorded `remove`.
+	fn replay(path: &Path, sandbox: &Path) -> Result<()> {
+		let file = File::open(path).with_context(|| format!("failed to open {path:?}"))?;
+		let mut events = vec![];
+		for (i, line) in BufReader::new(file).lines().enumerate() {
+			let line = line?;
+			if !line.trim().is_empty() {
+				let e: Recorded = serde_json::from_str(&line)
+					.with_context(|| format!("invalid event at line {} of {path:?}", i + 1))?;
+				events.push(e);
+			}
+		}
+
+		let Some(RecordedEvent::Cwd(cwd)) = events.first().map(|e| &e.event) else {
+			bail!("{path:?} doesn't tell the directory it's recorded in, nothing is replayed");
+		};
+		let Some(real) = sandbox.canonicalize().ok().filter(|p| p.is_dir()) else {
+			bail!("The sandbox {sandbox:?} isn't a directory");
+		};
+		if cwd.canonicalize().is_ok_and(|c| real.starts_with(&c) || c.starts_with(&real)) {
+			bail!("The sandbox {sandbox:?} isn't a copy of {cwd:?} recorded in, refusing to replay");
+		}
+
+		// The paths in the commands are of the recorded directory, and of the sandbox in a replay
+		let (from, to) = (cwd.to_string_lossy().into_owned(), sandbox.to_string_lossy().into_owned());
+		*EXPECTED.lock().unwrap() = Some(
+			events
+				.iter()
+				.filter_map(|e| match &e.event {
+					RecordedEvent::Cmd(s) => Some(Self::rebase(s, &from, &to)),
+					_ => None,
+				})
+				.collect(),
+		);
+
+		let start = tokio::time::Instant::now();
+		let task = tokio::spawn(async move {
+			let mut n = 0;
+			for e in events {
+				sleep_until(start + Duration::from_millis(e.at)).await;
+				match e.event {
+					RecordedEvent::Key(s) => match s.parse::<Key>() {
+						Ok(key) => Event::Key(key.into()).emit(),
+						Err(_) => continue,
+					},
+					RecordedEvent::Paste(s) => Event::Paste(s).emit(),
+					RecordedEvent::Cwd(_) | RecordedEvent::Cmd(_) => continue,
+				}
+				n += 1;
+			}
+			emit!(Notify(tr!("Replay"), tr!("Replayed {n} event{s}", n = n), "info", 5));
+		});
+		*REPLAY.lock().unwrap() = Some((real, task.abort_handle()));
+		Ok(())
+	}
+
+	// Resolves the `~`, the `..` and, as far as it exists, the symlinks of a path
+	fn resolve(p: &Path) -> PathBuf {
+		let mut out = PathBuf::new();
+		for c in expand_path(p).components() {
+			match c {
+				Component::CurDir => {}
+				Component::ParentDir => {
+					out.pop();
+				}
+				c => out.push(c),
+			}
+		}
+		out.canonicalize().unwrap_or(out)
+	}
+
+	// Replaces `from` only where it's a whole path or the leading components of one,
+	// e.g. `/tmp/a` in `cd /tmp/a/b` but not in `cd /tmp/ab` or `cd /x/tmp/a`
+	fn rebase(s: &str, from: &str, to: &str) -> String {
+		let mut out = String::with_capacity(s.len());
+		let mut last = 0;
+		for (i, _) in s.match_indices(from) {
+			let before = s[..i].chars().next_back();
+			let after = s[i + from.len()..].chars().next();
+			if !matches!(before, None | Some(' ' | '=')) || !matches!(after, None | Some(' ' | '/')) {
+				continue;
+			}
+			out.push_str(&s[last..i]);
+			out.push_str(to);
+			last = i + from.len();
+		}
+		out.push_str(&s[last..]);
+		out
+	}
+}
@@ -1,9 +1,10 @@
 use std::sync::atomic::AtomicBool;
 
 use ratatui::{buffer::Buffer, layout::{Constraint, Layout, Rect}, widgets::Widget};
+use yazi_config::MANAGER;
 
-use super::{completion, input, select, tasks, which};
-use crate::{components, help, Ctx};
+use super::{chmod, completion, input, mounts, select, sidebar, spot, tasks, which};
+use crate::{components, help, notify, Ctx};
 
 pub(super) static COLLISION: AtomicBool = AtomicBool::new(false);
 
@@ -21,8 +22,19 @@ impl<'a> Widget for Root<'a> {
 			Layout::vertical([Constraint::Length(1), Constraint::Fill(1), Constraint::Length(1)])
 				.split(area);
 
+		// The sidebar takes the left of the panes, up to half of the width
+		let manager = if self.cx.sidebar.visible {
+			let width = MANAGER.sidebar_width.min(chunks[1].width / 2);
+			let chunks =
+				Layout::horizontal([Constraint::Length(width), Constraint::Fill(1)]).split(chunks[1]);
+			sidebar::Sidebar::new(self.cx).render(chunks[0], buf);
+			chunks[1]
+		} else {
+			chunks[1]
+		};
+
 		components::Header.render(chunks[0], buf);
-		components::Manager.render(chunks[1], buf);
+		components::Manager.render(manager, buf);
 		components::Status.render(chunks[2], buf);
 		components::Preview::new(self.cx).render(area, buf);
 
@@ -30,6 +42,22 @@ impl<'a> Widget for Root<'a> {
 			tasks::Layout::new(self.cx).render(area, buf);
 		}
 
+		if self.cx.mounts.visible {
+			mounts::Layout::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.notify.visible {
+			notify::History::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.spot.visible {
+			spot::Spot::new(self.cx).render(area, buf);
+		}
+
+		if self.cx.chmod.visible {
+			chmod::Chmod::new(self.cx).render(area, buf);
+		}
+
 		if self.cx.select.visible {
 			select::Select::new(self.cx).render(area, buf);
 		}
@@ -33,8 +33,18 @@ impl<'a> Router<'a> {
 			self.matches(Layer::Input, key)
 		} else if cx.select.visible {
 			self.matches(Layer::Select, key)
+		} else if cx.chmod.visible {
+			self.matches(Layer::Chmod, key)
+		} else if cx.spot.visible {
+			self.matches(Layer::Spot, key)
 		} else if cx.tasks.visible {
 			self.matches(Layer::Tasks, key)
+		} else if cx.mounts.visible {
+			self.matches(Layer::Mounts, key)
+		} else if cx.notify.visible {
+			self.matches(Layer::Notify, key)
+		} else if cx.sidebar.focused {
+			self.matches(Layer::Sidebar, key)
 		} else {
 			self.matches(Layer::Manager, key)
 		}
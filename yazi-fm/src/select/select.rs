@@ -1,4 +1,4 @@
-use ratatui::{buffer::Buffer, layout::Rect, widgets::{Block, BorderType, List, ListItem, Widget}};
+use ratatui::{buffer::Buffer, layout::Rect, text::Line, widgets::{List, ListItem, Padding, Widget}};
 use yazi_config::THEME;
 
 use crate::{widgets, Ctx};
@@ -13,6 +13,7 @@ impl<'a> Select<'a> {
 
 impl<'a> Widget for Select<'a> {
 	fn render(self, _: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let select = &self.cx.select;
 		let area = self.cx.area(&select.position);
 
@@ -22,21 +23,25 @@ impl<'a> Widget for Select<'a> {
 			.enumerate()
 			.map(|(i, v)| {
 				if i != select.rel_cursor() {
-					return ListItem::new(format!("  {v}")).style(THEME.select.inactive);
+					return ListItem::new(format!("  {v}")).style(theme.select.inactive);
 				}
 
-				ListItem::new(format!(" {v}")).style(THEME.select.active)
+				ListItem::new(format!(" {v}")).style(theme.select.active)
 			})
 			.collect();
 
+		let border = theme.ui.border_popup;
+		let mut block = border
+			.block(Padding::zero())
+			.title(border.title(select.title(), theme.select.border))
+			.border_style(theme.select.border);
+		if let Some((label, on)) = select.toggle_state() {
+			let check = if on { "x" } else { " " };
+			let line = Line::styled(format!("[{check}] {label}"), theme.select.inactive);
+			block = block.title_bottom(border.title(line, theme.select.border));
+		}
+
 		widgets::Clear.render(area, buf);
-		List::new(items)
-			.block(
-				Block::bordered()
-					.title(select.title())
-					.border_type(BorderType::Rounded)
-					.border_style(THEME.select.border),
-			)
-			.render(area, buf);
+		List::new(items).block(block).render(area, buf);
 	}
 }
@@ -0,0 +1,352 @@
+use std::{env, path::Path, sync::{atomic::{AtomicBool, Ordering}, OnceLock}};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{io::{self, AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader}, select, sync::{broadcast::{self, error::RecvError}, oneshot}};
+use yazi_config::{ARGS, BOOT, MANAGER};
+use yazi_core::manager::State;
+use yazi_shared::{emit, event::Cmd, fs::{expand_path, lossless}, pid_alive, Layer};
+
+/// The commands a connection can emit, those moving around, not touching any file,
+/// for a stray script not to be able to e.g. `remove` or `shell` through the socket.
+const EMITTABLE: [&str; 11] = [
+	"arrow",
+	"back",
+	"cd",
+	"enter",
+	"forward",
+	"leave",
+	"refresh",
+	"reveal",
+	"tab_close",
+	"tab_create",
+	"tab_switch",
+];
+
+/// The changes of the state, as the feed writes them, for the connections subscribed to them.
+static EVENTS: OnceLock<broadcast::Sender<String>> = OnceLock::new();
+
+/// Whether a connection fell behind the changes and missed some, for the feed to tell
+/// the state afresh, as a subscriber would be out of step with it otherwise.
+static LAGGED: AtomicBool = AtomicBool::new(false);
+
+/// The socket of the instance, `<pid>.sock` in the instances directory, or the named pipe
+/// `\\.\pipe\yazi-<pid>` on Windows, that `ya` talks to, with a request and its response
+/// a line of JSON each, in the JSON-RPC 2.0 format.
+///
+/// A connection that's called `subscribe` is also sent the changes of the state, each with
+/// a notification of the `event` method, so an integration can follow the instance.
+/// One that's called `export_state` has the state written as `--state-file` does it,
+/// to the `path` given, or that file, and is answered with it.
+pub(super) struct Server;
+
+#[derive(Deserialize)]
+struct Request {
+	id:     Value,
+	method: String,
+	#[serde(default)]
+	params: Value,
+}
+
+#[derive(Serialize)]
+struct Response {
+	jsonrpc: &'static str,
+	id:      Value,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	result:  Option<Value>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	error:   Option<ResponseError>,
+}
+
+#[derive(Serialize)]
+struct ResponseError {
+	code:    i32,
+	message: String,
+}
+
+impl Server {
+	pub(super) fn start() {
+		if MANAGER.server && Self::listen() {
+			EVENTS.get_or_init(|| broadcast::channel(100).0);
+		}
+	}
+
+	#[cfg(unix)]
+	fn listen() -> bool {
+		use std::{fs, os::unix::fs::{DirBuilderExt, PermissionsExt}};
+
+		use tokio::net::UnixListener;
+		use tracing::warn;
+		use yazi_shared::is_private_dir;
+
+		// Only for the user, as whoever can connect can drive the instance
+		let path = Self::path();
+		let dir = path.parent().unwrap();
+		if fs::DirBuilder::new().recursive(true).mode(0o700).create(dir).is_err() {
+			return false;
+		}
+		// It may have been there already, made by someone else, or open to them
+		if !is_private_dir(dir) {
+			warn!("Not serving the socket, as {} isn't private to the user", dir.display());
+			return false;
+		}
+
+		fs::remove_file(&path).ok();
+		let Ok(listener) = UnixListener::bind(&path) else {
+			return false;
+		};
+		if fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).is_err() {
+			fs::remove_file(&path).ok();
+			return false;
+		}
+
+		tokio::spawn(async move {
+			while let Ok((stream, _)) = listener.accept().await {
+				tokio::spawn(Self::serve(stream));
+			}
+		});
+		true
+	}
+
+	// The pipe takes the default security of the user's processes, which only lets the user,
+	// and the administrators, write to it, and it's closed to the clients of other machines
+	#[cfg(windows)]
+	fn listen() -> bool {
+		use tokio::net::windows::named_pipe::ServerOptions;
+		use yazi_shared::instance_pipe;
+
+		// The first instance of the pipe, for it not to be one someone else made by the name
+		let name = instance_pipe(std::process::id());
+		let Ok(mut server) = ServerOptions::new().first_pipe_instance(true).create(&name) else {
+			return false;
+		};
+
+		tokio::spawn(async move {
+			while server.connect().await.is_ok() {
+				// Another instance for the next client to connect to, while this one is served
+				let Ok(next) = ServerOptions::new().create(&name) else {
+					break;
+				};
+				tokio::spawn(Self::serve(std::mem::replace(&mut server, next)));
+			}
+		});
+		true
+	}
+
+	/// Has the instance this is run from the shell of, by `$YAZI_PID`, reveal the `--reveal`
+	/// file, and open the entries given along with it as tabs, instead of starting another,
+	/// or returns false if there's none to.
+	///
+	/// Errors if that instance is running but can't be reached, e.g. with its server disabled,
+	/// rather than starting another one in its shell unasked.
+	pub(super) fn forward() -> Result<bool> {
+		use std::io::{BufRead, BufReader, Write};
+
+		let Some(file) = &ARGS.reveal else {
+			return Ok(false);
+		};
+		let Some(pid) = env::var("YAZI_PID").ok().and_then(|s| s.parse::<u32>().ok()) else {
+			return Ok(false);
+		};
+
+		#[cfg(unix)]
+		let stream = {
+			use yazi_shared::{instances_dir, is_private_dir};
+
+			let dir = instances_dir();
+			if is_private_dir(&dir) {
+				std::os::unix::net::UnixStream::connect(dir.join(format!("{pid}.sock")))
+			} else {
+				Err(std::io::ErrorKind::PermissionDenied.into())
+			}
+		};
+		#[cfg(windows)]
+		let stream = std::fs::OpenOptions::new()
+			.read(true)
+			.write(true)
+			.open(yazi_shared::instance_pipe(pid));
+		let stream = match stream {
+			Ok(s) => s,
+			Err(_) if !pid_alive(pid) => return Ok(false),
+			Err(e) => bail!(
+				"The server of the instance {pid} this is run from is disabled ({e}),\n\
+				 set `server = true` under `[manager]` of its yazi.toml to reveal the file in it,\n\
+				 or unset `YAZI_PID` to start another instance"
+			),
+		};
+
+		// Revealed in the tab active there first, as the tabs opened after it become the active one
+		let s = |p: &Path| p.to_string_lossy().into_owned();
+		let mut cmds = vec![vec!["reveal".to_owned(), s(&expand_path(file))]];
+		for (cwd, file) in BOOT.cwds.iter().zip(&BOOT.files).take(ARGS.entries.len()) {
+			cmds.push(vec!["tab_create".to_owned(), s(cwd)]);
+			if let Some(f) = file {
+				cmds.push(vec!["reveal".to_owned(), s(&cwd.join(f))]);
+			}
+		}
+
+		let (mut w, mut r) = (&stream, BufReader::new(&stream));
+		Ok(cmds.into_iter().enumerate().all(|(id, cmd)| {
+			let req = json!({ "jsonrpc": "2.0", "id": id, "method": "emit", "params": { "cmd": cmd } });
+			writeln!(w, "{req}").is_ok() && r.read_line(&mut String::new()).is_ok_and(|n| n > 0)
+		}))
+	}
+
+	// The pipe on Windows is gone along with the process
+	#[inline]
+	pub(super) fn stop() {
+		#[cfg(unix)]
+		if MANAGER.server {
+			std::fs::remove_file(Self::path()).ok();
+		}
+	}
+
+	/// The number of connections subscribed to the changes of the state.
+	#[inline]
+	pub(super) fn subscribers() -> usize { EVENTS.get().map_or(0, |tx| tx.receiver_count()) }
+
+	/// Whether the state has to be told afresh, as a subscriber missed some of the changes.
+	#[inline]
+	pub(super) fn lagged() -> bool { LAGGED.swap(false, Ordering::Relaxed) }
+
+	#[inline]
+	pub(super) fn publish(event: &str) {
+		if let Some(tx) = EVENTS.get() {
+			tx.send(event.to_owned()).ok();
+		}
+	}
+
+	async fn serve(stream: impl AsyncRead + AsyncWrite) {
+		let (r, mut w) = io::split(stream);
+		let mut lines = BufReader::new(r).lines();
+		let mut events: Option<broadcast::Receiver<String>> = None;
+
+		loop {
+			let mut s = select! {
+				Ok(Some(line)) = lines.next_line() => {
+					let resp = Self::handle(&line, &mut events).await;
+					let Ok(s) = serde_json::to_string(&resp) else { continue };
+					s
+				}
+				Some(result) = async { Some(events.as_mut()?.recv().await) } => match result {
+					Ok(event) => format!(r#"{{"jsonrpc":"2.0","method":"event","params":{event}}}"#),
+					Err(RecvError::Lagged(_)) => {
+						LAGGED.store(true, Ordering::Relaxed);
+						emit!(Render);
+						continue;
+					}
+					Err(RecvError::Closed) => break,
+				},
+				else => break,
+			};
+
+			s.push('\n');
+			if w.write_all(s.as_bytes()).await.is_err() {
+				break;
+			}
+		}
+	}
+
+	async fn handle(line: &str, events: &mut Option<broadcast::Receiver<String>>) -> Response {
+		let req = match serde_json::from_str::<Request>(line) {
+			Ok(req) => req,
+			Err(e) => return Self::error(Value::Null, -32700, e.to_string()),
+		};
+
+		match req.method.as_str() {
+			"get" => {
+				let Some(what) = req.params.get("what").and_then(|v| v.as_str()) else {
+					return Self::error(req.id, -32602, "Missing `what` to get".to_owned());
+				};
+
+				match Self::query(what).await {
+					Ok(result) => Self::result(req.id, result),
+					Err((code, e)) => Self::error(req.id, code, e),
+				}
+			}
+			"export_state" => {
+				// To the `path` given, or the `--state-file`, otherwise it's only answered with
+				let path = match req.params.get("path") {
+					None | Some(Value::Null) => ARGS.state_file.clone(),
+					Some(v) => match lossless::deserialize(v) {
+						Ok(p) => Some(expand_path(p)),
+						Err(e) => return Self::error(req.id, -32602, e.to_string()),
+					},
+				};
+				let state = match Self::query("state").await {
+					Ok(state) => state,
+					Err((code, e)) => return Self::error(req.id, code, e),
+				};
+				let Some(path) = path else {
+					return Self::result(req.id, state);
+				};
+
+				let json = serde_json::to_string_pretty(&state).unwrap_or_default();
+				let written = tokio::task::spawn_blocking({
+					let path = path.clone();
+					move || State::write(&path, &json)
+				});
+				match written.await {
+					Ok(Ok(())) => Self::result(req.id, state),
+					Ok(Err(e)) => Self::error(req.id, -32603, format!("Failed to write {path:?}: {e}")),
+					Err(e) => Self::error(req.id, -32603, e.to_string()),
+				}
+			}
+			"emit" => {
+				let words = req.params.get("cmd").and_then(|v| v.as_array()).map(|a| {
+					a.iter().map(|v| v.as_str().map(ToOwned::to_owned)).collect::<Option<Vec<_>>>()
+				});
+				let Some(Some(words)) = words else {
+					return Self::error(req.id, -32602, "Missing `cmd` to emit".to_owned());
+				};
+
+				match Cmd::from_words(words) {
+					Ok(cmd) if EMITTABLE.contains(&cmd.name.as_str()) => {
+						emit!(Call(cmd, Layer::Manager));
+						Self::result(req.id, Value::Null)
+					}
+					Ok(cmd) => Self::error(req.id, -32602, format!("`{}` can't be emitted", cmd.name)),
+					Err(e) => Self::error(req.id, -32602, e.to_string()),
+				}
+			}
+			"subscribe" => {
+				*events = EVENTS.get().map(|tx| tx.subscribe());
+				emit!(Render); // For the feed to tell the state as it is now
+				Self::result(req.id, Value::Bool(events.is_some()))
+			}
+			"unsubscribe" => {
+				*events = None;
+				Self::result(req.id, Value::Bool(true))
+			}
+			m => Self::error(req.id, -32601, format!("Unknown method `{m}`")),
+		}
+	}
+
+	// Asks the app for a part of its state, as the socket is served off the event loop
+	async fn query(what: &str) -> Result<Value, (i32, String)> {
+		let (tx, rx) = oneshot::channel();
+		emit!(Call(Cmd::args("query", vec![what.to_owned()]).with_data(tx), Layer::App));
+		match rx.await {
+			Ok(Ok(result)) => Ok(result),
+			Ok(Err(e)) => Err((-32602, e)),
+			Err(_) => Err((-32603, "No answer from the instance".to_owned())),
+		}
+	}
+
+	#[inline]
+	fn result(id: Value, result: Value) -> Response {
+		Response { jsonrpc: "2.0", id, result: Some(result), error: None }
+	}
+
+	#[inline]
+	fn error(id: Value, code: i32, message: String) -> Response {
+		Response { jsonrpc: "2.0", id, result: None, error: Some(ResponseError { code, message }) }
+	}
+
+	#[cfg(unix)]
+	#[inline]
+	fn path() -> std::path::PathBuf {
+		yazi_shared::instances_dir().join(format!("{}.sock", std::process::id()))
+	}
+}
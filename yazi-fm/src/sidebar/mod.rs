@@ -0,0 +1,3 @@
+mod sidebar;
+
+pub(super) use sidebar::*;
@@ -0,0 +1,59 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::{Block, Borders, Paragraph, Widget}};
+use yazi_config::{tr, THEME};
+use yazi_core::sidebar::{self, SidebarKind};
+
+use crate::Ctx;
+
+pub(crate) struct Sidebar<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Sidebar<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+
+	// Long paths are cut from the left, the end being the telling part
+	fn truncate(s: String, width: usize) -> String {
+		let len = s.chars().count();
+		if len <= width {
+			return s;
+		}
+		format!("…{}", s.chars().skip(len + 1 - width).collect::<String>())
+	}
+}
+
+impl<'a> Widget for Sidebar<'a> {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let block = Block::new().borders(Borders::RIGHT).border_style(theme.sidebar.border);
+		let inner = block.inner(area);
+		block.render(area, buf);
+
+		let (sidebar, cwd) = (&self.cx.sidebar, self.cx.manager.cwd());
+		let width = inner.width.saturating_sub(1) as usize;
+
+		let (mut lines, mut hovered, mut last) = (vec![], 0, None);
+		for (i, (kind, path)) in sidebar.entries().into_iter().enumerate() {
+			if last != Some(kind) {
+				last = Some(kind);
+				let title = match kind {
+					SidebarKind::Pinned => tr!("Pinned"),
+					SidebarKind::Mount => tr!("Mounts"),
+					SidebarKind::Frecent => tr!("Frequent"),
+				};
+				lines.push(Line::styled(Self::truncate(title.to_owned(), width + 1), theme.sidebar.title));
+			}
+
+			let mut style = if **cwd == *path { theme.sidebar.active.into() } else { Style::new() };
+			if sidebar.focused && i == sidebar.cursor {
+				hovered = lines.len();
+				style = style.patch(theme.sidebar.hovered);
+			}
+			let name = Self::truncate(sidebar::Sidebar::readable(path), width);
+			lines.push(Line::styled(format!(" {name}"), style));
+		}
+
+		// Keep the hovered entry in sight
+		let offset = (hovered + 1).saturating_sub(inner.height as usize);
+		Paragraph::new(lines.into_iter().skip(offset).collect::<Vec<_>>()).render(inner, buf);
+	}
+}
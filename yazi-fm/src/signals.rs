@@ -3,7 +3,7 @@ use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventK
 use futures::StreamExt;
 use tokio::{select, sync::mpsc, task::JoinHandle};
 use tokio_util::sync::CancellationToken;
-use yazi_shared::event::Event;
+use yazi_shared::{event::Event, term::Term};
 
 pub(super) struct Signals {
 	tx:     mpsc::UnboundedSender<Event>,
@@ -60,10 +60,10 @@ impl Signals {
 				}
 
 				match signal {
-					SIGHUP | SIGTERM | SIGQUIT | SIGINT => {
-						if tx.send(Event::Quit(Default::default())).is_err() {
-							break;
-						}
+					SIGHUP | SIGTERM | SIGQUIT | SIGINT
+						if tx.send(Event::Quit(Default::default())).is_err() =>
+					{
+						break;
 					}
 					SIGCONT => Scheduler::app_resume(),
 					_ => {}
@@ -85,8 +85,11 @@ impl Signals {
 							// We need to check key event kind;
 							// otherwise event will be dispatched twice.
 							CrosstermEvent::Key(key @ KeyEvent { kind: KeyEventKind::Press, .. }) => Event::Key(key),
+							CrosstermEvent::Mouse(mouse) => Event::Mouse(mouse),
 							CrosstermEvent::Paste(str) => Event::Paste(str),
 							CrosstermEvent::Resize(..) => Event::Resize,
+							CrosstermEvent::FocusGained => { Term::set_focused(true); continue }
+							CrosstermEvent::FocusLost => { Term::set_focused(false); continue }
 							_ => continue,
 						};
 						if tx.send(event).is_err() {
@@ -0,0 +1,3 @@
+mod spot;
+
+pub(super) use spot::*;
@@ -0,0 +1,99 @@
+use std::iter;
+
+use ratatui::{buffer::Buffer, layout::Rect, text::{Line, Span}, widgets::{List, ListItem, Padding, Widget}};
+use yazi_config::{theme::Style, tr, THEME};
+
+use crate::{widgets, Ctx};
+
+pub(crate) struct Spot<'a> {
+	cx: &'a Ctx,
+}
+
+impl<'a> Spot<'a> {
+	pub(crate) fn new(cx: &'a Ctx) -> Self { Self { cx } }
+}
+
+impl<'a> Spot<'a> {
+	// The name of the file, or the titles of the tabs with the one shown stood out
+	fn title(spot: &yazi_core::spot::Spot, styles: &Styles) -> Line<'static> {
+		let Some((tabs, active)) = spot.tabs() else {
+			return Line::styled(spot.title(), styles.title);
+		};
+
+		let mut spans = Vec::with_capacity(tabs.len() * 2 + 1);
+		let titles = iter::once(spot.title()).chain(tabs.into_iter().map(str::to_owned));
+		for (i, title) in titles.enumerate() {
+			if i != 0 {
+				spans.push(Span::styled(" │ ", styles.title));
+			}
+			let style = if i == active { styles.active } else { styles.title };
+			spans.push(Span::styled(title, style));
+		}
+		Line::from(spans)
+	}
+}
+
+// The styles of what's spotted of a file, or of the checksums, which have a theme of their own
+struct Styles {
+	border: Style,
+	title:  Style,
+	key:    Style,
+	value:  Style,
+	active: Style,
+}
+
+impl Styles {
+	fn of(spot: &yazi_core::spot::Spot) -> Self {
+		let theme = THEME.load();
+		if spot.checksum() {
+			let t = &theme.checksum;
+			Self { border: t.border, title: t.title, key: t.name, value: t.hash, active: t.active }
+		} else {
+			let t = &theme.spot;
+			Self { border: t.border, title: t.title, key: t.key, value: t.value, active: t.active }
+		}
+	}
+}
+
+impl<'a> Widget for Spot<'a> {
+	fn render(self, _: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
+		let spot = &self.cx.spot;
+		let area = self.cx.area(&spot.position);
+		let styles = Styles::of(spot);
+
+		let window = spot.window();
+		let width = window.iter().map(|(k, _)| k.chars().count()).max().unwrap_or(0).min(24);
+
+		let mut items: Vec<_> = window
+			.iter()
+			.enumerate()
+			.map(|(i, (k, v))| {
+				let value = match spot.verdict(i) {
+					Some(true) => theme.checksum.pass,
+					Some(false) => theme.checksum.fail,
+					None => styles.value,
+				};
+				let item = ListItem::new(Line::from(vec![
+					Span::styled(format!(" {k:<width$}  "), styles.key),
+					Span::styled(v.as_str(), value),
+				]));
+				if i == spot.rel_cursor() { item.style(styles.active) } else { item }
+			})
+			.collect();
+		if spot.loading() {
+			items.push(ListItem::new(Span::styled(format!(" {}", tr!("Loading...")), styles.value)));
+		}
+
+		let border = theme.ui.border_popup;
+		widgets::Clear.render(area, buf);
+		List::new(items)
+			.block(
+				border
+					.block(Padding::zero())
+					.title(border.title(Self::title(spot, &styles), styles.border))
+					.border_style(styles.border),
+			)
+			.render(area, buf);
+	}
+}
@@ -1,6 +1,7 @@
-use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::Line, widgets::{Block, BorderType, List, ListItem, Padding, Widget}};
-use yazi_config::THEME;
-use yazi_core::tasks::TASKS_PERCENT;
+use ratatui::{buffer::Buffer, layout::{self, Alignment, Constraint, Rect}, text::Line, widgets::{block::{Position, Title}, List, ListItem, Widget}};
+use yazi_config::{tr, THEME};
+use yazi_core::tasks::{TasksFilter, TASKS_PERCENT};
+use yazi_shared::fs::readable_size;
 
 use crate::{widgets, Ctx};
 
@@ -26,36 +27,98 @@ impl<'a> Layout<'a> {
 		])
 		.split(chunk)[1]
 	}
+
+	fn title(filter: TasksFilter) -> &'static str {
+		match filter {
+			TasksFilter::All => tr!("Tasks"),
+			TasksFilter::Running => tr!("Running tasks"),
+			TasksFilter::Failed => tr!("Failed tasks"),
+		}
+	}
+
+	// How busy the workers of each lane are, at the bottom
+	fn lanes(cx: &Ctx) -> Title<'static> {
+		let text = cx
+			.tasks
+			.lanes()
+			.into_iter()
+			.map(|l| {
+				tr!(
+					"{lane}: {busy}/{workers}, {queued} queued",
+					lane = l.lane,
+					busy = l.busy,
+					workers = l.workers,
+					queued = l.queued
+				)
+			})
+			.collect::<Vec<_>>()
+			.join(" · ");
+
+		let theme = THEME.load();
+		let line = Line::styled(text, theme.tasks.title);
+		Title::from(theme.ui.border_panel.title(line, theme.tasks.border))
+			.position(Position::Bottom)
+			.alignment(Alignment::Right)
+	}
 }
 
 impl<'a> Widget for Layout<'a> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let area = Self::area(area);
 
 		widgets::Clear.render(area, buf);
-		let block = Block::bordered()
-			.title(Line::styled("Tasks", THEME.tasks.title))
+		let border = theme.ui.border_panel;
+		let title = Line::styled(Self::title(self.cx.tasks.filter), theme.tasks.title);
+		let block = border
+			.block(theme.ui.density.padding())
+			.title(border.title(title, theme.tasks.border))
 			.title_alignment(Alignment::Center)
-			.padding(Padding::symmetric(1, 1))
-			.border_type(BorderType::Rounded)
-			.border_style(THEME.tasks.border);
+			.title(Self::lanes(self.cx))
+			.border_style(theme.tasks.border);
 		block.clone().render(area, buf);
 
+		let inner = block.inner(area);
 		let tasks = &self.cx.tasks;
 		let items = tasks
 			.summaries
 			.iter()
-			.take(area.height.saturating_sub(2) as usize)
+			.take(inner.height as usize)
 			.enumerate()
 			.map(|(i, v)| {
-				let mut item = ListItem::new(v.name.clone());
+				let mut parts = vec![];
+				if v.ids.len() > 1 {
+					parts.push(tr!("{n} tasks", n = v.ids.len()));
+					parts.push(format!("{}%", v.percent()));
+				}
+				if v.found > 0 {
+					parts.push(format!("{}/{}", readable_size(v.processed), readable_size(v.found)));
+				}
+				if v.boosted {
+					parts.push(tr!("boosted").to_owned());
+				}
+				if v.skipped > 0 {
+					parts.push(tr!("{n} skipped", n = v.skipped));
+				}
+				if v.fail > 0 {
+					parts.push(tr!("{n} failed", n = v.fail));
+				}
+				if let Some(error) = &v.error {
+					parts.push(error.lines().next().unwrap_or_default().to_owned());
+				}
+
+				let mut item = if parts.is_empty() {
+					ListItem::new(v.name.clone())
+				} else {
+					ListItem::new(format!("{} ({})", v.name, parts.join(", ")))
+				};
 				if i == tasks.cursor {
-					item = item.style(THEME.tasks.hovered);
+					item = item.style(theme.tasks.hovered);
 				}
 				item
 			})
 			.collect::<Vec<_>>();
 
-		List::new(items).render(block.inner(area), buf);
+		List::new(items).render(inner, buf);
 	}
 }
@@ -17,12 +17,13 @@ impl<'a> Which<'a> {
 
 impl Widget for Which<'_> {
 	fn render(self, area: Rect, buf: &mut Buffer) {
+		let theme = THEME.load();
 		let which = &self.cx.which;
 		if which.silent {
 			return;
 		}
 
-		let cols = THEME.which.cols as usize;
+		let cols = theme.which.cols as usize;
 		let height = area.height.min(which.cands.len().div_ceil(cols) as u16 + PADDING_Y * 2);
 		let area = Rect {
 			x: PADDING_X.min(area.width),
@@ -47,7 +48,7 @@ impl Widget for Which<'_> {
 		};
 
 		widgets::Clear.render(area, buf);
-		Block::new().style(THEME.which.mask).render(area, buf);
+		Block::new().style(theme.which.mask).render(area, buf);
 
 		for y in 0..area.height {
 			for (x, chunk) in chunks.iter().enumerate() {
@@ -1,7 +1,7 @@
 use std::sync::atomic::Ordering;
 
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
-use yazi_adaptor::ADAPTOR;
+use yazi_adaptor::{Thumbnail, ADAPTOR};
 
 use crate::root::COLLISION;
 
@@ -27,6 +27,7 @@ fn overlap(a: &Rect, b: &Rect) -> Option<Rect> {
 impl Widget for Clear {
 	fn render(self, area: Rect, buf: &mut Buffer) {
 		ratatui::widgets::Clear.render(area, buf);
+		Thumbnail::collide(area);
 
 		let Some(r) = ADAPTOR.shown_load().and_then(|r| overlap(&area, &r)) else {
 			return;
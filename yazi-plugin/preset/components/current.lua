@@ -1,8 +1,59 @@
 Current = {
 	area = ui.Rect.default,
+	warned = {},
+
+	-- Size of each cell in the grid view
+	grid_cell = { width = 20, height = 10 },
+
+	-- Columns of the details view, referred to by name in `manager.columns`
+	columns = {
+		size = {
+			title = "Size",
+			sort = "size",
+			value = function(file)
+				local size = file:size()
+				return size and ya.readable_size(size) or ""
+			end,
+		},
+		mtime = {
+			title = "Modified",
+			sort = "modified",
+			value = function(file)
+				local time = file.cha.modified
+				return time and ya.format_time(time, MANAGER.time_format) or ""
+			end,
+		},
+		permissions = {
+			title = "Permissions",
+			value = function(file) return file.cha:permissions() or "" end,
+		},
+		owner = {
+			title = "Owner",
+			value = function(file)
+				if ya.target_family() ~= "unix" then
+					return ""
+				end
+				local user = ya.user_name(file.cha.uid) or tostring(file.cha.uid)
+				local group = ya.group_name(file.cha.gid) or tostring(file.cha.gid)
+				return user .. ":" .. group
+			end,
+		},
+	},
 }
 
 function Current:render(area)
+	self.limit, self._grid = nil, nil
+
+	local view = cx.active.view
+	if view == "grid" then
+		self.area = area
+		return self:grid(area)
+	end
+
+	local details = view == "details"
+	if details then
+		area = ui.Rect { x = area.x, y = area.y + 1, w = area.w, h = math.max(0, area.h - 1) }
+	end
 	self.area = area
 
 	local files = Folder:by_kind(Folder.CURRENT).window
@@ -32,7 +83,218 @@ function Current:render(area)
 
 	return ya.flat {
 		ui.List(area, items),
-		Folder:linemode(area, files),
+		details and self:details(area, files) or Folder:linemode(area, files),
 		Folder:markers(area, markers),
 	}
 end
+
+function Current:pad(s, width, align)
+	local gap = width - ui.Span(s):width()
+	if gap <= 0 then
+		return s
+	elseif align == "right" then
+		return string.rep(" ", gap) .. s
+	elseif align == "center" then
+		return string.rep(" ", gap // 2) .. s .. string.rep(" ", gap - gap // 2)
+	end
+	return s .. string.rep(" ", gap)
+end
+
+function Current:details(area, files)
+	local conf = cx.active.conf
+	local indicator = conf.sort_reverse and " ↓" or " ↑"
+
+	-- Measure the columns, dropping those that don't fit while keeping a third for the names
+	local cols, fixed = {}, 0
+	for _, spec in ipairs(MANAGER.columns) do
+		local col = self.columns[spec.name]
+		if not col then
+			if not self.warned[spec.name] then
+				-- Once, not on every render
+				self.warned[spec.name] = true
+				ya.err("Unknown column: " .. spec.name)
+			end
+			break
+		end
+
+		local values, width = {}, ui.Span(ya.tr(col.title) .. indicator):width()
+		for i, f in ipairs(files) do
+			values[i] = col.value(f)
+			width = math.max(width, ui.Span(values[i]):width())
+		end
+		if fixed + width + 1 > area.w * 2 // 3 then
+			break
+		end
+
+		fixed = fixed + width + 1
+		cols[#cols + 1] = { col = col, align = spec.align, values = values, width = width }
+	end
+
+	-- Header, which sorts by a column when clicked
+	local name = area.w - fixed
+	local sorted = conf.sort_by == "alphabetical" or conf.sort_by == "natural"
+	local header = {
+		ui.Span(self:pad(ya.tr("Name") .. (sorted and indicator or ""), name, "left"))
+			:style(sorted and THEME.manager.column_sorted or THEME.manager.column_header),
+	}
+
+	local x = area.x + name
+	self._header = { y = area.y - 1, { area.x, x, "alphabetical" } }
+	for _, c in ipairs(cols) do
+		sorted = c.col.sort and c.col.sort == conf.sort_by
+		local title = ya.tr(c.col.title) .. (sorted and indicator or "")
+		header[#header + 1] = ui.Span(" " .. self:pad(title, c.width, c.align))
+			:style(sorted and THEME.manager.column_sorted or THEME.manager.column_header)
+
+		self._header[#self._header + 1] = { x + 1, x + 1 + c.width, c.col.sort }
+		x = x + 1 + c.width
+	end
+
+	-- Rows, padded so they cover any long names beneath them
+	local lines = {}
+	for i = 1, #files do
+		local spans = {}
+		for _, c in ipairs(cols) do
+			spans[#spans + 1] = ui.Span(" " .. self:pad(c.values[i], c.width, c.align))
+		end
+		lines[#lines + 1] = ui.Line(spans)
+	end
+
+	return {
+		ui.Paragraph(ui.Rect { x = area.x, y = area.y - 1, w = area.w, h = 1 }, { ui.Line(header) }),
+		ui.Paragraph(ui.Rect { x = area.x + name, y = area.y, w = fixed, h = area.h }, lines),
+	}
+end
+
+function Current:click_header(event)
+	local header = self._header
+	if cx.active.view ~= "details" or not header or event.y ~= header.y then
+		return
+	end
+
+	local conf = cx.active.conf
+	for _, r in ipairs(header) do
+		if event.x >= r[1] and event.x < r[2] and r[3] then
+			return ya.manager_emit("sort", {
+				r[3],
+				reverse = conf.sort_by == r[3] and not conf.sort_reverse,
+				dir_first = conf.sort_dir_first,
+				sensitive = conf.sort_sensitive,
+			})
+		end
+	end
+end
+
+function Current:grid(area)
+	local folder = Folder:by_kind(Folder.CURRENT)
+	local cols = math.max(1, area.w // self.grid_cell.width)
+	local rows = math.max(1, area.h // self.grid_cell.height)
+	self.limit = cols * rows
+
+	-- Scroll by rows, keeping the one with the cursor in view
+	local row = folder.cursor // cols
+	local top = ya.clamp(row - rows + 1, self._top or 0, row)
+	self._top = top
+	self._grid = { cols = cols, w = area.w // cols, h = self.grid_cell.height, top = top }
+
+	local elements = {}
+	for i = top * cols + 1, math.min(#folder.files, (top + rows) * cols) do
+		local n = i - 1 - top * cols
+		local rect = ui.Rect {
+			x = area.x + n % cols * self._grid.w,
+			y = area.y + n // cols * self._grid.h,
+			w = self._grid.w,
+			h = self._grid.h,
+		}
+		elements[#elements + 1] = self:cell(rect, folder.files[i])
+	end
+	return ya.flat(elements)
+end
+
+function Current:cell(rect, file)
+	local body = ui.Rect { x = rect.x + 1, y = rect.y, w = math.max(0, rect.w - 2), h = math.max(0, rect.h - 1) }
+	local foot = ui.Rect { x = body.x, y = rect.y + rect.h - 1, w = body.w, h = 1 }
+
+	-- Thumbnails are generated in the background, so show the icon until it's ready
+	local elements = {}
+	local mime = file:mime() or ""
+	local thumb = mime:find("^image/") and ui.Thumbnail(body, file.url)
+	if thumb then
+		elements[1] = thumb
+	else
+		local icon = ui.Rect { x = body.x, y = body.y + body.h // 2, w = body.w, h = 1 }
+		elements[1] = ui.Paragraph(icon, { ui.Line { Folder:icon(file) } }):align(ui.Paragraph.CENTER)
+	end
+
+	local name = ui.Line { ui.Span(ya.truncate(file.name, foot.w)) }
+	elements[2] = ui.Paragraph(foot, { name })
+		:align(ui.Paragraph.CENTER)
+		:style(file:is_hovered() and THEME.manager.hovered or file:style())
+
+	local marker = Folder:marker(file)
+	if marker ~= 0 then
+		local styles = {
+			THEME.manager.marker_copied,
+			THEME.manager.marker_cut,
+			THEME.manager.marker_marked,
+			THEME.manager.marker_selected,
+		}
+		elements[3] = ui.Bar(ui.Rect { x = rect.x, y = rect.y, w = 1, h = rect.h }, ui.Bar.LEFT):style(styles[marker])
+	end
+	return elements
+end
+
+function Current:row(event)
+	local folder = Folder:by_kind(Folder.CURRENT)
+	if self._grid then
+		local g = self._grid
+		local col = ya.clamp(0, (event.x - self.area.x) // g.w, g.cols - 1)
+		local idx = (g.top + (event.y - self.area.y) // g.h) * g.cols + col
+		return ya.clamp(0, idx, #folder.files - 1) - folder.cursor
+	end
+
+	local y = ya.clamp(1, event.y - self.area.y + 1, #folder.window)
+	return folder.offset + y - folder.cursor - 1
+end
+
+function Current:click(event, up)
+	if up then
+		if self._dragging then
+			self._dragging = false
+			ya.manager_emit("escape", { visual = true })
+		end
+		return
+	end
+
+	local folder = Folder:by_kind(Folder.CURRENT)
+	if not self._grid and event.y - self.area.y >= #folder.window then
+		return
+	end
+
+	-- Compare the clicked position rather than the cursor, as it may not have moved yet
+	local now, step = ya.time(), self:row(event)
+	local last = self._clicked
+	if last and last[2] == folder.cursor + step and now - last[1] < 0.4 then
+		self._clicked = nil
+		return ya.manager_emit(folder.hovered.cha.is_dir and "enter" or "open", {})
+	end
+
+	self._clicked = { now, folder.cursor + step }
+	ya.manager_emit("arrow", { step })
+end
+
+function Current:scroll(event, step)
+	ya.manager_emit("arrow", { step * (self._grid and self._grid.cols or 1) })
+end
+
+function Current:drag(event)
+	if #Folder:by_kind(Folder.CURRENT).window == 0 then
+		return
+	end
+
+	if not self._dragging then
+		self._dragging = true
+		ya.manager_emit("visual_mode", {})
+	end
+	ya.manager_emit("arrow", { self:row(event) })
+end
@@ -40,7 +40,7 @@ function Folder:highlight_ranges(s, ranges)
 end
 
 function Folder:highlighted_name(file)
-	-- Complete prefix when searching across directories
+	-- Complete prefix when searching across directories, or in the recent files
 	local prefix = file:prefix() or ""
 	if prefix ~= "" then
 		prefix = prefix .. "/"
@@ -76,12 +76,14 @@ function Folder:linemode(area, files)
 	local lines = {}
 	for _, f in ipairs(files) do
 		local spans = { ui.Span(" ") }
-		if mode == "size" then
+		if mode:find("{", 1, true) then
+			spans[#spans + 1] = ui.Span(self:format(mode, f))
+		elseif mode == "size" then
 			local size = f:size()
 			spans[#spans + 1] = ui.Span(size and ya.readable_size(size) or "")
 		elseif mode == "mtime" then
 			local time = f.cha.modified
-			spans[#spans + 1] = ui.Span(time and os.date("%y-%m-%d %H:%M", time // 1) or "")
+			spans[#spans + 1] = ui.Span(time and ya.format_time(time, MANAGER.time_format) or "")
 		elseif mode == "permissions" then
 			spans[#spans + 1] = ui.Span(f.cha:permissions() or "")
 		end
@@ -92,6 +94,64 @@ function Folder:linemode(area, files)
 	return ui.Paragraph(area, lines):align(ui.Paragraph.RIGHT)
 end
 
+-- The fields of a linemode format such as "{size:>8} {mtime:%Y-%m-%d} {owner}", each given
+-- the file and what's after the colon, if it isn't an alignment. Plugins can add their own,
+-- and the preloaders can fetch them with `update_fields`, for those not here.
+Folder.fields = {
+	name = function(file) return file.name end,
+	size = function(file)
+		local size = file:size()
+		return size and ya.readable_size(size) or ""
+	end,
+	mtime = function(file, spec)
+		local time = file.cha.modified
+		return time and ya.format_time(time, spec or MANAGER.time_format) or ""
+	end,
+	btime = function(file, spec)
+		local time = file.cha.created
+		return time and ya.format_time(time, spec or MANAGER.time_format) or ""
+	end,
+	permissions = function(file) return file.cha:permissions() or "" end,
+	owner = function(file)
+		if ya.target_family() ~= "unix" then
+			return ""
+		end
+		return ya.user_name(file.cha.uid) or tostring(file.cha.uid)
+	end,
+	group = function(file)
+		if ya.target_family() ~= "unix" then
+			return ""
+		end
+		return ya.group_name(file.cha.gid) or tostring(file.cha.gid)
+	end,
+	mime = function(file) return file:mime() or "" end,
+}
+
+function Folder:format(format, file)
+	return (format:gsub("{([%w_]+):?([^}]*)}", function(name, spec)
+		local field = self.fields[name]
+		if not field and file:field(name) == nil then
+			return nil -- Left as it is, for the mistake to be seen
+		elseif not field then
+			field = function(f) return f:field(name) end
+		end
+
+		local align, width = spec:match("^([<>^]?)(%d+)$")
+		if not width then
+			return tostring(field(file, spec ~= "" and spec or nil) or "")
+		end
+
+		local value = tostring(field(file) or "")
+		local pad = math.max(0, tonumber(width) - ui.Span(value):width())
+		if align == ">" then
+			return string.rep(" ", pad) .. value
+		elseif align == "^" then
+			return string.rep(" ", pad // 2) .. value .. string.rep(" ", pad - pad // 2)
+		end
+		return value .. string.rep(" ", pad)
+	end))
+end
+
 function Folder:marker(file)
 	local yanked = file:is_yanked()
 	if yanked ~= 0 then
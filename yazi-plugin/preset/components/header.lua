@@ -2,16 +2,75 @@ Header = {
 	area = ui.Rect.default,
 }
 
-function Header:cwd()
+function Header:crumbs()
+	local crumbs, url = {}, cx.active.current.cwd
+	if url.is_recent then
+		return { { text = "recent://", url = url } }
+	end
+	while url do
+		local path = ya.readable_path(tostring(url))
+		if path == "~" or not url:parent() then
+			table.insert(crumbs, 1, { text = path, url = url })
+			break
+		end
+		table.insert(crumbs, 1, { text = url:name(), url = url })
+		url = url:parent()
+	end
+	return crumbs
+end
+
+function Header:cwd(max)
 	local cwd = cx.active.current.cwd
 
-	local span
-	if not cwd.is_search then
-		span = ui.Span(ya.readable_path(tostring(cwd)))
-	else
-		span = ui.Span(string.format("%s (search: %s)", ya.readable_path(tostring(cwd)), cwd:frag()))
+	local suffix = ""
+	if cwd.is_search then
+		suffix = " (" .. ya.tr("search: {query}", { query = cwd:frag() }) .. ")"
+	end
+	local filter = cx.active.current.files:filter()
+	if filter then
+		suffix = suffix .. " (" .. ya.tr("filter: {query}", { query = filter }) .. ")"
+	end
+
+	local sep = ya.target_family() == "windows" and "\\" or "/"
+	local crumbs = self:crumbs()
+	for i = 2, #crumbs do
+		local prev = crumbs[i - 1].text
+		crumbs[i].text = (prev:sub(-1) == sep and "" or sep) .. crumbs[i].text
+	end
+
+	-- Middle-truncate deep paths, keeping the root and as many trailing segments as possible
+	local width = function(s) return ui.Span(s):width() end
+	local budget = max - width(suffix)
+	local used = 0
+	for _, c in ipairs(crumbs) do
+		used = used + width(c.text)
+	end
+	if used > budget and #crumbs > 2 then
+		local head, tail = crumbs[1], {}
+		local ellipsis = { text = (head.text:sub(-1) == sep and "" or sep) .. "…", url = nil }
+		used = width(head.text) + width(ellipsis.text)
+		for i = #crumbs, 2, -1 do
+			local w = width(crumbs[i].text)
+			if used + w > budget and #tail > 0 then
+				ellipsis.url = crumbs[i].url
+				break
+			end
+			used = used + w
+			table.insert(tail, 1, crumbs[i])
+		end
+		crumbs = ellipsis.url and { head, ellipsis, table.unpack(tail) } or { head, table.unpack(tail) }
+	end
+
+	local spans, x = {}, 0
+	self._crumbs = {}
+	for _, c in ipairs(crumbs) do
+		local w = width(c.text)
+		self._crumbs[#self._crumbs + 1] = { x, x + w, c.url }
+		spans[#spans + 1] = ui.Span(c.text)
+		x = x + w
 	end
-	return span:style(THEME.manager.cwd)
+	spans[#spans + 1] = ui.Span(suffix)
+	return ui.Line(spans):style(THEME.manager.cwd)
 end
 
 function Header:counter()
@@ -39,25 +98,66 @@ function Header:counter()
 	}
 end
 
-function Header:tabs()
+function Header:title(i)
+	local tab, width = cx.tabs[i], THEME.manager.tab_width
+	if width <= 2 and not tab.label then
+		return tostring(i)
+	end
+
+	local fields = {
+		index = tostring(i),
+		name = tab:name(),
+		path = ya.readable_path(tostring(tab.current.cwd)),
+	}
+	local title = THEME.manager.tab_format:gsub("{(%w+)}", function(k) return fields[k] end)
+	if width > 2 then
+		title = ya.truncate(title, width)
+	end
+	return tab.pinned and title .. " 󰐃" or title
+end
+
+function Header:tabs(max)
 	local tabs = #cx.tabs
 	if tabs == 1 then
+		self._tabs = {}
 		return ui.Line {}
 	end
 
-	local spans = {}
+	local active, spans, widths = cx.tabs.idx + 1, {}, {}
 	for i = 1, tabs do
-		local text = i
-		if THEME.manager.tab_width > 2 then
-			text = ya.truncate(text .. " " .. cx.tabs[i]:name(), THEME.manager.tab_width)
+		local span = ui.Span(" " .. self:title(i) .. " ")
+		spans[i] = span:style(i == active and THEME.manager.tab_active or THEME.manager.tab_inactive)
+		widths[i] = span:width()
+	end
+
+	-- Scroll to keep the active tab visible, leaving a column for the ellipsis on each overflowing side
+	local first, last, used = active, active, widths[active]
+	local function fits(a, b, w) return used + w <= max - (a > 1 and 1 or 0) - (b < tabs and 1 or 0) end
+	repeat
+		local grown = false
+		if last < tabs and fits(first, last + 1, widths[last + 1]) then
+			last, used, grown = last + 1, used + widths[last + 1], true
 		end
-		if i == cx.tabs.idx + 1 then
-			spans[#spans + 1] = ui.Span(" " .. text .. " "):style(THEME.manager.tab_active)
-		else
-			spans[#spans + 1] = ui.Span(" " .. text .. " "):style(THEME.manager.tab_inactive)
+		if first > 1 and fits(first - 1, last, widths[first - 1]) then
+			first, used, grown = first - 1, used + widths[first - 1], true
 		end
+	until not grown
+
+	local line, x = {}, 0
+	if first > 1 then
+		line[1], x = ui.Span("…"):style(THEME.manager.tab_overflow), 1
+	end
+
+	self._tabs = {}
+	for i = first, last do
+		self._tabs[#self._tabs + 1] = { x, x + widths[i], i }
+		line[#line + 1], x = spans[i], x + widths[i]
+	end
+
+	if last < tabs then
+		line[#line + 1] = ui.Span("…"):style(THEME.manager.tab_overflow)
 	end
-	return ui.Line(spans)
+	return ui.Line(line)
 end
 
 function Header:layout(area)
@@ -72,10 +172,38 @@ end
 function Header:render(area)
 	local chunks = self:layout(area)
 
-	local left = ui.Line { self:cwd() }
-	local right = ui.Line { self:counter(), self:tabs() }
+	local counter = self:counter()
+	local tabs = self:tabs(chunks[2].w - counter:width())
+	self._tabs_x = chunks[2].x + chunks[2].w - tabs:width()
+
+	local left = self:cwd(chunks[1].w)
+	local right = ui.Line { counter, tabs }
 	return {
 		ui.Paragraph(chunks[1], { left }),
 		ui.Paragraph(chunks[2], { right }):align(ui.Paragraph.RIGHT),
 	}
 end
+
+function Header:click(event, up)
+	if up or not event.is_left then
+		return
+	end
+
+	local x = event.x - self.area.x
+	for _, c in ipairs(self._crumbs or {}) do
+		if x >= c[1] and x < c[2] and c[3] then
+			return ya.manager_emit("cd", { tostring(c[3]) })
+		end
+	end
+
+	x = event.x - (self._tabs_x or 0)
+	for _, t in ipairs(self._tabs or {}) do
+		if x >= t[1] and x < t[2] then
+			return ya.manager_emit("tab_switch", { t[3] - 1 })
+		end
+	end
+end
+
+function Header:scroll(event, step) end
+
+function Header:drag(event) end
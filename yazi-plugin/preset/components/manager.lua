@@ -8,26 +8,94 @@ function Manager:layout(area)
 	return ui.Layout()
 		:direction(ui.Layout.HORIZONTAL)
 		:constraints({
-			ui.Constraint.Ratio(MANAGER.ratio.parent, MANAGER.ratio.all),
-			ui.Constraint.Ratio(MANAGER.ratio.current, MANAGER.ratio.all),
-			ui.Constraint.Ratio(MANAGER.ratio.preview, MANAGER.ratio.all),
+			ui.Constraint.Ratio(cx.ratio.parent, cx.ratio.all),
+			ui.Constraint.Ratio(cx.ratio.current, cx.ratio.all),
+			ui.Constraint.Ratio(cx.ratio.preview, cx.ratio.all),
 		})
 		:split(area)
 end
 
 function Manager:render(area)
 	local chunks = self:layout(area)
+	self._widths = { chunks[1].w, chunks[2].w, chunks[3].w }
+
+	-- The column of the border is kept even without one, to separate the panes by padding
+	local pad = ({ compact = 0, normal = 1, relaxed = 2 })[THEME.ui.density] or 1
+	local inner = math.max(pad, 1)
 
 	return ya.flat {
 		-- Borders
-		ui.Bar(chunks[1], ui.Bar.RIGHT):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
-		ui.Bar(chunks[3], ui.Bar.LEFT):symbol(THEME.manager.border_symbol):style(THEME.manager.border_style),
+		self:borders(chunks),
 
 		-- Parent
-		Parent:render(chunks[1]:padding(ui.Padding.x(1))),
+		Parent:render(chunks[1]:padding(ui.Padding(pad, inner, 0, 0))),
 		-- Current
 		Current:render(chunks[2]),
 		-- Preview
-		Preview:render(chunks[3]:padding(ui.Padding.x(1))),
+		Preview:render(chunks[3]:padding(ui.Padding(inner, pad, 0, 0))),
+	}
+end
+
+function Manager:borders(chunks)
+	local symbol = THEME.manager.border_symbol
+	if symbol == "" then
+		return {}
+	end
+
+	return {
+		ui.Bar(chunks[1], ui.Bar.RIGHT):symbol(symbol):style(THEME.manager.border_style),
+		ui.Bar(chunks[3], ui.Bar.LEFT):symbol(symbol):style(THEME.manager.border_style),
 	}
 end
+
+function Manager:hit(event)
+	for _, c in ipairs { Parent, Current, Preview } do
+		local a = c.area
+		if event.x >= a.x and event.x < a.x + a.w and event.y >= a.y and event.y < a.y + a.h then
+			return c
+		end
+	end
+end
+
+function Manager:click(event, up)
+	if up then
+		self._resize, self._press = nil, nil
+		return Current:click(event, up)
+	elseif not event.is_left then
+		return
+	end
+
+	-- Pressing on a border starts resizing the columns it separates
+	local w = self._widths
+	if event.x == self.area.x + w[1] - 1 then
+		self._resize = 1
+	elseif event.x == self.area.x + w[1] + w[2] then
+		self._resize = 2
+	else
+		self._press = self:hit(event)
+		if self._press then
+			return self._press:click(event, up)
+		end
+		return Current:click_header(event)
+	end
+end
+
+function Manager:scroll(event, step)
+	local c = self:hit(event)
+	return c and c:scroll(event, step)
+end
+
+function Manager:drag(event)
+	if not self._resize then
+		return self._press == Current and Current:drag(event)
+	end
+
+	local w, x = self._widths, event.x - self.area.x
+	if self._resize == 1 then
+		local parent = ya.clamp(0, x + 1, w[1] + w[2] - 1)
+		ya.manager_emit("ratio", { parent, w[1] + w[2] - parent, w[3] })
+	else
+		local preview = ya.clamp(0, self.area.w - x, w[2] + w[3] - 1)
+		ya.manager_emit("ratio", { w[1], w[2] + w[3] - preview, preview })
+	end
+end
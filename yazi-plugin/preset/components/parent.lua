@@ -33,3 +33,18 @@ function Parent:render(area)
 		Folder:markers(area, markers),
 	}
 end
+
+function Parent:click(event, up)
+	local folder = Folder:by_kind(Folder.PARENT)
+	local y = event.y - self.area.y + 1
+	if not folder or y > #folder.window then
+		return
+	end
+
+	local file = folder.window[y]
+	if file.cha.is_dir then
+		ya.manager_emit("cd", { tostring(file.url) })
+	end
+end
+
+function Parent:scroll(event, step) end
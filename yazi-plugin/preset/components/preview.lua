@@ -6,3 +6,7 @@ function Preview:render(area)
 	self.area = area
 	return {}
 end
+
+function Preview:click(event, up) end
+
+function Preview:scroll(event, step) ya.manager_emit("seek", { step }) end
@@ -36,10 +36,15 @@ function Progress:partial_render()
 		percent = math.min(99, ya.round(progress.processed * 100 / progress.found))
 	end
 
-	local left = progress.total - progress.succ
+	-- The time left is known for the tasks whose total is planned beforehand,
+	-- otherwise it's the number of tasks left
+	local label
+	if progress.eta > 0 then
+		label = string.format("%3d%%, %d:%02d left", percent, progress.eta // 60, progress.eta % 60)
+	else
+		label = string.format("%3d%%, %d left", percent, progress.total - progress.succ)
+	end
 	return {
-		gauge
-			:percent(percent)
-			:label(ui.Span(string.format("%3d%%, %d left", percent, left)):style(THEME.status.progress_label)),
+		gauge:percent(percent):label(ui.Span(label):style(THEME.status.progress_label)),
 	}
 end
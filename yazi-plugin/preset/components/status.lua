@@ -1,5 +1,6 @@
 Status = {
 	area = ui.Rect.default,
+	warned = {},
 }
 
 function Status.style()
@@ -108,14 +109,130 @@ function Status:position()
 	}
 end
 
+function Status:selected()
+	local count = #cx.active.selected
+	if count == 0 then
+		return ui.Line {}
+	end
+
+	-- The size is summed up in the background, and told as it goes
+	local text = ya.tr("{n} selected", { n = count })
+	local size = cx.active.selected_size
+	if size then
+		text = text .. ", " .. ya.readable_size(size) .. (cx.active.selected_sized and "" or "…")
+	end
+	return ui.Line {
+		ui.Span(" " .. text .. " "):style(THEME.status.selected),
+	}
+end
+
+function Status:view()
+	local conf, parts = cx.active.conf, {}
+	if conf.show_hidden then
+		parts[#parts + 1] = ya.tr("hidden")
+	end
+	local ignored = cx.active.current.ignored
+	if ignored > 0 then
+		parts[#parts + 1] = ya.tr("{n} ignored", { n = ignored })
+	end
+	if conf.linemode ~= "none" then
+		parts[#parts + 1] = conf.linemode
+	end
+	if #parts == 0 then
+		return ui.Line {}
+	end
+
+	return ui.Line {
+		ui.Span(string.format(" %s ", table.concat(parts, ", "))):style(THEME.status.view),
+	}
+end
+
+function Status:filter()
+	local filter = cx.active.current.files:filter()
+	if not filter then
+		return ui.Line {}
+	end
+
+	return ui.Line {
+		ui.Span(" " .. ya.tr("filter: {query}", { query = filter }) .. " "):style(THEME.status.filter),
+	}
+end
+
+function Status:tasks()
+	local progress = cx.tasks.progress
+	if progress.total == 0 then
+		return ui.Line {}
+	end
+
+	local text = ya.tr("{n} task{s}", { n = progress.total })
+	if progress.fail ~= 0 then
+		text = ya.tr("{n} task{s}, {fail} failed", { n = progress.total, fail = progress.fail })
+	end
+	return ui.Line { ui.Span(" " .. text .. " "):style(THEME.status.tasks) }
+end
+
+function Status:disk()
+	local disk = cx.disk
+	if not disk or disk.total == 0 then
+		return ui.Line {}
+	end
+
+	local used = (disk.total - disk.free) * 100 // disk.total
+	local style = used >= THEME.mounts.full and THEME.status.disk_full or THEME.status.disk
+	return ui.Line {
+		ui.Span(" " .. ya.tr("{free} free of {total}", {
+			free = ya.readable_size(disk.free),
+			total = ya.readable_size(disk.total),
+		}) .. " "):style(style),
+	}
+end
+
+function Status:git()
+	-- Read in the background on entering a directory
+	local branch = cx.active.branch
+	if not branch then
+		return ui.Line {}
+	end
+
+	return ui.Line {
+		ui.Span(string.format("  %s ", branch)):style(THEME.status.git),
+	}
+end
+
+-- Compose the given segments into a single line.
+-- A segment is any method of `Status` that returns a `ui.Line`, so plugins can
+-- provide their own by defining e.g. `function Status:battery() ... end`.
+function Status:segments(names)
+	local lines = {}
+	for _, name in ipairs(names) do
+		local f = self[name]
+		if type(f) == "function" then
+			lines[#lines + 1] = f(self)
+		elseif not self.warned[name] then
+			-- Once, not on every render
+			self.warned[name] = true
+			ya.err("Unknown status segment: " .. name)
+		end
+	end
+	return ui.Line(lines)
+end
+
 function Status:render(area)
 	self.area = area
 
-	local left = ui.Line { self:mode(), self:size(), self:name() }
-	local right = ui.Line { self:permissions(), self:percentage(), self:position() }
+	local left = self:segments(THEME.status.left)
+	local center = self:segments(THEME.status.center)
+	local right = self:segments(THEME.status.right)
 	return {
 		ui.Paragraph(area, { left }),
+		ui.Paragraph(area, { center }):align(ui.Paragraph.CENTER),
 		ui.Paragraph(area, { right }):align(ui.Paragraph.RIGHT),
 		table.unpack(Progress:render(area, right:width())),
 	}
 end
+
+function Status:click(event, up) end
+
+function Status:scroll(event, step) end
+
+function Status:drag(event) end
@@ -0,0 +1,29 @@
+local ancestors = ya.sync(function()
+	local urls, url = {}, cx.active.current.cwd:parent()
+	while url and #urls < 10 do
+		urls[#urls + 1] = tostring(url)
+		url = url:parent()
+	end
+	return urls
+end)
+
+local M = {}
+
+function M:entry()
+	local urls = ancestors()
+	if #urls == 0 then
+		return
+	end
+
+	local cands = {}
+	for i, url in ipairs(urls) do
+		cands[#cands + 1] = { on = tostring(i % 10), desc = ya.readable_path(url) }
+	end
+
+	local idx = ya.which { cands = cands }
+	if idx then
+		ya.manager_emit("cd", { urls[idx] })
+	end
+end
+
+return M
@@ -11,8 +11,13 @@ function M:seek(units)
 	local h = cx.active.current.hovered
 	if h and h.url == self.file.url then
 		local step = math.floor(units * self.area.h / 10)
+		-- Jump past EOF on `seek --end`, and let `peek` settle on the last page
+		local skip = math.max(0, cx.active.preview.skip + step)
+		if units == ya.SEEK_END then
+			skip = math.maxinteger
+		end
 		ya.manager_emit("peek", {
-			tostring(math.max(0, cx.active.preview.skip + step)),
+			tostring(skip),
 			only_if = tostring(self.file.url),
 		})
 	end
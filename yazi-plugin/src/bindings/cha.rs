@@ -49,5 +49,5 @@ impl Cha {
 }
 
 impl<T: Into<yazi_shared::fs::Cha>> Cast<T> for Cha {
-	fn cast(lua: &Lua, data: T) -> mlua::Result<AnyUserData> { lua.create_any_userdata(data.into()) }
+	fn cast(lua: &Lua, data: T) -> mlua::Result<AnyUserData<'_>> { lua.create_any_userdata(data.into()) }
 }
@@ -3,24 +3,36 @@ use mlua::{AnyUserData, Lua, UserDataFields};
 use super::Cast;
 use crate::elements::Style;
 
-pub struct Icon;
+pub struct Icon {
+	text:  String,
+	style: yazi_config::theme::Style,
+}
 
 impl Icon {
 	pub fn register(lua: &Lua) -> mlua::Result<()> {
-		lua.register_userdata_type::<&yazi_config::theme::Icon>(|reg| {
+		lua.register_userdata_type::<Self>(|reg| {
 			reg.add_field_method_get("text", |lua, me| lua.create_string(&me.text));
 			reg.add_field_method_get("style", |_, me| Ok(Style::from(me.style)));
 		})?;
 
 		Ok(())
 	}
+
+	/// The icon in another style, such as the one of the filetype rule matching the file.
+	pub fn cast_styled<'lua>(
+		lua: &'lua Lua,
+		data: &yazi_config::theme::Icon,
+		style: yazi_config::theme::Style,
+	) -> mlua::Result<AnyUserData<'lua>> {
+		lua.create_any_userdata(Self { text: data.text.clone(), style })
+	}
 }
 
-impl Cast<&'static yazi_config::theme::Icon> for Icon {
+impl Cast<&yazi_config::theme::Icon> for Icon {
 	fn cast<'lua>(
 		lua: &'lua Lua,
-		data: &'static yazi_config::theme::Icon,
+		data: &yazi_config::theme::Icon,
 	) -> mlua::Result<AnyUserData<'lua>> {
-		lua.create_any_userdata(data)
+		Self::cast_styled(lua, data, data.style)
 	}
 }
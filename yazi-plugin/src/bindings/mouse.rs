@@ -0,0 +1,36 @@
+use crossterm::event::{MouseButton, MouseEventKind};
+use mlua::{AnyUserData, Lua, UserDataFields};
+
+use super::Cast;
+
+pub struct MouseEvent;
+
+impl MouseEvent {
+	pub fn register(lua: &Lua) -> mlua::Result<()> {
+		lua.register_userdata_type::<crossterm::event::MouseEvent>(|reg| {
+			reg.add_field_method_get("x", |_, me| Ok(me.column));
+			reg.add_field_method_get("y", |_, me| Ok(me.row));
+			reg.add_field_method_get("is_left", |_, me| Ok(Self::button(me) == Some(MouseButton::Left)));
+			reg.add_field_method_get("is_right", |_, me| {
+				Ok(Self::button(me) == Some(MouseButton::Right))
+			});
+			reg.add_field_method_get("is_middle", |_, me| {
+				Ok(Self::button(me) == Some(MouseButton::Middle))
+			});
+		})?;
+
+		Ok(())
+	}
+
+	#[inline]
+	fn button(event: &crossterm::event::MouseEvent) -> Option<MouseButton> {
+		match event.kind {
+			MouseEventKind::Down(b) | MouseEventKind::Up(b) | MouseEventKind::Drag(b) => Some(b),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Into<crossterm::event::MouseEvent>> Cast<T> for MouseEvent {
+	fn cast(lua: &Lua, data: T) -> mlua::Result<AnyUserData<'_>> { lua.create_any_userdata(data.into()) }
+}
@@ -16,6 +16,8 @@ pub fn cast_to_renderable(ud: AnyUserData) -> Option<Box<dyn Renderable + Send>>
 		Some(Box::new(c))
 	} else if let Ok(c) = ud.take::<crate::elements::Gauge>() {
 		Some(Box::new(c))
+	} else if let Ok(c) = ud.take::<crate::elements::Thumbnail>() {
+		Some(Box::new(c))
 	} else {
 		None
 	}
@@ -59,7 +61,7 @@ impl<'a> TryFrom<Value<'a>> for ValueSendable {
 }
 
 impl<'lua> IntoLua<'lua> for ValueSendable {
-	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+	fn into_lua(self, lua: &'lua Lua) -> mlua::Result<Value<'lua>> {
 		match self {
 			ValueSendable::Nil => Ok(Value::Nil),
 			ValueSendable::Boolean(b) => Ok(Value::Boolean(b)),
@@ -129,7 +131,7 @@ impl TryInto<ValueSendableKey> for ValueSendable {
 }
 
 impl<'lua> IntoLua<'lua> for ValueSendableKey {
-	fn into_lua(self, lua: &Lua) -> mlua::Result<Value> {
+	fn into_lua(self, lua: &'lua Lua) -> mlua::Result<Value<'lua>> {
 		match self {
 			ValueSendableKey::Nil => Ok(Value::Nil),
 			ValueSendableKey::Boolean(b) => Ok(Value::Boolean(b)),
@@ -22,7 +22,7 @@ impl<'a> Config<'a> {
 	}
 
 	pub fn install_theme(self) -> mlua::Result<Self> {
-		self.lua.globals().set("THEME", self.lua.to_value_with(&*THEME, OPTIONS)?)?;
+		self.lua.globals().set("THEME", self.lua.to_value_with(&**THEME.load(), OPTIONS)?)?;
 		Ok(self)
 	}
 
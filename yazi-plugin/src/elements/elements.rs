@@ -23,6 +23,7 @@ pub fn pour(lua: &Lua) -> mlua::Result<()> {
 	super::Rect::install(lua, &ui)?;
 	super::Span::install(lua, &ui)?;
 	super::Style::install(lua, &ui)?;
+	super::Thumbnail::install(lua, &ui)?;
 
 	lua.globals().set("ui", ui)
 }
@@ -13,6 +13,7 @@ mod paragraph;
 mod rect;
 mod span;
 mod style;
+mod thumbnail;
 
 pub use bar::*;
 pub use border::*;
@@ -27,3 +28,4 @@ pub use paragraph::*;
 pub use rect::*;
 pub use span::*;
 pub use style::*;
+pub use thumbnail::*;
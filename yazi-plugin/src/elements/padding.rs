@@ -72,7 +72,7 @@ impl Padding {
 }
 
 impl Cast<ratatui::widgets::Padding> for Padding {
-	fn cast(lua: &Lua, data: ratatui::widgets::Padding) -> mlua::Result<AnyUserData> {
+	fn cast(lua: &Lua, data: ratatui::widgets::Padding) -> mlua::Result<AnyUserData<'_>> {
 		lua.create_any_userdata(data)
 	}
 }
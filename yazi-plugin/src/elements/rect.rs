@@ -54,7 +54,7 @@ impl Rect {
 }
 
 impl Cast<ratatui::layout::Rect> for Rect {
-	fn cast(lua: &Lua, data: ratatui::layout::Rect) -> mlua::Result<AnyUserData> {
+	fn cast(lua: &Lua, data: ratatui::layout::Rect) -> mlua::Result<AnyUserData<'_>> {
 		lua.create_any_userdata(data)
 	}
 }
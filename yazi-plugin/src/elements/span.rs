@@ -19,6 +19,7 @@ impl Span {
 
 impl UserData for Span {
 	fn add_methods<'lua, M: UserDataMethods<'lua, Self>>(methods: &mut M) {
+		methods.add_function("width", |_, ud: AnyUserData| Ok(ud.borrow::<Self>()?.0.width()));
 		methods.add_function("fg", |_, (ud, color): (AnyUserData, String)| {
 			ud.borrow_mut::<Self>()?.0.style.fg = Color::try_from(color).ok().map(Into::into);
 			Ok(ud)
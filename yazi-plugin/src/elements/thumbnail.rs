@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use mlua::{Lua, Table, UserData};
+use yazi_adaptor::{Thumb, Thumbnail as Thumbs};
+
+use super::{RectRef, Renderable};
+use crate::url::UrlRef;
+
+#[derive(Clone)]
+pub struct Thumbnail {
+	area: ratatui::layout::Rect,
+
+	thumb: Arc<Thumb>,
+}
+
+impl Thumbnail {
+	pub fn install(lua: &Lua, ui: &Table) -> mlua::Result<()> {
+		ui.set(
+			"Thumbnail",
+			lua.create_function(|_, (area, url): (RectRef, UrlRef)| {
+				Ok(Thumbs::get(&url, *area).map(|thumb| Self { area: *area, thumb }))
+			})?,
+		)
+	}
+}
+
+impl UserData for Thumbnail {}
+
+impl Renderable for Thumbnail {
+	fn area(&self) -> ratatui::layout::Rect { self.area }
+
+	fn render(self: Box<Self>, buf: &mut ratatui::buffer::Buffer) { self.clone_render(buf) }
+
+	fn clone_render(&self, buf: &mut ratatui::buffer::Buffer) {
+		Thumbs::draw(self.area, &self.thumb, buf)
+	}
+}
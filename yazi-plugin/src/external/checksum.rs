@@ -0,0 +1,122 @@
+use std::{fmt::{self, Display}, path::Path, process::Stdio, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+use tokio::process::Command;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Checksum {
+	Md5,
+	Sha1,
+	#[default]
+	Sha256,
+	Blake3,
+}
+
+impl FromStr for Checksum {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s.to_ascii_lowercase().as_str() {
+			"md5" => Self::Md5,
+			"sha1" => Self::Sha1,
+			"sha256" => Self::Sha256,
+			"blake3" | "b3" => Self::Blake3,
+			_ => bail!("invalid checksum algorithm: {s}"),
+		})
+	}
+}
+
+impl Display for Checksum {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Md5 => "MD5",
+			Self::Sha1 => "SHA1",
+			Self::Sha256 => "SHA256",
+			Self::Blake3 => "BLAKE3",
+		})
+	}
+}
+
+impl Checksum {
+	/// The conventional name of the file listing the checksums, e.g. `SHA256SUMS`.
+	#[inline]
+	pub fn sums_name(self) -> String { format!("{self}SUMS") }
+
+	/// Guesses the algorithm of a sums file from its name, or the length of a checksum in it.
+	pub fn guess(name: &str, hash: &str) -> Self {
+		let name = name.to_ascii_lowercase();
+		if name.contains("b3") || name.contains("blake3") {
+			Self::Blake3
+		} else if name.contains("sha256") {
+			Self::Sha256
+		} else if name.contains("sha1") {
+			Self::Sha1
+		} else if name.contains("md5") {
+			Self::Md5
+		} else {
+			match hash.len() {
+				32 => Self::Md5,
+				40 => Self::Sha1,
+				_ => Self::Sha256,
+			}
+		}
+	}
+
+	/// Computes the checksum of a file, with the first of the tools that's available.
+	pub async fn digest(self, path: &Path) -> Result<String> {
+		let tools: &[(&str, &[&str])] = match self {
+			Self::Md5 => &[("md5sum", &[]), ("md5", &["-r"])],
+			Self::Sha1 => &[("sha1sum", &[]), ("shasum", &["-a", "1"])],
+			Self::Sha256 => &[("sha256sum", &[]), ("shasum", &["-a", "256"])],
+			Self::Blake3 => &[("b3sum", &[])],
+		};
+
+		for &(bin, args) in tools {
+			let output = match Command::new(bin)
+				.args(args)
+				.arg(path)
+				.stdin(Stdio::null())
+				.kill_on_drop(true)
+				.output()
+				.await
+			{
+				Ok(o) => o,
+				Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+				Err(e) => Err(e)?,
+			};
+
+			if !output.status.success() {
+				bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+			}
+
+			// Names with special characters are escaped, and the checksum prefixed with a `\`
+			let stdout = String::from_utf8_lossy(&output.stdout);
+			let hash = stdout.split_whitespace().next().map(|s| s.trim_start_matches('\\'));
+			return hash.map(|s| s.to_ascii_lowercase()).ok_or_else(|| anyhow!("no output from `{bin}`"));
+		}
+
+		bail!("`{}` not found", tools[0].0)
+	}
+
+	/// Parses a sums file into the names and the checksums, in both the GNU style
+	/// `<hash>  <name>` and the BSD style `<ALGO> (<name>) = <hash>`.
+	pub fn parse_sums(s: &str) -> Vec<(String, String)> {
+		s.lines()
+			.filter_map(|line| {
+				let line = line.trim_end_matches('\r');
+				if line.is_empty() || line.starts_with('#') {
+					return None;
+				}
+
+				if let Some((head, hash)) = line.rsplit_once(") = ") {
+					let (_, name) = head.split_once(" (")?;
+					return Some((name.to_owned(), hash.trim().to_ascii_lowercase()));
+				}
+
+				let (hash, name) = line.split_once(' ')?;
+				let name = name.strip_prefix([' ', '*']).unwrap_or(name);
+				Some((name.to_owned(), hash.trim_start_matches('\\').to_ascii_lowercase()))
+			})
+			.collect()
+	}
+}
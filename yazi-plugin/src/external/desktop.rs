@@ -0,0 +1,53 @@
+use std::process::Stdio;
+
+use anyhow::{bail, Result};
+use tokio::process::Command;
+
+pub struct DesktopNotifyOpt<'a> {
+	pub title: &'a str,
+	pub body:  &'a str,
+}
+
+pub async fn desktop_notify(opt: DesktopNotifyOpt<'_>) -> Result<()> {
+	let mut cmd = if cfg!(target_os = "macos") {
+		let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+		let mut cmd = Command::new("osascript");
+		cmd.arg("-e").arg(format!(
+			"display notification \"{}\" with title \"{}\"",
+			escape(opt.body),
+			escape(opt.title)
+		));
+		cmd
+	} else if cfg!(windows) {
+		let escape = |s: &str| s.replace('\'', "''");
+		let mut cmd = Command::new("powershell");
+		cmd.args(["-NoProfile", "-NonInteractive", "-Command"]).arg(format!(
+			"[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+			 $t = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+			 $x = $t.GetElementsByTagName('text'); \
+			 $x.Item(0).AppendChild($t.CreateTextNode('{}')) > $null; \
+			 $x.Item(1).AppendChild($t.CreateTextNode('{}')) > $null; \
+			 [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('Yazi').Show([Windows.UI.Notifications.ToastNotification]::new($t))",
+			escape(opt.title),
+			escape(opt.body)
+		));
+		cmd
+	} else {
+		let mut cmd = Command::new("notify-send");
+		cmd.args(["--app-name=yazi", "--", opt.title, opt.body]);
+		cmd
+	};
+
+	let status = cmd
+		.stdin(Stdio::null())
+		.stdout(Stdio::null())
+		.stderr(Stdio::null())
+		.kill_on_drop(true)
+		.status()
+		.await?;
+
+	if !status.success() {
+		bail!("Desktop notifier exited with {status}");
+	}
+	Ok(())
+}
@@ -18,5 +18,5 @@ pub async fn fzf(opt: FzfOpt) -> Result<Url> {
 	if selected.is_empty() {
 		bail!("No match")
 	}
-	return Ok(Url::from(Path::new(&opt.cwd).join(selected)));
+	Ok(Url::from(Path::new(&opt.cwd).join(selected)))
 }
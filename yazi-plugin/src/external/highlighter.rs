@@ -1,9 +1,10 @@
-use std::{io::Cursor, mem, path::{Path, PathBuf}, sync::{atomic::{AtomicUsize, Ordering}, OnceLock}};
+use std::{collections::BTreeMap, io::{Cursor, SeekFrom}, mem, path::{Path, PathBuf}, sync::{atomic::{AtomicU64, AtomicUsize, Ordering}, Arc, OnceLock}, time::SystemTime};
 
 use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
 use ratatui::text::{Line, Span, Text};
 use syntect::{dumps, easy::HighlightLines, highlighting::{self, Theme, ThemeSet}, parsing::{SyntaxReference, SyntaxSet}};
-use tokio::{fs::File, io::{AsyncBufReadExt, BufReader}};
+use tokio::{fs::File, io::{AsyncBufReadExt, AsyncSeekExt, BufReader}};
 use yazi_config::{PREVIEW, THEME};
 use yazi_shared::PeekError;
 
@@ -11,6 +12,27 @@ static INCR: AtomicUsize = AtomicUsize::new(0);
 static SYNTECT_SYNTAX: OnceLock<SyntaxSet> = OnceLock::new();
 static SYNTECT_THEME: OnceLock<Theme> = OnceLock::new();
 
+// Files larger than this are read through a window instead of from the start
+const WINDOWED: u64 = 4 << 20;
+// Every `CHECKPOINT`th line offset is remembered, so scrolling can seek near it
+const CHECKPOINT: usize = 1000;
+// Anything beyond this in a single line is discarded
+const MAX_LINE: usize = 64 << 10;
+// The files whose checkpoints are kept
+const CHECKPOINTS_LIMIT: usize = 16;
+
+// The checkpoints of each file, and when they were last used, by `TICK`
+#[allow(clippy::type_complexity)]
+static CHECKPOINTS: Mutex<BTreeMap<PathBuf, (u64, Arc<Mutex<Checkpoints>>)>> =
+	Mutex::new(BTreeMap::new());
+static TICK: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Default)]
+struct Checkpoints {
+	mtime:   Option<SystemTime>,
+	offsets: Vec<u64>,
+}
+
 pub struct Highlighter {
 	path: PathBuf,
 }
@@ -22,7 +44,7 @@ impl Highlighter {
 	pub fn init() -> (&'static Theme, &'static SyntaxSet) {
 		#[inline]
 		fn from_file() -> Result<Theme> {
-			let file = std::fs::File::open(&THEME.manager.syntect_theme)?;
+			let file = std::fs::File::open(&THEME.load().manager.syntect_theme)?;
 			Ok(ThemeSet::load_from_reader(&mut std::io::BufReader::new(file))?)
 		}
 
@@ -57,18 +79,24 @@ impl Highlighter {
 	}
 
 	pub async fn highlight(&self, skip: usize, limit: usize) -> Result<Text<'static>, PeekError> {
+		let meta = tokio::fs::metadata(&self.path).await?;
+		if meta.len() > WINDOWED {
+			return self.highlight_windowed(skip, limit, meta.modified().ok()).await;
+		}
+
 		let mut reader = BufReader::new(File::open(&self.path).await?).lines();
 
 		let syntax = Self::find_syntax(&self.path).await;
 		let mut plain = syntax.is_err();
 
-		let mut before = Vec::with_capacity(if plain { 0 } else { skip });
+		// `skip` can be far past the end, e.g. for `seek --end`, so it's only a hint
+		let mut before = Vec::with_capacity(if plain { 0 } else { skip.min(1 << 16) });
 		let mut after = Vec::with_capacity(limit);
 
 		let mut i = 0;
 		while let Some(mut line) = reader.next_line().await? {
 			i += 1;
-			if i > skip + limit {
+			if i > skip.saturating_add(limit) {
 				break;
 			}
 
@@ -86,7 +114,7 @@ impl Highlighter {
 			}
 		}
 
-		if skip > 0 && i < skip + limit {
+		if skip > 0 && i < skip.saturating_add(limit) {
 			return Err(PeekError::Exceed(i.saturating_sub(limit)));
 		}
 
@@ -97,6 +125,95 @@ impl Highlighter {
 		}
 	}
 
+	async fn highlight_windowed(
+		&self,
+		skip: usize,
+		limit: usize,
+		mtime: Option<SystemTime>,
+	) -> Result<Text<'static>, PeekError> {
+		let cp = {
+			let mut checkpoints = CHECKPOINTS.lock();
+			if checkpoints.len() >= CHECKPOINTS_LIMIT && !checkpoints.contains_key(&self.path) {
+				// Makes room by dropping the least recently used one
+				let lru = checkpoints.iter().min_by_key(|(_, (u, _))| *u).map(|(p, _)| p.clone());
+				lru.map(|p| checkpoints.remove(&p));
+			}
+
+			let (used, cp) = checkpoints.entry(self.path.clone()).or_default();
+			*used = TICK.fetch_add(1, Ordering::Relaxed);
+			cp.clone()
+		};
+
+		let (k, start) = {
+			let mut cp = cp.lock();
+			if cp.mtime != mtime || cp.offsets.is_empty() {
+				*cp = Checkpoints { mtime, offsets: vec![0] };
+			}
+			let k = (skip / CHECKPOINT).min(cp.offsets.len() - 1);
+			(k, cp.offsets[k])
+		};
+
+		let mut file = File::open(&self.path).await?;
+		file.seek(SeekFrom::Start(start)).await?;
+
+		let mut reader = BufReader::new(file);
+		let (mut i, mut pos) = (k * CHECKPOINT, start);
+		let mut buf = Vec::new();
+		let mut after = Vec::with_capacity(limit);
+
+		while after.len() < limit {
+			let Some(n) = Self::read_line(&mut reader, &mut buf).await? else { break };
+			i += 1;
+			pos += n as u64;
+			if i % CHECKPOINT == 0 {
+				let mut cp = cp.lock();
+				if i / CHECKPOINT == cp.offsets.len() {
+					cp.offsets.push(pos);
+				}
+			}
+			if i > skip {
+				after.push(String::from_utf8_lossy(&buf).into_owned());
+			}
+		}
+
+		if skip > 0 && after.len() < limit {
+			return Err(PeekError::Exceed(i.saturating_sub(limit)));
+		}
+
+		let plain = after.iter().any(|l| l.len() > 6000);
+		match Self::find_syntax(&self.path).await {
+			Ok(syntax) if !plain => Self::highlight_with(vec![], after, syntax).await,
+			_ => Ok(Text::from(after.join(""))),
+		}
+	}
+
+	// Reads the next line into `buf`, returning the number of bytes consumed,
+	// or `None` at EOF. Only the first `MAX_LINE` bytes of a line are kept.
+	async fn read_line(reader: &mut BufReader<File>, buf: &mut Vec<u8>) -> std::io::Result<Option<usize>> {
+		buf.clear();
+		let mut total = 0;
+		loop {
+			let chunk = reader.fill_buf().await?;
+			if chunk.is_empty() {
+				return Ok(if total == 0 { None } else { Some(total) });
+			}
+
+			let (n, done) = match chunk.iter().position(|&b| b == b'\n') {
+				Some(i) => (i + 1, true),
+				None => (chunk.len(), false),
+			};
+			if buf.len() < MAX_LINE {
+				buf.extend_from_slice(&chunk[..n.min(MAX_LINE - buf.len())]);
+			}
+
+			reader.consume(n);
+			total += n;
+			if done {
+				return Ok(Some(total));
+			}
+		}
+	}
+
 	async fn highlight_with(
 		before: Vec<String>,
 		after: Vec<String>,
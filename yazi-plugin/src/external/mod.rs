@@ -1,3 +1,5 @@
+mod checksum;
+mod desktop;
 mod fd;
 mod fzf;
 mod highlighter;
@@ -6,6 +8,8 @@ mod rg;
 mod shell;
 mod zoxide;
 
+pub use checksum::*;
+pub use desktop::*;
 pub use fd::*;
 pub use fzf::*;
 pub use highlighter::*;
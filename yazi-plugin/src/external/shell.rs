@@ -4,10 +4,12 @@ use anyhow::Result;
 use tokio::process::{Child, Command};
 
 pub struct ShellOpt {
-	pub cmd:    OsString,
-	pub args:   Vec<OsString>,
-	pub piped:  bool,
-	pub orphan: bool,
+	pub cmd:     OsString,
+	pub args:    Vec<OsString>,
+	pub piped:   bool,
+	pub orphan:  bool,
+	// Only the stdout and stderr are piped, for the output to be copied while it's interactive
+	pub capture: bool,
 }
 
 impl ShellOpt {
@@ -26,6 +28,11 @@ impl ShellOpt {
 			Stdio::inherit()
 		}
 	}
+
+	#[inline]
+	fn output(&self) -> Stdio {
+		if self.capture && !self.orphan { Stdio::piped() } else { self.stdio() }
+	}
 }
 
 pub fn shell(opt: ShellOpt) -> Result<Child> {
@@ -35,16 +42,24 @@ pub fn shell(opt: ShellOpt) -> Result<Child> {
 	return Ok(unsafe {
 		Command::new("sh")
 			.env("YAZI_LEVEL", (level + 1).to_string())
+			.env("YAZI_PID", std::process::id().to_string())
 			.arg("-c")
 			.stdin(opt.stdio())
-			.stdout(opt.stdio())
-			.stderr(opt.stdio())
+			.stdout(opt.output())
+			.stderr(opt.output())
 			.arg(opt.cmd)
 			.arg("") // $0 is the command name
 			.args(opt.args)
 			.kill_on_drop(!opt.orphan)
 			.pre_exec(move || {
-				if opt.orphan && libc::setpgid(0i32, 0i32) < 0 {
+				// An orphan goes to a new session, away from the terminal, to survive yazi exiting,
+				// and one in the background to a new process group, to be killed with its children
+				let failed = if opt.orphan {
+					libc::setsid() < 0
+				} else {
+					opt.piped && libc::setpgid(0i32, 0i32) < 0
+				};
+				if failed {
 					libc::perror(std::ptr::null());
 				}
 				Ok(())
@@ -63,14 +78,26 @@ pub fn shell(opt: ShellOpt) -> Result<Child> {
 				.arg("/C")
 				.args(&expanded)
 				.stdin(opt.stdio())
-				.stdout(opt.stdio())
-				.stderr(opt.stdio())
+				.stdout(opt.output())
+				.stderr(opt.output())
 				.kill_on_drop(!opt.orphan)
+				// DETACHED_PROCESS and CREATE_NEW_PROCESS_GROUP, for an orphan to outlive yazi
+				.creation_flags(if opt.orphan { 0x8 | 0x200 } else { 0 })
 				.spawn()?,
 		)
 	}
 }
 
+/// Kills the process, and on Unix, the others in its process group too, the children of one
+/// spawned in the background.
+pub fn kill(child: &mut Child) {
+	#[cfg(unix)]
+	if let Some(pid) = child.id() {
+		unsafe { libc::kill(-(pid as i32), libc::SIGTERM) };
+	}
+	child.start_kill().ok();
+}
+
 #[cfg(windows)]
 mod parser {
 	use std::{iter::Peekable, str::Chars};
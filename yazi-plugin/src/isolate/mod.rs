@@ -5,9 +5,11 @@ mod isolate;
 mod peek;
 mod preload;
 mod seek;
+mod spot;
 
 pub use entry::*;
 pub use isolate::*;
 pub use peek::*;
 pub use preload::*;
 pub use seek::*;
+pub use spot::*;
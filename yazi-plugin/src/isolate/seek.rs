@@ -4,6 +4,10 @@ use yazi_shared::{emit, event::Cmd, Layer};
 
 use crate::{bindings::{Cast, File}, elements::Rect, OptData, LUA};
 
+/// The units of `seek --end`, the largest ones, telling a previewer to go to the end,
+/// which is `ya.SEEK_END` in Lua.
+pub const SEEK_END: i16 = i16::MAX;
+
 pub fn seek_sync(cmd: &Cmd, file: yazi_shared::fs::File, units: i16) {
 	let data = OptData {
 		cb: Some(Box::new(move |_, plugin| {
@@ -0,0 +1,61 @@
+use mlua::{ExternalError, ExternalResult, HookTriggers, Table, TableExt, Value};
+use tokio::{runtime::Handle, select};
+use tokio_util::sync::CancellationToken;
+
+use super::slim_lua;
+use crate::{bindings::{Cast, File}, LOADED};
+
+/// Calls the `spot` of the plugin for the rows of its tab in the spot, each one a
+/// `{ key = "Camera", value = "X100V" }`, with the title of the tab, the `title` of the plugin
+/// if it has one.
+///
+/// It's stopped once the token is cancelled, even if it's hung in a loop of its own.
+pub async fn spot(
+	name: &str,
+	file: yazi_shared::fs::File,
+	ct: CancellationToken,
+) -> mlua::Result<(Option<String>, Vec<(String, String)>)> {
+	LOADED.ensure(name).await.into_lua_err()?;
+
+	let name = name.to_owned();
+	tokio::task::spawn_blocking(move || {
+		let lua = slim_lua()?;
+		let ct1 = ct.clone();
+		lua.set_hook(
+			HookTriggers::new().on_calls().on_returns().every_nth_instruction(2000),
+			move |_, _| {
+				if ct1.is_cancelled() { Err("Spot task cancelled".into_lua_err()) } else { Ok(()) }
+			},
+		);
+
+		let plugin: Table = if let Some(b) = LOADED.read().get(&name) {
+			lua.load(b).call(())?
+		} else {
+			return Err("unloaded plugin".into_lua_err());
+		};
+
+		plugin.set("file", File::cast(&lua, file)?)?;
+		let rows: Table = Handle::current().block_on(async {
+			select! {
+				_ = ct.cancelled() => Err("Spot task cancelled".into_lua_err()),
+				r = plugin.call_async_method("spot", ()) => r,
+			}
+		})?;
+
+		let rows = rows
+			.sequence_values::<Table>()
+			.map(|row| {
+				let row = row?;
+				Ok((row.raw_get::<_, String>("key")?, row.raw_get::<_, String>("value")?))
+			})
+			.collect::<mlua::Result<_>>()?;
+
+		let title = match plugin.raw_get("title")? {
+			Value::String(s) => Some(s.to_str()?.to_owned()),
+			_ => None,
+		};
+		Ok((title, rows))
+	})
+	.await
+	.into_lua_err()?
+}
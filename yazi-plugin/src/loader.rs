@@ -26,6 +26,7 @@ impl Loader {
 		let b = fs::read(path).await.map(|v| v.into()).or_else(|_| {
 			Ok(Cow::from(match name {
 				"archive" => include_bytes!("../preset/plugins/archive.lua") as &[u8],
+				"breadcrumb" => include_bytes!("../preset/plugins/breadcrumb.lua"),
 				"code" => include_bytes!("../preset/plugins/code.lua"),
 				"file" => include_bytes!("../preset/plugins/file.lua"),
 				"folder" => include_bytes!("../preset/plugins/folder.lua"),
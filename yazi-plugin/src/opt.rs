@@ -4,6 +4,9 @@ use yazi_shared::event::Cmd;
 
 use crate::ValueSendable;
 
+pub type OptInit = Box<dyn FnOnce(&Lua) -> mlua::Result<()> + Send>;
+pub type OptCallback = Box<dyn FnOnce(&Lua, Table) -> mlua::Result<()> + Send>;
+
 pub struct Opt {
 	pub name: String,
 	pub sync: bool,
@@ -13,8 +16,8 @@ pub struct Opt {
 #[derive(Default)]
 pub struct OptData {
 	pub args: Vec<ValueSendable>,
-	pub init: Option<Box<dyn FnOnce(&Lua) -> mlua::Result<()> + Send>>,
-	pub cb:   Option<Box<dyn FnOnce(&Lua, Table) -> mlua::Result<()> + Send>>,
+	pub init: Option<OptInit>,
+	pub cb:   Option<OptCallback>,
 }
 
 impl TryFrom<Cmd> for Opt {
@@ -19,6 +19,7 @@ pub fn init() {
 		crate::bindings::Cha::register(lua)?;
 		crate::bindings::File::register(lua)?;
 		crate::bindings::Icon::register(lua)?;
+		crate::bindings::MouseEvent::register(lua)?;
 		crate::elements::pour(lua)?;
 		crate::url::pour(lua)?;
 
@@ -52,3 +53,45 @@ package.path = BOOT.plugin_dir .. "/?.yazi/init.lua;" .. package.path
 	stage_2(&lua);
 	LUA.init(lua);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn format(format: &str) -> String {
+		let lua = Lua::new();
+		let ui = lua.create_table().unwrap();
+		crate::elements::Span::install(&lua, &ui).unwrap();
+		lua.globals().set("ui", ui).unwrap();
+		lua.load(include_str!("../preset/components/folder.lua")).exec().unwrap();
+
+		// A file named "a.txt" with the "git" field fetched, and the "tag" one not yet
+		let code = r#"
+			Folder.fields.ext = function(file, spec)
+				return (spec or ".") .. file.name:match("%.(%w+)$")
+			end
+			local file = { name = "a.txt" }
+			function file:field(name)
+				return ({ git = "M", tag = "" })[name]
+			end
+			return Folder:format(..., file)
+		"#;
+		lua.load(code).call(format).unwrap()
+	}
+
+	#[test]
+	fn test_format() {
+		assert_eq!(format("{name}|{ext}|{ext:*}"), "a.txt|.txt|*txt");
+		assert_eq!(
+			format("[{name:7}][{name:<7}][{name:>7}][{name:^8}]"),
+			"[a.txt  ][a.txt  ][  a.txt][ a.txt  ]"
+		);
+		assert_eq!(format("[{name:3}]"), "[a.txt]");
+	}
+
+	#[test]
+	fn test_format_fetched() {
+		assert_eq!(format("{git} {git:>3}|{tag:2}|"), "M   M|  |");
+		assert_eq!(format("{nope} {nope:>3} {name"), "{nope} {nope:>3} {name");
+	}
+}
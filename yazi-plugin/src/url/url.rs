@@ -13,6 +13,7 @@ impl Url {
 			reg.add_field_method_get("is_regular", |_, me| Ok(me.is_regular()));
 			reg.add_field_method_get("is_search", |_, me| Ok(me.is_search()));
 			reg.add_field_method_get("is_archive", |_, me| Ok(me.is_archive()));
+			reg.add_field_method_get("is_recent", |_, me| Ok(me.is_recent()));
 
 			reg.add_method("name", |lua, me, ()| {
 				me.file_name().map(|s| lua.create_string(s.as_encoded_bytes())).transpose()
@@ -52,5 +53,5 @@ impl Url {
 }
 
 impl<T: Into<yazi_shared::fs::Url>> Cast<T> for Url {
-	fn cast(lua: &Lua, data: T) -> mlua::Result<AnyUserData> { lua.create_any_userdata(data.into()) }
+	fn cast(lua: &Lua, data: T) -> mlua::Result<AnyUserData<'_>> { lua.create_any_userdata(data.into()) }
 }
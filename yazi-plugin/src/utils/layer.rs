@@ -34,9 +34,10 @@ impl Utils {
 				for (i, cand) in t.get::<_, Table>("cands")?.sequence_values::<Table>().enumerate() {
 					let cand = cand?;
 					cands.push(Control {
-						on:   Self::parse_keys(cand.get("on")?)?,
-						exec: vec![Cmd::args("callback", vec![i.to_string()]).with_data(tx.clone())],
-						desc: cand.get("desc").ok(),
+						on:    Self::parse_keys(cand.get("on")?)?,
+						exec:  vec![Cmd::args("callback", vec![i.to_string()]).with_data(tx.clone())],
+						desc:  cand.get("desc").ok(),
+						group: None,
 					});
 				}
 
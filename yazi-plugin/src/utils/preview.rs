@@ -2,7 +2,7 @@ use mlua::{AnyUserData, IntoLuaMulti, Lua, Table, Value};
 use yazi_shared::{emit, event::Cmd, Layer, PeekError};
 
 use super::Utils;
-use crate::{bindings::{FileRef, Window}, cast_to_renderable, elements::{Paragraph, RectRef, Renderable}, external::{self, Highlighter}};
+use crate::{bindings::{FileRef, Window}, cast_to_renderable, elements::{Paragraph, RectRef, Renderable}, external::{self, Highlighter}, isolate};
 
 pub struct PreviewLock {
 	pub url: yazi_shared::fs::Url,
@@ -30,6 +30,8 @@ impl<'a> TryFrom<Table<'a>> for PreviewLock {
 
 impl Utils {
 	pub(super) fn preview(lua: &Lua, ya: &Table) -> mlua::Result<()> {
+		ya.set("SEEK_END", isolate::SEEK_END)?;
+
 		ya.set(
 			"preview_code",
 			lua.create_async_function(|lua, t: Table| async move {
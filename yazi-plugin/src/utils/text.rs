@@ -2,7 +2,8 @@ use std::ops::ControlFlow;
 
 use mlua::{Lua, Table};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
-use yazi_shared::mime_valid;
+use yazi_config::LOCALE;
+use yazi_shared::{fs::readable_size, mime_valid};
 
 use super::Utils;
 
@@ -41,6 +42,25 @@ impl Utils {
 			})?,
 		)?;
 
+		ya.set(
+			"tr",
+			lua.create_function(|_, (msgid, vars): (mlua::String, Option<Table>)| {
+				let msgid = msgid.to_str()?;
+				let Some(vars) = vars else {
+					return Ok(LOCALE.get(msgid).to_owned());
+				};
+
+				let vars = vars.pairs::<String, String>().collect::<mlua::Result<Vec<_>>>()?;
+				let vars: Vec<_> = vars.iter().map(|(k, v)| (k.as_str(), v.clone())).collect();
+				Ok(LOCALE.format(msgid, &vars))
+			})?,
+		)?;
+
+		ya.set(
+			"readable_size",
+			lua.create_function(|_, size: f64| Ok(readable_size(size.max(0.0) as u64)))?,
+		)?;
+
 		ya.set(
 			"mime_valid",
 			lua.create_function(|_, mime: mlua::String| Ok(mime_valid(mime.as_bytes())))?,
@@ -1,6 +1,7 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use mlua::{ExternalError, Lua, Table};
+use yazi_config::format_time;
 
 use super::Utils;
 
@@ -13,6 +14,14 @@ impl Utils {
 			})?,
 		)?;
 
+		ya.set(
+			"format_time",
+			lua.create_function(|_, (secs, format): (f64, mlua::String)| {
+				let time = UNIX_EPOCH + Duration::from_secs_f64(secs.max(0.0));
+				Ok(format_time(time, format.to_str()?))
+			})?,
+		)?;
+
 		ya.set(
 			"sleep",
 			lua.create_async_function(|_, secs: f64| async move {
@@ -0,0 +1,25 @@
+use yazi_plugin::external::Checksum;
+use yazi_shared::fs::Url;
+
+#[derive(Debug)]
+pub struct ChecksumEntry {
+	pub url:      Url,
+	pub name:     String,
+	pub expected: Option<String>,
+	pub actual:   Result<String, String>,
+}
+
+impl ChecksumEntry {
+	/// Whether the checksum matches the expected one, if verifying against a sums file.
+	#[inline]
+	pub fn passed(&self) -> Option<bool> {
+		self.expected.as_ref().map(|e| self.actual.as_ref().is_ok_and(|a| a == e))
+	}
+}
+
+#[derive(Debug)]
+pub struct ChecksumReport {
+	pub algo:    Checksum,
+	pub entries: Vec<ChecksumEntry>,
+	pub copy:    bool,
+}
@@ -0,0 +1,19 @@
+use std::{fmt::Debug, path::Path};
+
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+/// The rules of what's left out of a paste, e.g. the `node_modules` of a project.
+pub trait Exclude: Debug + Send + Sync {
+	/// Whether the entry found in the source tree is excluded, along with all under it.
+	fn excluded<'a>(&'a self, path: &'a Path, is_dir: bool) -> BoxFuture<'a, bool>;
+
+	/// The rules as they were given, for a paste resumed on the next launch to leave out the same.
+	fn rules(&self) -> ExcludeRules;
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ExcludeRules {
+	pub patterns:  Vec<String>,
+	pub gitignore: bool,
+}
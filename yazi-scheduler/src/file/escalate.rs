@@ -0,0 +1,147 @@
+use std::{ffi::OsString, process::Stdio};
+
+use anyhow::{bail, Result};
+use tokio::{fs, io::{AsyncBufReadExt, BufReader}, process::Command, select};
+use yazi_config::tr;
+use yazi_shared::{fs::calculate_size, in_gui_session, in_path};
+
+use super::{File, FileOp};
+use crate::{Scheduler, TaskProg, BLOCKER};
+
+// Does each of the operations in its arguments, `cp from to`, `mv from to` or `rm target`,
+// telling on a line of its own how it went, for the progress to be reported one by one.
+const HELPER: &str = r#"
+while [ $# -gt 0 ]; do
+	case "$1" in
+		cp) cp -R -- "$2" "$3"; s=$?; shift 3 ;;
+		mv) mv -f -- "$2" "$3"; s=$?; shift 3 ;;
+		rm) rm -f -- "$2"; s=$?; shift 2 ;;
+		*) exit 2 ;;
+	esac
+	[ $s -eq 0 ] && echo ok || echo failed
+done
+"#;
+
+/// The program to run the helper as root with, `pkexec` if there's a graphical session
+/// for its authentication agent to show up in, or `sudo` asking in the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Elevator {
+	Pkexec,
+	Sudo,
+}
+
+impl Elevator {
+	fn detect() -> Option<Self> {
+		if cfg!(windows) {
+			None
+		} else if in_gui_session() && in_path("pkexec") {
+			Some(Self::Pkexec)
+		} else if in_path("sudo") {
+			Some(Self::Sudo)
+		} else {
+			None
+		}
+	}
+
+	// Asks for the password with the terminal given back, for the helper not to have to
+	async fn authenticate(self) -> Result<()> {
+		if self == Self::Pkexec {
+			return Ok(());
+		}
+
+		let _guard = BLOCKER.acquire().await.unwrap();
+		Scheduler::app_stop().await;
+		let status = Command::new("sudo").arg("-v").status().await;
+		Scheduler::app_resume();
+
+		if !status?.success() {
+			bail!("Failed to authenticate with sudo");
+		}
+		Ok(())
+	}
+
+	fn command(self) -> Command {
+		let mut cmd = match self {
+			Self::Pkexec => Command::new("pkexec"),
+			Self::Sudo => {
+				let mut cmd = Command::new("sudo");
+				cmd.arg("-n");
+				cmd
+			}
+		};
+		cmd.args(["sh", "-c", HELPER, "sh"]);
+		cmd
+	}
+}
+
+impl File {
+	/// Retries the operations denied with a permission error as root, in a helper
+	/// that does just those, with the progress of the task reported as it goes.
+	pub async fn escalate(&self, id: usize, ops: Vec<FileOp>) -> Result<()> {
+		let Some(elevator) = Elevator::detect() else {
+			return self.fail(id, tr!("Neither pkexec nor sudo is available to retry as root").to_owned());
+		};
+		if let Err(e) = elevator.authenticate().await {
+			return self.fail(id, e.to_string());
+		}
+
+		let mut args: Vec<OsString> = vec![];
+		let mut sizes = Vec::with_capacity(ops.len());
+		for op in &ops {
+			match op {
+				FileOp::Paste(task) => {
+					args.push(if task.cut { "mv" } else { "cp" }.into());
+					args.push(task.from.as_os_str().into());
+					args.push(task.to.as_os_str().into());
+					sizes.push(match fs::symlink_metadata(&task.from).await {
+						Ok(m) if m.is_dir() => calculate_size(&task.from).await,
+						Ok(m) => m.len(),
+						Err(_) => 0,
+					});
+				}
+				FileOp::Delete(task) => {
+					args.extend(["rm".into(), task.target.as_os_str().into()]);
+					sizes.push(task.length);
+				}
+				_ => bail!("Cannot retry {op:?} as root"),
+			}
+		}
+
+		self.log(id, format!("Retrying {} operation(s) as root with {elevator:?}", ops.len()))?;
+		let mut child = elevator
+			.command()
+			.args(args)
+			.stdin(Stdio::null())
+			.stdout(Stdio::piped())
+			.stderr(Stdio::piped())
+			.kill_on_drop(true)
+			.spawn()?;
+
+		let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
+		let mut stderr = BufReader::new(child.stderr.take().unwrap()).lines();
+		let mut done = ops.iter().zip(sizes);
+		loop {
+			select! {
+				Ok(Some(line)) = stdout.next_line() => {
+					let Some((op, size)) = done.next() else { break };
+					if line == "ok" {
+						self.prog.send(TaskProg::Adv(id, 1, size))?;
+					} else {
+						self.fail(id, tr!("Failed to retry as root: {op}", op = format!("{op:?}")))?;
+					}
+				}
+				Ok(Some(line)) = stderr.next_line() => {
+					self.log(id, line)?;
+				}
+				else => break,
+			}
+		}
+
+		// What's left is never done, e.g. the authentication was dismissed
+		let status = child.wait().await?;
+		if done.len() > 0 {
+			self.fail(id, tr!("The helper exited with {status} before finishing", status = status))?;
+		}
+		Ok(())
+	}
+}
@@ -1,42 +1,58 @@
-use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::{Path, PathBuf}};
+use std::{borrow::Cow, collections::VecDeque, fs::Metadata, path::Path, sync::Arc, time::Duration};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use futures::{future::BoxFuture, FutureExt};
-use tokio::{fs, io::{self, ErrorKind::{AlreadyExists, NotFound}}, sync::mpsc};
+use parking_lot::Mutex;
+use tokio::{fs, io::{self, ErrorKind::{AlreadyExists, NotFound, PermissionDenied}}, select, sync::mpsc};
 use tracing::warn;
-use yazi_config::TASKS;
-use yazi_shared::fs::{calculate_size, copy_with_progress, path_relative_to, Url};
+use yazi_config::{tr, TASKS};
+use yazi_shared::fs::{calculate_size, copy_with_progress, extended_path, path_relative_to, rename_file, same_file, unextended_path, Url};
 
-use super::{FileOp, FileOpDelete, FileOpLink, FileOpPaste, FileOpTrash};
-use crate::{TaskOp, TaskProg, LOW, NORMAL};
+use super::{FileOp, FileOpChecksum, FileOpChmod, FileOpDelete, FileOpLink, FileOpPaste, FileOpTrash};
+use crate::{ChecksumEntry, Exclude, Running, TaskOp, TaskProg, HIGH};
+
+// How often the size left of a tree being trashed is checked
+const TRASH_PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
 
 pub struct File {
-	macro_: async_priority_channel::Sender<TaskOp, u8>,
-	prog:   mpsc::UnboundedSender<TaskProg>,
+	macro_:          async_priority_channel::Sender<TaskOp, u8>,
+	pub(super) prog: mpsc::UnboundedSender<TaskProg>,
+	running:         Arc<Mutex<Running>>,
 }
 
 impl File {
 	pub fn new(
 		macro_: async_priority_channel::Sender<TaskOp, u8>,
 		prog: mpsc::UnboundedSender<TaskProg>,
+		running: Arc<Mutex<Running>>,
 	) -> Self {
-		Self { macro_, prog }
+		Self { macro_, prog, running }
+	}
+
+	/// Works on the operation, which is given back with the error if it fails, to be retried as
+	/// it was without a copy of each one being made beforehand.
+	pub async fn work(&self, mut op: FileOp) -> Result<(), (anyhow::Error, FileOp)> {
+		match self.work_on(&mut op).await {
+			Ok(()) => Ok(()),
+			Err(e) => Err((e, op)),
+		}
 	}
 
-	pub async fn work(&self, op: FileOp) -> Result<()> {
+	async fn work_on(&self, op: &mut FileOp) -> Result<()> {
 		match op {
-			FileOp::Paste(mut task) => {
-				match fs::remove_file(&task.to).await {
+			FileOp::Paste(task) => {
+				match fs::remove_file(task.to.extended()).await {
+					Err(e) if e.kind() == PermissionDenied => return self.denied(FileOp::Paste(task.clone())),
 					Err(e) if e.kind() != NotFound => Err(e)?,
 					_ => {}
 				}
 
-				let mut it = copy_with_progress(&task.from, &task.to);
+				let mut it = copy_with_progress(&task.from.extended(), &task.to.extended());
 				while let Some(res) = it.recv().await {
 					match res {
 						Ok(0) => {
 							if task.cut {
-								fs::remove_file(&task.from).await.ok();
+								fs::remove_file(task.from.extended()).await.ok();
 							}
 							break;
 						}
@@ -53,7 +69,10 @@ impl File {
 						{
 							self.log(task.id, format!("Paste task retry: {:?}", task))?;
 							task.retry += 1;
-							return Ok(self.macro_.send(FileOp::Paste(task).into(), LOW).await?);
+							return self.queue(FileOp::Paste(task.clone())).await;
+						}
+						Err(e) if e.kind() == PermissionDenied => {
+							return self.denied(FileOp::Paste(task.clone()));
 						}
 						Err(e) => Err(e)?,
 					}
@@ -64,7 +83,7 @@ impl File {
 				let meta = task.meta.as_ref().unwrap();
 
 				let src = if task.resolve {
-					match fs::read_link(&task.from).await {
+					match fs::read_link(task.from.extended()).await {
 						Ok(p) => Cow::Owned(p),
 						Err(e) if e.kind() == NotFound => {
 							self.log(task.id, format!("Link task partially done: {:?}", task))?;
@@ -77,55 +96,114 @@ impl File {
 				};
 
 				let src = if task.relative {
-					path_relative_to(&src, &fs::canonicalize(task.to.parent().unwrap()).await?)
+					let dir = fs::canonicalize(extended_path(task.to.parent().unwrap())).await?;
+					path_relative_to(&src, &unextended_path(&dir))
 				} else {
 					src
 				};
 
-				match fs::remove_file(&task.to).await {
+				match fs::remove_file(task.to.extended()).await {
 					Err(e) if e.kind() != NotFound => Err(e)?,
 					_ => {
 						#[cfg(unix)]
 						{
-							fs::symlink(src, &task.to).await?
+							fs::symlink(src, task.to.extended()).await?
 						}
 						#[cfg(windows)]
 						{
 							if meta.is_dir() {
-								fs::symlink_dir(src, &task.to).await?
+								fs::symlink_dir(src, task.to.extended()).await?
 							} else {
-								fs::symlink_file(src, &task.to).await?
+								fs::symlink_file(src, task.to.extended()).await?
 							}
 						}
 					}
 				}
 
 				if task.delete {
-					fs::remove_file(&task.from).await.ok();
+					fs::remove_file(task.from.extended()).await.ok();
 				}
 				self.prog.send(TaskProg::Adv(task.id, 1, meta.len()))?;
 			}
 			FileOp::Delete(task) => {
-				if let Err(e) = fs::remove_file(&task.target).await {
-					if e.kind() != NotFound && fs::symlink_metadata(&task.target).await.is_ok() {
-						self.fail(task.id, format!("Delete task failed: {:?}, {e}", task))?;
-						Err(e)?
+				if let Err(e) = fs::remove_file(task.target.extended()).await {
+					if e.kind() == PermissionDenied {
+						return self.denied(FileOp::Delete(task.clone()));
+					}
+					if e.kind() != NotFound && fs::symlink_metadata(task.target.extended()).await.is_ok() {
+						bail!("Delete task failed: {:?}, {e}", task);
 					}
 				}
 				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?
 			}
 			FileOp::Trash(task) => {
-				#[cfg(target_os = "macos")]
+				// It blocks till the whole tree is moved, which can take long on Windows,
+				// so what's gone of it is told by the size left of it meanwhile
+				let target = task.target.clone();
+				let mut handle = tokio::task::spawn_blocking(move || {
+					#[cfg(target_os = "macos")]
+					{
+						use trash::{macos::{DeleteMethod, TrashContextExtMacos}, TrashContext};
+						let mut ctx = TrashContext::default();
+						ctx.set_delete_method(DeleteMethod::NsFileManager);
+						ctx.delete(&target)?;
+					}
+					#[cfg(all(not(target_os = "macos"), not(target_os = "android")))]
+					{
+						trash::delete(&target)?;
+					}
+					Ok::<_, anyhow::Error>(())
+				});
+
+				let mut gone = 0;
+				let mut interval = tokio::time::interval(TRASH_PROGRESS_INTERVAL);
+				interval.tick().await;
+				let result = loop {
+					select! {
+						result = &mut handle => break result,
+						_ = interval.tick() => {
+							let left = calculate_size(&task.target.extended()).await;
+							let now = task.length.saturating_sub(left);
+							if now > gone {
+								self.prog.send(TaskProg::Adv(task.id, 0, now - gone))?;
+								gone = now;
+							}
+						}
+					}
+				};
+				result??;
+				self.prog.send(TaskProg::Adv(task.id, 1, task.length.saturating_sub(gone)))?;
+			}
+			FileOp::Chmod(task) => {
+				#[cfg(unix)]
 				{
-					use trash::{macos::{DeleteMethod, TrashContextExtMacos}, TrashContext};
-					let mut ctx = TrashContext::default();
-					ctx.set_delete_method(DeleteMethod::NsFileManager);
-					ctx.delete(&task.target)?;
+					use std::os::unix::fs::PermissionsExt;
+					let mode = fs::metadata(task.target.extended()).await?.permissions().mode();
+					let mode = (mode & !task.mask | task.mode & task.mask) & 0o7777;
+					if let Err(e) =
+						fs::set_permissions(task.target.extended(), std::fs::Permissions::from_mode(mode)).await
+					{
+						bail!("Chmod task failed: {:?}, {e}", task);
+					}
 				}
-				#[cfg(all(not(target_os = "macos"), not(target_os = "android")))]
-				{
-					trash::delete(&task.target)?;
+				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
+			}
+			FileOp::Checksum(task) => {
+				let actual = task.algo.digest(&task.target).await.map_err(|e| e.to_string());
+				let entry = ChecksumEntry {
+					url: task.target.clone(),
+					name: task.name.clone(),
+					expected: task.expected.clone(),
+					actual,
+				};
+
+				match (&entry.actual, entry.passed()) {
+					(Err(e), _) => self.log(task.id, format!("{}: {e}", entry.name))?,
+					(_, Some(false)) => self.log(task.id, format!("{}: FAILED", entry.name))?,
+					_ => {}
 				}
+
+				task.entries.lock()[task.index] = Some(entry);
 				self.prog.send(TaskProg::Adv(task.id, 1, task.length))?;
 			}
 		}
@@ -133,8 +211,18 @@ impl File {
 	}
 
 	pub async fn paste(&self, mut task: FileOpPaste) -> Result<()> {
-		if task.cut {
-			match fs::rename(&task.from, &task.to).await {
+		// The same file by another case of its name on a case-insensitive filesystem,
+		// which would be removed as the destination to overwrite
+		if task.from != task.to && same_file(&task.from.extended(), &task.to.extended()).await {
+			if task.cut {
+				rename_file(&task.from.extended(), &task.to.extended()).await?;
+			}
+			return self.succ(task.id);
+		}
+
+		// Moved as a whole, unless some are to be left behind
+		if task.cut && task.exclude.is_none() {
+			match fs::rename(task.from.extended(), task.to.extended()).await {
 				Ok(_) => return self.succ(task.id),
 				Err(e) if e.kind() == NotFound => return self.succ(task.id),
 				_ => {}
@@ -147,85 +235,121 @@ impl File {
 			self.prog.send(TaskProg::New(id, meta.len()))?;
 
 			if meta.is_file() {
-				self.macro_.send(FileOp::Paste(task).into(), LOW).await?;
+				self.queue(FileOp::Paste(task)).await?;
 			} else if meta.is_symlink() {
-				self.macro_.send(FileOp::Link(task.to_link(meta)).into(), NORMAL).await?;
+				self.queue(FileOp::Link(task.to_link(meta))).await?;
 			}
 			return self.succ(id);
 		}
 
+		// Failed as a whole, for the entry from `$from` to `$to` to be retried as one
 		macro_rules! continue_unless_ok {
-			($result:expr) => {
+			($result:expr, $from:expr, $to:expr) => {
 				match $result {
 					Ok(v) => v,
 					Err(e) => {
+						let failed = FileOpPaste { from: $from.clone(), to: $to.clone(), ..task.clone() };
 						self.prog.send(TaskProg::New(task.id, 0))?;
-						self.fail(task.id, format!("An error occurred while pasting: {e}"))?;
+						self.fail(task.id, tr!("An error occurred while pasting: {error}", error = e))?;
+						self.prog.send(TaskProg::Failed(task.id, Box::new(FileOp::Paste(failed))))?;
 						continue;
 					}
 				}
 			};
 		}
 
-		let root = task.to.clone();
-		let skip = task.from.components().count();
-		let mut dirs = VecDeque::from([task.from]);
+		if TASKS.paste_prescan > 0 {
+			let (follow, exclude) = (task.follow, task.exclude.as_deref());
+			if let Some(size) = Self::prescan(&task.from, follow, exclude, TASKS.paste_prescan).await {
+				self.prog.send(TaskProg::Plan(task.id, size))?;
+			}
+		}
+
+		// Taken relative to where it's from by the path, not a count of the components,
+		// as a UNC prefix like `\\server\share` makes one component of two names
+		let (base, root) = (task.from.clone(), task.to.clone());
+		let mut dirs = VecDeque::from([task.from.clone()]);
+		let mut skipped = 0;
 
 		while let Some(src) = dirs.pop_front() {
-			let dest = root.join(src.components().skip(skip).collect::<PathBuf>());
-			continue_unless_ok!(match fs::create_dir(&dest).await {
+			let dest = root.join(src.strip_prefix(&base).unwrap_or(Path::new("")));
+			let created = match fs::create_dir(dest.extended()).await {
+				// Retried as a whole as root, being where we can't write to
+				Err(e) if e.kind() == PermissionDenied => {
+					self.prog.send(TaskProg::New(task.id, 0))?;
+					let (id, cut, follow, exclude) = (task.id, task.cut, task.follow, task.exclude.clone());
+					let task = FileOpPaste { id, from: src, to: dest, cut, follow, retry: 0, exclude };
+					self.denied(FileOp::Paste(task))?;
+					continue;
+				}
 				Err(e) if e.kind() != AlreadyExists => Err(e),
 				_ => Ok(()),
-			});
+			};
+			continue_unless_ok!(created, src, dest);
 
-			let mut it = continue_unless_ok!(fs::read_dir(&src).await);
+			let mut it = continue_unless_ok!(fs::read_dir(src.extended()).await, src, dest);
 			while let Ok(Some(entry)) = it.next_entry().await {
 				let src = Url::from(entry.path());
-				let meta = continue_unless_ok!(Self::metadata(&src, task.follow).await);
+				let to = dest.join(src.file_name().unwrap());
+				let meta = continue_unless_ok!(Self::metadata(&src, task.follow).await, src, to);
+
+				if let Some(exclude) = &task.exclude {
+					if exclude.excluded(&src, meta.is_dir()).await {
+						skipped += 1;
+						continue;
+					}
+				}
 
 				if meta.is_dir() {
 					dirs.push_back(src);
 					continue;
 				}
 
-				task.to = dest.join(src.file_name().unwrap());
+				task.to = to;
 				task.from = src;
 				self.prog.send(TaskProg::New(task.id, meta.len()))?;
 
 				if meta.is_file() {
-					self.macro_.send(FileOp::Paste(task.clone()).into(), LOW).await?;
+					self.queue(FileOp::Paste(task.clone())).await?;
 				} else if meta.is_symlink() {
-					self.macro_.send(FileOp::Link(task.to_link(meta)).into(), NORMAL).await?;
+					self.queue(FileOp::Link(task.to_link(meta))).await?;
 				}
 			}
 		}
+		if skipped > 0 {
+			self.prog.send(TaskProg::Skip(task.id, skipped))?;
+		}
 		self.succ(task.id)
 	}
 
 	pub async fn link(&self, mut task: FileOpLink) -> Result<()> {
 		let id = task.id;
+		if same_file(&task.from.extended(), &task.to.extended()).await {
+			self.prog.send(TaskProg::New(id, 0))?;
+			return self.fail(id, tr!("Cannot link {path} to itself", path = format!("{:?}", task.from)));
+		}
 		if task.meta.is_none() {
-			task.meta = Some(fs::symlink_metadata(&task.from).await?);
+			task.meta = Some(fs::symlink_metadata(task.from.extended()).await?);
 		}
 
 		self.prog.send(TaskProg::New(id, task.meta.as_ref().unwrap().len()))?;
-		self.macro_.send(FileOp::Link(task).into(), NORMAL).await?;
+		self.queue(FileOp::Link(task)).await?;
 		self.succ(id)
 	}
 
 	pub async fn delete(&self, mut task: FileOpDelete) -> Result<()> {
-		let meta = fs::symlink_metadata(&task.target).await?;
+		let meta = fs::symlink_metadata(task.target.extended()).await?;
 		if !meta.is_dir() {
 			let id = task.id;
 			task.length = meta.len();
 			self.prog.send(TaskProg::New(id, meta.len()))?;
-			self.macro_.send(FileOp::Delete(task).into(), NORMAL).await?;
+			self.queue(FileOp::Delete(task)).await?;
 			return self.succ(id);
 		}
 
 		let mut dirs = VecDeque::from([task.target]);
 		while let Some(target) = dirs.pop_front() {
-			let mut it = match fs::read_dir(target).await {
+			let mut it = match fs::read_dir(target.extended()).await {
 				Ok(it) => it,
 				Err(_) => continue,
 			};
@@ -244,7 +368,7 @@ impl File {
 				task.target = Url::from(entry.path());
 				task.length = meta.len();
 				self.prog.send(TaskProg::New(task.id, meta.len()))?;
-				self.macro_.send(FileOp::Delete(task.clone()).into(), NORMAL).await?;
+				self.queue(FileOp::Delete(task.clone())).await?;
 			}
 		}
 		self.succ(task.id)
@@ -252,25 +376,108 @@ impl File {
 
 	pub async fn trash(&self, mut task: FileOpTrash) -> Result<()> {
 		let id = task.id;
-		task.length = calculate_size(&task.target).await;
+		task.length = calculate_size(&task.target.extended()).await;
 
 		self.prog.send(TaskProg::New(id, task.length))?;
-		self.macro_.send(FileOp::Trash(task).into(), LOW).await?;
+		self.queue(FileOp::Trash(task)).await?;
 		self.succ(id)
 	}
 
+	pub async fn chmod(&self, task: FileOpChmod, recursive: bool) -> Result<()> {
+		let id = task.id;
+		let meta = fs::metadata(task.target.extended()).await?;
+
+		self.prog.send(TaskProg::New(id, 0))?;
+		self.queue(FileOp::Chmod(task.clone())).await?;
+		if !recursive || !meta.is_dir() {
+			return self.succ(id);
+		}
+
+		let mut dirs = VecDeque::from([task.target.clone()]);
+		while let Some(target) = dirs.pop_front() {
+			let mut it = match fs::read_dir(target.extended()).await {
+				Ok(it) => it,
+				Err(_) => continue,
+			};
+
+			while let Ok(Some(entry)) = it.next_entry().await {
+				// Following a symlink could change files outside of the directory
+				let Ok(ft) = entry.file_type().await else { continue };
+				if ft.is_symlink() {
+					continue;
+				} else if ft.is_dir() {
+					dirs.push_back(Url::from(entry.path()));
+				}
+
+				self.prog.send(TaskProg::New(id, 0))?;
+				let task = FileOpChmod { target: Url::from(entry.path()), ..task.clone() };
+				self.queue(FileOp::Chmod(task)).await?;
+			}
+		}
+		self.succ(id)
+	}
+
+	pub async fn checksum(&self, tasks: Vec<FileOpChecksum>) -> Result<()> {
+		let Some(id) = tasks.first().map(|t| t.id) else {
+			return Ok(());
+		};
+
+		for mut task in tasks {
+			task.length = fs::metadata(task.target.extended()).await.map(|m| m.len()).unwrap_or(0);
+			self.prog.send(TaskProg::New(id, task.length))?;
+			self.queue(FileOp::Checksum(task)).await?;
+		}
+		self.succ(id)
+	}
+
+	// The size of the files under `dir`, for the progress of a paste to have its total
+	// from the start, or `None` if there're more than `cap` entries to walk.
+	async fn prescan(
+		dir: &Path,
+		follow: bool,
+		exclude: Option<&dyn Exclude>,
+		cap: u32,
+	) -> Option<u64> {
+		let (mut size, mut entries) = (0, 0);
+		let mut dirs = VecDeque::from([dir.to_owned()]);
+		while let Some(dir) = dirs.pop_front() {
+			let Ok(mut it) = fs::read_dir(extended_path(&dir)).await else { continue };
+			while let Ok(Some(entry)) = it.next_entry().await {
+				entries += 1;
+				if entries > cap {
+					return None;
+				}
+
+				let path = unextended_path(&entry.path()).into_owned();
+				let Ok(meta) = Self::metadata(&path, follow).await else { continue };
+				if let Some(exclude) = exclude {
+					if exclude.excluded(&path, meta.is_dir()).await {
+						continue;
+					}
+				}
+				if meta.is_dir() {
+					dirs.push_back(path);
+				} else {
+					size += meta.len();
+				}
+			}
+		}
+		Some(size)
+	}
+
 	async fn metadata(path: &Path, follow: bool) -> io::Result<Metadata> {
+		let path = extended_path(path);
 		if !follow {
 			return fs::symlink_metadata(path).await;
 		}
 
-		let meta = fs::metadata(path).await;
+		let meta = fs::metadata(&path).await;
 		if meta.is_ok() { meta } else { fs::symlink_metadata(path).await }
 	}
 
-	pub(crate) fn remove_empty_dirs(dir: &Path) -> BoxFuture<()> {
+	pub(crate) fn remove_empty_dirs(dir: &Path) -> BoxFuture<'_, ()> {
 		async move {
-			let mut it = match fs::read_dir(dir).await {
+			let mut it = match fs::read_dir(extended_path(dir)).await {
 				Ok(it) => it,
 				Err(_) => return,
 			};
@@ -283,29 +490,63 @@ impl File {
 				}
 			}
 
-			fs::remove_dir(dir).await.ok();
+			fs::remove_dir(extended_path(dir)).await.ok();
 		}
 		.boxed()
 	}
 }
 
 impl File {
+	// Queues the operation for the workers by the priority of its type, or the high one if its task
+	// is boosted, told under the lock for a boost not to be missed while it's being queued.
+	pub(super) async fn queue(&self, op: FileOp) -> Result<()> {
+		let running = self.running.lock();
+		let boosted = running.get(op.id()).is_some_and(|t| t.boosted);
+		let prio = if boosted { HIGH } else { Self::prio(&op) };
+		if self.macro_.try_send(op.into(), prio).is_err() {
+			bail!("The queue of the workers is closed");
+		}
+		Ok(())
+	}
+
+	/// The priority of the operation's type.
+	pub(crate) fn prio(op: &FileOp) -> u8 {
+		let prio = match op {
+			FileOp::Paste(_) => TASKS.priority_paste,
+			FileOp::Link(_) => TASKS.priority_link,
+			FileOp::Delete(_) => TASKS.priority_delete,
+			FileOp::Trash(_) => TASKS.priority_trash,
+			FileOp::Chmod(_) => TASKS.priority_chmod,
+			FileOp::Checksum(_) => TASKS.priority_checksum,
+		};
+		prio as u8
+	}
+
+	/// Puts an operation taken out of the queue back in, at the given priority.
+	#[inline]
+	pub(crate) fn requeue(&self, op: TaskOp, prio: u8) { self.macro_.try_send(op, prio).ok(); }
+
 	#[inline]
-	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
+	pub(super) fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
 
 	#[inline]
-	fn fail(&self, id: usize, reason: String) -> Result<()> {
+	pub(super) fn fail(&self, id: usize, reason: String) -> Result<()> {
 		Ok(self.prog.send(TaskProg::Fail(id, reason))?)
 	}
 
 	#[inline]
-	fn log(&self, id: usize, line: String) -> Result<()> {
+	pub(super) fn log(&self, id: usize, line: String) -> Result<()> {
 		Ok(self.prog.send(TaskProg::Log(id, line))?)
 	}
+
+	#[inline]
+	fn denied(&self, op: FileOp) -> Result<()> {
+		Ok(self.prog.send(TaskProg::Denied(op.id(), Box::new(op)))?)
+	}
 }
 
 impl FileOpPaste {
-	fn to_link(&self, meta: Metadata) -> FileOpLink {
+	pub(super) fn to_link(&self, meta: Metadata) -> FileOpLink {
 		FileOpLink {
 			id:       self.id,
 			from:     self.from.clone(),
@@ -317,3 +558,89 @@ impl FileOpPaste {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use serde::Deserialize;
+	use yazi_config::TASKS;
+
+	use super::*;
+
+	// The operations are queued by the priorities of the preset
+	fn init() {
+		#[derive(Deserialize)]
+		struct Outer<T> {
+			tasks: T,
+		}
+		let s = include_str!("../../../yazi-config/preset/yazi.toml");
+		TASKS.init(toml::from_str::<Outer<_>>(s).unwrap().tasks);
+	}
+
+	// Runs what's queued for the workers, as they would, till there's nothing left
+	async fn drain(file: &File, rx: &async_priority_channel::Receiver<TaskOp, u8>) {
+		while let Ok((TaskOp::File(op), _)) = rx.try_recv() {
+			file.work(*op).await.unwrap();
+		}
+	}
+
+	#[tokio::test]
+	async fn test_deep_tree() {
+		init();
+		let (tx, rx) = async_priority_channel::unbounded();
+		let (prog, _prog) = mpsc::unbounded_channel();
+		let file = File::new(tx, prog, Default::default());
+
+		let root = std::env::temp_dir().join(format!("yazi-tree-{}", std::process::id()));
+		let deep = |p: &Path| (0..12).fold(p.to_owned(), |p, i| p.join(format!("{i:0>30}")));
+		let (from, to, moved) = (root.join("from"), root.join("to"), root.join("moved"));
+
+		fs::create_dir_all(extended_path(&deep(&from))).await.unwrap();
+		fs::write(extended_path(&deep(&from).join("a")), "yazi").await.unwrap();
+
+		let paste = |from: &Path, to: &Path, cut| FileOpPaste {
+			id: 0,
+			from: Url::from(from),
+			to: Url::from(to),
+			cut,
+			follow: false,
+			retry: 0,
+			exclude: None,
+		};
+		file.paste(paste(&from, &to, false)).await.unwrap();
+		drain(&file, &rx).await;
+		let (a, b) = (deep(&to).join("a"), deep(&to).join("b"));
+		assert_eq!(fs::read_to_string(extended_path(&a)).await.unwrap(), "yazi");
+
+		let link = FileOpLink {
+			id:       1,
+			from:     Url::from(&a),
+			to:       Url::from(&b),
+			meta:     None,
+			resolve:  false,
+			relative: true,
+			delete:   false,
+		};
+		file.link(link).await.unwrap();
+		drain(&file, &rx).await;
+		assert_eq!(fs::read_to_string(extended_path(&b)).await.unwrap(), "yazi");
+
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::PermissionsExt;
+			let chmod = FileOpChmod { id: 2, target: Url::from(&to), mode: 0o700, mask: 0o077 };
+			file.chmod(chmod, true).await.unwrap();
+			drain(&file, &rx).await;
+			assert_eq!(fs::metadata(&a).await.unwrap().permissions().mode() & 0o077, 0);
+		}
+
+		file.paste(paste(&to, &moved, true)).await.unwrap();
+		assert!(fs::symlink_metadata(extended_path(&to)).await.is_err());
+
+		file.delete(FileOpDelete { id: 3, target: Url::from(&moved), length: 0 }).await.unwrap();
+		drain(&file, &rx).await;
+		File::remove_empty_dirs(&moved).await;
+		assert!(fs::symlink_metadata(extended_path(&moved)).await.is_err());
+
+		fs::remove_dir_all(extended_path(&root)).await.unwrap();
+	}
+}
@@ -1,7 +1,10 @@
 #![allow(clippy::module_inception)]
 
+mod escalate;
 mod file;
 mod op;
+mod rename;
+mod retry;
 
 pub use file::*;
 pub use op::*;
@@ -1,13 +1,19 @@
-use std::fs::Metadata;
+use std::{fs::Metadata, sync::Arc};
 
+use parking_lot::Mutex;
+use yazi_plugin::external::Checksum;
 use yazi_shared::fs::Url;
 
-#[derive(Debug)]
+use crate::{ChecksumEntry, Exclude};
+
+#[derive(Clone, Debug)]
 pub enum FileOp {
 	Paste(FileOpPaste),
 	Link(FileOpLink),
 	Delete(FileOpDelete),
 	Trash(FileOpTrash),
+	Chmod(FileOpChmod),
+	Checksum(FileOpChecksum),
 }
 
 impl FileOp {
@@ -17,18 +23,33 @@ impl FileOp {
 			Self::Link(op) => op.id,
 			Self::Delete(op) => op.id,
 			Self::Trash(op) => op.id,
+			Self::Chmod(op) => op.id,
+			Self::Checksum(op) => op.id,
+		}
+	}
+
+	/// The file the operation works on, the destination of a paste or link.
+	pub fn url(&self) -> &Url {
+		match self {
+			Self::Paste(op) => &op.to,
+			Self::Link(op) => &op.to,
+			Self::Delete(op) => &op.target,
+			Self::Trash(op) => &op.target,
+			Self::Chmod(op) => &op.target,
+			Self::Checksum(op) => &op.target,
 		}
 	}
 }
 
 #[derive(Clone, Debug)]
 pub struct FileOpPaste {
-	pub id:     usize,
-	pub from:   Url,
-	pub to:     Url,
-	pub cut:    bool,
-	pub follow: bool,
-	pub retry:  u8,
+	pub id:      usize,
+	pub from:    Url,
+	pub to:      Url,
+	pub cut:     bool,
+	pub follow:  bool,
+	pub retry:   u8,
+	pub exclude: Option<Arc<dyn Exclude>>,
 }
 
 #[derive(Clone, Debug)]
@@ -55,3 +76,24 @@ pub struct FileOpTrash {
 	pub target: Url,
 	pub length: u64,
 }
+
+#[derive(Clone, Debug)]
+pub struct FileOpChmod {
+	pub id:     usize,
+	pub target: Url,
+	pub mode:   u32,
+	pub mask:   u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct FileOpChecksum {
+	pub id:       usize,
+	pub algo:     Checksum,
+	pub target:   Url,
+	pub name:     String,
+	pub expected: Option<String>,
+	pub length:   u64,
+	// Where the entry goes among those of the task, which are done in any order
+	pub index:    usize,
+	pub entries:  Arc<Mutex<Vec<Option<ChecksumEntry>>>>,
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashSet, path::{Path, PathBuf}};
+
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::tr;
+use yazi_shared::fs::{rename_file, same_file, Url};
+
+use super::File;
+use crate::TaskProg;
+
+/// The order to rename a batch of files in, so that no rename overwrites a file
+/// that's yet to be renamed itself.
+///
+/// A rename goes once its destination is freed, so a chain like `a -> b, b -> c`
+/// is done from its end, and each cycle like `a -> b, b -> a` is broken by moving
+/// one of its files aside to a temporary name first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct RenamePlan {
+	pub(super) steps:  Vec<(PathBuf, PathBuf)>,
+	pub(super) cycles: Vec<Vec<PathBuf>>,
+}
+
+impl RenamePlan {
+	pub(super) fn new(pairs: &[(PathBuf, PathBuf)]) -> Result<Self, &Path> {
+		let mut seen = HashSet::new();
+		if let Some((_, to)) = pairs.iter().find(|(_, to)| !seen.insert(to)) {
+			return Err(to);
+		}
+
+		let mut plan = Self::default();
+		let mut pending: Vec<_> = pairs.iter().filter(|(from, to)| from != to).cloned().collect();
+		while !pending.is_empty() {
+			let sources: HashSet<_> = pending.iter().map(|(from, _)| from.clone()).collect();
+			if let Some(i) = pending.iter().position(|(_, to)| !sources.contains(to)) {
+				plan.steps.push(pending.remove(i));
+				continue;
+			}
+
+			// Each of the left is waiting for another, so they're all in cycles
+			let mut cycle = vec![pending[0].0.clone()];
+			let mut next = &pending[0].1;
+			while *next != cycle[0] {
+				cycle.push(next.clone());
+				next = &pending.iter().find(|(from, _)| from == next).unwrap().1;
+			}
+			plan.cycles.push(cycle);
+
+			let (from, to) = pending.remove(0);
+			let tmp = Self::tmp(&from, plan.steps.len());
+			plan.steps.push((from, tmp.clone()));
+			pending.push((tmp, to));
+		}
+
+		Ok(plan)
+	}
+
+	#[inline]
+	fn tmp(path: &Path, n: usize) -> PathBuf {
+		let name = path.file_name().map(|s| s.to_string_lossy()).unwrap_or_default();
+		path.with_file_name(format!(".{name}.yazi-rename-{}-{n}", std::process::id()))
+	}
+}
+
+impl File {
+	/// Renames the files in the order of the [`RenamePlan`], after making sure none would
+	/// overwrite a file outside the batch, and renames them back if any of it fails.
+	///
+	/// Whether all of them are renamed, as none is left renamed otherwise.
+	pub async fn rename(&self, id: usize, pairs: Vec<(Url, Url)>) -> Result<bool> {
+		let pairs: Vec<_> =
+			pairs.into_iter().map(|(from, to)| (from.extended().into(), to.extended().into())).collect();
+		let plan = match RenamePlan::new(&pairs) {
+			Ok(plan) => plan,
+			Err(to) => {
+				let e = tr!("More than one file would be renamed to {path}", path = to.display());
+				return self.fail(id, e).map(|_| false);
+			}
+		};
+
+		self.prog.send(TaskProg::New(id, 0))?;
+		for cycle in &plan.cycles {
+			let names: Vec<_> = cycle.iter().map(|p| p.display().to_string()).collect();
+			self.log(id, format!("Cycle: {} -> {}", names.join(" -> "), names[0]))?;
+		}
+
+		// Prepare: the destinations must be free, or be freed by the batch
+		let sources: HashSet<_> = plan.steps.iter().map(|(from, _)| from).collect();
+		for (from, to) in &plan.steps {
+			if !sources.contains(to) && Self::taken(from, to).await {
+				let e = tr!("Destination already exists: {path}", path = to.display());
+				return self.fail(id, e).map(|_| false);
+			}
+		}
+
+		// Commit, keeping what's done to roll it back
+		let mut done: Vec<(&PathBuf, &PathBuf)> = Vec::with_capacity(plan.steps.len());
+		for (from, to) in &plan.steps {
+			self.log(id, tr!("Rename {from} -> {to}", from = from.display(), to = to.display()))?;
+			if let Err(e) = rename_file(from, to).await {
+				self.log(id, tr!("Failed: {error}, rolling back", error = e))?;
+				for (from, to) in done.into_iter().rev() {
+					if let Err(e) = rename_file(to, from).await {
+						// Back from where it's renamed to
+						let (from, to) = (to.display(), from.display());
+						self.log(
+							id,
+							tr!("Failed to roll back {from} -> {to}: {error}", from = from, to = to, error = e),
+						)?;
+					}
+				}
+				let e = tr!("Failed to rename {path}: {error}", path = from.display(), error = e);
+				return self.fail(id, e).map(|_| false);
+			}
+			done.push((from, to));
+		}
+
+		self.prog.send(TaskProg::Adv(id, 1, 0))?;
+		self.succ(id).map(|_| true)
+	}
+
+	// Whether the destination is another file, so that a case-only rename on
+	// a case-insensitive filesystem isn't taken as a conflict
+	#[inline]
+	async fn taken(from: &Path, to: &Path) -> bool {
+		fs::symlink_metadata(to).await.is_ok() && !same_file(from, to).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_plan() {
+		let p = |s: &str| PathBuf::from(s);
+		let pairs = [(p("a"), p("b")), (p("b"), p("c")), (p("x"), p("y")), (p("y"), p("x"))];
+		let plan = RenamePlan::new(&pairs).unwrap();
+
+		assert_eq!(plan.cycles, vec![vec![p("x"), p("y")]]);
+		let tmp = RenamePlan::tmp(&p("x"), 2);
+		assert_eq!(plan.steps, vec![
+			(p("b"), p("c")),
+			(p("a"), p("b")),
+			(p("x"), tmp.clone()),
+			(p("y"), p("x")),
+			(tmp, p("y")),
+		]);
+
+		let pairs = [(p("a"), p("c")), (p("b"), p("c"))];
+		assert_eq!(RenamePlan::new(&pairs).err(), Some(p("c").as_path()));
+	}
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use tokio::fs;
+use yazi_config::tr;
+
+use super::{File, FileOp};
+use crate::TaskProg;
+
+impl File {
+	/// Retries the failed operations of a task, each one as it was, where a directory failed
+	/// as a whole is walked again, and the rest are sent back to the workers as they are.
+	/// One that can't be retried is kept failed as it was, for another retry.
+	pub async fn retry(&self, id: usize, ops: Vec<FileOp>) -> Result<()> {
+		for op in ops {
+			if let Err(e) = self.retry_one(id, op.clone()).await {
+				self.fail(id, tr!("Failed to retry {op}: {error}", op = format!("{op:?}"), error = e))?;
+				self.prog.send(TaskProg::Failed(id, Box::new(op)))?;
+			}
+		}
+		Ok(())
+	}
+
+	async fn retry_one(&self, id: usize, op: FileOp) -> Result<()> {
+		match op {
+			FileOp::Paste(task) => match fs::symlink_metadata(&task.from).await {
+				// The directory counted as one, now done by the walk of it
+				Ok(m) if m.is_dir() => {
+					self.paste(task).await?;
+					Ok(self.prog.send(TaskProg::Adv(id, 1, 0))?)
+				}
+				Ok(m) if m.is_symlink() => self.queue(FileOp::Link(task.to_link(m))).await,
+				_ => self.queue(FileOp::Paste(task)).await,
+			},
+			op => self.queue(op).await,
+		}
+	}
+}
@@ -0,0 +1,69 @@
+use std::{fmt::{self, Display}, str::FromStr, sync::{atomic::AtomicUsize, Arc}};
+
+use anyhow::bail;
+use futures::future::BoxFuture;
+use tokio::sync::oneshot;
+
+use crate::TaskOp;
+
+/// The pools of the workers, each taking up from the queues of its own, for the preview work,
+/// such as the preloaders and calculating the sizes, not to hold up the operations asked for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lane {
+	Micro,
+	Macro,
+	Prework,
+}
+
+impl Lane {
+	pub const ALL: [Self; 3] = [Self::Micro, Self::Macro, Self::Prework];
+}
+
+impl FromStr for Lane {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ok(match s {
+			"micro" => Self::Micro,
+			"macro" => Self::Macro,
+			"prework" => Self::Prework,
+			_ => bail!("Invalid lane: {s}"),
+		})
+	}
+}
+
+impl Display for Lane {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Micro => "micro",
+			Self::Macro => "macro",
+			Self::Prework => "prework",
+		})
+	}
+}
+
+/// How busy the workers of a lane are, and how much is waiting for them.
+#[derive(Clone, Copy, Debug)]
+pub struct LaneStat {
+	pub lane:    Lane,
+	pub workers: usize,
+	pub busy:    usize,
+	pub queued:  usize,
+}
+
+// The workers of a lane, each stopped by dropping its sender, once it's done with what it's on
+pub(super) struct Pool {
+	pub(super) micro:   async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>,
+	pub(super) macro_:  Option<async_priority_channel::Receiver<TaskOp, u8>>,
+	pub(super) busy:    Arc<AtomicUsize>,
+	pub(super) workers: Vec<oneshot::Sender<()>>,
+}
+
+impl Pool {
+	pub(super) fn new(
+		micro: async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>,
+		macro_: Option<async_priority_channel::Receiver<TaskOp, u8>>,
+	) -> Self {
+		Self { micro, macro_, busy: Default::default(), workers: vec![] }
+	}
+}
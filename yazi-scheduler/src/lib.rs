@@ -1,17 +1,25 @@
 #![allow(clippy::option_map_unit_fn, clippy::unit_arg)]
 
 mod blocker;
+mod checksum;
+mod exclude;
 mod file;
+mod lane;
 mod op;
 mod plugin;
 mod preload;
 mod process;
+mod resumable;
 mod running;
 mod scheduler;
 mod task;
 
 pub use blocker::*;
+pub use checksum::*;
+pub use exclude::*;
+pub use lane::*;
 pub use op::*;
+pub use resumable::*;
 pub use running::*;
 pub use scheduler::*;
 pub use task::*;
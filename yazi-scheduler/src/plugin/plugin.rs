@@ -1,9 +1,10 @@
 use anyhow::Result;
 use tokio::sync::mpsc;
+use yazi_config::TASKS;
 use yazi_plugin::isolate;
 
 use super::{PluginOp, PluginOpEntry};
-use crate::{TaskOp, TaskProg, HIGH};
+use crate::{TaskOp, TaskProg};
 
 pub struct Plugin {
 	macro_: async_priority_channel::Sender<TaskOp, u8>,
@@ -43,7 +44,7 @@ impl Plugin {
 		let id = task.id;
 
 		self.prog.send(TaskProg::New(id, 0))?;
-		self.macro_.try_send(PluginOp::Entry(task).into(), HIGH)?;
+		self.macro_.try_send(PluginOp::Entry(task).into(), TASKS.priority_plugin as u8)?;
 		self.succ(id)
 	}
 }
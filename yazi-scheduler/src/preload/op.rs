@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use tokio::sync::oneshot;
 use yazi_config::plugin::PluginProps;
 use yazi_shared::{fs::Url, Throttle};
 
@@ -29,5 +30,6 @@ pub struct PreloadOpRule {
 pub struct PreloadOpSize {
 	pub id:       usize,
 	pub target:   Url,
-	pub throttle: Arc<Throttle<(Url, u64)>>,
+	pub throttle: Arc<Throttle<(Url, Option<u64>)>>,
+	pub cancel:   oneshot::Sender<()>,
 }
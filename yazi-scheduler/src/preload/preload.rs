@@ -1,15 +1,15 @@
-use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::collections::{BTreeSet, HashMap};
 
 use anyhow::Result;
 use parking_lot::RwLock;
-use tokio::sync::mpsc;
+use tokio::{select, sync::mpsc};
 use tracing::error;
-use yazi_config::Priority;
+use yazi_config::{Priority, TASKS};
 use yazi_plugin::isolate;
-use yazi_shared::fs::{calculate_size, FilesOp, Url};
+use yazi_shared::fs::{calculate_size_with_progress, FilesOp, Url};
 
 use super::{PreloadOp, PreloadOpRule, PreloadOpSize};
-use crate::{TaskOp, TaskProg, HIGH, NORMAL};
+use crate::{TaskOp, TaskProg};
 
 pub struct Preload {
 	macro_: async_priority_channel::Sender<TaskOp, u8>,
@@ -49,9 +49,27 @@ impl Preload {
 				}
 				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
 			}
-			PreloadOp::Size(task) => {
-				let length = calculate_size(&task.target).await;
-				task.throttle.done((task.target, length), |buf| {
+			PreloadOp::Size(mut task) => {
+				let mut rx = calculate_size_with_progress(&task.target);
+				let (mut length, mut canceled) = (0, false);
+				loop {
+					select! {
+						_ = task.cancel.closed() => {
+							canceled = true;
+							break;
+						}
+						n = rx.recv() => match n {
+							Some(n) => {
+								self.prog.send(TaskProg::Grow(task.id, n - length))?;
+								length = n;
+							}
+							None => break,
+						}
+					}
+				}
+
+				// A canceled one is left out, so that it can be calculated again later
+				task.throttle.done((task.target, (!canceled).then_some(length)), |buf| {
 					{
 						let mut loading = self.size_loading.write();
 						for (path, _) in &buf {
@@ -60,7 +78,8 @@ impl Preload {
 					}
 
 					let parent = buf[0].0.parent_url().unwrap();
-					FilesOp::Size(parent, BTreeMap::from_iter(buf)).emit();
+					FilesOp::Size(parent, buf.into_iter().filter_map(|(u, s)| Some((u, s?))).collect())
+						.emit();
 				});
 				self.prog.send(TaskProg::Adv(task.id, 1, 0))?;
 			}
@@ -72,9 +91,13 @@ impl Preload {
 		let id = task.id;
 		self.prog.send(TaskProg::New(id, 0))?;
 
+		// Of the priority of the preloaders, or the one below it for those of a low one
+		let prio = TASKS.priority_preload as u8;
 		match task.plugin.prio {
-			Priority::Low => self.macro_.send(PreloadOp::Rule(task).into(), NORMAL).await?,
-			Priority::Normal => self.macro_.send(PreloadOp::Rule(task).into(), HIGH).await?,
+			Priority::Low => {
+				self.macro_.send(PreloadOp::Rule(task).into(), prio.saturating_sub(1)).await?
+			}
+			Priority::Normal => self.macro_.send(PreloadOp::Rule(task).into(), prio).await?,
 			Priority::High => self.work(PreloadOp::Rule(task)).await?,
 		}
 		self.succ(id)
@@ -5,21 +5,23 @@ use yazi_plugin::external::ShellOpt;
 
 #[derive(Debug)]
 pub struct ProcessOpOpen {
-	pub id:     usize,
-	pub cmd:    OsString,
-	pub args:   Vec<OsString>,
-	pub block:  bool,
-	pub orphan: bool,
-	pub cancel: oneshot::Sender<()>,
+	pub id:      usize,
+	pub cmd:     OsString,
+	pub args:    Vec<OsString>,
+	pub block:   bool,
+	pub orphan:  bool,
+	pub capture: bool,
+	pub cancel:  oneshot::Sender<()>,
 }
 
 impl From<&mut ProcessOpOpen> for ShellOpt {
 	fn from(op: &mut ProcessOpOpen) -> Self {
 		Self {
-			cmd:    mem::take(&mut op.cmd),
-			args:   mem::take(&mut op.args),
-			piped:  false,
-			orphan: op.orphan,
+			cmd:     mem::take(&mut op.cmd),
+			args:    mem::take(&mut op.args),
+			piped:   false,
+			orphan:  op.orphan,
+			capture: op.capture && op.block,
 		}
 	}
 }
@@ -1,5 +1,6 @@
 use anyhow::Result;
-use tokio::{io::{AsyncBufReadExt, BufReader}, select, sync::mpsc};
+use tokio::{io::{self, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader}, process::Child, select, sync::mpsc};
+use yazi_config::tr;
 use yazi_plugin::external::{self, ShellOpt};
 
 use super::ProcessOpOpen;
@@ -19,13 +20,14 @@ impl Process {
 			Scheduler::app_stop().await;
 
 			match external::shell(opt) {
+				Ok(child) if task.capture => self.capture(task.id, child).await?,
 				Ok(mut child) => {
 					child.wait().await.ok();
 					self.succ(task.id)?;
 				}
 				Err(e) => {
 					self.prog.send(TaskProg::New(task.id, 0))?;
-					self.fail(task.id, format!("Failed to spawn process: {e}"))?;
+					self.fail(task.id, tr!("Failed to spawn process: {error}", error = e))?;
 				}
 			}
 			return Ok(Scheduler::app_resume());
@@ -36,7 +38,7 @@ impl Process {
 				Ok(_) => self.succ(task.id)?,
 				Err(e) => {
 					self.prog.send(TaskProg::New(task.id, 0))?;
-					self.fail(task.id, format!("Failed to spawn process: {e}"))?;
+					self.fail(task.id, tr!("Failed to spawn process: {error}", error = e))?;
 				}
 			}
 			return Ok(());
@@ -50,7 +52,7 @@ impl Process {
 		loop {
 			select! {
 				_ = task.cancel.closed() => {
-					child.start_kill().ok();
+					external::kill(&mut child);
 					break;
 				}
 				Ok(Some(line)) = stdout.next_line() => {
@@ -61,11 +63,11 @@ impl Process {
 				}
 				Ok(status) = child.wait() => {
 					self.log(task.id, match status.code() {
-						Some(code) => format!("Exited with status code: {code}"),
-						None => "Process terminated by signal".to_string(),
+						Some(code) => tr!("Exited with status code: {code}", code = code),
+						None => tr!("Process terminated by signal").to_owned(),
 					})?;
 					if !status.success() {
-						return self.fail(task.id, "Process failed".to_string());
+						return self.fail(task.id, tr!("Process failed").to_owned());
 					}
 					break;
 				}
@@ -78,6 +80,57 @@ impl Process {
 }
 
 impl Process {
+	/// Copies the output of a blocking process to the terminal as it's written, chunk by chunk
+	/// for a prompt without a trailing newline to show, and into the log of the task by lines,
+	/// which is kept if it fails.
+	async fn capture(&self, id: usize, mut child: Child) -> Result<()> {
+		self.prog.send(TaskProg::New(id, 0))?;
+		let (mut stdout, mut stderr) = (child.stdout.take().unwrap(), child.stderr.take().unwrap());
+		let (mut out, mut err) = (io::stdout(), io::stderr());
+		let (mut buf1, mut buf2) = ([0; 4096], [0; 4096]);
+		let (mut line1, mut line2) = (vec![], vec![]);
+
+		let status = loop {
+			select! {
+				Ok(n @ 1..) = stdout.read(&mut buf1) => {
+					out.write_all(&buf1[..n]).await.ok();
+					out.flush().await.ok();
+					self.log_chunk(id, &mut line1, &buf1[..n])?;
+				}
+				Ok(n @ 1..) = stderr.read(&mut buf2) => {
+					err.write_all(&buf2[..n]).await.ok();
+					self.log_chunk(id, &mut line2, &buf2[..n])?;
+				}
+				Ok(status) = child.wait() => break status,
+			}
+		};
+
+		// What's left in the pipes after it exited
+		while let Ok(n @ 1..) = stdout.read(&mut buf1).await {
+			out.write_all(&buf1[..n]).await.ok();
+			self.log_chunk(id, &mut line1, &buf1[..n])?;
+		}
+		while let Ok(n @ 1..) = stderr.read(&mut buf2).await {
+			err.write_all(&buf2[..n]).await.ok();
+			self.log_chunk(id, &mut line2, &buf2[..n])?;
+		}
+		out.flush().await.ok();
+		for line in [line1, line2].into_iter().filter(|l| !l.is_empty()) {
+			self.log(id, String::from_utf8_lossy(&line).into_owned())?;
+		}
+
+		self.log(id, match status.code() {
+			Some(code) => tr!("Exited with status code: {code}", code = code),
+			None => tr!("Process terminated by signal").to_owned(),
+		})?;
+		if !status.success() {
+			return self.fail(id, tr!("Process failed").to_owned());
+		}
+
+		self.prog.send(TaskProg::Adv(id, 1, 0))?;
+		self.succ(id)
+	}
+
 	#[inline]
 	fn succ(&self, id: usize) -> Result<()> { Ok(self.prog.send(TaskProg::Succ(id))?) }
 
@@ -90,4 +143,16 @@ impl Process {
 	fn log(&self, id: usize, line: String) -> Result<()> {
 		Ok(self.prog.send(TaskProg::Log(id, line))?)
 	}
+
+	// Logs the lines a chunk completes, keeping the rest of it for the next one
+	fn log_chunk(&self, id: usize, line: &mut Vec<u8>, chunk: &[u8]) -> Result<()> {
+		line.extend_from_slice(chunk);
+		while let Some(i) = line.iter().position(|&b| b == b'\n') {
+			let rest = line.split_off(i + 1);
+			line.pop();
+			self.log(id, String::from_utf8_lossy(line).into_owned())?;
+			*line = rest;
+		}
+		Ok(())
+	}
 }
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use yazi_config::tr;
+use yazi_shared::fs::lossless;
+
+use crate::ExcludeRules;
+
+/// A task as it was asked for, to be offered again on the next launch
+/// if it's still waiting to be started by the exit.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(tag = "op", rename_all = "kebab-case")]
+pub enum Resumable {
+	Cut {
+		#[serde(with = "lossless")]
+		from:    PathBuf,
+		#[serde(with = "lossless")]
+		to:      PathBuf,
+		force:   bool,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		exclude: Option<ExcludeRules>,
+	},
+	Copy {
+		#[serde(with = "lossless")]
+		from:    PathBuf,
+		#[serde(with = "lossless")]
+		to:      PathBuf,
+		force:   bool,
+		follow:  bool,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		exclude: Option<ExcludeRules>,
+	},
+	Link {
+		#[serde(with = "lossless")]
+		from:     PathBuf,
+		#[serde(with = "lossless")]
+		to:       PathBuf,
+		relative: bool,
+		resolve:  bool,
+		force:    bool,
+	},
+	Delete {
+		#[serde(with = "lossless")]
+		target: PathBuf,
+	},
+	Trash {
+		#[serde(with = "lossless")]
+		target: PathBuf,
+	},
+}
+
+impl Resumable {
+	pub fn name(&self) -> String {
+		match self {
+			Self::Cut { from, to, .. } => {
+				tr!("Cut {from} to {to}", from = from.display(), to = to.display())
+			}
+			Self::Copy { from, to, .. } => {
+				tr!("Copy {from} to {to}", from = from.display(), to = to.display())
+			}
+			Self::Link { from, to, .. } => {
+				tr!("Link {from} to {to}", from = from.display(), to = to.display())
+			}
+			Self::Delete { target } => tr!("Delete {path}", path = target.display()),
+			Self::Trash { target } => tr!("Trash {path}", path = target.display()),
+		}
+	}
+}
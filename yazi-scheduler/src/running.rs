@@ -1,9 +1,11 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 use futures::future::BoxFuture;
-use yazi_config::TASKS;
+use yazi_config::{tr, TASKS};
+use yazi_plugin::external::{desktop_notify, DesktopNotifyOpt};
+use yazi_shared::term::Term;
 
-use super::{Task, TaskStage};
+use super::{Resumable, Task, TaskStage, TaskSummary};
 use crate::TaskKind;
 
 #[derive(Default)]
@@ -13,6 +15,9 @@ pub struct Running {
 	pub(super) hooks:
 		BTreeMap<usize, Box<dyn (FnOnce(bool) -> BoxFuture<'static, ()>) + Send + Sync>>,
 	pub(super) all:   BTreeMap<usize, Task>,
+
+	// The names of the groups of tasks, by their ids
+	groups: HashMap<usize, String>,
 }
 
 impl Running {
@@ -22,14 +27,42 @@ impl Running {
 		self.incr
 	}
 
+	/// Remembers how the task was asked for, for it to be resumed on the next launch.
+	#[inline]
+	pub(super) fn resumable(&mut self, id: usize, resumable: Resumable) {
+		if let Some(task) = self.all.get_mut(&id) {
+			task.resumable = Some(resumable);
+		}
+	}
+
+	/// Marks the task as taken up by a worker, from when it's no longer only queued.
+	#[inline]
+	pub(super) fn begin(&mut self, id: usize) {
+		if let Some(task) = self.all.get_mut(&id) {
+			task.begun = true;
+		}
+	}
+
 	#[inline]
 	pub fn get(&self, id: usize) -> Option<&Task> { self.all.get(&id) }
 
 	#[inline]
 	pub fn get_mut(&mut self, id: usize) -> Option<&mut Task> { self.all.get_mut(&id) }
 
-	#[inline]
-	pub fn get_id(&self, idx: usize) -> Option<usize> { self.values().nth(idx).map(|t| t.id) }
+	/// Groups the tasks under the name, to be summed up as one while more than one of them is left.
+	pub fn group(&mut self, name: String, ids: &[usize]) {
+		if ids.len() < 2 {
+			return;
+		}
+
+		self.incr += 1;
+		for id in ids {
+			if let Some(task) = self.all.get_mut(id) {
+				task.group = Some(self.incr);
+			}
+		}
+		self.groups.insert(self.incr, name);
+	}
 
 	#[inline]
 	pub fn len(&self) -> usize {
@@ -55,6 +88,43 @@ impl Running {
 	#[inline]
 	pub fn is_empty(&self) -> bool { self.len() == 0 }
 
+	/// The summaries of the tasks for which `f` is true, with those of a group summed up as one.
+	pub fn summaries(&self, f: impl Fn(&Task) -> bool) -> Vec<TaskSummary> {
+		let tasks: Vec<_> = self.values().filter(|&t| f(t)).collect();
+
+		let mut sizes: HashMap<usize, usize> = HashMap::new();
+		for group in tasks.iter().filter_map(|t| t.group) {
+			*sizes.entry(group).or_default() += 1;
+		}
+
+		let mut summaries: Vec<TaskSummary> = Vec::with_capacity(tasks.len());
+		let mut seen: HashMap<usize, usize> = HashMap::new();
+		for task in tasks {
+			let Some(group) = task.group.filter(|g| sizes[g] > 1) else {
+				summaries.push(task.into());
+				continue;
+			};
+
+			if let Some(&i) = seen.get(&group) {
+				summaries[i].merge(task);
+			} else {
+				let mut summary = TaskSummary::from(task);
+				summary.name = self.groups.get(&group).cloned().unwrap_or_default();
+				seen.insert(group, summaries.len());
+				summaries.push(summary);
+			}
+		}
+		summaries
+	}
+
+	/// Forgets the group of the removed task if it was the last one left.
+	pub(super) fn ungroup(&mut self, task: &Task) {
+		let Some(group) = task.group else { return };
+		if !self.all.values().any(|t| t.group == Some(group)) {
+			self.groups.remove(&group);
+		}
+	}
+
 	pub fn try_remove(&mut self, id: usize, stage: TaskStage) -> Option<BoxFuture<'static, ()>> {
 		if let Some(task) = self.get_mut(id) {
 			if stage > task.stage {
@@ -74,8 +144,28 @@ impl Running {
 				TaskStage::Hooked => {}
 			}
 
-			self.all.remove(&id);
+			if let Some(task) = self.all.remove(&id) {
+				self.ungroup(&task);
+				Self::notify_done(task);
+			}
 		}
 		None
 	}
+
+	// Tells the desktop about a long task once it's done, in case we have lost the focus
+	fn notify_done(task: Task) {
+		let elapsed = task.started.elapsed().as_secs();
+		if task.kind != TaskKind::User
+			|| TASKS.notify_after == 0
+			|| elapsed < TASKS.notify_after as u64
+			|| Term::focused()
+		{
+			return;
+		}
+
+		tokio::spawn(async move {
+			let title = if task.fail > 0 { tr!("Task failed") } else { tr!("Task done") };
+			desktop_notify(DesktopNotifyOpt { title, body: &task.name }).await.ok();
+		});
+	}
 }
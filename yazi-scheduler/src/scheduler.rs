@@ -1,14 +1,14 @@
-use std::{ffi::OsStr, sync::Arc, time::Duration};
+use std::{collections::BTreeMap, ffi::OsStr, mem, path::Path, sync::{atomic::Ordering, Arc}, time::Duration};
 
 use futures::{future::BoxFuture, FutureExt};
 use parking_lot::Mutex;
-use tokio::{fs, select, sync::{mpsc::{self, UnboundedReceiver}, oneshot}};
-use yazi_config::{open::Opener, plugin::PluginRule, TASKS};
-use yazi_plugin::ValueSendable;
-use yazi_shared::{emit, event::Cmd, fs::{unique_path, Url}, Layer, Throttle};
+use tokio::{fs, select, sync::{mpsc::{self, UnboundedReceiver}, oneshot}, time::sleep};
+use yazi_config::{open::Opener, plugin::PluginRule, tr, TASKS};
+use yazi_plugin::{external::Checksum, ValueSendable};
+use yazi_shared::{emit, event::Cmd, fs::{resolve_link, unique_path, Url}, Layer, Throttle};
 
-use super::{Running, TaskProg, TaskStage};
-use crate::{file::{File, FileOpDelete, FileOpLink, FileOpPaste, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, preload::{Preload, PreloadOpRule, PreloadOpSize}, process::{Process, ProcessOpOpen}, TaskKind, TaskOp, HIGH, LOW, NORMAL};
+use super::{ChecksumEntry, ChecksumReport, Exclude, Lane, LaneStat, Pool, Resumable, Running, TaskProg, TaskStage};
+use crate::{file::{File, FileOpChecksum, FileOpChmod, FileOpDelete, FileOpLink, FileOpPaste, FileOpTrash}, plugin::{Plugin, PluginOpEntry}, preload::{Preload, PreloadOpRule, PreloadOpSize}, process::{Process, ProcessOpOpen}, TaskKind, TaskOp, HIGH, LOW, NORMAL};
 
 pub struct Scheduler {
 	pub file:    Arc<File>,
@@ -19,50 +19,83 @@ pub struct Scheduler {
 	micro:       async_priority_channel::Sender<BoxFuture<'static, ()>, u8>,
 	prog:        mpsc::UnboundedSender<TaskProg>,
 	pub running: Arc<Mutex<Running>>,
+	prework:     async_priority_channel::Sender<BoxFuture<'static, ()>, u8>,
+	pools:       Mutex<[Pool; 3]>,
 }
 
 impl Scheduler {
 	pub fn start() -> Self {
 		let (micro_tx, micro_rx) = async_priority_channel::unbounded();
 		let (macro_tx, macro_rx) = async_priority_channel::unbounded();
+		let (prework_tx, prework_rx) = async_priority_channel::unbounded();
+		let (prework_macro_tx, prework_macro_rx) = async_priority_channel::unbounded();
 		let (prog_tx, prog_rx) = mpsc::unbounded_channel();
 
+		let running = Arc::new(Mutex::new(Running::default()));
 		let scheduler = Self {
-			file:    Arc::new(File::new(macro_tx.clone(), prog_tx.clone())),
+			file:    Arc::new(File::new(macro_tx.clone(), prog_tx.clone(), running.clone())),
 			plugin:  Arc::new(Plugin::new(macro_tx.clone(), prog_tx.clone())),
-			preload: Arc::new(Preload::new(macro_tx.clone(), prog_tx.clone())),
+			preload: Arc::new(Preload::new(prework_macro_tx, prog_tx.clone())),
 			process: Arc::new(Process::new(prog_tx.clone())),
 
 			micro:   micro_tx,
 			prog:    prog_tx,
-			running: Default::default(),
+			running,
+			prework: prework_tx,
+			pools:   Mutex::new([
+				Pool::new(micro_rx.clone(), None),
+				Pool::new(micro_rx, Some(macro_rx)),
+				Pool::new(prework_rx, Some(prework_macro_rx)),
+			]),
 		};
 
-		for _ in 0..TASKS.micro_workers {
-			scheduler.schedule_micro(micro_rx.clone());
-		}
-		for _ in 0..TASKS.macro_workers {
-			scheduler.schedule_macro(micro_rx.clone(), macro_rx.clone());
-		}
+		scheduler.resize(Lane::Micro, TASKS.micro_workers as usize);
+		scheduler.resize(Lane::Macro, TASKS.macro_workers as usize);
+		scheduler.resize(Lane::Prework, TASKS.prework_workers as usize);
 		scheduler.progress(prog_rx);
+		scheduler.surface();
 		scheduler
 	}
 
-	fn schedule_micro(&self, rx: async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>) {
-		tokio::spawn(async move {
-			loop {
-				if let Ok((fut, _)) = rx.recv().await {
-					fut.await;
+	/// How busy the workers of each lane are.
+	pub fn lanes(&self) -> Vec<LaneStat> {
+		let pools = self.pools.lock();
+		Lane::ALL
+			.into_iter()
+			.map(|lane| {
+				let pool = &pools[lane as usize];
+				LaneStat {
+					lane,
+					workers: pool.workers.len(),
+					busy: pool.busy.load(Ordering::Relaxed),
+					queued: match (lane, &pool.macro_) {
+						(Lane::Prework, Some(m)) => (pool.micro.len() + m.len()) as usize,
+						(_, Some(m)) => m.len() as usize,
+						(_, None) => pool.micro.len() as usize,
+					},
 				}
-			}
-		});
+			})
+			.collect()
 	}
 
-	fn schedule_macro(
-		&self,
-		micro: async_priority_channel::Receiver<BoxFuture<'static, ()>, u8>,
-		macro_: async_priority_channel::Receiver<TaskOp, u8>,
-	) {
+	/// Starts or stops the workers of the lane to have as many as given, at least one,
+	/// where those stopped finish what they're on first, and take up nothing more.
+	pub fn resize(&self, lane: Lane, n: usize) {
+		let mut pools = self.pools.lock();
+		let pool = &mut pools[lane as usize];
+
+		let n = n.clamp(1, u8::MAX as usize);
+		pool.workers.truncate(n);
+		while pool.workers.len() < n {
+			let stop = self.schedule(pool);
+			pool.workers.push(stop);
+		}
+	}
+
+	fn schedule(&self, pool: &Pool) -> oneshot::Sender<()> {
+		let (stop_tx, mut stop) = oneshot::channel();
+		let (micro, macro_, busy) = (pool.micro.clone(), pool.macro_.clone(), pool.busy.clone());
+
 		let file = self.file.clone();
 		let plugin = self.plugin.clone();
 		let preload = self.preload.clone();
@@ -73,20 +106,28 @@ impl Scheduler {
 		tokio::spawn(async move {
 			loop {
 				select! {
+					_ = &mut stop => break,
 					Ok((fut, _)) = micro.recv() => {
+						busy.fetch_add(1, Ordering::Relaxed);
 						fut.await;
+						busy.fetch_sub(1, Ordering::Relaxed);
 					}
-					Ok((op, _)) = macro_.recv() => {
+					Ok((op, _)) = async { macro_.as_ref().unwrap().recv().await }, if macro_.is_some() => {
 						let id = op.id();
 						if !running.lock().exists(id) {
 							continue;
 						}
 
+						busy.fetch_add(1, Ordering::Relaxed);
 						let result = match op {
-							TaskOp::File(op) => file.work(*op).await,
+							TaskOp::File(op) => file.work(*op).await.map_err(|(e, op)| {
+								prog.send(TaskProg::Failed(id, Box::new(op))).ok();
+								e
+							}),
 							TaskOp::Plugin(op) => plugin.work(*op).await,
 							TaskOp::Preload(op) => preload.work(*op).await,
 						};
+						busy.fetch_sub(1, Ordering::Relaxed);
 
 						if let Err(e) = result {
 							prog.send(TaskProg::Fail(id, format!("Failed to work on this task: {:?}", e))).ok();
@@ -95,6 +136,7 @@ impl Scheduler {
 				}
 			}
 		});
+		stop_tx
 	}
 
 	fn progress(&self, mut rx: UnboundedReceiver<TaskProg>) {
@@ -122,6 +164,22 @@ impl Scheduler {
 							}
 						}
 					}
+					TaskProg::Skip(id, n) => {
+						if let Some(task) = running.lock().get_mut(id) {
+							task.skipped += n;
+						}
+					}
+					TaskProg::Plan(id, size) => {
+						if let Some(task) = running.lock().get_mut(id) {
+							task.planned = size;
+						}
+					}
+					TaskProg::Grow(id, size) => {
+						if let Some(task) = running.lock().get_mut(id) {
+							task.found += size;
+							task.processed += size;
+						}
+					}
 					TaskProg::Succ(id) => {
 						if let Some(fut) = running.lock().try_remove(id, TaskStage::Dispatched) {
 							micro.try_send(fut, NORMAL).ok();
@@ -129,7 +187,17 @@ impl Scheduler {
 					}
 					TaskProg::Fail(id, reason) => {
 						if let Some(task) = running.lock().get_mut(id) {
+							if task.kind == TaskKind::User {
+								emit!(Call(
+									Cmd::notify(tr!("Task failed"), format!("{}: {reason}", task.name), "error", 5)
+										.with("group", "task-failed")
+										.with("action", "tasks_show"),
+									Layer::App
+								));
+							}
+
 							task.fail += 1;
+							task.error = Some(reason.clone());
 							task.logs.push_str(&reason);
 							task.logs.push('\n');
 
@@ -138,6 +206,20 @@ impl Scheduler {
 							}
 						}
 					}
+					TaskProg::Denied(id, op) => {
+						if let Some(task) = running.lock().get_mut(id) {
+							// Asked once for those denied about the same time
+							if task.denied.is_empty() {
+								emit!(Call(Cmd::new("escalate").with("id", id), Layer::Tasks));
+							}
+							task.denied.push(*op);
+						}
+					}
+					TaskProg::Failed(id, op) => {
+						if let Some(task) = running.lock().get_mut(id) {
+							task.failed.push(*op);
+						}
+					}
 					TaskProg::Log(id, line) => {
 						if let Some(task) = running.lock().get_mut(id) {
 							task.logs.push_str(&line);
@@ -153,14 +235,73 @@ impl Scheduler {
 		});
 	}
 
+	// Surfaces the long-running tasks as notifications, one for each,
+	// which are kept up to date with their progress until they finish.
+	fn surface(&self) {
+		const LONG_RUNNING: Duration = Duration::from_secs(3);
+
+		let running = self.running.clone();
+		tokio::spawn(async move {
+			let mut shown: BTreeMap<usize, (String, u8)> = BTreeMap::new();
+			loop {
+				sleep(Duration::from_secs(1)).await;
+
+				let (mut alive, mut failed) = (vec![], vec![]);
+				for task in running.lock().values() {
+					if task.kind != TaskKind::User {
+						continue;
+					} else if task.fail > 0 {
+						failed.push(task.id);
+						continue;
+					} else if task.started.elapsed() < LONG_RUNNING {
+						continue;
+					}
+
+					alive.push(task.id);
+					let percent = task.percent();
+					if shown.get(&task.id).is_some_and(|&(_, p)| p == percent) {
+						continue;
+					}
+
+					shown.insert(task.id, (task.name.clone(), percent));
+					emit!(Call(
+						Cmd::notify(tr!("Running"), &task.name, "info", 3600)
+							.with("id", format!("task-{}", task.id))
+							.with("progress", percent),
+						Layer::App
+					));
+				}
+
+				shown.retain(|&id, (name, _)| {
+					if alive.contains(&id) {
+						return true;
+					}
+					let notice = if failed.contains(&id) {
+						Cmd::notify(tr!("Failed"), name, "error", 5).with("action", "tasks_show")
+					} else {
+						Cmd::notify(tr!("Finished"), name, "info", 3).with("progress", 100)
+					};
+					emit!(Call(notice.with("id", format!("task-{id}")), Layer::App));
+					false
+				});
+			}
+		});
+	}
+
+	#[inline]
+	pub fn group(&self, name: String, ids: &[usize]) { self.running.lock().group(name, ids); }
+
 	pub fn cancel(&self, id: usize) -> bool {
 		let mut running = self.running.lock();
-		let b = running.all.remove(&id).is_some();
+		let Some(task) = running.all.remove(&id) else {
+			return false;
+		};
+		running.ungroup(&task);
 
 		if let Some(hook) = running.hooks.remove(&id) {
 			self.micro.try_send(hook(true), HIGH).ok();
 		}
-		b
+		true
 	}
 
 	pub async fn app_stop() {
@@ -173,17 +314,33 @@ impl Scheduler {
 		emit!(Call(Cmd::new("resume"), Layer::App));
 	}
 
-	pub fn file_cut(&self, from: Url, mut to: Url, force: bool) {
+	pub fn file_cut(
+		&self,
+		from: Url,
+		mut to: Url,
+		force: bool,
+		exclude: Option<Arc<dyn Exclude>>,
+	) -> usize {
 		let mut running = self.running.lock();
-		let id = running.add(TaskKind::User, format!("Cut {:?} to {:?}", from, to));
+		let name = tr!("Cut {from} to {to}", from = from.display(), to = to.display());
+		let id = running.add(TaskKind::User, name);
+		running.resumable(id, Resumable::Cut {
+			from: from.to_path_buf(),
+			to: to.to_path_buf(),
+			force,
+			exclude: exclude.as_ref().map(|e| e.rules()),
+		});
 
+		let (mut cancel_tx, mut cancel_rx) = oneshot::channel::<()>();
 		running.hooks.insert(id, {
 			let from = from.clone();
 			let running = self.running.clone();
 
 			Box::new(move |canceled: bool| {
 				async move {
-					if !canceled {
+					if canceled {
+						cancel_rx.close();
+					} else {
 						File::remove_empty_dirs(&from).await;
 					}
 					running.lock().try_remove(id, TaskStage::Hooked);
@@ -193,58 +350,140 @@ impl Scheduler {
 		});
 
 		let file = self.file.clone();
+		let running = self.running.clone();
 		_ = self.micro.try_send(
 			async move {
+				running.lock().begin(id);
 				if !force {
 					to = unique_path(to).await;
 				}
-				file.paste(FileOpPaste { id, from, to, cut: true, follow: false, retry: 0 }).await.ok();
+				let task = FileOpPaste { id, from, to, cut: true, follow: false, retry: 0, exclude };
+				select! {
+					_ = cancel_tx.closed() => {},
+					_ = file.paste(task) => {},
+				}
 			}
 			.boxed(),
-			LOW,
+			TASKS.priority_paste as u8,
 		);
+		id
 	}
 
-	pub fn file_copy(&self, from: Url, mut to: Url, force: bool, follow: bool) {
-		let name = format!("Copy {:?} to {:?}", from, to);
+	/// Renames the files as one task, telling if all of them are renamed once it's done,
+	/// as none of them is otherwise.
+	pub fn file_rename(&self, pairs: Vec<(Url, Url)>) -> oneshot::Receiver<bool> {
+		let name = tr!("Rename {n} file{s}", n = pairs.len());
 		let id = self.running.lock().add(TaskKind::User, name);
 
+		let (tx, rx) = oneshot::channel();
+		let file = self.file.clone();
+		_ = self.micro.try_send(
+			async move {
+				tx.send(file.rename(id, pairs).await.unwrap_or(false)).ok();
+			}
+			.boxed(),
+			LOW,
+		);
+		rx
+	}
+
+	pub fn file_copy(
+		&self,
+		from: Url,
+		mut to: Url,
+		force: bool,
+		follow: bool,
+		exclude: Option<Arc<dyn Exclude>>,
+	) -> usize {
+		let mut running = self.running.lock();
+		let name = tr!("Copy {from} to {to}", from = from.display(), to = to.display());
+		let id = running.add(TaskKind::User, name);
+		running.resumable(id, Resumable::Copy {
+			from: from.to_path_buf(),
+			to: to.to_path_buf(),
+			force,
+			follow,
+			exclude: exclude.as_ref().map(|e| e.rules()),
+		});
+
+		// Canceling stops the walk of the source, the files found so far are skipped by the workers
+		let (mut cancel_tx, mut cancel_rx) = oneshot::channel::<()>();
+		running.hooks.insert(id, {
+			let running = self.running.clone();
+			Box::new(move |canceled: bool| {
+				async move {
+					if canceled {
+						cancel_rx.close();
+					}
+					running.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
 		let file = self.file.clone();
+		let running = self.running.clone();
 		_ = self.micro.try_send(
 			async move {
+				running.lock().begin(id);
 				if !force {
 					to = unique_path(to).await;
 				}
-				file.paste(FileOpPaste { id, from, to, cut: false, follow, retry: 0 }).await.ok();
+				let task = FileOpPaste { id, from, to, cut: false, follow, retry: 0, exclude };
+				select! {
+					_ = cancel_tx.closed() => {},
+					_ = file.paste(task) => {},
+				}
 			}
 			.boxed(),
-			LOW,
+			TASKS.priority_paste as u8,
 		);
+		id
 	}
 
-	pub fn file_link(&self, from: Url, mut to: Url, relative: bool, force: bool) {
-		let name = format!("Link {from:?} to {to:?}");
-		let id = self.running.lock().add(TaskKind::User, name);
+	pub fn file_link(
+		&self,
+		mut from: Url,
+		mut to: Url,
+		relative: bool,
+		resolve: bool,
+		force: bool,
+	) -> usize {
+		let name = tr!("Link {from} to {to}", from = from.display(), to = to.display());
+		let id = {
+			let mut running = self.running.lock();
+			let id = running.add(TaskKind::User, name);
+			let (from, to) = (from.to_path_buf(), to.to_path_buf());
+			running.resumable(id, Resumable::Link { from, to, relative, resolve, force });
+			id
+		};
 
-		let file = self.file.clone();
+		let (file, running) = (self.file.clone(), self.running.clone());
 		_ = self.micro.try_send(
 			async move {
+				running.lock().begin(id);
 				if !force {
 					to = unique_path(to).await;
 				}
+				// To where the chain of symlinks ends, or the file itself if it's not one
+				if resolve {
+					from = resolve_link(&from).await.map(Url::from).unwrap_or(from);
+				}
 				file
 					.link(FileOpLink { id, from, to, meta: None, resolve: false, relative, delete: false })
 					.await
 					.ok();
 			}
 			.boxed(),
-			LOW,
+			TASKS.priority_link as u8,
 		);
+		id
 	}
 
-	pub fn file_delete(&self, target: Url) {
+	pub fn file_delete(&self, target: Url) -> usize {
 		let mut running = self.running.lock();
-		let id = running.add(TaskKind::User, format!("Delete {:?}", target));
+		let id = running.add(TaskKind::User, tr!("Delete {path}", path = target.display()));
+		running.resumable(id, Resumable::Delete { target: target.to_path_buf() });
 
 		running.hooks.insert(id, {
 			let target = target.clone();
@@ -253,7 +492,7 @@ impl Scheduler {
 			Box::new(move |canceled: bool| {
 				async move {
 					if !canceled {
-						fs::remove_dir_all(target).await.ok();
+						fs::remove_dir_all(target.extended()).await.ok();
 					}
 					running.lock().try_remove(id, TaskStage::Hooked);
 				}
@@ -261,32 +500,250 @@ impl Scheduler {
 			})
 		});
 
-		let file = self.file.clone();
+		let (file, running) = (self.file.clone(), self.running.clone());
 		_ = self.micro.try_send(
 			async move {
+				running.lock().begin(id);
 				file.delete(FileOpDelete { id, target, length: 0 }).await.ok();
 			}
 			.boxed(),
-			LOW,
+			TASKS.priority_delete as u8,
+		);
+		id
+	}
+
+	pub fn file_escalate(&self, id: usize) {
+		let Some(ops) = self.running.lock().get_mut(id).map(|t| mem::take(&mut t.denied)) else {
+			return;
+		};
+
+		let file = self.file.clone();
+		_ = self.micro.try_send(
+			async move {
+				file.escalate(id, ops).await.ok();
+			}
+			.boxed(),
+			HIGH,
 		);
 	}
 
-	pub fn file_trash(&self, target: Url) {
-		let name = format!("Trash {:?}", target);
+	// The operations not to be retried as root fail, as they would have with no asking
+	pub fn file_escalate_decline(&self, id: usize) {
+		let Some(ops) = self.running.lock().get_mut(id).map(|t| mem::take(&mut t.denied)) else {
+			return;
+		};
+		for op in ops {
+			let reason = tr!("Permission denied for {path}", path = op.url().display());
+			self.prog.send(TaskProg::Fail(id, reason)).ok();
+			self.prog.send(TaskProg::Failed(id, Box::new(op))).ok();
+		}
+	}
+
+	/// Retries the failed operations of the task, as they were, leaving the rest of it done.
+	pub fn file_retry(&self, id: usize) -> bool {
+		let ops = {
+			let mut running = self.running.lock();
+			let Some(task) = running.get_mut(id).filter(|t| !t.failed.is_empty()) else {
+				return false;
+			};
+
+			task.fail = task.fail.saturating_sub(task.failed.len() as u32);
+			task.error = None;
+			mem::take(&mut task.failed)
+		};
+
+		let (file, prog) = (self.file.clone(), self.prog.clone());
+		_ = self.micro.try_send(
+			async move {
+				if let Err(e) = file.retry(id, ops).await {
+					prog.send(TaskProg::Fail(id, format!("Failed to retry this task: {e:?}"))).ok();
+				}
+			}
+			.boxed(),
+			NORMAL,
+		);
+		true
+	}
+
+	/// Raises the task to the high priority, or puts it back to the priority of the type of each
+	/// of its operations, both those queued from then on and those waiting for a worker already.
+	pub fn boost(&self, id: usize, boosted: bool) -> bool {
+		let mut running = self.running.lock();
+		let Some(task) = running.get_mut(id) else { return false };
+		task.boosted = boosted;
+
+		// The queue can't be reordered, so it's taken out in whole and put back
+		let pools = self.pools.lock();
+		let Some(macro_) = &pools[Lane::Macro as usize].macro_ else { return true };
+		let mut ops = vec![];
+		while let Ok(op) = macro_.try_recv() {
+			ops.push(op);
+		}
+
+		for (op, prio) in ops {
+			let prio = match &op {
+				_ if op.id() != id => prio,
+				_ if boosted => HIGH,
+				TaskOp::File(f) => File::prio(f),
+				_ => prio,
+			};
+			self.file.requeue(op, prio);
+		}
+		true
+	}
+
+	/// The tasks still waiting for a worker, as they were asked for, to be resumed later.
+	pub fn file_queued(&self) -> Vec<Resumable> {
+		let running = self.running.lock();
+		running.values().filter(|t| t.is_queued()).filter_map(|t| t.resumable.clone()).collect()
+	}
+
+	pub fn file_chmod(&self, target: Url, mode: u32, mask: u32, recursive: bool) {
+		let name = tr!("Chmod {path}", path = target.display());
 		let id = self.running.lock().add(TaskKind::User, name);
 
 		let file = self.file.clone();
 		_ = self.micro.try_send(
 			async move {
+				file.chmod(FileOpChmod { id, target, mode, mask }, recursive).await.ok();
+			}
+			.boxed(),
+			TASKS.priority_chmod as u8,
+		);
+	}
+
+	/// Whether the path is on a volume with a recycle bin, which on Windows is asked of the shell,
+	/// as a removable drive may have one and a network one has none.
+	pub fn can_trash(path: &Path) -> bool {
+		#[cfg(windows)]
+		{
+			use std::{os::windows::ffi::OsStrExt, path::Component};
+
+			use windows_sys::Win32::UI::Shell::{SHQueryRecycleBinW, SHQUERYRBINFO};
+
+			let Some(Component::Prefix(prefix)) = path.components().next() else {
+				return true;
+			};
+			let root: Vec<u16> = prefix.as_os_str().encode_wide().chain([b'\\' as u16, 0]).collect();
+			let mut info = SHQUERYRBINFO {
+				cbSize:      std::mem::size_of::<SHQUERYRBINFO>() as u32,
+				i64Size:     0,
+				i64NumItems: 0,
+			};
+			unsafe { SHQueryRecycleBinW(root.as_ptr(), &mut info) == 0 }
+		}
+		#[cfg(not(windows))]
+		{
+			_ = path;
+			true
+		}
+	}
+
+	pub fn file_trash(&self, target: Url) -> usize {
+		let name = tr!("Trash {path}", path = target.display());
+		let id = {
+			let mut running = self.running.lock();
+			let id = running.add(TaskKind::User, name);
+			running.resumable(id, Resumable::Trash { target: target.to_path_buf() });
+			id
+		};
+
+		let (file, running) = (self.file.clone(), self.running.clone());
+		_ = self.micro.try_send(
+			async move {
+				running.lock().begin(id);
 				file.trash(FileOpTrash { id, target, length: 0 }).await.ok();
 			}
 			.boxed(),
-			LOW,
+			TASKS.priority_trash as u8,
+		);
+		id
+	}
+
+	pub fn file_checksum(
+		&self,
+		algo: Checksum,
+		targets: Vec<(Url, String, Option<String>)>,
+		write: Option<Url>,
+		copy: bool,
+	) {
+		let name = if targets.iter().any(|t| t.2.is_some()) {
+			tr!("Verify the {algo} checksums of {n} file{s}", algo = algo, n = targets.len())
+		} else {
+			tr!("Calculate the {algo} checksums of {n} file{s}", algo = algo, n = targets.len())
+		};
+
+		let mut running = self.running.lock();
+		let id = running.add(TaskKind::User, name);
+
+		// Filled in by the workers, for the report once all of them are done
+		let entries = Arc::new(Mutex::new(Vec::new()));
+		entries.lock().resize_with(targets.len(), || None);
+
+		running.hooks.insert(id, {
+			let (running, entries) = (self.running.clone(), entries.clone());
+			Box::new(move |canceled: bool| {
+				async move {
+					if !canceled {
+						let entries = mem::take(&mut *entries.lock());
+						Self::checksum_report(algo, entries, write, copy).await;
+					}
+					running.lock().try_remove(id, TaskStage::Hooked);
+				}
+				.boxed()
+			})
+		});
+
+		let tasks: Vec<_> = targets
+			.into_iter()
+			.enumerate()
+			.map(|(index, (target, name, expected))| FileOpChecksum {
+				id,
+				algo,
+				target,
+				name,
+				expected,
+				length: 0,
+				index,
+				entries: entries.clone(),
+			})
+			.collect();
+
+		let (file, running) = (self.file.clone(), self.running.clone());
+		_ = self.micro.try_send(
+			async move {
+				running.lock().begin(id);
+				file.checksum(tasks).await.ok();
+			}
+			.boxed(),
+			TASKS.priority_checksum as u8,
 		);
 	}
 
+	async fn checksum_report(
+		algo: Checksum,
+		entries: Vec<Option<ChecksumEntry>>,
+		write: Option<Url>,
+		copy: bool,
+	) {
+		let entries: Vec<_> = entries.into_iter().flatten().collect();
+		if let Some(path) = write {
+			let sums: String = entries
+				.iter()
+				.filter_map(|e| e.actual.as_ref().ok().map(|a| format!("{a}  {}\n", e.name)))
+				.collect();
+			if let Err(e) = fs::write(&path, sums).await {
+				let content = tr!("Failed to write {path}: {error}", path = format!("{path:?}"), error = e);
+				emit!(Notify(tr!("Checksum"), content, "error", 5));
+			}
+		}
+
+		let report = ChecksumReport { algo, entries, copy };
+		emit!(Call(Cmd::new("checksums").with_data(report), Layer::Spot));
+	}
+
 	pub fn plugin_micro(&self, name: String, args: Vec<ValueSendable>) {
-		let id = self.running.lock().add(TaskKind::User, format!("Run micro plugin `{name}`"));
+		let id = self.running.lock().add(TaskKind::User, tr!("Run micro plugin `{name}`", name = name));
 
 		let plugin = self.plugin.clone();
 		_ = self.micro.try_send(
@@ -294,12 +751,12 @@ impl Scheduler {
 				plugin.micro(PluginOpEntry { id, name, args }).await.ok();
 			}
 			.boxed(),
-			HIGH,
+			TASKS.priority_plugin as u8,
 		);
 	}
 
 	pub fn plugin_macro(&self, name: String, args: Vec<ValueSendable>) {
-		let id = self.running.lock().add(TaskKind::User, format!("Run macro plugin `{name}`"));
+		let id = self.running.lock().add(TaskKind::User, tr!("Run macro plugin `{name}`", name = name));
 
 		self.plugin.macro_(PluginOpEntry { id, name, args }).ok();
 	}
@@ -307,18 +764,18 @@ impl Scheduler {
 	pub fn preload_paged(&self, rule: &PluginRule, targets: Vec<&yazi_shared::fs::File>) {
 		let id = self.running.lock().add(
 			TaskKind::Preload,
-			format!("Run preloader `{}` with {} target(s)", rule.cmd.name, targets.len()),
+			tr!("Run preloader `{name}` with {n} target{s}", name = rule.cmd.name, n = targets.len()),
 		);
 
 		let plugin = rule.into();
 		let targets = targets.into_iter().cloned().collect();
 		let preload = self.preload.clone();
-		_ = self.micro.try_send(
+		_ = self.prework.try_send(
 			async move {
 				preload.rule(PreloadOpRule { id, plugin, targets }).await.ok();
 			}
 			.boxed(),
-			HIGH,
+			TASKS.priority_preload as u8,
 		);
 	}
 
@@ -327,26 +784,44 @@ impl Scheduler {
 		let mut running = self.running.lock();
 
 		for target in targets {
-			let id = running.add(TaskKind::Preload, format!("Calculate the size of {:?}", target));
+			let name = tr!("Calculate the size of {path}", path = target.display());
+			let id = running.add(TaskKind::Preload, name);
 			let target = target.clone();
 			let throttle = throttle.clone();
 
+			let (cancel_tx, mut cancel_rx) = oneshot::channel();
+			running.hooks.insert(id, {
+				let running = self.running.clone();
+				Box::new(move |canceled: bool| {
+					async move {
+						if canceled {
+							cancel_rx.close();
+						}
+						running.lock().try_remove(id, TaskStage::Hooked);
+					}
+					.boxed()
+				})
+			});
+
 			let preload = self.preload.clone();
-			_ = self.micro.try_send(
+			_ = self.prework.try_send(
 				async move {
-					preload.size(PreloadOpSize { id, target, throttle }).await.ok();
+					preload.size(PreloadOpSize { id, target, throttle, cancel: cancel_tx }).await.ok();
 				}
 				.boxed(),
-				HIGH,
+				TASKS.priority_preload as u8,
 			);
 		}
 	}
 
 	pub fn process_open(&self, opener: &Opener, args: &[impl AsRef<OsStr>]) {
 		let name = {
-			let s = format!("Execute `{}`", opener.exec);
 			let args = args.iter().map(|a| a.as_ref().to_string_lossy()).collect::<Vec<_>>().join(" ");
-			if args.is_empty() { s } else { format!("{s} with `{args}`") }
+			if args.is_empty() {
+				tr!("Execute `{exec}`", exec = opener.exec)
+			} else {
+				tr!("Execute `{exec}` with `{args}`", exec = opener.exec, args = args)
+			}
 		};
 
 		let mut running = self.running.lock();
@@ -378,6 +853,7 @@ impl Scheduler {
 						args,
 						block: opener.block,
 						orphan: opener.orphan,
+						capture: opener.capture,
 						cancel: cancel_tx,
 					})
 					.await
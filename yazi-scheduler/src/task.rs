@@ -1,26 +1,106 @@
+use std::time::Instant;
+
 use tokio::sync::mpsc;
 
-#[derive(Debug, Default)]
+use crate::{file::FileOp, Resumable};
+
+#[derive(Debug)]
 pub struct Task {
 	pub id:    usize,
 	pub kind:  TaskKind,
 	pub name:  String,
 	pub stage: TaskStage,
 
-	pub total: u32,
-	pub succ:  u32,
-	pub fail:  u32,
+	pub total:   u32,
+	pub succ:    u32,
+	pub fail:    u32,
+	pub skipped: u32,
 
 	pub found:     u64,
 	pub processed: u64,
+	pub planned:   u64,
 
 	pub logs:   String,
 	pub logger: Option<mpsc::UnboundedSender<String>>,
+
+	// The operations failed with a permission error, waiting to be retried as root
+	pub denied: Vec<FileOp>,
+	// The operations failed otherwise, and the error of the last one, to be retried as they were
+	pub failed: Vec<FileOp>,
+	pub error:  Option<String>,
+
+	// The group of the tasks started together, such as the paste of several files
+	pub group:     Option<usize>,
+	// How the task was asked for, if it can be resumed on the next launch
+	pub resumable: Option<Resumable>,
+	// Taken up by a worker, with something of it maybe done, not to be resumed from the start
+	pub begun:     bool,
+	// Raised to the high priority from the task manager, for its operations queued from then on
+	pub boosted:   bool,
+
+	pub started: Instant,
 }
 
 impl Task {
 	pub fn new(id: usize, kind: TaskKind, name: String) -> Self {
-		Self { id, kind, name, ..Default::default() }
+		Self {
+			id,
+			kind,
+			name,
+			stage: Default::default(),
+
+			total: 0,
+			succ: 0,
+			fail: 0,
+			skipped: 0,
+
+			found: 0,
+			processed: 0,
+			planned: 0,
+
+			logs: Default::default(),
+			logger: None,
+
+			denied: vec![],
+			failed: vec![],
+			error: None,
+
+			group: None,
+			resumable: None,
+			begun: false,
+			boosted: false,
+
+			started: Instant::now(),
+		}
+	}
+
+	/// Whether the task is still waiting for a worker, with nothing of it done yet.
+	#[inline]
+	pub fn is_queued(&self) -> bool { !self.begun }
+
+	/// The size of the task, the planned one if it's walked beforehand,
+	/// or what's found so far otherwise.
+	#[inline]
+	pub fn size(&self) -> u64 { self.found.max(self.planned) }
+
+	#[inline]
+	pub fn percent(&self) -> u8 {
+		let size = self.size();
+		if size == 0 {
+			return 0;
+		}
+		(self.processed * 100 / size).min(99) as u8
+	}
+
+	/// The seconds left, estimated by the speed so far, for the tasks of a planned size.
+	pub fn eta(&self) -> Option<u64> {
+		if self.planned == 0 || self.processed == 0 {
+			return None;
+		}
+
+		let elapsed = self.started.elapsed().as_secs_f64();
+		let left = self.size().saturating_sub(self.processed) as f64;
+		Some((elapsed * left / self.processed as f64).ceil() as u64)
 	}
 }
 
@@ -33,11 +113,16 @@ pub enum TaskKind {
 
 #[derive(Debug)]
 pub struct TaskSummary {
-	pub name: String,
+	pub name:  String,
+	// The tasks summed up, more than one for a group
+	pub ids:     Vec<usize>,
+	pub error:   Option<String>,
+	pub boosted: bool,
 
-	pub total: u32,
-	pub succ:  u32,
-	pub fail:  u32,
+	pub total:   u32,
+	pub succ:    u32,
+	pub fail:    u32,
+	pub skipped: u32,
 
 	pub found:     u64,
 	pub processed: u64,
@@ -46,30 +131,71 @@ pub struct TaskSummary {
 impl From<&Task> for TaskSummary {
 	fn from(task: &Task) -> Self {
 		TaskSummary {
-			name: task.name.clone(),
+			name:    task.name.clone(),
+			ids:     vec![task.id],
+			error:   task.error.clone(),
+			boosted: task.boosted,
 
-			total: task.total,
-			succ:  task.succ,
-			fail:  task.fail,
+			total:   task.total,
+			succ:    task.succ,
+			fail:    task.fail,
+			skipped: task.skipped,
 
-			found:     task.found,
+			found:     task.size(),
 			processed: task.processed,
 		}
 	}
 }
 
+impl TaskSummary {
+	/// Sums the task up into the summary of the group it's in.
+	pub fn merge(&mut self, task: &Task) {
+		self.ids.push(task.id);
+		if self.error.is_none() {
+			self.error = task.error.clone();
+		}
+		self.boosted |= task.boosted;
+
+		self.total += task.total;
+		self.succ += task.succ;
+		self.fail += task.fail;
+		self.skipped += task.skipped;
+
+		self.found += task.size();
+		self.processed += task.processed;
+	}
+
+	#[inline]
+	pub fn percent(&self) -> u8 {
+		if self.found == 0 {
+			return 0;
+		}
+		(self.processed * 100 / self.found).min(99) as u8
+	}
+}
+
 #[derive(Debug)]
 pub enum TaskProg {
 	// id, size
 	New(usize, u64),
 	// id, processed, size
 	Adv(usize, u32, u64),
+	// id, number of the entries left out
+	Skip(usize, u32),
+	// id, size, of all the files walked beforehand
+	Plan(usize, u64),
+	// id, size, for tasks that don't know their size beforehand
+	Grow(usize, u64),
 	// id
 	Succ(usize),
 	// id
 	Fail(usize, String),
 	// id, line
 	Log(usize, String),
+	// id, operation, failed with a permission error
+	Denied(usize, Box<FileOp>),
+	// id, operation, failed otherwise, to be retried as it was
+	Failed(usize, Box<FileOp>),
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
@@ -0,0 +1,215 @@
+use std::iter;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edit {
+	Equal,
+	Delete,
+	Insert,
+}
+
+/// The shortest edit script from `a` to `b`, with Myers' algorithm in linear space, or `None`
+/// if they differ in more than `limit` items, which would take too long to find.
+///
+/// Each [`Edit::Equal`] and [`Edit::Delete`] consumes an item of `a`, and each
+/// [`Edit::Equal`] and [`Edit::Insert`] consumes an item of `b`.
+pub fn diff<T: PartialEq>(a: &[T], b: &[T], limit: usize) -> Option<Vec<Edit>> {
+	let (mut vf, mut vb) = (vec![], vec![]);
+
+	// Only the whole is checked against the limit, as each part of it has fewer edits
+	let (pre, suf) = common(a, b);
+	let (ma, mb) = (&a[pre..a.len() - suf], &b[pre..b.len() - suf]);
+	if !ma.is_empty() && !mb.is_empty() {
+		middle_snake(ma, mb, limit, &mut vf, &mut vb)?;
+	} else if ma.len() + mb.len() > limit {
+		return None;
+	}
+
+	let mut edits = Vec::with_capacity(a.len() + b.len());
+	conquer(a, b, &mut edits, &mut vf, &mut vb);
+	Some(edits)
+}
+
+// The lengths of the common prefix and suffix, not overlapping
+fn common<T: PartialEq>(a: &[T], b: &[T]) -> (usize, usize) {
+	let pre = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+	let suf = a[pre..].iter().rev().zip(b[pre..].iter().rev()).take_while(|(x, y)| x == y).count();
+	(pre, suf)
+}
+
+fn conquer<T: PartialEq>(
+	a: &[T],
+	b: &[T],
+	edits: &mut Vec<Edit>,
+	vf: &mut Vec<isize>,
+	vb: &mut Vec<isize>,
+) {
+	let (pre, suf) = common(a, b);
+	let (a, b) = (&a[pre..a.len() - suf], &b[pre..b.len() - suf]);
+	edits.extend(iter::repeat_n(Edit::Equal, pre));
+
+	if a.is_empty() || b.is_empty() {
+		edits.extend(iter::repeat_n(Edit::Delete, a.len()));
+		edits.extend(iter::repeat_n(Edit::Insert, b.len()));
+	} else {
+		// Without a common prefix or suffix, they differ in at least two edits,
+		// so both parts around the snake are smaller than the whole
+		let (x, y, u, v) = middle_snake(a, b, usize::MAX, vf, vb).unwrap();
+		conquer(&a[..x], &b[..y], edits, vf, vb);
+		edits.extend(iter::repeat_n(Edit::Equal, u - x));
+		conquer(&a[u..], &b[v..], edits, vf, vb);
+	}
+
+	edits.extend(iter::repeat_n(Edit::Equal, suf));
+}
+
+/// The snake in the middle of a shortest edit script, from `(x, y)` to `(u, v)`, found by
+/// searching from both ends at once, or `None` if the script is longer than `limit`.
+fn middle_snake<T: PartialEq>(
+	a: &[T],
+	b: &[T],
+	limit: usize,
+	vf: &mut Vec<isize>,
+	vb: &mut Vec<isize>,
+) -> Option<(usize, usize, usize, usize)> {
+	let (n, m) = (a.len() as isize, b.len() as isize);
+	let delta = n - m;
+	let max = (n + m + 1) / 2;
+	let limit = limit.min(isize::MAX as usize) as isize;
+
+	// The furthest x on each diagonal k = x - y, offset by `max + 1`, from the start
+	// in `vf`, and from the end in `vb`, where the diagonals are of the reversed sequences
+	let at = |k: isize| (k + max + 1) as usize;
+	for v in [&mut *vf, &mut *vb] {
+		v.clear();
+		v.resize(2 * max as usize + 3, 0);
+	}
+
+	for d in 0..=max {
+		if 2 * d - 1 > limit {
+			break;
+		}
+		for k in (-d..=d).step_by(2) {
+			let mut x = if k == -d || (k != d && vf[at(k - 1)] < vf[at(k + 1)]) {
+				vf[at(k + 1)]
+			} else {
+				vf[at(k - 1)] + 1
+			};
+			let (x0, y0) = (x, x - k);
+			while x < n && x - k < m && a[x as usize] == b[(x - k) as usize] {
+				x += 1;
+			}
+			vf[at(k)] = x;
+
+			// Overlapping the path from the end, which is one edit shorter
+			let r = delta - k;
+			if delta % 2 != 0 && r.abs() < d && x + vb[at(r)] >= n {
+				return Some((x0 as usize, y0 as usize, x as usize, (x - k) as usize));
+			}
+		}
+
+		if 2 * d > limit {
+			break;
+		}
+		for k in (-d..=d).step_by(2) {
+			let mut x = if k == -d || (k != d && vb[at(k - 1)] < vb[at(k + 1)]) {
+				vb[at(k + 1)]
+			} else {
+				vb[at(k - 1)] + 1
+			};
+			let (x0, y0) = (x, x - k);
+			while x < n && x - k < m && a[(n - x - 1) as usize] == b[(m - x + k - 1) as usize] {
+				x += 1;
+			}
+			vb[at(k)] = x;
+
+			// Overlapping the path from the start, which is as long
+			let r = delta - k;
+			if delta % 2 == 0 && r.abs() <= d && x + vf[at(r)] >= n {
+				let (u, v) = ((n - x0) as usize, (m - y0) as usize);
+				return Some(((n - x) as usize, (m - x + k) as usize, u, v));
+			}
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn apply<'a>(a: &[&'a str], b: &[&'a str], edits: &[Edit]) -> Vec<&'a str> {
+		let (mut i, mut j, mut out) = (0, 0, vec![]);
+		for e in edits {
+			match e {
+				Edit::Equal => {
+					assert_eq!(a[i], b[j]);
+					out.push(a[i]);
+					(i, j) = (i + 1, j + 1);
+				}
+				Edit::Delete => i += 1,
+				Edit::Insert => {
+					out.push(b[j]);
+					j += 1;
+				}
+			}
+		}
+		assert_eq!((i, j), (a.len(), b.len()));
+		out
+	}
+
+	#[test]
+	fn test_diff() {
+		let a = ["a", "b", "c", "a", "b", "b", "a"];
+		let b = ["c", "b", "a", "b", "a", "c"];
+
+		let edits = diff(&a, &b, 100).unwrap();
+		assert_eq!(edits.iter().filter(|&&e| e != Edit::Equal).count(), 5);
+		assert_eq!(apply(&a, &b, &edits), b);
+
+		assert_eq!(diff(&a, &a, 0).unwrap(), [Edit::Equal; 7]);
+		assert_eq!(diff(&[], &b, 100).unwrap(), [Edit::Insert; 6]);
+		assert_eq!(diff(&a, &[], 100).unwrap(), [Edit::Delete; 7]);
+		assert!(diff(&a, &b, 4).is_none());
+	}
+
+	#[test]
+	fn test_diff_shortest() {
+		// As long as the longest common subsequence allows, of all the sequences of a few letters
+		fn lcs(a: &[u8], b: &[u8]) -> usize {
+			let mut t = vec![vec![0; b.len() + 1]; a.len() + 1];
+			for i in 0..a.len() {
+				for j in 0..b.len() {
+					t[i + 1][j + 1] = if a[i] == b[j] { t[i][j] + 1 } else { t[i][j + 1].max(t[i + 1][j]) };
+				}
+			}
+			t[a.len()][b.len()]
+		}
+
+		let mut seed = 1u32;
+		let mut rand = |n: u32| {
+			seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+			(seed >> 16) % n
+		};
+		let mut next = || (0..rand(12)).map(|_| b"abc"[rand(3) as usize]).collect::<Vec<_>>();
+
+		for _ in 0..2000 {
+			let (a, b) = (next(), next());
+			let edits = diff(&a, &b, usize::MAX).unwrap();
+			let changed = edits.iter().filter(|&&e| e != Edit::Equal).count();
+			assert_eq!(changed, a.len() + b.len() - 2 * lcs(&a, &b));
+
+			let (mut i, mut j) = (0, 0);
+			for e in edits {
+				match e {
+					Edit::Equal => {
+						assert_eq!(a[i], b[j]);
+						(i, j) = (i + 1, j + 1);
+					}
+					Edit::Delete => i += 1,
+					Edit::Insert => j += 1,
+				}
+			}
+			assert_eq!((i, j), (a.len(), b.len()));
+		}
+	}
+}
@@ -1,8 +1,24 @@
 #[inline]
 pub fn env_exists(name: &str) -> bool { std::env::var_os(name).is_some_and(|s| !s.is_empty()) }
 
-#[cfg(unix)]
+/// Whether an executable with this name exists in one of the `$PATH` directories.
+pub fn in_path(name: &str) -> bool {
+	let paths = std::env::var_os("PATH").unwrap_or_default();
+	std::env::split_paths(&paths).any(|p| p.join(name).is_file())
+}
+
 #[inline]
 pub fn in_ssh_connection() -> bool {
 	env_exists("SSH_CLIENT") || env_exists("SSH_TTY") || env_exists("SSH_CONNECTION")
 }
+
+/// Whether there's a graphical session for the GUI applications to show up in, which on
+/// macOS and Windows is only not the case over SSH.
+#[inline]
+pub fn in_gui_session() -> bool {
+	if cfg!(any(target_os = "macos", windows)) {
+		!in_ssh_connection()
+	} else {
+		env_exists("DISPLAY") || env_exists("WAYLAND_DISPLAY")
+	}
+}
@@ -1,4 +1,6 @@
-use std::{any::Any, collections::BTreeMap, fmt::{self, Display}, mem};
+use std::{any::Any, collections::BTreeMap, fmt::{self, Display}, mem, str::FromStr};
+
+use anyhow::bail;
 
 #[derive(Debug, Default)]
 pub struct Cmd {
@@ -17,6 +19,44 @@ impl Cmd {
 		Self { name: name.to_owned(), args, ..Default::default() }
 	}
 
+	/// The `notify` of the app, see `emit!(Notify(..))`, for a notification to be built on,
+	/// e.g. with an `id` to replace the one of it, or a `progress`.
+	#[inline]
+	pub fn notify(title: impl ToString, content: impl ToString, level: &str, timeout: u64) -> Self {
+		Self::new("notify")
+			.with("title", title)
+			.with("content", content)
+			.with("level", level)
+			.with("timeout", timeout)
+	}
+
+	/// The command of the words already split, the name first, and the arguments of it.
+	pub fn from_words(s: Vec<String>) -> anyhow::Result<Self> {
+		if s.is_empty() {
+			bail!("`exec` cannot be empty");
+		}
+
+		let mut cmd = Cmd { name: s[0].clone(), ..Default::default() };
+		for arg in s.into_iter().skip(1) {
+			if arg.starts_with("--") {
+				let mut arg = arg.splitn(2, '=');
+				let key = arg.next().unwrap().trim_start_matches('-');
+				let val = arg.next().unwrap_or("").to_string();
+				// A repeated one has each of its values on a line, see `take_names()`
+				match cmd.named.get_mut(key) {
+					Some(v) if !v.is_empty() && !val.is_empty() => {
+						v.push('\n');
+						v.push_str(&val);
+					}
+					_ => _ = cmd.named.insert(key.to_string(), val),
+				}
+			} else {
+				cmd.args.push(arg);
+			}
+		}
+		Ok(cmd)
+	}
+
 	#[inline]
 	pub fn with(mut self, name: impl ToString, value: impl ToString) -> Self {
 		self.named.insert(name.to_string(), value.to_string());
@@ -50,6 +90,14 @@ impl Cmd {
 	#[inline]
 	pub fn take_name(&mut self, name: &str) -> Option<String> { self.named.remove(name) }
 
+	/// Each of the values of a repeatable argument, e.g. `--exclude=a --exclude=b`.
+	pub fn take_names(&mut self, name: &str) -> Vec<String> {
+		let Some(s) = self.named.remove(name) else {
+			return vec![];
+		};
+		s.lines().filter(|s| !s.is_empty()).map(ToOwned::to_owned).collect()
+	}
+
 	#[inline]
 	pub fn clone_without_data(&self) -> Self {
 		Self {
@@ -68,11 +116,34 @@ impl Display for Cmd {
 			write!(f, " {}", self.args.join(" "))?;
 		}
 		for (k, v) in &self.named {
-			write!(f, " --{k}")?;
-			if !v.is_empty() {
-				write!(f, "={v}")?;
+			if v.is_empty() {
+				write!(f, " --{k}")?;
+			}
+			for v in v.lines() {
+				write!(f, " --{k}={v}")?;
 			}
 		}
 		Ok(())
 	}
 }
+
+impl FromStr for Cmd {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_words(shell_words::split(s)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_repeated() {
+		let mut cmd: Cmd = "paste --exclude=a,b --force --exclude='*.log'".parse().unwrap();
+		assert_eq!(cmd.to_string(), "paste --exclude=a,b --exclude=*.log --force");
+		assert_eq!(cmd.take_names("exclude"), ["a,b", "*.log"]);
+		assert!(cmd.take_names("exclude").is_empty());
+	}
+}
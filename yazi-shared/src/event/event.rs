@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, ffi::OsString};
 
-use crossterm::event::KeyEvent;
+use crossterm::event::{KeyEvent, MouseEvent};
 use tokio::sync::{mpsc, oneshot};
 
 use super::Cmd;
@@ -14,6 +14,7 @@ pub enum Event {
 	Seq(VecDeque<Cmd>, Layer),
 	Render,
 	Key(KeyEvent),
+	Mouse(MouseEvent),
 	Resize,
 	Paste(String),
 	Quit(EventQuit),
@@ -50,6 +51,13 @@ macro_rules! emit {
 	(Seq($cmds:expr, $layer:expr)) => {
 		$crate::event::Event::Seq($cmds, $layer).emit();
 	};
+	(Notify($title:expr, $content:expr, $level:expr, $timeout:expr)) => {
+		$crate::event::Event::Call(
+			$crate::event::Cmd::notify($title, $content, $level, $timeout),
+			$crate::Layer::App,
+		)
+		.emit();
+	};
 	($event:ident) => {
 		$crate::event::Event::$event.emit();
 	};
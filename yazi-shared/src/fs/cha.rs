@@ -31,6 +31,10 @@ pub struct Cha {
 	pub uid:         u32,
 	#[cfg(unix)]
 	pub gid:         u32,
+	#[cfg(unix)]
+	pub dev:         u64,
+	#[cfg(unix)]
+	pub ino:         u64,
 }
 
 impl From<Metadata> for Cha {
@@ -80,6 +84,16 @@ impl From<Metadata> for Cha {
 				use std::os::unix::fs::MetadataExt;
 				m.gid()
 			},
+			#[cfg(unix)]
+			dev:                      {
+				use std::os::unix::fs::MetadataExt;
+				m.dev()
+			},
+			#[cfg(unix)]
+			ino:                      {
+				use std::os::unix::fs::MetadataExt;
+				m.ino()
+			},
 		}
 	}
 }
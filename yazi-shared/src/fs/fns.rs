@@ -1,40 +1,8 @@
-use std::{collections::VecDeque, path::{Path, PathBuf}};
+use std::{path::{Path, PathBuf}, sync::atomic::{AtomicBool, Ordering}};
 
 use anyhow::Result;
 use tokio::{fs, io, select, sync::{mpsc, oneshot}, time};
 
-pub async fn calculate_size(path: &Path) -> u64 {
-	let mut total = 0;
-	let mut stack = VecDeque::from([path.to_path_buf()]);
-	while let Some(path) = stack.pop_front() {
-		let Ok(meta) = fs::symlink_metadata(&path).await else {
-			continue;
-		};
-
-		if !meta.is_dir() {
-			total += meta.len();
-			continue;
-		}
-
-		let Ok(mut it) = fs::read_dir(path).await else {
-			continue;
-		};
-
-		while let Ok(Some(entry)) = it.next_entry().await {
-			let Ok(meta) = entry.metadata().await else {
-				continue;
-			};
-
-			if meta.is_dir() {
-				stack.push_back(entry.path());
-			} else {
-				total += meta.len();
-			}
-		}
-	}
-	total
-}
-
 pub fn copy_with_progress(from: &Path, to: &Path) -> mpsc::Receiver<Result<u64, io::Error>> {
 	let (tx, rx) = mpsc::channel(1);
 	let (tick_tx, mut tick_rx) = oneshot::channel();
@@ -91,6 +59,93 @@ pub fn copy_with_progress(from: &Path, to: &Path) -> mpsc::Receiver<Result<u64,
 	rx
 }
 
+/// Whether the two paths are of the same file, as the identical ones are, or the ones whose names
+/// differ only in case on a case-insensitive filesystem, which is how that's told.
+///
+/// Two hard links of a file are not, though they're of the same inode, for one of them not to be
+/// taken as the other and replaced by it.
+pub async fn same_file(a: &Path, b: &Path) -> bool {
+	if a == b {
+		return true;
+	}
+
+	let (Some(parent), Some(na), Some(nb)) = (a.parent(), a.file_name(), b.file_name()) else {
+		return false;
+	};
+	if b.parent() != Some(parent)
+		|| na.to_string_lossy().to_lowercase() != nb.to_string_lossy().to_lowercase()
+	{
+		return false;
+	}
+
+	let identical = {
+		#[cfg(unix)]
+		{
+			use std::os::unix::fs::MetadataExt;
+			match (fs::symlink_metadata(a).await, fs::symlink_metadata(b).await) {
+				(Ok(a), Ok(b)) => (a.dev(), a.ino()) == (b.dev(), b.ino()),
+				_ => false,
+			}
+		}
+		#[cfg(not(unix))]
+		{
+			match (fs::canonicalize(a).await, fs::canonicalize(b).await) {
+				(Ok(a), Ok(b)) => a == b,
+				_ => false,
+			}
+		}
+	};
+	if !identical {
+		return false;
+	}
+
+	// Only one of the names is in the directory if it's the same file, and both of them if they're
+	// two hard links on a case-sensitive filesystem
+	let Ok(mut it) = fs::read_dir(parent).await else {
+		return false;
+	};
+	let mut listed = 0;
+	while let Ok(Some(entry)) = it.next_entry().await {
+		let name = entry.file_name();
+		listed += (name == na || name == nb) as u8;
+	}
+	listed < 2
+}
+
+/// Where the chain of symlinks the path is ends, following only its last component and not
+/// the symlinks among its parents, as `canonicalize()` would, or the path itself if it's not one.
+pub async fn resolve_link(path: &Path) -> io::Result<PathBuf> {
+	// The most symlinks followed, like the `MAXSYMLINKS` of Linux
+	const MAX: usize = 40;
+
+	let mut path = path.to_owned();
+	for _ in 0..MAX {
+		if !fs::symlink_metadata(&path).await?.is_symlink() {
+			return Ok(path);
+		}
+		let target = fs::read_link(&path).await?;
+		path = path.parent().map_or_else(|| target.clone(), |p| p.join(&target));
+	}
+	Err(io::Error::other("too many levels of symbolic links"))
+}
+
+/// Renames the file, even if only the case of its name is changed on a case-insensitive
+/// filesystem, where it might be a no-op otherwise, by a temporary name in between.
+pub async fn rename_file(from: &Path, to: &Path) -> io::Result<()> {
+	if from == to || !same_file(from, to).await {
+		return fs::rename(from, to).await;
+	}
+
+	let name = from.file_name().map(|s| s.to_string_lossy()).unwrap_or_default();
+	let tmp = from.with_file_name(format!(".{name}.yazi-case-{}", std::process::id()));
+	fs::rename(from, &tmp).await?;
+	if let Err(e) = fs::rename(&tmp, to).await {
+		fs::rename(&tmp, from).await.ok();
+		return Err(e);
+	}
+	Ok(())
+}
+
 // Convert a file mode to a string representation
 #[cfg(unix)]
 #[allow(clippy::collapsible_else_if)]
@@ -139,6 +194,42 @@ pub fn permissions(m: libc::mode_t) -> String {
 	s
 }
 
+// Convert a size in bytes to a human-readable string, e.g. 1.5K
+// Whether the sizes are in the powers of 1000 rather than 1024, and whether they're exact bytes,
+// set from the config, the latter also toggled by `size_format`
+pub static SIZE_DECIMAL: AtomicBool = AtomicBool::new(false);
+pub static SIZE_RAW: AtomicBool = AtomicBool::new(false);
+
+#[inline]
+pub fn readable_size(size: u64) -> String {
+	format_size(size, SIZE_DECIMAL.load(Ordering::Relaxed), SIZE_RAW.load(Ordering::Relaxed))
+}
+
+// The exact bytes are grouped by the thousands, e.g. 1,234,567B
+fn format_size(size: u64, decimal: bool, raw: bool) -> String {
+	const BINARY: [&str; 11] = ["B", "K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+	const DECIMAL: [&str; 11] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB", "RB", "QB"];
+	if raw {
+		let digits = size.to_string();
+		let mut s = String::with_capacity(digits.len() * 4 / 3 + 1);
+		for (i, c) in digits.chars().enumerate() {
+			if i > 0 && (digits.len() - i).is_multiple_of(3) {
+				s.push(',');
+			}
+			s.push(c);
+		}
+		return s + "B";
+	}
+
+	let (base, units) = if decimal { (1000.0, DECIMAL) } else { (1024.0, BINARY) };
+	let (mut size, mut i) = (size as f64, 0);
+	while size > base && i < units.len() - 1 {
+		size /= base;
+		i += 1;
+	}
+	format!("{size:.1}{}", units[i])
+}
+
 // Find the max common root in a list of files
 // e.g. /a/b/c, /a/b/d       -> /a/b
 //      /aa/bb/cc, /aa/dd/ee -> /aa
@@ -162,6 +253,19 @@ pub fn max_common_root(files: &[impl AsRef<Path>]) -> PathBuf {
 	root
 }
 
+#[test]
+fn test_format_size() {
+	assert_eq!(format_size(0, false, false), "0.0B");
+	assert_eq!(format_size(1536, false, false), "1.5K");
+	assert_eq!(format_size(1536, true, false), "1.5kB");
+	assert_eq!(format_size(2_500_000, true, false), "2.5MB");
+
+	assert_eq!(format_size(0, false, true), "0B");
+	assert_eq!(format_size(999, true, true), "999B");
+	assert_eq!(format_size(1000, false, true), "1,000B");
+	assert_eq!(format_size(1_234_567, false, true), "1,234,567B");
+}
+
 #[cfg(unix)]
 #[test]
 fn test_max_common_root() {
@@ -186,3 +290,56 @@ fn test_max_common_root() {
 		"/aa/bb"
 	);
 }
+
+#[tokio::test]
+async fn test_rename_file() {
+	let dir = std::env::temp_dir().join(format!("yazi-rename-{}", std::process::id()));
+	fs::create_dir_all(&dir).await.unwrap();
+
+	let (a, b) = (dir.join("readme"), dir.join("README"));
+	fs::write(&a, "yazi").await.unwrap();
+	assert!(same_file(&a, &a).await);
+
+	rename_file(&a, &b).await.unwrap();
+	assert!(same_file(&b, &b).await);
+	assert_eq!(fs::read_to_string(&b).await.unwrap(), "yazi");
+	fs::remove_dir_all(&dir).await.unwrap();
+}
+
+// Two hard links aren't the same file, even of the names differing only in case,
+// unlike the one name in different cases on a case-insensitive filesystem
+#[cfg(unix)]
+#[tokio::test]
+async fn test_same_file_hard_link() {
+	let dir = std::env::temp_dir().join(format!("yazi-same-{}", std::process::id()));
+	fs::create_dir_all(&dir).await.unwrap();
+
+	let (a, b, c) = (dir.join("readme"), dir.join("README"), dir.join("other"));
+	fs::write(&a, "yazi").await.unwrap();
+	fs::hard_link(&a, &b).await.unwrap();
+	fs::hard_link(&a, &c).await.unwrap();
+	assert!(!same_file(&a, &b).await);
+	assert!(!same_file(&a, &c).await);
+	fs::remove_dir_all(&dir).await.unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_resolve_link() {
+	let dir = std::env::temp_dir().join(format!("yazi-resolve-{}", std::process::id()));
+	fs::create_dir_all(dir.join("real")).await.unwrap();
+	fs::symlink("real", dir.join("parent")).await.unwrap();
+
+	// parent/a -> b -> c, where the parent is a symlink itself, left as it is
+	fs::write(dir.join("real/c"), "").await.unwrap();
+	fs::symlink("b", dir.join("real/a")).await.unwrap();
+	fs::symlink(dir.join("parent/c"), dir.join("real/b")).await.unwrap();
+	assert_eq!(resolve_link(&dir.join("parent/a")).await.unwrap(), dir.join("parent/c"));
+	assert_eq!(resolve_link(&dir.join("parent/c")).await.unwrap(), dir.join("parent/c"));
+
+	fs::symlink("loop", dir.join("real/loop")).await.unwrap();
+	assert!(resolve_link(&dir.join("real/loop")).await.is_err());
+	fs::symlink("missing", dir.join("real/broken")).await.unwrap();
+	assert!(resolve_link(&dir.join("real/broken")).await.is_err());
+	fs::remove_dir_all(&dir).await.unwrap();
+}
@@ -0,0 +1,67 @@
+use std::{ffi::OsString, fmt, path::{Path, PathBuf}};
+
+use serde::{de::{self, SeqAccess, Visitor}, Deserializer, Serialize, Serializer};
+
+/// Serializes a path as the string it is, or as its bytes if it's not UTF-8, for a path of any
+/// name to be written to a state file and read back as it was, with
+/// `#[serde(with = "yazi_shared::fs::lossless")]`.
+pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+	match path.to_str() {
+		Some(s) => serializer.serialize_str(s),
+		None => path.as_os_str().as_encoded_bytes().serialize(serializer),
+	}
+}
+
+/// Deserializes a path written by [`serialize`], either way.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+	deserializer.deserialize_any(PathVisitor)
+}
+
+/// A path serialized as [`serialize`] does it, for the ones in e.g. an `Option` or a `Vec`.
+#[derive(Clone, Copy, Debug)]
+pub struct Lossless<'a>(pub &'a Path);
+
+impl Serialize for Lossless<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serialize(self.0, serializer)
+	}
+}
+
+struct PathVisitor;
+
+impl<'de> Visitor<'de> for PathVisitor {
+	type Value = PathBuf;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("a path, as a string or its bytes")
+	}
+
+	fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> { Ok(v.into()) }
+
+	fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+		Ok(path_from_bytes(v.to_vec()))
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+		while let Some(b) = seq.next_element()? {
+			bytes.push(b);
+		}
+		Ok(path_from_bytes(bytes))
+	}
+}
+
+/// The path of the bytes of [`std::ffi::OsStr::as_encoded_bytes`].
+#[cfg(unix)]
+#[inline]
+pub fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+	use std::os::unix::ffi::OsStringExt;
+	OsString::from_vec(bytes).into()
+}
+
+// Not any bytes make a valid `OsString` on Windows, where a path that's not UTF-8 is rare
+#[cfg(windows)]
+#[inline]
+pub fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+	OsString::from(String::from_utf8_lossy(&bytes).into_owned()).into()
+}
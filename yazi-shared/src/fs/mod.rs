@@ -1,8 +1,10 @@
 mod cha;
 mod file;
 mod fns;
+pub mod lossless;
 mod op;
 mod path;
+mod size;
 mod url;
 
 pub use cha::*;
@@ -10,4 +12,5 @@ pub use file::*;
 pub use fns::*;
 pub use op::*;
 pub use path::*;
+pub use size::*;
 pub use url::*;
@@ -37,12 +37,37 @@ fn _expand_path(p: &Path) -> PathBuf {
 		return if let Some(p) = home { PathBuf::from(p).join(rest) } else { rest.to_path_buf() };
 	}
 
+	// ~user
+	#[cfg(unix)]
+	if let Some(Component::Normal(first)) = p.components().next() {
+		if let Some(home) = first.to_str().and_then(|s| s.strip_prefix('~')).and_then(user_home) {
+			return home.join(p.strip_prefix(first).unwrap());
+		}
+	}
+
 	if p.is_absolute() {
 		return p.to_path_buf();
 	}
 	current_cwd().map_or_else(|| p.to_path_buf(), |c| c.join(p))
 }
 
+#[cfg(unix)]
+fn user_home(name: &str) -> Option<PathBuf> {
+	use std::{ffi::{CStr, CString, OsStr}, os::unix::ffi::OsStrExt};
+
+	let name = CString::new(name).ok()?;
+	let mut buf = vec![0; 4096];
+	let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+	let mut result = std::ptr::null_mut();
+	unsafe { libc::getpwnam_r(name.as_ptr(), &mut pwd, buf.as_mut_ptr(), buf.len(), &mut result) };
+	if result.is_null() {
+		return None;
+	}
+
+	let dir = unsafe { CStr::from_ptr(pwd.pw_dir) };
+	Some(PathBuf::from(OsStr::from_bytes(dir.to_bytes())))
+}
+
 #[inline]
 pub fn expand_path(p: impl AsRef<Path>) -> PathBuf { _expand_path(p.as_ref()) }
 
@@ -83,6 +108,72 @@ pub async fn unique_path(mut p: Url) -> Url {
 	p
 }
 
+/// The extended-length form of an absolute path on Windows, `\\?\C:\a` or
+/// `\\?\UNC\server\share\a`, for the operations on it not to be limited to 260 characters.
+///
+/// Windows takes the form literally, so the `/` in it are turned into `\`, and the `.`
+/// and `..` are resolved beforehand. Other paths, and those on other platforms, are as is.
+pub fn extended_path(p: &Path) -> Cow<'_, Path> {
+	#[cfg(windows)]
+	if let Some(s) = p.to_str().and_then(extended_str) {
+		return Cow::Owned(s.into());
+	}
+	Cow::Borrowed(p)
+}
+
+/// The usual form of a path in the extended-length form, the reverse of [`extended_path`].
+pub fn unextended_path(p: &Path) -> Cow<'_, Path> {
+	#[cfg(windows)]
+	if let Some(s) = p.to_str().and_then(unextended_str) {
+		return Cow::Owned(s.into());
+	}
+	Cow::Borrowed(p)
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+fn extended_str(s: &str) -> Option<String> {
+	if s.starts_with(r"\\?\") || s.starts_with(r"\\.\") {
+		return None;
+	}
+
+	let s = s.replace('/', "\\");
+	let (prefix, rest) = if let Some(unc) = s.strip_prefix(r"\\") {
+		let mut it = unc.splitn(3, '\\');
+		let (server, share) = (it.next()?, it.next()?);
+		if server.is_empty() || share.is_empty() {
+			return None;
+		}
+		(format!(r"\\?\UNC\{server}\{share}"), it.next().unwrap_or_default())
+	} else {
+		let b = s.as_bytes();
+		if b.len() < 3 || !b[0].is_ascii_alphabetic() || b[1] != b':' || b[2] != b'\\' {
+			return None;
+		}
+		(format!(r"\\?\{}", &s[..2]), &s[3..])
+	};
+
+	let mut parts = vec![];
+	for part in rest.split('\\') {
+		match part {
+			"" | "." => {}
+			".." => _ = parts.pop(),
+			_ => parts.push(part),
+		}
+	}
+	Some(format!(r"{prefix}\{}", parts.join("\\")))
+}
+
+#[cfg_attr(not(windows), allow(dead_code))]
+fn unextended_str(s: &str) -> Option<String> {
+	if let Some(unc) = s.strip_prefix(r"\\?\UNC\") {
+		return Some(format!(r"\\{unc}"));
+	}
+
+	let rest = s.strip_prefix(r"\\?\")?;
+	let b = rest.as_bytes();
+	(b.len() >= 2 && b[0].is_ascii_alphabetic() && b[1] == b':').then(|| rest.to_owned())
+}
+
 // Parameters
 // * `path`: The absolute path(contains no `/./`) to get relative path.
 // * `root`: The absolute path(contains no `/./`) to be compared.
@@ -132,7 +223,7 @@ pub fn path_relative_to<'a>(path: &'a Path, root: &Path) -> Cow<'a, Path> {
 mod tests {
 	use std::{borrow::Cow, path::Path};
 
-	use super::path_relative_to;
+	use super::{expand_path, extended_str, path_relative_to, unextended_str};
 
 	#[cfg(unix)]
 	#[test]
@@ -148,6 +239,84 @@ mod tests {
 		assert("/a/a/b", "/a/b/b", "../../a/b");
 	}
 
+	#[cfg(unix)]
+	#[test]
+	fn test_expand_user() {
+		let home = super::user_home("root").unwrap();
+		assert_eq!(expand_path("~root"), home);
+		assert_eq!(expand_path("~root/a/b"), home.join("a/b"));
+		assert_eq!(expand_path("/a/~root"), Path::new("/a/~root"));
+	}
+
+	#[test]
+	fn test_extended_str() {
+		assert_eq!(extended_str(r"C:\a\b").as_deref(), Some(r"\\?\C:\a\b"));
+		assert_eq!(extended_str(r"C:/a/./b/../c\").as_deref(), Some(r"\\?\C:\a\c"));
+		assert_eq!(extended_str(r"C:\").as_deref(), Some(r"\\?\C:\"));
+		assert_eq!(extended_str(r"\\server\share\a").as_deref(), Some(r"\\?\UNC\server\share\a"));
+		assert_eq!(extended_str(r"\\server\share").as_deref(), Some(r"\\?\UNC\server\share\"));
+		assert_eq!(extended_str(r"//server/share/a").as_deref(), Some(r"\\?\UNC\server\share\a"));
+		assert_eq!(extended_str(r"\\server\share\..\..\b").as_deref(), Some(r"\\?\UNC\server\share\b"));
+		assert_eq!(extended_str(r"\\?\C:\a"), None);
+		assert_eq!(extended_str(r"\\server"), None);
+		assert_eq!(extended_str(r"a\b"), None);
+
+		assert_eq!(unextended_str(r"\\?\C:\a").as_deref(), Some(r"C:\a"));
+		assert_eq!(unextended_str(r"\\?\UNC\server\share\a").as_deref(), Some(r"\\server\share\a"));
+		assert_eq!(unextended_str(r"\\?\Volume{x}\a"), None);
+		assert_eq!(unextended_str(r"C:\a"), None);
+	}
+
+	// Kept in the usual form, for the parent of it to stop at the share, and extended again after
+	#[cfg(windows)]
+	#[test]
+	fn test_unc_url() {
+		use crate::fs::Url;
+
+		let url = Url::from(r"\\?\UNC\server\share\a\b");
+		assert_eq!(url.as_path(), Path::new(r"\\server\share\a\b"));
+
+		let parent = url.parent_url().unwrap();
+		assert_eq!(parent.extended(), Path::new(r"\\?\UNC\server\share\a"));
+		assert_eq!(parent.join("c").extended(), Path::new(r"\\?\UNC\server\share\a\c"));
+
+		let share = parent.parent_url().unwrap();
+		assert_eq!(share.extended(), Path::new(r"\\?\UNC\server\share\"));
+		assert!(share.parent_url().is_none());
+	}
+
+	// Each of the components is short, but the whole is longer than the 260 characters of Windows
+	#[tokio::test]
+	async fn test_long_path() {
+		use tokio::fs;
+
+		use super::extended_path;
+		use crate::fs::copy_with_progress;
+
+		let root = std::env::temp_dir().join(format!("yazi-long-{}", std::process::id()));
+		let dir = (0..12).fold(root.clone(), |p, i| p.join(format!("{i:0>30}")));
+		assert!(dir.as_os_str().len() > 360);
+
+		let dir = extended_path(&dir).into_owned();
+		fs::create_dir_all(&dir).await.unwrap();
+
+		let (a, b, c) = (dir.join("a"), dir.join("b"), dir.join("c"));
+		fs::write(&a, "yazi").await.unwrap();
+		let mut it = copy_with_progress(&extended_path(&a), &extended_path(&b));
+		while let Some(n) = it.recv().await {
+			if n.unwrap() == 0 {
+				break;
+			}
+		}
+		fs::rename(extended_path(&b), extended_path(&c)).await.unwrap();
+		assert_eq!(fs::read_to_string(&c).await.unwrap(), "yazi");
+
+		fs::remove_file(extended_path(&a)).await.unwrap();
+		fs::remove_file(extended_path(&c)).await.unwrap();
+		assert!(fs::symlink_metadata(&b).await.is_err());
+		fs::remove_dir_all(&root).await.unwrap();
+	}
+
 	#[cfg(windows)]
 	#[test]
 	fn test_path_relative_to() {
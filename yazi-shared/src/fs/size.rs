@@ -0,0 +1,80 @@
+use std::{collections::VecDeque, path::{Path, PathBuf}};
+
+use tokio::{fs, sync::mpsc, task::JoinSet};
+
+// Directories read at the same time
+const WORKERS: usize = 8;
+
+pub async fn calculate_size(path: &Path) -> u64 {
+	let mut rx = calculate_size_with_progress(path);
+	let mut total = 0;
+	while let Some(n) = rx.recv().await {
+		total = n;
+	}
+	total
+}
+
+/// Walks the tree in parallel, sending the running total as it grows,
+/// and the final one before the channel is closed.
+///
+/// Dropping the receiver cancels the walk.
+pub fn calculate_size_with_progress(path: &Path) -> mpsc::Receiver<u64> {
+	let (tx, rx) = mpsc::channel(1);
+	let root = path.to_owned();
+
+	tokio::spawn(async move {
+		let Ok(meta) = fs::symlink_metadata(&root).await else {
+			return tx.send(0).await.ok();
+		};
+		if !meta.is_dir() {
+			return tx.send(meta.len()).await.ok();
+		}
+
+		let mut total = 0;
+		let mut pending = VecDeque::from([root]);
+		let mut set = JoinSet::new();
+		loop {
+			while set.len() < WORKERS {
+				let Some(dir) = pending.pop_front() else { break };
+				set.spawn(read_dir(dir));
+			}
+
+			let Some(result) = set.join_next().await else { break };
+			if tx.is_closed() {
+				return None;
+			}
+
+			let Ok((size, dirs)) = result else { continue };
+			total += size;
+			pending.extend(dirs);
+			tx.try_send(total).ok();
+		}
+
+		tx.send(total).await.ok()
+	});
+
+	rx
+}
+
+// Sums up the files directly under `dir`, returning the subdirectories that have to be walked.
+//
+// Not cached, as a directory's mtime doesn't change with the files deeper in it.
+async fn read_dir(dir: PathBuf) -> (u64, Vec<PathBuf>) {
+	let (mut size, mut dirs) = (0, vec![]);
+	let Ok(mut it) = fs::read_dir(dir).await else {
+		return (size, dirs);
+	};
+
+	while let Ok(Some(entry)) = it.next_entry().await {
+		let Ok(meta) = entry.metadata().await else {
+			continue;
+		};
+
+		if meta.is_dir() {
+			dirs.push(entry.path());
+		} else {
+			size += meta.len();
+		}
+	}
+	(size, dirs)
+}
@@ -1,7 +1,9 @@
-use std::{ffi::{OsStr, OsString}, fmt::{Debug, Formatter}, ops::{Deref, DerefMut}, path::{Path, PathBuf}};
+use std::{borrow::Cow, ffi::{OsStr, OsString}, fmt::{self, Debug, Display, Formatter}, ops::{Deref, DerefMut}, path::{Path, PathBuf}};
 
 use percent_encoding::{percent_decode_str, percent_encode, AsciiSet, CONTROLS};
 
+use super::{extended_path, unextended_path};
+
 const ENCODE_SET: &AsciiSet = &CONTROLS.add(b'#');
 
 #[derive(Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -17,6 +19,7 @@ pub enum UrlScheme {
 	Regular,
 	Search,
 	Archive,
+	Recent,
 }
 
 impl Deref for Url {
@@ -33,8 +36,16 @@ impl Debug for Url {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "{}", self.path.display()) }
 }
 
+// Kept in the usual form, even if it's come in the extended-length one, e.g. from
+// `canonicalize()`, for them to be compared and shown alike
 impl From<PathBuf> for Url {
-	fn from(path: PathBuf) -> Self { Self { path, ..Default::default() } }
+	fn from(path: PathBuf) -> Self {
+		let path = match unextended_path(&path) {
+			Cow::Owned(p) => p,
+			Cow::Borrowed(_) => path,
+		};
+		Self { path, ..Default::default() }
+	}
 }
 
 impl From<&PathBuf> for Url {
@@ -95,22 +106,23 @@ impl AsRef<OsStr> for Url {
 	fn as_ref(&self) -> &OsStr { self.path.as_os_str() }
 }
 
-impl ToString for Url {
-	fn to_string(&self) -> String {
+impl Display for Url {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
 		if self.scheme == UrlScheme::Regular {
-			return self.path.to_string_lossy().into_owned();
+			return write!(f, "{}", self.path.display());
 		}
 
 		let scheme = match self.scheme {
 			UrlScheme::Regular => unreachable!(),
 			UrlScheme::Search => "search://",
 			UrlScheme::Archive => "archive://",
+			UrlScheme::Recent => "recent://",
 		};
 
 		let path = percent_encode(self.path.as_os_str().as_encoded_bytes(), ENCODE_SET);
 		let frag =
 			Some(&self.frag).filter(|&s| !s.is_empty()).map(|s| format!("#{s}")).unwrap_or_default();
-		format!("{scheme}{path}{frag}")
+		write!(f, "{scheme}{path}{frag}")
 	}
 }
 
@@ -122,6 +134,7 @@ impl Url {
 			UrlScheme::Regular => url,
 			UrlScheme::Search => url,
 			UrlScheme::Archive => url.into_archive(),
+			UrlScheme::Recent => url,
 		}
 	}
 
@@ -133,6 +146,7 @@ impl Url {
 				UrlScheme::Regular => url,
 				UrlScheme::Search => url,
 				UrlScheme::Archive => url,
+				UrlScheme::Recent => url,
 			}
 		})
 	}
@@ -145,10 +159,15 @@ impl Url {
 	#[inline]
 	pub fn into_os_string(self) -> OsString { self.path.into_os_string() }
 
+	/// The path in the extended-length form on Windows, for the file operations
+	/// on it to work however long it is, see [`extended_path`].
+	#[inline]
+	pub fn extended(&self) -> Cow<'_, Path> { extended_path(&self.path) }
+
 	#[cfg(unix)]
 	#[inline]
 	pub fn is_hidden(&self) -> bool {
-		self.file_name().map_or(false, |s| s.as_encoded_bytes().starts_with(&[b'.']))
+		self.file_name().is_some_and(|s| s.as_encoded_bytes().starts_with(b"."))
 	}
 }
 
@@ -191,6 +210,9 @@ impl Url {
 		self
 	}
 
+	#[inline]
+	pub fn is_recent(&self) -> bool { self.scheme == UrlScheme::Recent }
+
 	// --- Path
 	#[inline]
 	pub fn set_path(&mut self, path: PathBuf) { self.path = path; }
@@ -198,6 +220,35 @@ impl Url {
 	// --- Frag
 	#[inline]
 	pub fn frag(&self) -> &str { &self.frag }
+
+	// --- Bytes
+	/// The url as bytes, `<scheme>:<length of the fragment>:<fragment><path>`, with the path as it
+	/// is, of any encoding, to be written to a file and read back by [`Self::from_bytes`].
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let scheme = match self.scheme {
+			UrlScheme::Regular => "regular",
+			UrlScheme::Search => "search",
+			UrlScheme::Archive => "archive",
+			UrlScheme::Recent => "recent",
+		};
+
+		let mut b = format!("{scheme}:{}:{}", self.frag.len(), self.frag).into_bytes();
+		b.extend_from_slice(self.path.as_os_str().as_encoded_bytes());
+		b
+	}
+
+	pub fn from_bytes(b: &[u8]) -> Option<Self> {
+		let mut it = b.splitn(3, |&c| c == b':');
+		let (scheme, len, rest) = (it.next()?, it.next()?, it.next()?);
+
+		let len: usize = std::str::from_utf8(len).ok()?.parse().ok()?;
+		let (frag, path) = (rest.get(..len)?, rest.get(len..)?);
+		Some(Self {
+			scheme: std::str::from_utf8(scheme).ok()?.into(),
+			path:   super::lossless::path_from_bytes(path.to_vec()),
+			frag:   String::from_utf8(frag.to_vec()).ok()?,
+		})
+	}
 }
 
 impl From<&str> for UrlScheme {
@@ -205,7 +256,35 @@ impl From<&str> for UrlScheme {
 		match value {
 			"search" => UrlScheme::Search,
 			"archive" => UrlScheme::Archive,
+			"recent" => UrlScheme::Recent,
 			_ => UrlScheme::Regular,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let urls = [
+			Url::from("/a/b c"),
+			Url::from("/a/b").into_search("k:w#1".to_owned()),
+			Url::from("/a/b.zip/c").into_archive(),
+		];
+		for url in urls {
+			assert_eq!(Url::from_bytes(&url.to_bytes()).unwrap(), url);
+		}
+	}
+
+	#[cfg(unix)]
+	#[test]
+	fn test_bytes_non_utf8() {
+		use std::os::unix::ffi::OsStrExt;
+
+		let url = Url::from(Path::new(OsStr::from_bytes(b"/a/\xff\n\x00")));
+		assert_eq!(Url::from_bytes(&url.to_bytes()).unwrap(), url);
+		assert_eq!(Url::from_bytes(b"regular:5:ab"), None);
+	}
+}
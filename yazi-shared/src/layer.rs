@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt::{self, Display}, str::FromStr};
 
 use anyhow::bail;
 
@@ -8,26 +8,35 @@ pub enum Layer {
 	App,
 	Manager,
 	Tasks,
+	Mounts,
+	Sidebar,
+	Notify,
 	Select,
+	Chmod,
+	Spot,
 	Input,
 	Help,
 	Completion,
 	Which,
 }
 
-impl ToString for Layer {
-	fn to_string(&self) -> String {
-		match self {
+impl Display for Layer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
 			Self::App => "app",
 			Self::Manager => "manager",
 			Self::Tasks => "tasks",
+			Self::Mounts => "mounts",
+			Self::Sidebar => "sidebar",
+			Self::Notify => "notify",
 			Self::Select => "select",
+			Self::Chmod => "chmod",
+			Self::Spot => "spot",
 			Self::Input => "input",
 			Self::Help => "help",
 			Self::Completion => "completion",
 			Self::Which => "which",
-		}
-		.to_string()
+		})
 	}
 }
 
@@ -39,7 +48,12 @@ impl FromStr for Layer {
 			"app" => Self::App,
 			"manager" => Self::Manager,
 			"tasks" => Self::Tasks,
+			"mounts" => Self::Mounts,
+			"sidebar" => Self::Sidebar,
+			"notify" => Self::Notify,
 			"select" => Self::Select,
+			"chmod" => Self::Chmod,
+			"spot" => Self::Spot,
 			"input" => Self::Input,
 			"help" => Self::Help,
 			"completion" => Self::Completion,
@@ -4,6 +4,7 @@ mod chars;
 mod condition;
 mod debounce;
 mod defer;
+pub mod diff;
 mod env;
 mod errors;
 pub mod event;
@@ -1,6 +1,6 @@
 #[cfg(unix)]
 pub fn hostname() -> Result<String, std::io::Error> {
-	use std::io::{Error, ErrorKind};
+	use std::io::Error;
 
 	use libc::{gethostname, strlen};
 
@@ -14,6 +14,116 @@ pub fn hostname() -> Result<String, std::io::Error> {
 	};
 
 	std::str::from_utf8(&s[..len])
-		.map_err(|_| Error::new(ErrorKind::Other, "invalid hostname"))
+		.map_err(|_| Error::other("invalid hostname"))
 		.map(|s| s.to_owned())
 }
+
+// Free and total space in bytes of the filesystem containing the given path
+#[cfg(unix)]
+#[allow(clippy::unnecessary_cast)]
+pub fn disk_space(path: &std::path::Path) -> Result<(u64, u64), std::io::Error> {
+	use std::{ffi::CString, mem::MaybeUninit};
+
+	let path = CString::new(path.as_os_str().as_encoded_bytes())?;
+	let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+	if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } == -1 {
+		return Err(std::io::Error::last_os_error());
+	}
+
+	let stat = unsafe { stat.assume_init() };
+	let frsize = stat.f_frsize as u64;
+	Ok((stat.f_bavail as u64 * frsize, stat.f_blocks as u64 * frsize))
+}
+
+// Whether the given path is on a network filesystem, where native watching isn't reliable
+#[cfg(target_os = "linux")]
+pub fn is_remote_fs(path: &std::path::Path) -> bool {
+	use std::{ffi::CString, mem::MaybeUninit};
+
+	let Ok(path) = CString::new(path.as_os_str().as_encoded_bytes()) else {
+		return false;
+	};
+
+	let mut stat = MaybeUninit::<libc::statfs>::uninit();
+	if unsafe { libc::statfs(path.as_ptr(), stat.as_mut_ptr()) } == -1 {
+		return false;
+	}
+
+	// NFS, SMB, CIFS, SMB2, FUSE (sshfs, rclone, etc.), 9P, AFS, Coda, NCP
+	const REMOTE: [u32; 9] = [
+		0x6969, 0x517b, 0xff534d42, 0xfe534d42, 0x65735546, 0x01021997, 0x5346414f, 0x73757245, 0x564c,
+	];
+	REMOTE.contains(&(unsafe { stat.assume_init() }.f_type as u32))
+}
+
+#[cfg(target_os = "macos")]
+pub fn is_remote_fs(path: &std::path::Path) -> bool {
+	use std::{ffi::{CStr, CString}, mem::MaybeUninit};
+
+	let Ok(path) = CString::new(path.as_os_str().as_encoded_bytes()) else {
+		return false;
+	};
+
+	let mut stat = MaybeUninit::<libc::statfs>::uninit();
+	if unsafe { libc::statfs(path.as_ptr(), stat.as_mut_ptr()) } == -1 {
+		return false;
+	}
+
+	let stat = unsafe { stat.assume_init() };
+	let name = unsafe { CStr::from_ptr(stat.f_fstypename.as_ptr()) }.to_bytes();
+	matches!(name, b"nfs" | b"smbfs" | b"afpfs" | b"webdav" | b"macfuse" | b"osxfuse")
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn is_remote_fs(_: &std::path::Path) -> bool { false }
+
+/// The directory of the sockets of the running instances, a `<pid>.sock` for each,
+/// `$XDG_RUNTIME_DIR/yazi`, or one of the user's own in the temporary directory.
+#[cfg(unix)]
+pub fn instances_dir() -> std::path::PathBuf {
+	use std::{env, path::PathBuf};
+
+	match env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from).filter(|p| p.is_absolute()) {
+		Some(p) => p.join("yazi"),
+		None => env::temp_dir().join(format!("yazi-{}", unsafe { libc::getuid() })),
+	}
+}
+
+/// The named pipe of the instance of the process ID, what its `<pid>.sock` is on Unix.
+#[cfg(windows)]
+pub fn instance_pipe(pid: u32) -> String { format!(r"\\.\pipe\yazi-{pid}") }
+
+/// Whether the directory is a real one of the user's own, closed to everyone else, for no one
+/// else to be able to plant a socket in it, or to connect to those of the instances there.
+#[cfg(unix)]
+pub fn is_private_dir(path: &std::path::Path) -> bool {
+	use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+	std::fs::symlink_metadata(path).is_ok_and(|m| {
+		m.is_dir() && m.uid() == unsafe { libc::getuid() } && m.permissions().mode() & 0o077 == 0
+	})
+}
+
+/// Whether the process is still running, i.e. a file it left, such as its journal or socket,
+/// is of a live instance rather than one gone without cleaning up.
+#[cfg(unix)]
+pub fn pid_alive(pid: u32) -> bool {
+	// Signal 0 only checks it exists, which it does too if it's another user's
+	let ok = unsafe { libc::kill(pid as libc::pid_t, 0) } == 0;
+	ok || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(windows)]
+pub fn pid_alive(pid: u32) -> bool {
+	use windows_sys::Win32::{Foundation::{CloseHandle, STILL_ACTIVE}, System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION}};
+
+	let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+	if handle == 0 {
+		return false;
+	}
+
+	let mut code = 0;
+	let ok = unsafe { GetExitCodeProcess(handle, &mut code) } != 0;
+	unsafe { CloseHandle(handle) };
+	ok && code == STILL_ACTIVE as u32
+}
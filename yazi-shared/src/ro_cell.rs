@@ -34,6 +34,10 @@ impl<T> RoCell<T> {
 	}
 }
 
+impl<T> Default for RoCell<T> {
+	fn default() -> Self { Self::new() }
+}
+
 impl<T> Deref for RoCell<T> {
 	type Target = T;
 
@@ -0,0 +1,242 @@
+use std::{env, process::Command, sync::atomic::{AtomicU8, Ordering}, thread};
+
+use ratatui::{buffer::{Buffer, Cell}, style::{Color, Modifier}};
+
+use crate::emit;
+
+static COLORS: AtomicU8 = AtomicU8::new(Colors::TrueColor as u8);
+
+// The default palette of xterm, to find the nearest of the 16 colors to the others
+const ANSI16: [(u8, u8, u8); 16] = [
+	(0, 0, 0),
+	(205, 0, 0),
+	(0, 205, 0),
+	(205, 205, 0),
+	(0, 0, 238),
+	(205, 0, 205),
+	(0, 205, 205),
+	(229, 229, 229),
+	(127, 127, 127),
+	(255, 0, 0),
+	(0, 255, 0),
+	(255, 255, 0),
+	(92, 92, 255),
+	(255, 0, 255),
+	(0, 255, 255),
+	(255, 255, 255),
+];
+
+// The levels of each channel in the 6x6x6 color cube of the 256 colors
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The colors the terminal supports, which those of the theme are degraded to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Colors {
+	None,
+	Ansi16,
+	Ansi256,
+	TrueColor,
+}
+
+impl Colors {
+	#[inline]
+	pub fn get() -> Self {
+		match COLORS.load(Ordering::Relaxed) {
+			0 => Self::None,
+			1 => Self::Ansi16,
+			2 => Self::Ansi256,
+			_ => Self::TrueColor,
+		}
+	}
+
+	/// Uses the colors configured, or those detected by `NO_COLOR`, `COLORTERM` and `TERM`,
+	/// and by the terminfo of `TERM` through `tput` if they don't tell, which is asked in
+	/// the background for the first draw not to wait for it, redrawing once it answers.
+	pub fn init(configured: Option<Self>) {
+		let (colors, known) = configured.map_or_else(Self::detect, |c| (c, true));
+		COLORS.store(colors as u8, Ordering::Relaxed);
+		if known {
+			return;
+		}
+
+		let term = env::var("TERM").unwrap_or_default();
+		thread::spawn(move || {
+			let tput = Command::new("tput").arg("colors").output().ok().filter(|o| o.status.success());
+			let n = tput.and_then(|o| String::from_utf8_lossy(&o.stdout).trim().parse::<u32>().ok());
+			let detected = Self::by_terminfo(n, &term);
+			if detected != colors {
+				COLORS.store(detected as u8, Ordering::Relaxed);
+				emit!(Render);
+			}
+		});
+	}
+
+	// The colors told by the environment, and whether it's for sure, or a guess by `TERM`
+	// to be told by the terminfo
+	fn detect() -> (Self, bool) {
+		if env::var_os("NO_COLOR").is_some_and(|s| !s.is_empty()) {
+			return (Self::None, true);
+		}
+		if env::var("COLORTERM").is_ok_and(|s| s == "truecolor" || s == "24bit") {
+			return (Self::TrueColor, true);
+		}
+
+		let term = env::var("TERM").unwrap_or_default();
+		if term == "dumb" {
+			(Self::None, true)
+		} else if term.ends_with("-direct") || term.contains("truecolor") {
+			(Self::TrueColor, true)
+		} else {
+			(Self::by_terminfo(None, &term), false)
+		}
+	}
+
+	// By the number of colors of the terminfo, if `tput` tells it, assuming truecolor if nothing does
+	fn by_terminfo(n: Option<u32>, term: &str) -> Self {
+		match n {
+			Some(n) if n >= 1 << 24 => Self::TrueColor,
+			Some(n) if n >= 256 => Self::Ansi256,
+			Some(n) if n >= 8 => Self::Ansi16,
+			Some(_) => Self::None,
+			None if term.contains("256color") => Self::Ansi256,
+			None if term == "linux" || term.starts_with("vt") => Self::Ansi16,
+			None => Self::TrueColor,
+		}
+	}
+
+	/// Degrades the colors of the buffer to those supported.
+	#[inline]
+	pub fn degrade(self, buf: &mut Buffer) {
+		if self != Self::TrueColor {
+			buf.content.iter_mut().for_each(|c| self.degrade_cell(c));
+		}
+	}
+
+	pub fn degrade_cell(self, cell: &mut Cell) {
+		if self != Self::None {
+			cell.fg = self.degrade_color(cell.fg);
+			cell.bg = self.degrade_color(cell.bg);
+			cell.underline_color = self.degrade_color(cell.underline_color);
+			return;
+		}
+
+		// Without colors, a background is what sets something apart, such as the hovered file
+		if cell.bg != Color::Reset {
+			cell.modifier.insert(Modifier::REVERSED);
+		}
+		(cell.fg, cell.bg, cell.underline_color) = (Color::Reset, Color::Reset, Color::Reset);
+	}
+
+	fn degrade_color(self, color: Color) -> Color {
+		match (self, color) {
+			(Self::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(Self::nearest256(r, g, b)),
+			(Self::Ansi16, Color::Rgb(r, g, b)) => Self::named(Self::nearest16(r, g, b)),
+			(Self::Ansi16, Color::Indexed(i)) if i >= 16 => {
+				let (r, g, b) = Self::rgb256(i);
+				Self::named(Self::nearest16(r, g, b))
+			}
+			(Self::Ansi16, Color::Indexed(i)) => Self::named(i),
+			_ => color,
+		}
+	}
+
+	#[inline]
+	fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+		let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+		2 * d(r1, r2) + 4 * d(g1, g2) + 3 * d(b1, b2)
+	}
+
+	fn nearest16(r: u8, g: u8, b: u8) -> u8 {
+		(0..16).min_by_key(|&i| Self::distance((r, g, b), ANSI16[i as usize])).unwrap_or(0)
+	}
+
+	/// The nearest of the color cube and the grayscale ramp of the 256 colors.
+	fn nearest256(r: u8, g: u8, b: u8) -> u8 {
+		let level = |c: u8| (0..6).min_by_key(|&i| (CUBE[i] as i32 - c as i32).abs()).unwrap_or(0);
+		let (cr, cg, cb) = (level(r), level(g), level(b));
+		let cube = (CUBE[cr], CUBE[cg], CUBE[cb]);
+
+		let avg = (r as u32 + g as u32 + b as u32) / 3;
+		let gray = ((avg.saturating_sub(8) + 5) / 10).min(23) as u8;
+		let level = 8 + 10 * gray;
+
+		if Self::distance((r, g, b), (level, level, level)) < Self::distance((r, g, b), cube) {
+			232 + gray
+		} else {
+			16 + 36 * cr as u8 + 6 * cg as u8 + cb as u8
+		}
+	}
+
+	fn rgb256(i: u8) -> (u8, u8, u8) {
+		match i {
+			0..=15 => ANSI16[i as usize],
+			16..=231 => {
+				let i = i - 16;
+				(CUBE[(i / 36) as usize], CUBE[(i / 6 % 6) as usize], CUBE[(i % 6) as usize])
+			}
+			_ => {
+				let level = 8 + 10 * (i - 232);
+				(level, level, level)
+			}
+		}
+	}
+
+	fn named(i: u8) -> Color {
+		const NAMED: [Color; 16] = [
+			Color::Black,
+			Color::Red,
+			Color::Green,
+			Color::Yellow,
+			Color::Blue,
+			Color::Magenta,
+			Color::Cyan,
+			Color::Gray,
+			Color::DarkGray,
+			Color::LightRed,
+			Color::LightGreen,
+			Color::LightYellow,
+			Color::LightBlue,
+			Color::LightMagenta,
+			Color::LightCyan,
+			Color::White,
+		];
+		NAMED[i as usize % 16]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_degrade() {
+		assert_eq!(Colors::nearest256(0, 0, 0), 16);
+		assert_eq!(Colors::nearest256(255, 0, 0), 196);
+		assert_eq!(Colors::nearest256(128, 128, 128), 244);
+		assert_eq!(Colors::nearest16(250, 10, 10), 9);
+		assert_eq!(Colors::nearest16(30, 30, 30), 0);
+
+		for i in 16..=255 {
+			let (r, g, b) = Colors::rgb256(i);
+			assert_eq!(Colors::rgb256(Colors::nearest256(r, g, b)), (r, g, b));
+		}
+
+		assert_eq!(Colors::Ansi16.degrade_color(Color::Indexed(196)), Color::LightRed);
+		assert_eq!(Colors::Ansi16.degrade_color(Color::Indexed(4)), Color::Blue);
+		assert_eq!(Colors::Ansi256.degrade_color(Color::Cyan), Color::Cyan);
+	}
+
+	#[test]
+	fn test_by_terminfo() {
+		assert_eq!(Colors::by_terminfo(Some(1 << 24), "xterm-256color"), Colors::TrueColor);
+		assert_eq!(Colors::by_terminfo(Some(256), "xterm-256color"), Colors::Ansi256);
+		assert_eq!(Colors::by_terminfo(Some(8), "linux"), Colors::Ansi16);
+		assert_eq!(Colors::by_terminfo(Some(2), "vt100"), Colors::None);
+
+		// Without `tput`, it's guessed by the name
+		assert_eq!(Colors::by_terminfo(None, "xterm-256color"), Colors::Ansi256);
+		assert_eq!(Colors::by_terminfo(None, "linux"), Colors::Ansi16);
+		assert_eq!(Colors::by_terminfo(None, "xterm-kitty"), Colors::TrueColor);
+	}
+}
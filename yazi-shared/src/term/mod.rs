@@ -1,6 +1,9 @@
 #![allow(clippy::module_inception)]
 
+mod colors;
 mod cursor;
+mod progress;
 mod term;
 
+pub use colors::*;
 pub use term::*;
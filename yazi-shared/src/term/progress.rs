@@ -0,0 +1,37 @@
+use std::io::{stdout, Write};
+
+use parking_lot::Mutex;
+
+use super::Term;
+
+// The last progress reported, as the percentage and whether any task has failed
+static REPORTED: Mutex<Option<(u8, bool)>> = Mutex::new(None);
+
+impl Term {
+	/// Reports the progress of the tasks in the terminal title, and through
+	/// `OSC 9;4`, which the terminals supporting it show on the taskbar.
+	pub fn report_progress(progress: Option<(u8, bool)>) {
+		let mut reported = REPORTED.lock();
+		if *reported == progress {
+			return;
+		}
+
+		let mut stdout = stdout().lock();
+		match progress {
+			Some((percent, error)) => {
+				if reported.is_none() {
+					// Save the title, to restore it once all tasks are done
+					write!(stdout, "\x1b[22;0t").ok();
+				}
+				write!(stdout, "\x1b]9;4;{};{percent}\x1b\\", if error { 2 } else { 1 }).ok();
+				write!(stdout, "\x1b]0;Yazi ({percent}%)\x1b\\").ok();
+			}
+			None => {
+				write!(stdout, "\x1b]9;4;0;0\x1b\\\x1b[23;0t").ok();
+			}
+		}
+
+		stdout.flush().ok();
+		*reported = progress;
+	}
+}
@@ -1,10 +1,13 @@
 use std::{io::{self, stdout, Stdout, Write}, mem, ops::{Deref, DerefMut}, sync::atomic::{AtomicBool, Ordering}};
 
 use anyhow::Result;
-use crossterm::{event::{DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, WindowSize}};
+use crossterm::{event::{DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste, EnableFocusChange, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags}, execute, queue, terminal::{disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, WindowSize}};
 use ratatui::{backend::CrosstermBackend, buffer::Buffer, layout::Rect, CompletedFrame, Frame, Terminal};
 
+use super::Colors;
+
 static CSI_U: AtomicBool = AtomicBool::new(false);
+static FOCUSED: AtomicBool = AtomicBool::new(true);
 
 pub struct Term {
 	inner:       Terminal<CrosstermBackend<Stdout>>,
@@ -13,7 +16,7 @@ pub struct Term {
 }
 
 impl Term {
-	pub fn start() -> Result<Self> {
+	pub fn start(mouse: bool) -> Result<Self> {
 		let mut term = Self {
 			inner:       Terminal::new(CrosstermBackend::new(stdout()))?,
 			last_area:   Default::default(),
@@ -22,6 +25,9 @@ impl Term {
 
 		enable_raw_mode()?;
 		queue!(stdout(), EnterAlternateScreen, EnableBracketedPaste, EnableFocusChange)?;
+		if mouse {
+			queue!(stdout(), EnableMouseCapture)?;
+		}
 
 		if let Ok(true) = supports_keyboard_enhancement() {
 			queue!(
@@ -41,12 +47,14 @@ impl Term {
 	}
 
 	fn stop(&mut self) -> Result<()> {
+		Self::report_progress(None);
 		if CSI_U.swap(false, Ordering::Relaxed) {
 			execute!(stdout(), PopKeyboardEnhancementFlags)?;
 		}
 
 		execute!(
 			stdout(),
+			DisableMouseCapture,
 			DisableFocusChange,
 			DisableBracketedPaste,
 			LeaveAlternateScreen,
@@ -58,12 +66,14 @@ impl Term {
 	}
 
 	pub fn goodbye(f: impl FnOnce() -> bool) -> ! {
+		Self::report_progress(None);
 		if CSI_U.swap(false, Ordering::Relaxed) {
 			execute!(stdout(), PopKeyboardEnhancementFlags).ok();
 		}
 
 		execute!(
 			stdout(),
+			DisableMouseCapture,
 			DisableFocusChange,
 			DisableBracketedPaste,
 			LeaveAlternateScreen,
@@ -77,15 +87,18 @@ impl Term {
 		std::process::exit(f() as i32);
 	}
 
-	pub fn draw(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame> {
-		let last = self.inner.draw(f)?;
+	pub fn draw(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame<'_>> {
+		let last = self.inner.draw(|frame| {
+			f(frame);
+			Colors::get().degrade(frame.buffer_mut());
+		})?;
 
 		self.last_area = last.area;
 		self.last_buffer = last.buffer.clone();
 		Ok(last)
 	}
 
-	pub fn draw_partial(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame> {
+	pub fn draw_partial(&mut self, f: impl FnOnce(&mut Frame)) -> io::Result<CompletedFrame<'_>> {
 		self.inner.draw(|frame| {
 			let buffer = frame.buffer_mut();
 			for y in self.last_area.top()..self.last_area.bottom() {
@@ -97,6 +110,7 @@ impl Term {
 			}
 
 			f(frame);
+			Colors::get().degrade(frame.buffer_mut());
 		})
 	}
 
@@ -131,12 +145,26 @@ impl Term {
 		Some((f64::from(s.width) / f64::from(s.columns), f64::from(s.height) / f64::from(s.rows)))
 	}
 
+	/// Whether the terminal has the focus, as reported by the focus events,
+	/// assumed to be the case for terminals that don't report them.
+	#[inline]
+	pub fn focused() -> bool { FOCUSED.load(Ordering::Relaxed) }
+
+	#[inline]
+	pub fn set_focused(state: bool) { FOCUSED.store(state, Ordering::Relaxed) }
+
 	#[inline]
 	pub fn clear(stdout: &mut impl Write) -> Result<()> {
 		queue!(stdout, Clear(ClearType::All))?;
 		writeln!(stdout)?;
 		Ok(stdout.flush()?)
 	}
+
+	#[inline]
+	pub fn bell() {
+		let mut stdout = stdout().lock();
+		stdout.write_all(b"\x07").and_then(|_| stdout.flush()).ok();
+	}
 }
 
 impl Drop for Term {